@@ -1,5 +1,7 @@
 use bitfield_struct::bitfield;
 
+use crate::elements::{ht::HTProtectionMode, ERPInformationElement};
+
 #[bitfield(u16, defmt = cfg(feature = "defmt"))]
 #[derive(PartialEq, Eq, Hash)]
 /// This bitfield contains the capabilities of the sender.
@@ -21,3 +23,80 @@ pub struct CapabilitiesInformation {
     #[bits(2)]
     __: u8,
 }
+impl CapabilitiesInformation {
+    /// Returns the protection mode of the BSS, derived from the [ERPInformationElement] and HT protection mode.
+    ///
+    /// If neither is present, protection isn't required.
+    pub const fn protection_mode(
+        erp_information: Option<ERPInformationElement>,
+        ht_protection_mode: Option<HTProtectionMode>,
+    ) -> ProtectionMode {
+        let (non_erp_present, use_protection) = match erp_information {
+            Some(erp_information) => (
+                erp_information.non_erp_present(),
+                erp_information.use_protection(),
+            ),
+            None => (false, false),
+        };
+        match ht_protection_mode {
+            _ if use_protection => ProtectionMode::Required,
+            // IEEE 802.11-2020 10.26.3 requires protection in all HT protection modes except no protection mode.
+            Some(
+                HTProtectionMode::NonMember
+                | HTProtectionMode::TwentyMHz
+                | HTProtectionMode::NonHTMixed,
+            ) => ProtectionMode::Required,
+            _ if non_erp_present => ProtectionMode::Capable,
+            _ => ProtectionMode::None,
+        }
+    }
+    /// Check if protection is required, for transmissions in the BSS.
+    pub const fn requires_protection(
+        erp_information: Option<ERPInformationElement>,
+        ht_protection_mode: Option<HTProtectionMode>,
+    ) -> bool {
+        matches!(
+            Self::protection_mode(erp_information, ht_protection_mode),
+            ProtectionMode::Required
+        )
+    }
+    /// Check if the short preamble can be used.
+    ///
+    /// This is the case, if it's allowed by the capabilities and the Barker Preamble Mode bit of the [ERPInformationElement] isn't set.
+    pub const fn uses_short_preamble(
+        &self,
+        erp_information: Option<ERPInformationElement>,
+    ) -> bool {
+        self.is_short_preamble_allowed()
+            && match erp_information {
+                Some(erp_information) => !erp_information.barker_preamble_mode(),
+                None => true,
+            }
+    }
+    /// Check if the short slot time can be used.
+    ///
+    /// The short slot time can't be used, if non-ERP STAs are present.
+    pub const fn uses_short_slot_time(
+        &self,
+        erp_information: Option<ERPInformationElement>,
+    ) -> bool {
+        self.is_short_time_slot_in_use()
+            && match erp_information {
+                Some(erp_information) => !erp_information.non_erp_present(),
+                None => true,
+            }
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The protection mode of a BSS.
+pub enum ProtectionMode {
+    #[default]
+    /// No protection is required.
+    None,
+    /// STAs, which would require protection, are present, but protection isn't required for all transmissions.
+    Capable,
+    /// Protection is required.
+    Required,
+}
@@ -1,4 +1,4 @@
-use core::{mem::discriminant, time::Duration};
+use core::{fmt::Display, mem::discriminant, time::Duration};
 
 use bitfield_struct::bitfield;
 use macro_bits::bit;
@@ -29,8 +29,39 @@ pub use sig::*;
 /// This is one **T**ime **U**nit, which equalls 1024µs.
 pub const TU: Duration = Duration::from_micros(1024);
 
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A beacon interval in [TU]s.
+///
+/// This exists to prevent confusing the interval, which is transmitted in [TU]s, with milliseconds.
+pub struct BeaconInterval(pub u16);
+impl BeaconInterval {
+    /// Returns the raw beacon interval in [TU]s.
+    pub const fn tu(&self) -> u16 {
+        self.0
+    }
+    /// Returns the beacon interval as a [Duration].
+    pub const fn as_duration(&self) -> Duration {
+        Duration::from_micros(self.0 as u64 * TU.as_micros() as u64)
+    }
+}
+impl From<u16> for BeaconInterval {
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl From<BeaconInterval> for Duration {
+    fn from(value: BeaconInterval) -> Self {
+        value.as_duration()
+    }
+}
+
 pub const IEEE_OUI: [u8; 3] = [0x00, 0x0f, 0xac];
 pub const WIFI_ALLIANCE_OUI: [u8; 3] = [0x50, 0x6f, 0x9a];
+pub const MICROSOFT_OUI: [u8; 3] = [0x00, 0x50, 0xf2];
+pub const APPLE_OUI: [u8; 3] = [0x00, 0x17, 0xf2];
+pub const CISCO_OUI: [u8; 3] = [0x00, 0x40, 0x96];
+pub const ARUBA_OUI: [u8; 3] = [0x00, 0x0b, 0x86];
 
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -83,6 +114,16 @@ impl FrameType {
         matches!(self, Self::Data(_) | Self::Management(_))
     }
 }
+impl Display for FrameType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Management(subtype) => write!(f, "Management/{subtype}"),
+            Self::Control(subtype) => write!(f, "Control/{subtype}"),
+            Self::Data(subtype) => write!(f, "Data/{subtype}"),
+            Self::Unknown(subtype) => write!(f, "Unknown({subtype})"),
+        }
+    }
+}
 impl From<u16> for FrameType {
     fn from(value: u16) -> Self {
         Self::from_bits(value as u8)
@@ -135,6 +176,38 @@ pub struct SequenceControl {
     #[bits(12)]
     pub sequence_number: u16,
 }
+impl SequenceControl {
+    /// The highest possible sequence number, after which it wraps around to zero.
+    pub const MAX_SEQUENCE_NUMBER: u16 = 4095;
+    /// The highest possible fragment number.
+    pub const MAX_FRAGMENT_NUMBER: u8 = 15;
+
+    /// Returns the sequence control for the next MSDU or MMPDU.
+    ///
+    /// The sequence number is incremented, wrapping around to zero after [Self::MAX_SEQUENCE_NUMBER], and the fragment number is reset to zero.
+    pub const fn next(self) -> Self {
+        Self::new().with_sequence_number(if self.sequence_number() == Self::MAX_SEQUENCE_NUMBER {
+            0
+        } else {
+            self.sequence_number() + 1
+        })
+    }
+    /// Returns the sequence control for the next fragment of the same MSDU or MMPDU.
+    ///
+    /// The sequence number stays the same and the fragment number is incremented.
+    /// This returns [None], if the fragment number is already [Self::MAX_FRAGMENT_NUMBER], since an MSDU or MMPDU can't have more than 16 fragments.
+    pub const fn with_next_fragment(self) -> Option<Self> {
+        if self.fragment_number() == Self::MAX_FRAGMENT_NUMBER {
+            None
+        } else {
+            Some(self.with_fragment_number(self.fragment_number() + 1))
+        }
+    }
+    /// Check if this is the first fragment, which is also the case for unfragmented frames.
+    pub const fn is_first_fragment(&self) -> bool {
+        self.fragment_number() == 0
+    }
+}
 
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
@@ -158,9 +231,43 @@ impl TryIntoCtx for Empty {
     }
 }
 
-pub(crate) fn strip_and_validate_fcs(bytes: &[u8]) -> Result<&[u8], scroll::Error> {
+#[derive(Clone, Debug, Default)]
+/// An incremental accumulator for the **F**rame **C**heck **S**equence.
+///
+/// This is useful, if the frame isn't available as one contiguous slice, for example when it's assembled from a header and multiple chunks of elements.
+/// Feeding all chunks in order yields the same result, as calculating the FCS over their concatenation.
+pub struct Fcs {
+    hasher: crc32fast::Hasher,
+}
+impl Fcs {
+    /// Create a new FCS accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Feed the bytes into the accumulator.
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.hasher.update(bytes);
+    }
+    /// Finalize the accumulator and return the FCS.
+    pub fn finalize(self) -> u32 {
+        self.hasher.finalize()
+    }
+}
+
+/// Split the FCS off the end of the bytes, without validating it.
+pub(crate) fn strip_fcs(bytes: &[u8]) -> Result<(&[u8], u32), scroll::Error> {
+    if bytes.len() < 4 {
+        return Err(scroll::Error::TooBig {
+            size: 4,
+            len: bytes.len(),
+        });
+    }
     let (slice_without_fcs, fcs) = bytes.split_at(bytes.len() - 4);
-    if fcs.pread_with::<u32>(0, Endian::Little)? == crc32fast::hash(slice_without_fcs) {
+    Ok((slice_without_fcs, fcs.pread_with(0, Endian::Little)?))
+}
+pub(crate) fn strip_and_validate_fcs(bytes: &[u8]) -> Result<&[u8], scroll::Error> {
+    let (slice_without_fcs, fcs) = strip_fcs(bytes)?;
+    if fcs == crc32fast::hash(slice_without_fcs) {
         Ok(slice_without_fcs)
     } else {
         Err(scroll::Error::BadInput {
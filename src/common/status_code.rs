@@ -1,3 +1,5 @@
+use core::fmt::Display;
+
 use macro_bits::serializable_enum;
 
 serializable_enum! {
@@ -120,3 +122,160 @@ serializable_enum! {
         TclasProcessingTerminatedPolicyConflict => 129
     }
 }
+impl IEEE80211StatusCode {
+    /// Check if the status code indicates success.
+    ///
+    /// Apart from [Self::Success], this is also the case for the status codes, which indicate success with additional information, like [Self::SaeHashToElement].
+    pub const fn is_success(&self) -> bool {
+        matches!(
+            self,
+            Self::Success
+                | Self::SuccessPowerSaveMode
+                | Self::SuccessCagVersionsMatch
+                | Self::SaeHashToElement
+        )
+    }
+    /// Check if the status code indicates a temporary rejection.
+    ///
+    /// The same request may succeed, if it's retried later or, in the case of [Self::AntiCloggingTokenRequired], with the requested token.
+    pub const fn is_temporary(&self) -> bool {
+        matches!(
+            self,
+            Self::DeniedNoMoreStas
+                | Self::RefusedTemporarily
+                | Self::DeniedInsufficientBandwidth
+                | Self::DeniedPoorChannelConditions
+                | Self::RejectedForDelayPeriod
+                | Self::AntiCloggingTokenRequired
+                | Self::PendingAdmittingFstSession
+                | Self::PendingGapInBaWindow
+                | Self::RefusedApOutOfMemory
+                | Self::QueryResponseOutstanding
+        )
+    }
+    /// Check if the status code indicates a permanent rejection.
+    ///
+    /// This is the case, if it neither indicates success nor a temporary rejection.
+    pub const fn is_permanent_rejection(&self) -> bool {
+        !self.is_success() && !self.is_temporary()
+    }
+}
+impl Display for IEEE80211StatusCode {
+    /// Writes the meaning of the status code, as specified in Table 9-80 of IEEE 802.11-2020.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Success => "Successful",
+            Self::RefusedReasonUnspec => "Unspecified failure",
+            Self::TdlsRejectedAlternativeProvided => "TDLS wakeup schedule rejected but alternative schedule provided",
+            Self::TdlsRejected => "TDLS wakeup schedule rejected",
+            Self::SecurityDisabled => "Security disabled",
+            Self::UnacceptableLifetime => "Unacceptable lifetime",
+            Self::NotInSameBss => "Not in same BSS",
+            Self::RefusedCapabilitiesMismatch => "Cannot support all requested capabilities in the Capability Information field",
+            Self::DeniedNoAssociationExists => "Reassociation denied due to inability to confirm that association exists",
+            Self::DeniedOtherReason => "Association denied due to reason outside the scope of this standard",
+            Self::UnsupportedAuthAlgorithm => "Responding STA does not support the specified authentication algorithm",
+            Self::TransactionSequenceError => "Received an authentication frame with authentication transaction sequence number out of expected sequence",
+            Self::ChallengeFailure => "Authentication rejected because of challenge failure",
+            Self::RejectedSequenceTimeout => "Authentication rejected due to timeout waiting for next frame in sequence",
+            Self::DeniedNoMoreStas => "Association denied because AP is unable to handle additional associated STAs",
+            Self::RefusedBasicRatesMismatch => "Association denied due to requesting STA not supporting all of the data rates in the BSSBasicRateSet parameter",
+            Self::DeniedNoShortPreambleSupport => "Association denied due to requesting STA not supporting the short preamble option",
+            Self::RejectedSpectrumManagementRequired => "Association request rejected because Spectrum Management capability is required",
+            Self::RejectedBadPowerCapability => "Association request rejected because the information in the Power Capability element is unacceptable",
+            Self::RejectedBadSupportedChannels => "Association request rejected because the information in the Supported Channels element is unacceptable",
+            Self::DeniedNoShortSlotTimeSupport => "Association denied due to requesting STA not supporting the Short Slot Time option",
+            Self::DeniedNoHtSupport => "Association denied because the requesting STA does not support HT features",
+            Self::R0khUnreachable => "R0KH unreachable",
+            Self::RefusedTemporarily => "Association request rejected temporarily; try again later",
+            Self::RobustManagementPolicyViolation => "Robust management frame policy violation",
+            Self::UnspecifiedQosFailure => "Unspecified, QoS-related failure",
+            Self::DeniedInsufficientBandwidth => "Association denied because QoS AP or PCP has insufficient bandwidth to handle another QoS STA",
+            Self::DeniedPoorChannelConditions => "Association denied due to excessive frame loss rates and/or poor conditions on current operating channel",
+            Self::DeniedQosNotSupported => "Association (with QoS BSS) denied because the requesting STA does not support the QoS facility",
+            Self::RequestDeclined => "The request has been declined",
+            Self::InvalidParameters => "The request has not been successful as one or more parameters have invalid values",
+            Self::RejectedWithSuggestedChanges => "The allocation or TS has not been created because the request cannot be honored; however, a suggested TSPEC/DMG TSPEC is provided",
+            Self::StatusInvalidElement => "Invalid element, i.e., an element defined in this standard for which the content does not meet the specifications",
+            Self::StatusInvalidGroupCipher => "Invalid group cipher",
+            Self::StatusInvalidPairwiseCipher => "Invalid pairwise cipher",
+            Self::StatusInvalidAkmp => "Invalid AKMP",
+            Self::UnsupportedRsneVersion => "Unsupported RSNE version",
+            Self::InvalidRsneCapabilities => "Invalid RSNE capabilities",
+            Self::StatusCipherOutOfPolicy => "Cipher suite rejected because of security policy",
+            Self::RejectedForDelayPeriod => "The TS or allocation has not been created; however, the HC or PCP might be able to create a new TS or allocation in response to a request after the time indicated in the TS Delay element",
+            Self::NotPresent => "The destination STA is not present within this BSS",
+            Self::NotQosSta => "The destination STA is not a QoS STA",
+            Self::DeniedListenIntervalTooLarge => "Association denied because the listen interval is too large",
+            Self::StatusInvalidFtActionFrameCount => "Invalid FT Action frame count",
+            Self::StatusInvalidPmkid => "Invalid pairwise master key identifier (PMKID)",
+            Self::StatusInvalidMde => "Invalid MDE",
+            Self::StatusInvalidFte => "Invalid FTE",
+            Self::RequestedTclasNotSupported => "Requested TCLAS processing is not supported by the AP or PCP",
+            Self::InsufficientTclasProcessingResources => "The AP or PCP has insufficient TCLAS processing resources to satisfy the request",
+            Self::TryAnotherBss => "The TS has not been created because the request cannot be honored; however, the HC or PCP suggests that the STA transition to a different BSS to set up the TS",
+            Self::GasAdvertisementProtocolNotSupported => "GAS Advertisement Protocol not supported",
+            Self::NoOutstandingGasRequest => "No outstanding GAS request",
+            Self::GasResponseNotReceivedFrom => "GAS Response not received from the Advertisement Server",
+            Self::GasQueryTimeout => "STA timed out waiting for GAS Query Response",
+            Self::GasQueryResponseToo => "GAS Response is larger than query response length limit",
+            Self::RejectedHomeWithSuggestedChanges => "Request refused because home network does not support request",
+            Self::ServerUnreachable => "Advertisement Server in the network is not currently reachable",
+            Self::RejectedForSspPermissions => "Request refused due to permissions received via SSPN interface",
+            Self::RefusedUnauthenticatedAccessNotSupported => "Request refused because the AP or PCP does not support unauthenticated access",
+            Self::InvalidRsne => "Invalid contents of RSNE",
+            Self::UApsdCoexistenceNotSupported => "U-APSD coexistence is not supported",
+            Self::UApsdCoexModeNotSupported => "Requested U-APSD coexistence mode is not supported",
+            Self::BadIntervalWithUApsdCoex => "Requested interval/duration value cannot be supported with U-APSD coexistence",
+            Self::AntiCloggingTokenRequired => "Authentication is rejected because an anti-clogging token is required",
+            Self::UnsupportedFiniteCyclicGroup => "Authentication is rejected because the offered finite cyclic group is not supported",
+            Self::CannotFindAlternativeTbtt => "The TBTT adjustment request has not been successful because the STA could not find an alternative TBTT",
+            Self::TransmissionFailure => "Transmission failure",
+            Self::RequestedTclasNotSupported2 => "Requested TCLAS not supported",
+            Self::TclasResourcesExhausted => "TCLAS resources exhausted",
+            Self::RejectedWithSuggestedBssTransition => "Rejected with suggested BSS transition",
+            Self::RejectWithSchedule => "Reject with recommended schedule",
+            Self::RejectNoWakeupSpecified => "Reject because no wakeup schedule specified",
+            Self::SuccessPowerSaveMode => "Success, the destination STA is in power save mode",
+            Self::PendingAdmittingFstSession => "FST pending, in process of admitting FST session",
+            Self::PerformingFstNow => "Performing FST now",
+            Self::PendingGapInBaWindow => "FST pending, gap(s) in block ack window",
+            Self::RejectUpidSetting => "Reject because of U-PID setting",
+            Self::RefusedExternalReason => "Refused because of external reason",
+            Self::RefusedApOutOfMemory => "Refused because the AP is out of memory",
+            Self::RejectedEmergencyServicesNotSupported => "Refused because emergency services are not supported at the AP",
+            Self::QueryResponseOutstanding => "GAS query response not yet received",
+            Self::RejectDseBand => "Reject since the request is for transition to a frequency band subject to DSE procedures and the FST Initiator is a dependent STA",
+            Self::TclasProcessingTerminated => "Requested TCLAS processing has been terminated by the AP",
+            Self::TsScheduleConflict => "The TS schedule conflicts with an existing schedule; an alternative schedule is provided",
+            Self::DeniedWithSuggestedBandAndChannel => "The association has been denied; however, one or more Multi-band elements are included that can be used by the receiving STA to join the BSS",
+            Self::MccaopReservationConflict => "The request failed due to a reservation conflict",
+            Self::MafLimitExceeded => "The request failed due to exceeded MAF limit",
+            Self::MccaTrackLimitExceeded => "The request failed due to exceeded MCCA track limit",
+            Self::DeniedDueToSpectrumManagement => "Association denied because the information in the Spectrum Management field is unacceptable",
+            Self::DeniedVhtNotSupported => "Association denied because the requesting STA does not support VHT features",
+            Self::Enablement => "Enablement denied",
+            Self::Restriction => "Enablement denied due to restriction from an authorized GDB",
+            Self::Authorization => "Authorization deenabled",
+            Self::EnergyLimitedOperationNotSupported => "Energy limited operation is not supported",
+            Self::RejectedNdpBlockAckSuggested => "Rejected, NDP Block Ack suggested",
+            Self::RejectedMaxAwayDurationUnacceptable => "Rejected, the maximum away duration is unacceptable",
+            Self::FlowControlOperationSupported => "Flow control operation is supported",
+            Self::FilsAuthenticationFailure => "FILS authentication failure",
+            Self::UnknownAuthenticationServer => "Unknown authentication server",
+            Self::DeniedNotificationPeriodAllocation => "Request denied due to the allocation of notification period",
+            Self::DeniedChannelSplitting => "Request denied due to channel splitting",
+            Self::DeniedAllocation => "Request denied due to allocation",
+            Self::CmmgFeaturesNotSupported => "Association denied because the requesting STA does not support CMMG features",
+            Self::GasFragmentNotAvailable => "The requested GAS fragment is not available",
+            Self::SuccessCagVersionsMatch => "Success, the CAG versions match",
+            Self::GlkNotAuthorized => "The GLK-AP is not authorized",
+            Self::UnknownPasswordIdentifier => "Unknown password identifier",
+            Self::DeniedLocalMacAddressPolicyViolation => "Association denied because the requesting STA violates the local MAC address policy",
+            Self::SaeHashToElement => "SAE authentication using the hash-to-element method",
+            Self::TclasProcessingTerminatedInsufficientQos => "TCLAS processing terminated due to insufficient QoS",
+            Self::TclasProcessingTerminatedPolicyConflict => "TCLAS processing terminated due to a policy conflict",
+            Self::Unknown(status_code) => return write!(f, "Unknown({status_code})"),
+        })
+    }
+}
@@ -1,3 +1,5 @@
+use core::fmt::Display;
+
 use macro_bits::serializable_enum;
 
 serializable_enum! {
@@ -27,7 +29,35 @@ impl ControlFrameSubtype {
     pub const fn has_address_2(&self) -> bool {
         matches!(
             self,
-            Self::PSPoll | Self::CFEnd | Self::BlockAckRequest | Self::BlockAck
+            Self::TACK
+                | Self::BeamformingReportPoll
+                | Self::VHTNDPAnnouncement
+                | Self::PSPoll
+                | Self::RTS
+                | Self::CFEnd
+                | Self::CFEndAck
+                | Self::BlockAckRequest
+                | Self::BlockAck
         )
     }
 }
+impl Display for ControlFrameSubtype {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::TACK => "TACK",
+            Self::BeamformingReportPoll => "Beamforming Report Poll",
+            Self::VHTNDPAnnouncement => "VHT NDP Announcement",
+            Self::ControlFrameExtension => "Control Frame Extension",
+            Self::ControlWrapper => "Control Wrapper",
+            Self::BlockAckRequest => "BlockAckReq",
+            Self::BlockAck => "BlockAck",
+            Self::PSPoll => "PS-Poll",
+            Self::RTS => "RTS",
+            Self::CTS => "CTS",
+            Self::Ack => "Ack",
+            Self::CFEnd => "CF-End",
+            Self::CFEndAck => "CF-End+CF-Ack",
+            Self::Unknown(subtype) => return write!(f, "Unknown({subtype})"),
+        })
+    }
+}
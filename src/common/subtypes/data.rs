@@ -1,3 +1,5 @@
+use core::fmt::Display;
+
 use macro_bits::serializable_enum;
 
 serializable_enum! {
@@ -69,3 +71,25 @@ impl DataFrameSubtype {
         )
     }
 }
+impl Display for DataFrameSubtype {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Data => "Data",
+            Self::DataCFAck => "Data+CF-Ack",
+            Self::DataCFPoll => "Data+CF-Poll",
+            Self::DataCFAckCFPoll => "Data+CF-Ack+CF-Poll",
+            Self::Null => "Null",
+            Self::CFAck => "CF-Ack",
+            Self::CFPoll => "CF-Poll",
+            Self::CFAckCFPoll => "CF-Ack+CF-Poll",
+            Self::QoSData => "QoS-Data",
+            Self::QoSDataCFAck => "QoS-Data+CF-Ack",
+            Self::QoSDataCFPoll => "QoS-Data+CF-Poll",
+            Self::QoSDataCFAckCFPoll => "QoS-Data+CF-Ack+CF-Poll",
+            Self::QoSNull => "QoS-Null",
+            Self::QoSCFPoll => "QoS-CF-Poll",
+            Self::QoSCFAckCFPoll => "QoS-CF-Ack+CF-Poll",
+            Self::Unknown(subtype) => return write!(f, "Unknown({subtype})"),
+        })
+    }
+}
@@ -1,3 +1,5 @@
+use core::fmt::Display;
+
 use macro_bits::serializable_enum;
 
 serializable_enum! {
@@ -17,3 +19,21 @@ serializable_enum! {
         ActionNoACK => 0b1110
     }
 }
+impl Display for ManagementFrameSubtype {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::AssociationRequest => "Association Request",
+            Self::AssociationResponse => "Association Response",
+            Self::ProbeRequest => "Probe Request",
+            Self::ProbeResponse => "Probe Response",
+            Self::Beacon => "Beacon",
+            Self::ATIM => "ATIM",
+            Self::Disassociation => "Disassociation",
+            Self::Authentication => "Authentication",
+            Self::Deauthentication => "Deauthentication",
+            Self::Action => "Action",
+            Self::ActionNoACK => "Action No Ack",
+            Self::Unknown(subtype) => return write!(f, "Unknown({subtype})"),
+        })
+    }
+}
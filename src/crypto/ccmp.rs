@@ -0,0 +1,239 @@
+use aes::{
+    cipher::{BlockEncrypt, KeyInit},
+    Aes128, Block,
+};
+use macro_bits::bit;
+use scroll::Pread;
+use subtle::ConstantTimeEq;
+
+use crate::data_frame::header::DataFrameHeader;
+
+use super::crypto_header::CryptoHeader;
+
+/// The length of the CCMP header, which is prepended to the encrypted payload.
+pub const CCMP_HEADER_LENGTH: usize = 8;
+/// The length of the MIC used by CCMP-128.
+pub const CCMP_128_MIC_LENGTH: usize = 8;
+
+/// Calculates the length of a CCMP encrypted payload, from the length of the plaintext and the MIC.
+///
+/// The MIC length is eight bytes for CCMP-128 and 16 bytes for CCMP-256.
+/// This can be used to size the output buffer before encryption.
+pub const fn ccmp_output_len(plaintext_len: usize, mic_len: usize) -> usize {
+    CCMP_HEADER_LENGTH + plaintext_len + mic_len
+}
+
+/// The maximum length of the AAD, which is the case for a QoS data frame with four addresses.
+const MAX_AAD_LENGTH: usize = 30;
+
+/// Generate the CCM nonce, as specified in 12.5.3.3.4 of IEEE 802.11-2020.
+fn generate_nonce(header: &DataFrameHeader, pn: u64) -> [u8; 13] {
+    let mut nonce = [0x00; 13];
+    // The priority is the TID for QoS data frames and zero otherwise.
+    nonce[0] = header.qos.map_or(0, |qos| qos[0] & 0x0f);
+    nonce[1..7].copy_from_slice(header.address_2.as_slice());
+    // The PN is encoded big endian here.
+    nonce[7..13].copy_from_slice(&pn.to_be_bytes()[2..]);
+    nonce
+}
+/// Generate the AAD, as specified in 12.5.3.3.3 of IEEE 802.11-2020.
+///
+/// This returns the AAD buffer and the length of the AAD.
+pub(super) fn generate_aad(header: &DataFrameHeader) -> ([u8; MAX_AAD_LENGTH], usize) {
+    let mut aad = [0x00; MAX_AAD_LENGTH];
+
+    let mut fcf = header.get_fcf().into_bits().to_le_bytes();
+    // Subtype bits 4, 5 and 6 are masked.
+    fcf[0] &= !(bit!(4) | bit!(5) | bit!(6));
+    // The Retry, Power Management and More Data bits are masked and the Protected bit is set.
+    fcf[1] &= !(bit!(3) | bit!(4) | bit!(5));
+    fcf[1] |= bit!(6);
+    // The Order bit is masked for QoS data frames.
+    if header.qos.is_some() {
+        fcf[1] &= !bit!(7);
+    }
+    aad[0..2].copy_from_slice(&fcf);
+    aad[2..8].copy_from_slice(header.address_1.as_slice());
+    aad[8..14].copy_from_slice(header.address_2.as_slice());
+    aad[14..20].copy_from_slice(header.address_3.as_slice());
+    // Only the fragment number of the sequence control field is kept.
+    aad[20] = header.sequence_control.into_bits().to_le_bytes()[0] & 0x0f;
+    let mut length = 22;
+    if let Some(address_4) = header.address_4 {
+        aad[length..length + 6].copy_from_slice(address_4.as_slice());
+        length += 6;
+    }
+    if let Some(qos) = header.qos {
+        // Only the TID is kept.
+        aad[length] = qos[0] & 0x0f;
+        length += 2;
+    }
+    (aad, length)
+}
+/// Generate the CCMP header, for the specified PN and key ID.
+///
+/// The GCMP header has the same format.
+pub(super) fn generate_ccmp_header(pn: u64, key_id: u8) -> [u8; CCMP_HEADER_LENGTH] {
+    CryptoHeader::new_ccmp(pn, key_id).into_bytes()
+}
+/// Extract the PN from a CCMP header.
+///
+/// The GCMP header has the same format.
+pub(super) fn parse_ccmp_header(ccmp_header: &[u8]) -> Result<u64, scroll::Error> {
+    let ccmp_header = ccmp_header.pread::<CryptoHeader>(0)?;
+    if !ccmp_header.ext_iv() {
+        return Err(scroll::Error::BadInput {
+            size: CCMP_HEADER_LENGTH,
+            msg: "The Ext IV bit in the CCMP header wasn't set.",
+        });
+    }
+    Ok(ccmp_header.packet_number())
+}
+/// Encrypt a single block in place.
+fn encrypt_block(cipher: &Aes128, block: &mut [u8; 16]) {
+    let mut aes_block = Block::from(*block);
+    cipher.encrypt_block(&mut aes_block);
+    block.copy_from_slice(&aes_block);
+}
+/// Generate the counter block `A_i` for CTR mode.
+fn counter_block(nonce: &[u8; 13], counter: u16) -> [u8; 16] {
+    let mut block = [0x00; 16];
+    // L - 1, with a length field of two bytes.
+    block[0] = 0x01;
+    block[1..14].copy_from_slice(nonce);
+    block[14..16].copy_from_slice(&counter.to_be_bytes());
+    block
+}
+/// XOR the data with the CBC-MAC state, block by block, padding the last block with zeroes.
+fn cbc_mac_update(cipher: &Aes128, state: &mut [u8; 16], data: &[u8]) {
+    for chunk in data.chunks(16) {
+        state
+            .iter_mut()
+            .zip(chunk.iter())
+            .for_each(|(state, byte)| *state ^= byte);
+        encrypt_block(cipher, state);
+    }
+}
+/// Calculate the unencrypted MIC over the AAD and plaintext.
+fn calculate_mic(cipher: &Aes128, nonce: &[u8; 13], aad: &[u8], plaintext: &[u8]) -> [u8; 16] {
+    let mut state = [0x00; 16];
+    // Adata is set, M is eight and L is two.
+    state[0] = bit!(6) | ((((CCMP_128_MIC_LENGTH - 2) / 2) as u8) << 3) | 0x01;
+    state[1..14].copy_from_slice(nonce);
+    state[14..16].copy_from_slice(&(plaintext.len() as u16).to_be_bytes());
+    encrypt_block(cipher, &mut state);
+
+    // The AAD is prefixed with it's length and the result is padded to the block size.
+    let mut aad_blocks = [0x00; 32];
+    aad_blocks[..2].copy_from_slice(&(aad.len() as u16).to_be_bytes());
+    aad_blocks[2..2 + aad.len()].copy_from_slice(aad);
+    cbc_mac_update(cipher, &mut state, &aad_blocks[..2 + aad.len()]);
+    cbc_mac_update(cipher, &mut state, plaintext);
+
+    state
+}
+/// Apply the CTR mode keystream, starting at counter one, to the data in place.
+fn apply_keystream(cipher: &Aes128, nonce: &[u8; 13], data: &mut [u8]) {
+    for (i, chunk) in data.chunks_mut(16).enumerate() {
+        let mut keystream = counter_block(nonce, i as u16 + 1);
+        encrypt_block(cipher, &mut keystream);
+        chunk
+            .iter_mut()
+            .zip(keystream.iter())
+            .for_each(|(byte, keystream)| *byte ^= keystream);
+    }
+}
+/// Encrypt and authenticate the MIC with `S_0`.
+fn encrypt_mic(cipher: &Aes128, nonce: &[u8; 13], mic: &[u8; 16]) -> [u8; CCMP_128_MIC_LENGTH] {
+    let mut s0 = counter_block(nonce, 0);
+    encrypt_block(cipher, &mut s0);
+    let mut encrypted_mic = [0x00; CCMP_128_MIC_LENGTH];
+    encrypted_mic
+        .iter_mut()
+        .zip(mic.iter().zip(s0.iter()))
+        .for_each(|(encrypted, (mic, s0))| *encrypted = mic ^ s0);
+    encrypted_mic
+}
+/// Check the length of a buffer.
+pub(super) fn check_buffer_length(required: usize, len: usize) -> Result<(), scroll::Error> {
+    if len < required {
+        Err(scroll::Error::TooBig {
+            size: required,
+            len,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Encrypt the payload of a data frame with CCMP-128, as specified in 12.5.3 of IEEE 802.11-2020.
+///
+/// The CCMP header, encrypted payload and MIC are written to `out`, which has to be at least [ccmp_output_len] bytes long.
+/// The key ID is always zero and only the lower 48 bits of the `pn` are used.
+/// On success, the amount of bytes written is returned.
+pub fn ccmp_encrypt(
+    tk: &[u8; 16],
+    header: &DataFrameHeader,
+    pn: u64,
+    payload: &[u8],
+    out: &mut [u8],
+) -> Result<usize, scroll::Error> {
+    let output_len = ccmp_output_len(payload.len(), CCMP_128_MIC_LENGTH);
+    check_buffer_length(output_len, out.len())?;
+
+    let cipher = Aes128::new(tk.into());
+    let nonce = generate_nonce(header, pn);
+    let (aad, aad_length) = generate_aad(header);
+    let mic = calculate_mic(&cipher, &nonce, &aad[..aad_length], payload);
+
+    out[..CCMP_HEADER_LENGTH].copy_from_slice(&generate_ccmp_header(pn, 0));
+    let ciphertext = &mut out[CCMP_HEADER_LENGTH..CCMP_HEADER_LENGTH + payload.len()];
+    ciphertext.copy_from_slice(payload);
+    apply_keystream(&cipher, &nonce, ciphertext);
+    out[CCMP_HEADER_LENGTH + payload.len()..output_len]
+        .copy_from_slice(&encrypt_mic(&cipher, &nonce, &mic));
+
+    Ok(output_len)
+}
+/// Decrypt the payload of a data frame, which was encrypted with CCMP-128.
+///
+/// The `protected_payload` starts with the CCMP header, from which the PN is extracted, and ends with the MIC.
+/// The plaintext is written to `out` and it's length is returned, if the MIC matches.
+pub fn ccmp_decrypt(
+    tk: &[u8; 16],
+    header: &DataFrameHeader,
+    protected_payload: &[u8],
+    out: &mut [u8],
+) -> Result<usize, scroll::Error> {
+    check_buffer_length(
+        CCMP_HEADER_LENGTH + CCMP_128_MIC_LENGTH,
+        protected_payload.len(),
+    )?;
+    let plaintext_len = protected_payload.len() - CCMP_HEADER_LENGTH - CCMP_128_MIC_LENGTH;
+    check_buffer_length(plaintext_len, out.len())?;
+
+    let pn = parse_ccmp_header(&protected_payload[..CCMP_HEADER_LENGTH])?;
+
+    let cipher = Aes128::new(tk.into());
+    let nonce = generate_nonce(header, pn);
+    let (aad, aad_length) = generate_aad(header);
+
+    let plaintext = &mut out[..plaintext_len];
+    plaintext.copy_from_slice(
+        &protected_payload[CCMP_HEADER_LENGTH..CCMP_HEADER_LENGTH + plaintext_len],
+    );
+    apply_keystream(&cipher, &nonce, plaintext);
+
+    let mic = calculate_mic(&cipher, &nonce, &aad[..aad_length], plaintext);
+    let mic = encrypt_mic(&cipher, &nonce, &mic);
+    if !bool::from(mic.ct_eq(&protected_payload[CCMP_HEADER_LENGTH + plaintext_len..])) {
+        // Don't leak the unauthenticated plaintext.
+        plaintext.fill(0x00);
+        return Err(scroll::Error::BadInput {
+            size: protected_payload.len(),
+            msg: "The MIC didn't match.",
+        });
+    }
+
+    Ok(plaintext_len)
+}
@@ -0,0 +1,96 @@
+use macro_bits::{bit, check_bit};
+use scroll::{
+    ctx::{MeasureWith, SizeWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+/// The length of the CCMP and GCMP header.
+const CRYPTO_HEADER_LENGTH: usize = 8;
+/// The Ext IV bit in the fourth byte of the header.
+const EXT_IV_BIT: u8 = bit!(5);
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The header preceding the encrypted payload of a frame protected with CCMP or GCMP, as specified in 12.5.3.2 of IEEE 802.11-2020.
+///
+/// The 48 bit PN is split across the header, with PN0 and PN1 in the first two bytes and PN2 to PN5 in the last four bytes.
+/// The byte in between them carries the key ID and the Ext IV bit.
+pub struct CryptoHeader {
+    bytes: [u8; CRYPTO_HEADER_LENGTH],
+}
+impl CryptoHeader {
+    /// Create a header from it's raw bytes.
+    pub const fn from_bytes(bytes: [u8; CRYPTO_HEADER_LENGTH]) -> Self {
+        Self { bytes }
+    }
+    /// Returns the raw bytes of the header.
+    pub const fn into_bytes(self) -> [u8; CRYPTO_HEADER_LENGTH] {
+        self.bytes
+    }
+    /// Create a CCMP header with the specified PN and key ID.
+    ///
+    /// Only the lower 48 bits of the `pn` and the lower two bits of the `key_id` are used and the Ext IV bit is always set.
+    /// The GCMP header has the same format.
+    pub const fn new_ccmp(pn: u64, key_id: u8) -> Self {
+        let pn = pn.to_le_bytes();
+        Self::from_bytes([
+            pn[0],
+            pn[1],
+            0x00,
+            EXT_IV_BIT | ((key_id & 0b11) << 6),
+            pn[2],
+            pn[3],
+            pn[4],
+            pn[5],
+        ])
+    }
+    /// Returns the 48 bit PN.
+    pub const fn packet_number(&self) -> u64 {
+        u64::from_le_bytes([
+            self.bytes[0],
+            self.bytes[1],
+            self.bytes[4],
+            self.bytes[5],
+            self.bytes[6],
+            self.bytes[7],
+            0x00,
+            0x00,
+        ])
+    }
+    /// Returns the key ID, which is in the range zero to three.
+    pub const fn key_id(&self) -> u8 {
+        self.bytes[3] >> 6
+    }
+    /// Check if the Ext IV bit is set.
+    ///
+    /// This is always the case for CCMP and GCMP.
+    pub const fn ext_iv(&self) -> bool {
+        check_bit!(self.bytes[3], EXT_IV_BIT)
+    }
+}
+impl SizeWith for CryptoHeader {
+    fn size_with(_ctx: &()) -> usize {
+        CRYPTO_HEADER_LENGTH
+    }
+}
+impl MeasureWith<()> for CryptoHeader {
+    fn measure_with(&self, ctx: &()) -> usize {
+        Self::size_with(ctx)
+    }
+}
+impl TryFromCtx<'_> for CryptoHeader {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let bytes = from.gread(&mut offset)?;
+
+        Ok((Self { bytes }, offset))
+    }
+}
+impl TryIntoCtx for CryptoHeader {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        buf.pwrite(self.bytes, 0)
+    }
+}
@@ -0,0 +1,152 @@
+use bitfield_struct::bitfield;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+/// The length of an EAPOL-Key frame without the key data.
+pub const EAPOL_KEY_FRAME_FIXED_LENGTH: usize = 99;
+/// The offset of the Key MIC field from the start of the EAPOL-Key frame.
+pub(crate) const EAPOL_KEY_MIC_OFFSET: usize = 81;
+
+/// The EAPOL packet type of an EAPOL-Key frame.
+const EAPOL_PACKET_TYPE_KEY: u8 = 3;
+/// The descriptor type of an RSN EAPOL-Key frame.
+const RSN_KEY_DESCRIPTOR_TYPE: u8 = 2;
+
+#[bitfield(u16, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The Key Information field of an EAPOL-Key frame.
+pub struct KeyInformation {
+    #[bits(3)]
+    /// The version of the key descriptor, which determines the MIC and key wrap algorithms.
+    ///
+    /// A value of two indicates HMAC-SHA1-128 and AES key wrap.
+    pub key_descriptor_version: u8,
+    /// Indicates, that this frame is part of a PTK derivation.
+    pub pairwise: bool,
+    #[bits(2)]
+    __: u8,
+    /// Indicates, that the supplicant should install the temporal key.
+    pub install: bool,
+    /// Set by the authenticator, if it expects a response.
+    pub ack: bool,
+    /// Indicates, that the frame carries a MIC.
+    pub mic: bool,
+    /// Indicates, that the initial key exchange is complete.
+    pub secure: bool,
+    pub error: bool,
+    pub request: bool,
+    /// Indicates, that the key data is encrypted with the KEK.
+    pub encrypted_key_data: bool,
+    pub smk_message: bool,
+    #[bits(2)]
+    __: u8,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// An EAPOL-Key frame, as specified in 12.7.2 of IEEE 802.11-2020.
+///
+/// This includes the EAPOL header and assumes a MIC length of 16 bytes, which is the case for all AKMs using key descriptor version one to three.
+/// Unlike the rest of IEEE 802.11, all multi byte fields are big endian.
+pub struct EAPOLKeyFrame<'a> {
+    /// The version of the EAPOL protocol.
+    pub protocol_version: u8,
+    pub key_information: KeyInformation,
+    /// The length of the pairwise key in bytes.
+    pub key_length: u16,
+    /// A counter used to detect replayed frames.
+    pub key_replay_counter: u64,
+    /// The ANonce or SNonce.
+    pub key_nonce: [u8; 32],
+    pub key_iv: [u8; 16],
+    pub key_rsc: u64,
+    pub key_mic: [u8; 16],
+    pub key_data: &'a [u8],
+}
+impl MeasureWith<()> for EAPOLKeyFrame<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        EAPOL_KEY_FRAME_FIXED_LENGTH + self.key_data.len()
+    }
+}
+impl<'a> TryFromCtx<'a> for EAPOLKeyFrame<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let protocol_version = from.gread(&mut offset)?;
+        if from.gread::<u8>(&mut offset)? != EAPOL_PACKET_TYPE_KEY {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "The EAPOL packet type wasn't Key.",
+            });
+        }
+        let body_length = from.gread_with::<u16>(&mut offset, Endian::Big)?;
+        if from.gread::<u8>(&mut offset)? != RSN_KEY_DESCRIPTOR_TYPE {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "The key descriptor type wasn't RSN.",
+            });
+        }
+        let key_information = KeyInformation::from_bits(from.gread_with(&mut offset, Endian::Big)?);
+        let key_length = from.gread_with(&mut offset, Endian::Big)?;
+        let key_replay_counter = from.gread_with(&mut offset, Endian::Big)?;
+        let key_nonce = from.gread(&mut offset)?;
+        let key_iv = from.gread(&mut offset)?;
+        let key_rsc = from.gread_with(&mut offset, Endian::Big)?;
+        // Reserved
+        offset += 8;
+        let key_mic = from.gread(&mut offset)?;
+        let key_data_length = from.gread_with::<u16>(&mut offset, Endian::Big)? as usize;
+        if body_length as usize != EAPOL_KEY_FRAME_FIXED_LENGTH - 4 + key_data_length {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "The EAPOL body length didn't match the key data length.",
+            });
+        }
+        let key_data = from.gread_with(&mut offset, key_data_length)?;
+
+        Ok((
+            Self {
+                protocol_version,
+                key_information,
+                key_length,
+                key_replay_counter,
+                key_nonce,
+                key_iv,
+                key_rsc,
+                key_mic,
+                key_data,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for EAPOLKeyFrame<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.protocol_version, &mut offset)?;
+        buf.gwrite(EAPOL_PACKET_TYPE_KEY, &mut offset)?;
+        buf.gwrite_with(
+            (EAPOL_KEY_FRAME_FIXED_LENGTH - 4 + self.key_data.len()) as u16,
+            &mut offset,
+            Endian::Big,
+        )?;
+        buf.gwrite(RSN_KEY_DESCRIPTOR_TYPE, &mut offset)?;
+        buf.gwrite_with(self.key_information.into_bits(), &mut offset, Endian::Big)?;
+        buf.gwrite_with(self.key_length, &mut offset, Endian::Big)?;
+        buf.gwrite_with(self.key_replay_counter, &mut offset, Endian::Big)?;
+        buf.gwrite(self.key_nonce, &mut offset)?;
+        buf.gwrite(self.key_iv, &mut offset)?;
+        buf.gwrite_with(self.key_rsc, &mut offset, Endian::Big)?;
+        buf.gwrite([0x00u8; 8], &mut offset)?;
+        buf.gwrite(self.key_mic, &mut offset)?;
+        buf.gwrite_with(self.key_data.len() as u16, &mut offset, Endian::Big)?;
+        buf.gwrite(self.key_data, &mut offset)?;
+
+        Ok(offset)
+    }
+}
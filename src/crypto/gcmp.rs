@@ -0,0 +1,179 @@
+use aes::{
+    cipher::{BlockEncrypt, KeyInit},
+    Aes128, Aes256, Block,
+};
+
+use ghash::{universal_hash::UniversalHash, GHash};
+use subtle::ConstantTimeEq;
+
+use crate::data_frame::header::DataFrameHeader;
+
+use super::ccmp::{check_buffer_length, generate_aad, generate_ccmp_header, parse_ccmp_header};
+
+/// The length of the GCMP header, which is prepended to the encrypted payload.
+pub const GCMP_HEADER_LENGTH: usize = 8;
+/// The length of the MIC used by GCMP-128 and GCMP-256.
+pub const GCMP_MIC_LENGTH: usize = 16;
+
+/// Calculates the length of a GCMP encrypted payload, from the length of the plaintext.
+///
+/// This can be used to size the output buffer before encryption.
+pub const fn gcmp_output_len(plaintext_len: usize) -> usize {
+    GCMP_HEADER_LENGTH + plaintext_len + GCMP_MIC_LENGTH
+}
+
+/// The block cipher used by GCMP, which depends on the length of the TK.
+// This only ever lives on the stack for the duration of a single call and boxing isn't possible in no_std.
+#[allow(clippy::large_enum_variant)]
+enum GCMPCipher {
+    Aes128(Aes128),
+    Aes256(Aes256),
+}
+impl GCMPCipher {
+    /// Create the cipher from the TK.
+    ///
+    /// A 16 byte TK is used for GCMP-128 and a 32 byte TK for GCMP-256.
+    fn new(tk: &[u8]) -> Result<Self, scroll::Error> {
+        match tk.len() {
+            16 => Ok(Self::Aes128(Aes128::new(tk.into()))),
+            32 => Ok(Self::Aes256(Aes256::new(tk.into()))),
+            _ => Err(scroll::Error::BadInput {
+                size: tk.len(),
+                msg: "The TK for GCMP has to be either 16 or 32 bytes long.",
+            }),
+        }
+    }
+    /// Encrypt a single block in place.
+    fn encrypt_block(&self, block: &mut [u8; 16]) {
+        let mut aes_block = Block::from(*block);
+        match self {
+            Self::Aes128(cipher) => cipher.encrypt_block(&mut aes_block),
+            Self::Aes256(cipher) => cipher.encrypt_block(&mut aes_block),
+        }
+        block.copy_from_slice(&aes_block);
+    }
+}
+
+/// Generate the GCM nonce, as specified in 12.5.5.3.4 of IEEE 802.11-2020.
+fn generate_nonce(header: &DataFrameHeader, pn: u64) -> [u8; 12] {
+    let mut nonce = [0x00; 12];
+    nonce[..6].copy_from_slice(header.address_2.as_slice());
+    // The PN is encoded big endian here.
+    nonce[6..12].copy_from_slice(&pn.to_be_bytes()[2..]);
+    nonce
+}
+/// Generate the counter block for the specified counter.
+///
+/// Since the nonce is 96 bits long, `J_0` is the block with the counter set to one.
+fn counter_block(nonce: &[u8; 12], counter: u32) -> [u8; 16] {
+    let mut block = [0x00; 16];
+    block[..12].copy_from_slice(nonce);
+    block[12..].copy_from_slice(&counter.to_be_bytes());
+    block
+}
+/// Calculate the MIC over the AAD and ciphertext.
+fn calculate_mic(
+    cipher: &GCMPCipher,
+    nonce: &[u8; 12],
+    aad: &[u8],
+    ciphertext: &[u8],
+) -> [u8; GCMP_MIC_LENGTH] {
+    let mut h = [0x00; 16];
+    cipher.encrypt_block(&mut h);
+
+    let mut ghash = GHash::new(&h.into());
+    ghash.update_padded(aad);
+    ghash.update_padded(ciphertext);
+    // The lengths are encoded in bits.
+    let mut lengths = [0x00; 16];
+    lengths[..8].copy_from_slice(&(aad.len() as u64 * 8).to_be_bytes());
+    lengths[8..].copy_from_slice(&(ciphertext.len() as u64 * 8).to_be_bytes());
+    ghash.update(&[lengths.into()]);
+
+    let mut mic = counter_block(nonce, 1);
+    cipher.encrypt_block(&mut mic);
+    mic.iter_mut()
+        .zip(ghash.finalize())
+        .for_each(|(mic, ghash)| *mic ^= ghash);
+    mic
+}
+/// Apply the CTR mode keystream, starting at counter two, to the data in place.
+fn apply_keystream(cipher: &GCMPCipher, nonce: &[u8; 12], data: &mut [u8]) {
+    for (i, chunk) in data.chunks_mut(16).enumerate() {
+        let mut keystream = counter_block(nonce, i as u32 + 2);
+        cipher.encrypt_block(&mut keystream);
+        chunk
+            .iter_mut()
+            .zip(keystream.iter())
+            .for_each(|(byte, keystream)| *byte ^= keystream);
+    }
+}
+
+/// Encrypt the payload of a data frame with GCMP, as specified in 12.5.5 of IEEE 802.11-2020.
+///
+/// GCMP-128 is used for a 16 byte `tk` and GCMP-256 for a 32 byte `tk`. For any other length, an error is returned.
+/// The GCMP header, encrypted payload and MIC are written to `out`, which has to be at least [gcmp_output_len] bytes long.
+/// The key ID is always zero and only the lower 48 bits of the `pn` are used.
+/// On success, the amount of bytes written is returned.
+pub fn gcmp_encrypt(
+    tk: &[u8],
+    header: &DataFrameHeader,
+    pn: u64,
+    payload: &[u8],
+    out: &mut [u8],
+) -> Result<usize, scroll::Error> {
+    let cipher = GCMPCipher::new(tk)?;
+    let output_len = gcmp_output_len(payload.len());
+    check_buffer_length(output_len, out.len())?;
+
+    let nonce = generate_nonce(header, pn);
+    let (aad, aad_length) = generate_aad(header);
+
+    out[..GCMP_HEADER_LENGTH].copy_from_slice(&generate_ccmp_header(pn, 0));
+    let ciphertext = &mut out[GCMP_HEADER_LENGTH..GCMP_HEADER_LENGTH + payload.len()];
+    ciphertext.copy_from_slice(payload);
+    apply_keystream(&cipher, &nonce, ciphertext);
+    let mic = calculate_mic(&cipher, &nonce, &aad[..aad_length], ciphertext);
+    out[GCMP_HEADER_LENGTH + payload.len()..output_len].copy_from_slice(&mic);
+
+    Ok(output_len)
+}
+/// Decrypt the payload of a data frame, which was encrypted with GCMP-128 or GCMP-256.
+///
+/// The cipher is selected based on the length of the `tk`, like for [gcmp_encrypt].
+/// The `protected_payload` starts with the GCMP header, from which the PN is extracted, and ends with the MIC.
+/// The plaintext is written to `out` and it's length is returned, if the MIC matches.
+pub fn gcmp_decrypt(
+    tk: &[u8],
+    header: &DataFrameHeader,
+    protected_payload: &[u8],
+    out: &mut [u8],
+) -> Result<usize, scroll::Error> {
+    let cipher = GCMPCipher::new(tk)?;
+    check_buffer_length(
+        GCMP_HEADER_LENGTH + GCMP_MIC_LENGTH,
+        protected_payload.len(),
+    )?;
+    let plaintext_len = protected_payload.len() - GCMP_HEADER_LENGTH - GCMP_MIC_LENGTH;
+    check_buffer_length(plaintext_len, out.len())?;
+
+    let pn = parse_ccmp_header(&protected_payload[..GCMP_HEADER_LENGTH])?;
+    let nonce = generate_nonce(header, pn);
+    let (aad, aad_length) = generate_aad(header);
+
+    let ciphertext = &protected_payload[GCMP_HEADER_LENGTH..GCMP_HEADER_LENGTH + plaintext_len];
+    // The MIC is calculated over the ciphertext, so it's checked before decrypting.
+    let mic = calculate_mic(&cipher, &nonce, &aad[..aad_length], ciphertext);
+    if !bool::from(mic.ct_eq(&protected_payload[GCMP_HEADER_LENGTH + plaintext_len..])) {
+        return Err(scroll::Error::BadInput {
+            size: protected_payload.len(),
+            msg: "The MIC didn't match.",
+        });
+    }
+
+    let plaintext = &mut out[..plaintext_len];
+    plaintext.copy_from_slice(ciphertext);
+    apply_keystream(&cipher, &nonce, plaintext);
+
+    Ok(plaintext_len)
+}
@@ -0,0 +1,593 @@
+use core::fmt::Debug;
+
+use mac_parser::MACAddress;
+use scroll::Pwrite;
+use subtle::ConstantTimeEq;
+
+use crate::elements::{kde::GTKKDE, Element, ReadElements};
+
+use super::{
+    eapol::{EAPOLKeyFrame, KeyInformation},
+    key_mgmt::{calculate_eapol_key_mic, derive_ptk, partition_ptk, PairwiseTransientKey},
+    key_wrap::{unwrap_eapol_key_data, wrap_eapol_key_data, wrapped_eapol_key_data_len},
+};
+
+/// The EAPOL protocol version used for frames sent by the handshake.
+const EAPOL_PROTOCOL_VERSION: u8 = 2;
+/// Key descriptor version two, which uses HMAC-SHA1-128 for the MIC.
+const KEY_DESCRIPTOR_VERSION: u8 = 2;
+/// The length of the CCMP-128 temporal key.
+const KEY_LENGTH: u16 = 16;
+/// The length of the GTK KDE for a CCMP-128 GTK, including the element header.
+const GTK_KDE_LENGTH: usize = 2 + 4 + 2 + KEY_LENGTH as usize;
+/// The maximum length of the key data, which can be unwrapped by the supplicant of the group key handshake.
+const MAX_GROUP_KEY_DATA_LENGTH: usize = 256;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The role of a party in the 4-way or group key handshake.
+pub enum HandshakeRole {
+    /// The authenticator, which is usually the AP.
+    Authenticator,
+    /// The supplicant, which is usually the non-AP STA.
+    Supplicant,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The state of the 4-way or group key handshake.
+pub enum HandshakeState {
+    /// The authenticator hasn't sent message 1 yet.
+    Idle,
+    /// The party is waiting for the message with the specified number.
+    WaitingForMessage(u8),
+    /// The handshake completed successfully and the PTK can be installed.
+    Complete,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// An error, which occured while processing a message of the 4-way or group key handshake.
+pub enum HandshakeError {
+    /// The message wasn't the one expected in the current state.
+    UnexpectedMessage,
+    /// The replay counter of the message was invalid.
+    ReplayCounterMismatch,
+    /// The nonce in message 3 didn't match the one in message 1.
+    NonceMismatch,
+    /// The MIC of the message was invalid.
+    MICMismatch,
+    /// The key data couldn't be unwrapped or didn't contain the expected KDE.
+    InvalidKeyData,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+/// A state machine for the 4-way handshake, as specified in 12.7.6 of IEEE 802.11-2020.
+///
+/// This drives the exchange of the four EAPOL-Key frames for a PSK or a PMK obtained otherwise, and derives the PTK for CCMP-128.
+/// Since this library has no source of randomness, the nonces have to be supplied by the user.
+/// The GTK isn't distributed in message 3, which only carries the key data supplied to the authenticator, which is usually it's RSN element.
+/// Instead, the [GroupKeyHandshake] can be used to distribute the GTK, once the handshake is complete.
+pub struct FourWayHandshake<'a> {
+    role: HandshakeRole,
+    state: HandshakeState,
+    pmk: [u8; 32],
+    authenticator_address: MACAddress,
+    supplicant_address: MACAddress,
+    own_nonce: [u8; 32],
+    peer_nonce: [u8; 32],
+    key_replay_counter: Option<u64>,
+    ptk: Option<PairwiseTransientKey>,
+    key_data: &'a [u8],
+}
+impl Debug for FourWayHandshake<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // The PMK and PTK are left out, so they don't end up in logs.
+        f.debug_struct("FourWayHandshake")
+            .field("role", &self.role)
+            .field("state", &self.state)
+            .field("authenticator_address", &self.authenticator_address)
+            .field("supplicant_address", &self.supplicant_address)
+            .field("key_replay_counter", &self.key_replay_counter)
+            .finish_non_exhaustive()
+    }
+}
+impl<'a> FourWayHandshake<'a> {
+    const fn new(
+        role: HandshakeRole,
+        state: HandshakeState,
+        pmk: [u8; 32],
+        authenticator_address: MACAddress,
+        supplicant_address: MACAddress,
+        own_nonce: [u8; 32],
+        key_data: &'a [u8],
+    ) -> Self {
+        Self {
+            role,
+            state,
+            pmk,
+            authenticator_address,
+            supplicant_address,
+            own_nonce,
+            peer_nonce: [0x00; 32],
+            key_replay_counter: None,
+            ptk: None,
+            key_data,
+        }
+    }
+    /// Create a new handshake in the authenticator role.
+    ///
+    /// The `key_data` is sent in message 3 and should contain the RSN element of the authenticator.
+    pub const fn new_authenticator(
+        pmk: [u8; 32],
+        authenticator_address: MACAddress,
+        supplicant_address: MACAddress,
+        anonce: [u8; 32],
+        key_data: &'a [u8],
+    ) -> Self {
+        Self::new(
+            HandshakeRole::Authenticator,
+            HandshakeState::Idle,
+            pmk,
+            authenticator_address,
+            supplicant_address,
+            anonce,
+            key_data,
+        )
+    }
+    /// Create a new handshake in the supplicant role.
+    ///
+    /// The `key_data` is sent in message 2 and should contain the RSN element of the supplicant.
+    pub const fn new_supplicant(
+        pmk: [u8; 32],
+        authenticator_address: MACAddress,
+        supplicant_address: MACAddress,
+        snonce: [u8; 32],
+        key_data: &'a [u8],
+    ) -> Self {
+        Self::new(
+            HandshakeRole::Supplicant,
+            HandshakeState::WaitingForMessage(1),
+            pmk,
+            authenticator_address,
+            supplicant_address,
+            snonce,
+            key_data,
+        )
+    }
+    /// Returns the role of this party.
+    pub const fn role(&self) -> HandshakeRole {
+        self.role
+    }
+    /// Returns the current state of the handshake.
+    pub const fn state(&self) -> HandshakeState {
+        self.state
+    }
+    /// Check if the handshake completed successfully.
+    pub const fn is_complete(&self) -> bool {
+        matches!(self.state, HandshakeState::Complete)
+    }
+    /// Returns the last key replay counter used in the handshake.
+    ///
+    /// This is required for continuing with the [GroupKeyHandshake].
+    pub const fn key_replay_counter(&self) -> Option<u64> {
+        self.key_replay_counter
+    }
+    /// Returns the derived keys, once the handshake is complete.
+    pub const fn ptk(&self) -> Option<PairwiseTransientKey> {
+        if self.is_complete() {
+            self.ptk
+        } else {
+            None
+        }
+    }
+
+    /// Derive the PTK from the nonces.
+    fn derive_ptk(&mut self) -> PairwiseTransientKey {
+        let (anonce, snonce) = match self.role {
+            HandshakeRole::Authenticator => (&self.own_nonce, &self.peer_nonce),
+            HandshakeRole::Supplicant => (&self.peer_nonce, &self.own_nonce),
+        };
+        let ptk = partition_ptk(&derive_ptk(
+            &self.pmk,
+            self.authenticator_address,
+            self.supplicant_address,
+            anonce,
+            snonce,
+        ));
+        self.ptk = Some(ptk);
+        ptk
+    }
+    /// Create an EAPOL-Key frame and calculate the MIC, if requested in the key information.
+    fn create_frame(
+        &self,
+        key_information: KeyInformation,
+        key_replay_counter: u64,
+        key_nonce: [u8; 32],
+        key_data: &'a [u8],
+    ) -> EAPOLKeyFrame<'a> {
+        let mut frame = EAPOLKeyFrame {
+            protocol_version: EAPOL_PROTOCOL_VERSION,
+            key_information: key_information
+                .with_key_descriptor_version(KEY_DESCRIPTOR_VERSION)
+                .with_pairwise(true),
+            // Only messages 1 and 3 carry the key length, it's zero in messages 2 and 4 (12.7.6.3 and 12.7.6.5).
+            key_length: if key_information.ack() { KEY_LENGTH } else { 0 },
+            key_replay_counter,
+            key_nonce,
+            key_data,
+            ..Default::default()
+        };
+        if let (true, Some(ptk)) = (key_information.mic(), self.ptk) {
+            frame.key_mic = calculate_eapol_key_mic(&ptk.kck, &frame);
+        }
+        frame
+    }
+    /// Verify the MIC of a received frame with the current PTK.
+    fn verify_mic(&self, frame: &EAPOLKeyFrame<'_>) -> Result<(), HandshakeError> {
+        match self.ptk {
+            Some(ptk)
+                if bool::from(calculate_eapol_key_mic(&ptk.kck, frame).ct_eq(&frame.key_mic)) =>
+            {
+                Ok(())
+            }
+            _ => Err(HandshakeError::MICMismatch),
+        }
+    }
+
+    /// Start the handshake, by generating message 1.
+    ///
+    /// This returns [None], if this party isn't an idle authenticator.
+    pub fn initiate(&mut self) -> Option<EAPOLKeyFrame<'a>> {
+        if self.role != HandshakeRole::Authenticator || self.state != HandshakeState::Idle {
+            return None;
+        }
+        let key_replay_counter = self.key_replay_counter.map_or(0, |counter| counter + 1);
+        self.key_replay_counter = Some(key_replay_counter);
+        self.state = HandshakeState::WaitingForMessage(2);
+        Some(self.create_frame(
+            KeyInformation::new().with_ack(true),
+            key_replay_counter,
+            self.own_nonce,
+            &[],
+        ))
+    }
+    /// Process a received message and advance the handshake.
+    ///
+    /// If a response has to be sent, it's returned. If an error is returned, the state remains unchanged.
+    pub fn process_message(
+        &mut self,
+        frame: &EAPOLKeyFrame<'_>,
+    ) -> Result<Option<EAPOLKeyFrame<'a>>, HandshakeError> {
+        let key_information = frame.key_information;
+        if !key_information.pairwise() {
+            return Err(HandshakeError::UnexpectedMessage);
+        }
+        match (self.role, self.state) {
+            (HandshakeRole::Supplicant, HandshakeState::WaitingForMessage(1 | 3))
+                if key_information.ack() && !key_information.mic() =>
+            {
+                // Message 1 may be retransmitted, while we're waiting for message 3.
+                if self
+                    .key_replay_counter
+                    .map_or(false, |counter| frame.key_replay_counter < counter)
+                {
+                    return Err(HandshakeError::ReplayCounterMismatch);
+                }
+                self.key_replay_counter = Some(frame.key_replay_counter);
+                self.peer_nonce = frame.key_nonce;
+                self.derive_ptk();
+                self.state = HandshakeState::WaitingForMessage(3);
+                Ok(Some(self.create_frame(
+                    KeyInformation::new().with_mic(true),
+                    frame.key_replay_counter,
+                    self.own_nonce,
+                    self.key_data,
+                )))
+            }
+            (HandshakeRole::Supplicant, HandshakeState::WaitingForMessage(3))
+                if key_information.ack() && key_information.mic() && key_information.install() =>
+            {
+                if self
+                    .key_replay_counter
+                    .map_or(false, |counter| frame.key_replay_counter <= counter)
+                {
+                    return Err(HandshakeError::ReplayCounterMismatch);
+                }
+                if frame.key_nonce != self.peer_nonce {
+                    return Err(HandshakeError::NonceMismatch);
+                }
+                self.verify_mic(frame)?;
+                self.key_replay_counter = Some(frame.key_replay_counter);
+                self.state = HandshakeState::Complete;
+                Ok(Some(self.create_frame(
+                    KeyInformation::new().with_mic(true).with_secure(true),
+                    frame.key_replay_counter,
+                    [0x00; 32],
+                    &[],
+                )))
+            }
+            (HandshakeRole::Authenticator, HandshakeState::WaitingForMessage(2))
+                if !key_information.ack() && key_information.mic() && !key_information.secure() =>
+            {
+                if Some(frame.key_replay_counter) != self.key_replay_counter {
+                    return Err(HandshakeError::ReplayCounterMismatch);
+                }
+                let previous_peer_nonce = self.peer_nonce;
+                self.peer_nonce = frame.key_nonce;
+                self.derive_ptk();
+                if let Err(err) = self.verify_mic(frame) {
+                    self.peer_nonce = previous_peer_nonce;
+                    self.ptk = None;
+                    return Err(err);
+                }
+                let key_replay_counter = frame.key_replay_counter + 1;
+                self.key_replay_counter = Some(key_replay_counter);
+                self.state = HandshakeState::WaitingForMessage(4);
+                Ok(Some(
+                    self.create_frame(
+                        KeyInformation::new()
+                            .with_ack(true)
+                            .with_mic(true)
+                            .with_install(true)
+                            .with_secure(true),
+                        key_replay_counter,
+                        self.own_nonce,
+                        self.key_data,
+                    ),
+                ))
+            }
+            (HandshakeRole::Authenticator, HandshakeState::WaitingForMessage(4))
+                if !key_information.ack() && key_information.mic() && key_information.secure() =>
+            {
+                if Some(frame.key_replay_counter) != self.key_replay_counter {
+                    return Err(HandshakeError::ReplayCounterMismatch);
+                }
+                self.verify_mic(frame)?;
+                self.state = HandshakeState::Complete;
+                Ok(None)
+            }
+            _ => Err(HandshakeError::UnexpectedMessage),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// A GTK for CCMP-128, which is used for protecting group addressed frames.
+///
+/// The key itself isn't included in the [Debug] output.
+pub struct GroupTemporalKey {
+    /// The key ID of the GTK, which is in the range one to three, since key ID zero is reserved for the PTK.
+    pub key_id: u8,
+    pub gtk: [u8; 16],
+    /// The PN of the last group addressed frame, which was transmitted with this GTK.
+    pub key_rsc: u64,
+}
+impl Debug for GroupTemporalKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GroupTemporalKey")
+            .field("key_id", &self.key_id)
+            .field("key_rsc", &self.key_rsc)
+            .finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "defmt")]
+impl defmt::Format for GroupTemporalKey {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(
+            fmt,
+            "GroupTemporalKey {{ key_id: {}, key_rsc: {}, .. }}",
+            self.key_id,
+            self.key_rsc
+        )
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+/// A state machine for the group key handshake, as specified in 12.7.7 of IEEE 802.11-2020.
+///
+/// This distributes a new GTK to the supplicant, after the [FourWayHandshake] has completed.
+/// The GTK is carried in a [GTK KDE](GTKKDE), which is wrapped with the KEK of the PTK.
+/// A handshake can be used for multiple rekeys, by initiating it again once complete.
+pub struct GroupKeyHandshake {
+    role: HandshakeRole,
+    state: HandshakeState,
+    ptk: PairwiseTransientKey,
+    key_replay_counter: u64,
+    gtk: Option<GroupTemporalKey>,
+    wrapped_key_data: [u8; wrapped_eapol_key_data_len(GTK_KDE_LENGTH)],
+}
+impl Debug for GroupKeyHandshake {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // The PTK and GTK are left out, so they don't end up in logs.
+        f.debug_struct("GroupKeyHandshake")
+            .field("role", &self.role)
+            .field("state", &self.state)
+            .field("key_replay_counter", &self.key_replay_counter)
+            .finish_non_exhaustive()
+    }
+}
+impl GroupKeyHandshake {
+    const fn new(
+        role: HandshakeRole,
+        state: HandshakeState,
+        ptk: PairwiseTransientKey,
+        key_replay_counter: u64,
+    ) -> Self {
+        Self {
+            role,
+            state,
+            ptk,
+            key_replay_counter,
+            gtk: None,
+            wrapped_key_data: [0x00; wrapped_eapol_key_data_len(GTK_KDE_LENGTH)],
+        }
+    }
+    /// Create a new handshake in the authenticator role.
+    ///
+    /// The `key_replay_counter` is the last one used with the supplicant, which can be obtained from [FourWayHandshake::key_replay_counter].
+    pub const fn new_authenticator(ptk: PairwiseTransientKey, key_replay_counter: u64) -> Self {
+        Self::new(
+            HandshakeRole::Authenticator,
+            HandshakeState::Idle,
+            ptk,
+            key_replay_counter,
+        )
+    }
+    /// Create a new handshake in the supplicant role.
+    ///
+    /// The `key_replay_counter` is the last one received from the authenticator, which can be obtained from [FourWayHandshake::key_replay_counter].
+    pub const fn new_supplicant(ptk: PairwiseTransientKey, key_replay_counter: u64) -> Self {
+        Self::new(
+            HandshakeRole::Supplicant,
+            HandshakeState::WaitingForMessage(1),
+            ptk,
+            key_replay_counter,
+        )
+    }
+    /// Returns the role of this party.
+    pub const fn role(&self) -> HandshakeRole {
+        self.role
+    }
+    /// Returns the current state of the handshake.
+    pub const fn state(&self) -> HandshakeState {
+        self.state
+    }
+    /// Check if the handshake completed successfully.
+    pub const fn is_complete(&self) -> bool {
+        matches!(self.state, HandshakeState::Complete)
+    }
+    /// Returns the GTK, once the handshake is complete.
+    pub const fn gtk(&self) -> Option<GroupTemporalKey> {
+        if self.is_complete() {
+            self.gtk
+        } else {
+            None
+        }
+    }
+
+    /// Create an EAPOL-Key frame and calculate the MIC.
+    fn create_frame<'a>(
+        &self,
+        key_information: KeyInformation,
+        key_rsc: u64,
+        key_data: &'a [u8],
+    ) -> EAPOLKeyFrame<'a> {
+        let mut frame = EAPOLKeyFrame {
+            protocol_version: EAPOL_PROTOCOL_VERSION,
+            key_information: key_information
+                .with_key_descriptor_version(KEY_DESCRIPTOR_VERSION)
+                .with_mic(true)
+                .with_secure(true),
+            key_replay_counter: self.key_replay_counter,
+            key_rsc,
+            key_data,
+            ..Default::default()
+        };
+        frame.key_mic = calculate_eapol_key_mic(&self.ptk.kck, &frame);
+        frame
+    }
+    /// Verify the MIC of a received frame with the KCK.
+    fn verify_mic(&self, frame: &EAPOLKeyFrame<'_>) -> Result<(), HandshakeError> {
+        if bool::from(calculate_eapol_key_mic(&self.ptk.kck, frame).ct_eq(&frame.key_mic)) {
+            Ok(())
+        } else {
+            Err(HandshakeError::MICMismatch)
+        }
+    }
+    /// Extract the GTK from the wrapped key data of message 1.
+    fn unwrap_gtk(&self, frame: &EAPOLKeyFrame<'_>) -> Result<GroupTemporalKey, HandshakeError> {
+        let mut key_data = [0x00; MAX_GROUP_KEY_DATA_LENGTH];
+        let key_data_length = unwrap_eapol_key_data(&self.ptk.kek, frame.key_data, &mut key_data)
+            .map_err(|_| HandshakeError::InvalidKeyData)?;
+        let gtk_kde = ReadElements {
+            bytes: &key_data[..key_data_length],
+        }
+        .get_first_element::<GTKKDE>()
+        .ok_or(HandshakeError::InvalidKeyData)?;
+        Ok(GroupTemporalKey {
+            key_id: gtk_kde.key_id,
+            gtk: gtk_kde
+                .gtk
+                .try_into()
+                .map_err(|_| HandshakeError::InvalidKeyData)?,
+            key_rsc: frame.key_rsc,
+        })
+    }
+
+    /// Start a group rekey, by generating message 1, which distributes the specified GTK.
+    ///
+    /// This returns [None], if this party isn't an authenticator or a rekey is already in progress.
+    pub fn initiate(&mut self, gtk: GroupTemporalKey) -> Option<EAPOLKeyFrame<'_>> {
+        if self.role != HandshakeRole::Authenticator
+            || !matches!(self.state, HandshakeState::Idle | HandshakeState::Complete)
+        {
+            return None;
+        }
+        let mut gtk_kde = [0x00; GTK_KDE_LENGTH];
+        gtk_kde
+            .pwrite(
+                GTKKDE {
+                    key_id: gtk.key_id,
+                    tx: true,
+                    gtk: &gtk.gtk,
+                }
+                .wrap(),
+                0,
+            )
+            .ok()?;
+        wrap_eapol_key_data(&self.ptk.kek, &gtk_kde, &mut self.wrapped_key_data).ok()?;
+
+        self.key_replay_counter += 1;
+        self.gtk = Some(gtk);
+        self.state = HandshakeState::WaitingForMessage(2);
+        Some(
+            self.create_frame(
+                KeyInformation::new()
+                    .with_ack(true)
+                    .with_encrypted_key_data(true),
+                gtk.key_rsc,
+                &self.wrapped_key_data,
+            ),
+        )
+    }
+    /// Process a received message and advance the handshake.
+    ///
+    /// If a response has to be sent, it's returned. If an error is returned, the state remains unchanged.
+    pub fn process_message(
+        &mut self,
+        frame: &EAPOLKeyFrame<'_>,
+    ) -> Result<Option<EAPOLKeyFrame<'static>>, HandshakeError> {
+        let key_information = frame.key_information;
+        if key_information.pairwise() || !key_information.mic() || !key_information.secure() {
+            return Err(HandshakeError::UnexpectedMessage);
+        }
+        match (self.role, self.state) {
+            (
+                HandshakeRole::Supplicant,
+                HandshakeState::WaitingForMessage(1) | HandshakeState::Complete,
+            ) if key_information.ack() && key_information.encrypted_key_data() => {
+                if frame.key_replay_counter <= self.key_replay_counter {
+                    return Err(HandshakeError::ReplayCounterMismatch);
+                }
+                self.verify_mic(frame)?;
+                let gtk = self.unwrap_gtk(frame)?;
+                self.key_replay_counter = frame.key_replay_counter;
+                self.gtk = Some(gtk);
+                self.state = HandshakeState::Complete;
+                Ok(Some(self.create_frame(KeyInformation::new(), 0, &[])))
+            }
+            (HandshakeRole::Authenticator, HandshakeState::WaitingForMessage(2))
+                if !key_information.ack() =>
+            {
+                if frame.key_replay_counter != self.key_replay_counter {
+                    return Err(HandshakeError::ReplayCounterMismatch);
+                }
+                self.verify_mic(frame)?;
+                self.state = HandshakeState::Complete;
+                Ok(None)
+            }
+            _ => Err(HandshakeError::UnexpectedMessage),
+        }
+    }
+}
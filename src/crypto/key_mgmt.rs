@@ -0,0 +1,110 @@
+use core::fmt::Debug;
+
+use hmac::{Hmac, Mac};
+use mac_parser::MACAddress;
+use scroll::Pwrite;
+use sha1::Sha1;
+
+use super::eapol::{EAPOLKeyFrame, EAPOL_KEY_FRAME_FIXED_LENGTH, EAPOL_KEY_MIC_OFFSET};
+
+/// The length of the PTK for CCMP-128.
+pub const PTK_LENGTH: usize = 48;
+
+/// The pseudo random function, as specified in 12.7.1.2 of IEEE 802.11-2020.
+///
+/// `N` is the output length in bytes and is usually 48 or 64.
+pub fn prf<const N: usize>(key: &[u8], label: &str, data: &[u8]) -> [u8; N] {
+    let mut output = [0x00; N];
+    for (i, chunk) in output.chunks_mut(20).enumerate() {
+        let mut hmac = Hmac::<Sha1>::new_from_slice(key).expect("HMAC accepts keys of any length.");
+        hmac.update(label.as_bytes());
+        hmac.update(&[0x00]);
+        hmac.update(data);
+        hmac.update(&[i as u8]);
+        chunk.copy_from_slice(&hmac.finalize().into_bytes()[..chunk.len()]);
+    }
+    output
+}
+/// Derive the PTK from the PMK, as specified in 12.7.1.3 of IEEE 802.11-2020.
+///
+/// The addresses and nonces are sorted internally, so the order in which they are supplied doesn't matter.
+pub fn derive_ptk(
+    pmk: &[u8; 32],
+    authenticator_address: MACAddress,
+    supplicant_address: MACAddress,
+    anonce: &[u8; 32],
+    snonce: &[u8; 32],
+) -> [u8; PTK_LENGTH] {
+    let (min_address, max_address) = if authenticator_address.0 < supplicant_address.0 {
+        (authenticator_address, supplicant_address)
+    } else {
+        (supplicant_address, authenticator_address)
+    };
+    let (min_nonce, max_nonce) = if anonce < snonce {
+        (anonce, snonce)
+    } else {
+        (snonce, anonce)
+    };
+
+    let mut data = [0x00; 76];
+    data[0..6].copy_from_slice(min_address.as_slice());
+    data[6..12].copy_from_slice(max_address.as_slice());
+    data[12..44].copy_from_slice(min_nonce);
+    data[44..76].copy_from_slice(max_nonce);
+
+    prf(pmk, "Pairwise key expansion", &data)
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// The keys contained in the PTK.
+///
+/// The keys aren't included in the [Debug] output.
+pub struct PairwiseTransientKey {
+    /// The EAPOL-Key confirmation key, used for the MIC.
+    pub kck: [u8; 16],
+    /// The EAPOL-Key encryption key, used for encrypting the key data.
+    pub kek: [u8; 16],
+    /// The temporal key, used for encrypting data frames.
+    pub tk: [u8; 16],
+}
+impl Debug for PairwiseTransientKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PairwiseTransientKey")
+            .finish_non_exhaustive()
+    }
+}
+/// Split the PTK into the KCK, KEK and TK.
+pub fn partition_ptk(ptk: &[u8; PTK_LENGTH]) -> PairwiseTransientKey {
+    let mut keys = PairwiseTransientKey::default();
+    keys.kck.copy_from_slice(&ptk[0..16]);
+    keys.kek.copy_from_slice(&ptk[16..32]);
+    keys.tk.copy_from_slice(&ptk[32..48]);
+    keys
+}
+/// Calculate the MIC of an EAPOL-Key frame with HMAC-SHA1-128.
+///
+/// The Key MIC field of the frame is treated as zero.
+pub fn calculate_eapol_key_mic(kck: &[u8; 16], frame: &EAPOLKeyFrame<'_>) -> [u8; 16] {
+    let mut fixed_fields = [0x00; EAPOL_KEY_FRAME_FIXED_LENGTH];
+    // The key data is hashed separately, so it doesn't need to fit into the buffer.
+    let _ = fixed_fields.pwrite(
+        EAPOLKeyFrame {
+            key_mic: [0x00; 16],
+            key_data: &[],
+            ..*frame
+        },
+        0,
+    );
+    // Fix up the lengths, which were written for an empty key data field.
+    let body_length = (EAPOL_KEY_FRAME_FIXED_LENGTH - 4 + frame.key_data.len()) as u16;
+    fixed_fields[2..4].copy_from_slice(&body_length.to_be_bytes());
+    fixed_fields[EAPOL_KEY_MIC_OFFSET + 16..]
+        .copy_from_slice(&(frame.key_data.len() as u16).to_be_bytes());
+
+    let mut hmac = Hmac::<Sha1>::new_from_slice(kck).expect("HMAC accepts keys of any length.");
+    hmac.update(&fixed_fields);
+    hmac.update(frame.key_data);
+    let mut mic = [0x00; 16];
+    mic.copy_from_slice(&hmac.finalize().into_bytes()[..16]);
+    mic
+}
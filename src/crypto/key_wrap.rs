@@ -0,0 +1,113 @@
+use aes::{
+    cipher::{BlockDecrypt, BlockEncrypt, KeyInit},
+    Aes128, Block,
+};
+
+use super::ccmp::check_buffer_length;
+
+/// The default initial value of the AES key wrap, as specified in RFC 3394.
+const KEY_WRAP_IV: [u8; 8] = [0xa6; 8];
+/// The first byte of the padding appended to the key data.
+const KEY_DATA_PADDING: u8 = 0xdd;
+
+/// Calculates the length of the wrapped key data, from the length of the plaintext key data.
+///
+/// The key data is padded to a multiple of eight bytes and at least 16 bytes, after which the eight byte integrity check value is prepended.
+pub const fn wrapped_eapol_key_data_len(key_data_len: usize) -> usize {
+    let padded_len = if key_data_len < 16 {
+        16
+    } else {
+        (key_data_len + 7) & !7
+    };
+    padded_len + 8
+}
+
+/// Wrap the key data of an EAPOL-Key frame with the KEK, as specified in 12.7.2 of IEEE 802.11-2020.
+///
+/// This pads the key data and applies the AES key wrap specified in RFC 3394.
+/// The wrapped key data is written to `out`, which has to be at least [wrapped_eapol_key_data_len] bytes long.
+/// On success, the amount of bytes written is returned.
+pub fn wrap_eapol_key_data(
+    kek: &[u8; 16],
+    key_data: &[u8],
+    out: &mut [u8],
+) -> Result<usize, scroll::Error> {
+    let output_len = wrapped_eapol_key_data_len(key_data.len());
+    check_buffer_length(output_len, out.len())?;
+
+    let (a, r) = out[..output_len].split_at_mut(8);
+    a.copy_from_slice(&KEY_WRAP_IV);
+    r[..key_data.len()].copy_from_slice(key_data);
+    if let Some((first, rest)) = r[key_data.len()..].split_first_mut() {
+        *first = KEY_DATA_PADDING;
+        rest.fill(0x00);
+    }
+
+    let cipher = Aes128::new(kek.into());
+    let n = r.len() / 8;
+    let mut block = Block::default();
+    for j in 0..6 {
+        for (i, r_i) in r.chunks_mut(8).enumerate() {
+            block[..8].copy_from_slice(a);
+            block[8..].copy_from_slice(r_i);
+            cipher.encrypt_block(&mut block);
+            let t = (n * j + i + 1) as u64;
+            for (a, (b, t)) in a.iter_mut().zip(block[..8].iter().zip(t.to_be_bytes())) {
+                *a = b ^ t;
+            }
+            r_i.copy_from_slice(&block[8..]);
+        }
+    }
+
+    Ok(output_len)
+}
+/// Unwrap the key data of an EAPOL-Key frame with the KEK.
+///
+/// The unwrapped key data is written to `out`, which has to be at least eight bytes shorter than the `wrapped_key_data`, and it's length is returned.
+/// The padding isn't removed, since it's parsed as an empty vendor specific element, when reading the key data with [ReadElements](crate::elements::ReadElements).
+/// If the integrity check fails, an error is returned.
+pub fn unwrap_eapol_key_data(
+    kek: &[u8; 16],
+    wrapped_key_data: &[u8],
+    out: &mut [u8],
+) -> Result<usize, scroll::Error> {
+    if wrapped_key_data.len() < 24 || wrapped_key_data.len() % 8 != 0 {
+        return Err(scroll::Error::BadInput {
+            size: wrapped_key_data.len(),
+            msg: "The wrapped key data has to be a multiple of eight bytes and at least 24 bytes long.",
+        });
+    }
+    let output_len = wrapped_key_data.len() - 8;
+    check_buffer_length(output_len, out.len())?;
+
+    let mut a = [0x00; 8];
+    a.copy_from_slice(&wrapped_key_data[..8]);
+    let r = &mut out[..output_len];
+    r.copy_from_slice(&wrapped_key_data[8..]);
+
+    let cipher = Aes128::new(kek.into());
+    let n = r.len() / 8;
+    let mut block = Block::default();
+    for j in (0..6).rev() {
+        for (i, r_i) in r.chunks_mut(8).enumerate().rev() {
+            let t = (n * j + i + 1) as u64;
+            for (b, (a, t)) in block[..8].iter_mut().zip(a.iter().zip(t.to_be_bytes())) {
+                *b = a ^ t;
+            }
+            block[8..].copy_from_slice(r_i);
+            cipher.decrypt_block(&mut block);
+            a.copy_from_slice(&block[..8]);
+            r_i.copy_from_slice(&block[8..]);
+        }
+    }
+    if a != KEY_WRAP_IV {
+        // Don't leak the unauthenticated key data.
+        r.fill(0x00);
+        return Err(scroll::Error::BadInput {
+            size: wrapped_key_data.len(),
+            msg: "The integrity check of the wrapped key data failed.",
+        });
+    }
+
+    Ok(output_len)
+}
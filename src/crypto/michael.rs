@@ -1,3 +1,10 @@
+use subtle::ConstantTimeEq;
+
+use crate::data_frame::header::DataFrameHeader;
+
+/// The length of the Michael MIC in bytes.
+pub const MICHAEL_MIC_LENGTH: usize = 8;
+
 fn xswap(l: u32) -> u32 {
     ((l & 0xff00ff00) >> 8) | ((l & 0x00ff00ff) << 8)
 }
@@ -13,32 +20,106 @@ pub fn michael_block_function(l: u32, r: u32) -> (u32, u32) {
     l = l.wrapping_add(r);
     (l, r)
 }
+/// The internal state of the michael algorithm, which allows processing the input in multiple parts.
+struct MichaelState {
+    l: u32,
+    r: u32,
+    block: [u8; 4],
+    block_length: usize,
+}
+impl MichaelState {
+    fn new(key: u64) -> Self {
+        Self {
+            l: ((key >> 32) as u32).to_be(),
+            r: (key as u32).to_be(),
+            block: [0x00; 4],
+            block_length: 0,
+        }
+    }
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.block[self.block_length] = *byte;
+            self.block_length += 1;
+            if self.block_length == 4 {
+                self.l ^= u32::from_le_bytes(self.block);
+                (self.l, self.r) = michael_block_function(self.l, self.r);
+                self.block_length = 0;
+            }
+        }
+    }
+    fn finalize(mut self) -> u64 {
+        // Pad the last block with 0x5a and zeroes.
+        self.block[self.block_length..].fill(0x00);
+        self.block[self.block_length] = 0x5a;
+        self.l ^= u32::from_le_bytes(self.block);
+        (self.l, self.r) = michael_block_function(self.l, self.r);
+
+        // Run the final block, which is all zeroes.
+        (self.l, self.r) = michael_block_function(self.l, self.r);
+
+        let mut mic = [0x00; 8];
+        mic[..4].copy_from_slice(self.l.to_le_bytes().as_slice());
+        mic[4..8].copy_from_slice(self.r.to_le_bytes().as_slice());
+        u64::from_be_bytes(mic)
+    }
+}
 /// Compute the michael MIC of the bytes, with the key.
 pub fn michael(key: u64, bytes: &[u8]) -> u64 {
     // NOTE: This implementation is partially adapted from https://github.com/torvalds/linux/blob/master/net/mac80211/michael.c
-    let (mut l, mut r) = (((key >> 32) as u32).to_be(), (key as u32).to_be());
-
-    let blocks = bytes.len() / 4;
-    let left = bytes.len() % 4;
-
-    for i in 0..blocks {
-        let block = &bytes[(i * 4)..(i * 4 + 4)];
-        let block = u32::from_le_bytes(block.try_into().unwrap());
-        l ^= block;
-        (l, r) = michael_block_function(l, r);
+    let mut state = MichaelState::new(key);
+    state.update(bytes);
+    state.finalize()
+}
+/// Compute the michael MIC over the MSDU of a data frame, as specified in 12.5.2.3.3 of IEEE 802.11-2020.
+///
+/// The input consists of the DA, SA, priority and three reserved bytes, followed by the MSDU.
+/// This returns [None], if the header doesn't contain a DA or SA, which is the case for A-MSDUs.
+fn michael_for_msdu(
+    mic_key: &[u8; 8],
+    header: &DataFrameHeader,
+    msdu: &[u8],
+) -> Option<[u8; MICHAEL_MIC_LENGTH]> {
+    let mut state = MichaelState::new(u64::from_be_bytes(*mic_key));
+    state.update(header.destination_address()?.as_slice());
+    state.update(header.source_address()?.as_slice());
+    // The priority is the TID for QoS data frames and zero otherwise.
+    state.update(&[header.qos.map_or(0, |qos| qos[0] & 0x0f), 0x00, 0x00, 0x00]);
+    state.update(msdu);
+    Some(state.finalize().to_be_bytes())
+}
+/// Verify the michael MIC at the end of the MSDU.
+///
+/// The `msdu` has to include the trailing MIC. This returns false, if the MIC doesn't match, the MSDU is shorter than the MIC or no DA or SA are present.
+/// A failed verification should be reported, so that TKIP countermeasures can be started.
+pub fn verify_michael_mic(mic_key: &[u8; 8], header: &DataFrameHeader, msdu: &[u8]) -> bool {
+    let Some(msdu_length) = msdu.len().checked_sub(MICHAEL_MIC_LENGTH) else {
+        return false;
+    };
+    let (msdu, mic) = msdu.split_at(msdu_length);
+    michael_for_msdu(mic_key, header, msdu)
+        .map_or(false, |expected_mic| bool::from(expected_mic.ct_eq(mic)))
+}
+/// Calculate the michael MIC over the first `msdu_length` bytes of `buf` and append it.
+///
+/// On success, the length of the MSDU including the MIC is returned.
+pub fn append_michael_mic(
+    mic_key: &[u8; 8],
+    header: &DataFrameHeader,
+    buf: &mut [u8],
+    msdu_length: usize,
+) -> Result<usize, scroll::Error> {
+    let total_length = msdu_length + MICHAEL_MIC_LENGTH;
+    if buf.len() < total_length {
+        return Err(scroll::Error::TooBig {
+            size: total_length,
+            len: buf.len(),
+        });
     }
-    let mut block = [0x00; 4];
-    block[..left].copy_from_slice(&bytes[(blocks * 4)..(blocks * 4 + left)]);
-    block[left] = 0x5a;
-    let block = u32::from_le_bytes(block);
-    l ^= block;
-    (l, r) = michael_block_function(l, r);
-
-    // Run the final block, which is all zeroes.
-    (l, r) = michael_block_function(l, r);
-
-    let mut mic = [0x00; 8];
-    mic[..4].copy_from_slice(l.to_le_bytes().as_slice());
-    mic[4..8].copy_from_slice(r.to_le_bytes().as_slice());
-    u64::from_be_bytes(mic)
+    let mic =
+        michael_for_msdu(mic_key, header, &buf[..msdu_length]).ok_or(scroll::Error::BadInput {
+            size: 0,
+            msg: "The data frame header didn't contain a DA and SA.",
+        })?;
+    buf[msdu_length..total_length].copy_from_slice(&mic);
+    Ok(total_length)
 }
@@ -2,4 +2,37 @@ mod psk;
 pub use psk::map_passphrase_to_psk;
 
 mod michael;
-pub use michael::{michael, michael_block_function};
+pub use michael::{
+    append_michael_mic, michael, michael_block_function, verify_michael_mic, MICHAEL_MIC_LENGTH,
+};
+
+mod crypto_header;
+pub use crypto_header::CryptoHeader;
+
+mod replay;
+pub use replay::ReplayWindow;
+
+mod ccmp;
+pub use ccmp::{
+    ccmp_decrypt, ccmp_encrypt, ccmp_output_len, CCMP_128_MIC_LENGTH, CCMP_HEADER_LENGTH,
+};
+
+mod gcmp;
+pub use gcmp::{gcmp_decrypt, gcmp_encrypt, gcmp_output_len, GCMP_HEADER_LENGTH, GCMP_MIC_LENGTH};
+
+mod eapol;
+pub use eapol::{EAPOLKeyFrame, KeyInformation, EAPOL_KEY_FRAME_FIXED_LENGTH};
+
+mod key_mgmt;
+pub use key_mgmt::{
+    calculate_eapol_key_mic, derive_ptk, partition_ptk, prf, PairwiseTransientKey, PTK_LENGTH,
+};
+
+mod key_wrap;
+pub use key_wrap::{unwrap_eapol_key_data, wrap_eapol_key_data, wrapped_eapol_key_data_len};
+
+mod handshake;
+pub use handshake::{
+    FourWayHandshake, GroupKeyHandshake, GroupTemporalKey, HandshakeError, HandshakeRole,
+    HandshakeState,
+};
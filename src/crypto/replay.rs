@@ -0,0 +1,43 @@
+/// The number of key IDs, which can be used for protecting frames.
+const KEY_ID_COUNT: usize = 4;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// Replay detection for received protected frames, as specified in 12.5.3.4.4 of IEEE 802.11-2020.
+///
+/// This tracks the highest accepted PN for every key ID and rejects frames, which don't have a strictly higher PN.
+/// Since there's no window, frames received out of order are rejected, which is the expected behaviour for all frames, that aren't part of a block ack agreement.
+/// For frames received under a block ack agreement, one instance per TID should be used after reordering.
+pub struct ReplayWindow {
+    last_accepted_pn: [Option<u64>; KEY_ID_COUNT],
+}
+impl ReplayWindow {
+    /// Create a new replay window, which accepts any PN for all key IDs.
+    pub const fn new() -> Self {
+        Self {
+            last_accepted_pn: [None; KEY_ID_COUNT],
+        }
+    }
+    /// Check if the PN is higher than the last accepted one for the key ID and update it, if that's the case.
+    ///
+    /// Only the lower two bits of the `key_id` are used.
+    /// If this returns false, the frame is a replay and should be dropped.
+    pub fn check_and_update(&mut self, key_id: u8, pn: u64) -> bool {
+        let last_accepted_pn = &mut self.last_accepted_pn[(key_id & 0b11) as usize];
+        if last_accepted_pn.map_or(false, |last_accepted_pn| pn <= last_accepted_pn) {
+            return false;
+        }
+        *last_accepted_pn = Some(pn);
+        true
+    }
+    /// Returns the last accepted PN for the key ID.
+    pub const fn last_accepted_pn(&self, key_id: u8) -> Option<u64> {
+        self.last_accepted_pn[(key_id & 0b11) as usize]
+    }
+    /// Reset the tracked PN for the key ID.
+    ///
+    /// This has to be done, when a new key is installed for the key ID, since the PN starts over.
+    pub fn reset(&mut self, key_id: u8) {
+        self.last_accepted_pn[(key_id & 0b11) as usize] = None;
+    }
+}
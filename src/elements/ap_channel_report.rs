@@ -0,0 +1,61 @@
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+use super::{Element, ElementID};
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The AP Channel Report element lists the channels, on which APs may be found.
+///
+/// This is used by STAs to limit the channels scanned, when searching for neighboring APs.
+pub struct APChannelReportElement<'a> {
+    /// The operating class, in which the channels are located.
+    pub operating_class: u8,
+    /// The raw list of channel numbers.
+    pub channel_list: &'a [u8],
+}
+impl<'a> APChannelReportElement<'a> {
+    /// Returns an [Iterator] over the channel numbers.
+    pub fn channels(&self) -> impl Iterator<Item = u8> + 'a {
+        self.channel_list.iter().copied()
+    }
+}
+impl MeasureWith<()> for APChannelReportElement<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        1 + self.channel_list.len()
+    }
+}
+impl<'a> TryFromCtx<'a> for APChannelReportElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let operating_class = from.gread(&mut offset)?;
+        let channel_list = &from[offset..];
+
+        Ok((
+            Self {
+                operating_class,
+                channel_list,
+            },
+            from.len(),
+        ))
+    }
+}
+impl TryIntoCtx for APChannelReportElement<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.operating_class, &mut offset)?;
+        buf.gwrite(self.channel_list, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl Element for APChannelReportElement<'_> {
+    const ELEMENT_ID: ElementID = ElementID::Id(51);
+    type ReadType<'a> = APChannelReportElement<'a>;
+}
@@ -0,0 +1,81 @@
+use core::time::Duration;
+
+use bitfield_struct::bitfield;
+use scroll::{
+    ctx::{MeasureWith, SizeWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use crate::common::TU;
+
+use super::{Element, ElementID};
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The Idle Options field of the [BSSMaxIdlePeriodElement].
+pub struct IdleOptions {
+    /// Indicates, that only protected frames are accepted as keep-alive frames.
+    pub protected_keep_alive_required: bool,
+    #[bits(7)]
+    __: u8,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The BSS Max Idle Period element indicates the time, for which an AP doesn't disassociate a STA, which didn't transmit any frames.
+pub struct BSSMaxIdlePeriodElement {
+    /// The max idle period in units of 1000 TUs.
+    ///
+    /// Use [Self::max_idle_period_tu] to get the duration.
+    pub max_idle_period: u16,
+    pub idle_options: IdleOptions,
+}
+impl BSSMaxIdlePeriodElement {
+    /// Returns a [Duration] for the max idle period.
+    pub const fn max_idle_period_tu(&self) -> Duration {
+        Duration::from_micros(TU.as_micros() as u64 * 1000 * self.max_idle_period as u64)
+    }
+}
+impl SizeWith for BSSMaxIdlePeriodElement {
+    fn size_with(_ctx: &()) -> usize {
+        3
+    }
+}
+impl MeasureWith<()> for BSSMaxIdlePeriodElement {
+    fn measure_with(&self, ctx: &()) -> usize {
+        Self::size_with(ctx)
+    }
+}
+impl TryFromCtx<'_> for BSSMaxIdlePeriodElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let max_idle_period = from.gread_with(&mut offset, Endian::Little)?;
+        let idle_options = IdleOptions::from_bits(from.gread(&mut offset)?);
+
+        Ok((
+            Self {
+                max_idle_period,
+                idle_options,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for BSSMaxIdlePeriodElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite_with(self.max_idle_period, &mut offset, Endian::Little)?;
+        buf.gwrite(self.idle_options.into_bits(), &mut offset)?;
+
+        Ok(offset)
+    }
+}
+
+impl Element for BSSMaxIdlePeriodElement {
+    const ELEMENT_ID: ElementID = ElementID::Id(90);
+    type ReadType<'a> = Self;
+}
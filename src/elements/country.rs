@@ -0,0 +1,236 @@
+//! This module contains support for the Country element.
+
+use core::marker::PhantomData;
+
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+use super::{Element, ElementID};
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The environment, to which the regulations of the country apply.
+    pub enum CountryEnvironment: u8 {
+        #[default]
+        Any => b' ',
+        Outdoor => b'O',
+        Indoor => b'I',
+        NonCountry => b'X'
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A triplet in the Country element.
+pub enum CountrySubbandTriplet {
+    /// A range of channels and the maximum transmit power allowed on them.
+    Channels {
+        first_channel: u8,
+        number_of_channels: u8,
+        /// The maximum transmit power in dBm.
+        max_transmit_power: i8,
+    },
+    /// An operating class, to which the following channel triplets refer.
+    ///
+    /// The operating extension identifier is always greater than or equal to 201.
+    OperatingClass {
+        operating_extension_identifier: u8,
+        operating_class: u8,
+        coverage_class: u8,
+    },
+}
+impl CountrySubbandTriplet {
+    /// The first byte of a triplet, from which on it's an operating triplet.
+    pub const FIRST_OPERATING_EXTENSION_IDENTIFIER: u8 = 201;
+
+    /// Parse a triplet from it's raw bytes.
+    pub const fn from_bytes(bytes: [u8; 3]) -> Self {
+        if bytes[0] >= Self::FIRST_OPERATING_EXTENSION_IDENTIFIER {
+            Self::OperatingClass {
+                operating_extension_identifier: bytes[0],
+                operating_class: bytes[1],
+                coverage_class: bytes[2],
+            }
+        } else {
+            Self::Channels {
+                first_channel: bytes[0],
+                number_of_channels: bytes[1],
+                max_transmit_power: bytes[2] as i8,
+            }
+        }
+    }
+    /// Serialize the triplet into it's raw bytes.
+    pub const fn to_bytes(self) -> [u8; 3] {
+        match self {
+            Self::Channels {
+                first_channel,
+                number_of_channels,
+                max_transmit_power,
+            } => [first_channel, number_of_channels, max_transmit_power as u8],
+            Self::OperatingClass {
+                operating_extension_identifier,
+                operating_class,
+                coverage_class,
+            } => [
+                operating_extension_identifier,
+                operating_class,
+                coverage_class,
+            ],
+        }
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// An [Iterator] over the [CountrySubbandTriplets](CountrySubbandTriplet) of a Country element.
+pub struct CountrySubbandTripletIterator<'a> {
+    bytes: &'a [u8],
+}
+impl<'a> CountrySubbandTripletIterator<'a> {
+    /// Create a new iterator over the triplets.
+    ///
+    /// Any trailing bytes, which don't form a complete triplet, are ignored.
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+}
+impl Iterator for CountrySubbandTripletIterator<'_> {
+    type Item = CountrySubbandTriplet;
+    fn next(&mut self) -> Option<Self::Item> {
+        let triplet = self.bytes.pread::<[u8; 3]>(0).ok()?;
+        self.bytes = &self.bytes[3..];
+        Some(CountrySubbandTriplet::from_bytes(triplet))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.bytes.len() / 3;
+        (remaining, Some(remaining))
+    }
+}
+impl ExactSizeIterator for CountrySubbandTripletIterator<'_> {}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, Hash)]
+/// The Country element contains the regulatory information for the country, in which the STA is located.
+///
+/// The `subband_triplets` field is an [Iterator] over [CountrySubbandTriplet], which allows passing the triplets agnostic of the collection.
+/// When deserializing this struct, the Iterator is [CountrySubbandTripletIterator].
+/// The element is padded to an even length, which is handled transparently.
+pub struct CountryElement<'a, Triplets = CountrySubbandTripletIterator<'a>>
+where
+    Triplets: IntoIterator<Item = CountrySubbandTriplet>,
+{
+    /// The two letter ISO 3166-1 country code.
+    pub country_string: [u8; 2],
+    pub environment: CountryEnvironment,
+    pub subband_triplets: Triplets,
+    pub _phantom: PhantomData<&'a ()>,
+}
+impl<Triplets: IntoIterator<Item = CountrySubbandTriplet>> CountryElement<'_, Triplets> {
+    /// Create a new Country element.
+    pub const fn new(
+        country_string: [u8; 2],
+        environment: CountryEnvironment,
+        subband_triplets: Triplets,
+    ) -> Self {
+        Self {
+            country_string,
+            environment,
+            subband_triplets,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<Triplets: IntoIterator<Item = CountrySubbandTriplet> + Clone> CountryElement<'_, Triplets> {
+    /// Returns an [Iterator] over the [CountrySubbandTriplets](CountrySubbandTriplet).
+    pub fn subband_triplets(&self) -> Triplets::IntoIter {
+        self.subband_triplets.clone().into_iter()
+    }
+    /// Returns the length of the element body without padding.
+    fn unpadded_length(&self) -> usize {
+        3 + self.subband_triplets().count() * 3
+    }
+}
+impl<LhsTriplets, RhsTriplets> PartialEq<CountryElement<'_, RhsTriplets>>
+    for CountryElement<'_, LhsTriplets>
+where
+    LhsTriplets: IntoIterator<Item = CountrySubbandTriplet> + Clone,
+    RhsTriplets: IntoIterator<Item = CountrySubbandTriplet> + Clone,
+{
+    fn eq(&self, other: &CountryElement<'_, RhsTriplets>) -> bool {
+        self.country_string == other.country_string
+            && self.environment == other.environment
+            && self.subband_triplets().eq(other.subband_triplets())
+    }
+}
+impl<Triplets> Eq for CountryElement<'_, Triplets> where
+    Triplets: IntoIterator<Item = CountrySubbandTriplet> + Clone
+{
+}
+impl<Triplets: IntoIterator<Item = CountrySubbandTriplet> + Clone> MeasureWith<()>
+    for CountryElement<'_, Triplets>
+{
+    fn measure_with(&self, _ctx: &()) -> usize {
+        let length = self.unpadded_length();
+        // A pad byte is appended, if the length is odd.
+        length + length % 2
+    }
+}
+impl<'a> TryFromCtx<'a> for CountryElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let country_string = from.gread(&mut offset)?;
+        let environment = CountryEnvironment::from_bits(from.gread(&mut offset)?);
+        let mut triplets = &from[offset..];
+        match triplets.len() % 3 {
+            0 => {}
+            // Strip the pad byte.
+            1 => triplets = &triplets[..triplets.len() - 1],
+            _ => {
+                return Err(scroll::Error::BadInput {
+                    size: from.len(),
+                    msg: "The length of the subband triplets wasn't a multiple of three.",
+                })
+            }
+        }
+
+        Ok((
+            Self::new(
+                country_string,
+                environment,
+                CountrySubbandTripletIterator::new(triplets),
+            ),
+            from.len(),
+        ))
+    }
+}
+impl<Triplets: IntoIterator<Item = CountrySubbandTriplet>> TryIntoCtx
+    for CountryElement<'_, Triplets>
+{
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.country_string, &mut offset)?;
+        buf.gwrite(self.environment.into_bits(), &mut offset)?;
+        for triplet in self.subband_triplets {
+            buf.gwrite(triplet.to_bytes(), &mut offset)?;
+        }
+        if offset % 2 != 0 {
+            buf.gwrite(0u8, &mut offset)?;
+        }
+
+        Ok(offset)
+    }
+}
+impl<Triplets: IntoIterator<Item = CountrySubbandTriplet> + Clone> Element
+    for CountryElement<'_, Triplets>
+{
+    const ELEMENT_ID: ElementID = ElementID::Id(7);
+    type ReadType<'a> = CountryElement<'a>;
+}
@@ -0,0 +1,145 @@
+use scroll::{
+    ctx::{MeasureWith, SizeWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+use super::{Element, ElementID};
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Channel Switch Announcement element is used by an AP, IBSS STA or mesh STA, to announce when it's switching to a new channel.
+pub struct ChannelSwitchAnnouncementElement {
+    /// Indicates any restrictions on transmissions until the channel switch.
+    ///
+    /// A value of one means, that no frames should be transmitted until the channel switch.
+    pub channel_switch_mode: u8,
+    pub new_channel_number: u8,
+    /// The number of TBTTs until the channel switch.
+    ///
+    /// A value of zero indicates, that the switch may happen at any time after the frame containing this element.
+    pub channel_switch_count: u8,
+}
+impl ChannelSwitchAnnouncementElement {
+    /// Check if transmissions are restricted until the channel switch.
+    pub const fn transmissions_restricted(&self) -> bool {
+        self.channel_switch_mode == 1
+    }
+}
+impl SizeWith for ChannelSwitchAnnouncementElement {
+    fn size_with(_ctx: &()) -> usize {
+        3
+    }
+}
+impl MeasureWith<()> for ChannelSwitchAnnouncementElement {
+    fn measure_with(&self, ctx: &()) -> usize {
+        Self::size_with(ctx)
+    }
+}
+impl TryFromCtx<'_> for ChannelSwitchAnnouncementElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let channel_switch_mode = from.gread(&mut offset)?;
+        let new_channel_number = from.gread(&mut offset)?;
+        let channel_switch_count = from.gread(&mut offset)?;
+
+        Ok((
+            Self {
+                channel_switch_mode,
+                new_channel_number,
+                channel_switch_count,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for ChannelSwitchAnnouncementElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.channel_switch_mode, &mut offset)?;
+        buf.gwrite(self.new_channel_number, &mut offset)?;
+        buf.gwrite(self.channel_switch_count, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+
+impl Element for ChannelSwitchAnnouncementElement {
+    const ELEMENT_ID: ElementID = ElementID::Id(37);
+    type ReadType<'a> = Self;
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Extended Channel Switch Announcement element is used to announce a switch to a new channel, which may be in a different operating class.
+pub struct ExtendedChannelSwitchAnnouncementElement {
+    /// Indicates any restrictions on transmissions until the channel switch.
+    ///
+    /// A value of one means, that no frames should be transmitted until the channel switch.
+    pub channel_switch_mode: u8,
+    /// The operating class after the channel switch.
+    pub new_operating_class: u8,
+    pub new_channel_number: u8,
+    /// The number of TBTTs until the channel switch.
+    ///
+    /// A value of zero indicates, that the switch may happen at any time after the frame containing this element.
+    pub channel_switch_count: u8,
+}
+impl ExtendedChannelSwitchAnnouncementElement {
+    /// Check if transmissions are restricted until the channel switch.
+    pub const fn transmissions_restricted(&self) -> bool {
+        self.channel_switch_mode == 1
+    }
+}
+impl SizeWith for ExtendedChannelSwitchAnnouncementElement {
+    fn size_with(_ctx: &()) -> usize {
+        4
+    }
+}
+impl MeasureWith<()> for ExtendedChannelSwitchAnnouncementElement {
+    fn measure_with(&self, ctx: &()) -> usize {
+        Self::size_with(ctx)
+    }
+}
+impl TryFromCtx<'_> for ExtendedChannelSwitchAnnouncementElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let channel_switch_mode = from.gread(&mut offset)?;
+        let new_operating_class = from.gread(&mut offset)?;
+        let new_channel_number = from.gread(&mut offset)?;
+        let channel_switch_count = from.gread(&mut offset)?;
+
+        Ok((
+            Self {
+                channel_switch_mode,
+                new_operating_class,
+                new_channel_number,
+                channel_switch_count,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for ExtendedChannelSwitchAnnouncementElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.channel_switch_mode, &mut offset)?;
+        buf.gwrite(self.new_operating_class, &mut offset)?;
+        buf.gwrite(self.new_channel_number, &mut offset)?;
+        buf.gwrite(self.channel_switch_count, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+
+impl Element for ExtendedChannelSwitchAnnouncementElement {
+    const ELEMENT_ID: ElementID = ElementID::Id(60);
+    type ReadType<'a> = Self;
+}
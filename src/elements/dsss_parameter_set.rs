@@ -11,6 +11,31 @@ use super::{Element, ElementID};
 pub struct DSSSParameterSetElement {
     pub current_channel: u8,
 }
+impl DSSSParameterSetElement {
+    /// Create a new DSSS Parameter Set element for the specified channel.
+    ///
+    /// This returns [None], if the channel isn't a valid 2.4 GHz channel, i.e. isn't in the range 1-14.
+    pub const fn from_channel(channel: u8) -> Option<Self> {
+        if matches!(channel, 1..=14) {
+            Some(Self {
+                current_channel: channel,
+            })
+        } else {
+            None
+        }
+    }
+    /// Returns the center frequency of the current channel in MHz.
+    ///
+    /// The result is only meaningful, if the current channel is in the range 1-14.
+    pub const fn center_frequency_mhz(&self) -> u32 {
+        // Channel 14 doesn't follow the 5 MHz spacing of the other channels.
+        if self.current_channel == 14 {
+            2484
+        } else {
+            2407 + 5 * self.current_channel as u32
+        }
+    }
+}
 impl SizeWith for DSSSParameterSetElement {
     fn size_with(_ctx: &()) -> usize {
         1
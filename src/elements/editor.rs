@@ -0,0 +1,73 @@
+use alloc::vec::Vec;
+
+use scroll::{ctx::MeasureWith, Pwrite};
+
+use super::{Element, ElementID, ReadElements, WrappedIEEE80211Element};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+/// An owned buffer of elements, which can be modified.
+///
+/// This is mostly used through [ManagementFrame::edit_elements](crate::mgmt_frame::ManagementFrame::edit_elements), but can also be used on it's own.
+pub struct ElementEditor {
+    bytes: Vec<u8>,
+}
+impl ElementEditor {
+    /// Create a new editor, containing a copy of the supplied elements.
+    pub fn new(elements: ReadElements<'_>) -> Self {
+        Self {
+            bytes: elements.bytes.to_vec(),
+        }
+    }
+    /// Serialize the element, including it's header.
+    fn serialize_element(element: impl Element) -> Result<Vec<u8>, scroll::Error> {
+        let element = WrappedIEEE80211Element(element);
+        let mut buf = alloc::vec![0x00; element.measure_with(&())];
+        buf.pwrite(element, 0)?;
+        Ok(buf)
+    }
+    /// Returns the byte range of the first element matching the [ElementID].
+    fn find_element(&self, element_id: ElementID) -> Option<(usize, usize)> {
+        let mut offset = 0;
+        for raw_element in self.elements().raw_element_iterator() {
+            let length = 2 + raw_element.slice.len();
+            if ReadElements::element_id_matches(&raw_element, element_id) {
+                return Some((offset, offset + length));
+            }
+            offset += length;
+        }
+        None
+    }
+    /// Remove all elements matching the [ElementID].
+    pub fn remove(&mut self, element_id: ElementID) {
+        while let Some((start, end)) = self.find_element(element_id) {
+            self.bytes.drain(start..end);
+        }
+    }
+    /// Replace the first element of the same type with the supplied element.
+    ///
+    /// If no element of that type is present, the element is appended.
+    pub fn replace<T: Element>(&mut self, element: T) -> Result<(), scroll::Error> {
+        let serialized = Self::serialize_element(element)?;
+        match self.find_element(T::ELEMENT_ID) {
+            Some((start, end)) => {
+                self.bytes.splice(start..end, serialized);
+            }
+            None => self.bytes.extend(serialized),
+        }
+        Ok(())
+    }
+    /// Append an element.
+    pub fn add(&mut self, element: impl Element) -> Result<(), scroll::Error> {
+        let serialized = Self::serialize_element(element)?;
+        self.bytes.extend(serialized);
+        Ok(())
+    }
+    /// Returns the current elements.
+    pub fn elements(&self) -> ReadElements<'_> {
+        ReadElements { bytes: &self.bytes }
+    }
+    /// Returns the raw bytes of the elements.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
@@ -0,0 +1,402 @@
+//! This module contains support for the EHT (802.11be) Capabilities and Operation elements.
+
+use bitfield_struct::bitfield;
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use super::{
+    he::{HECapabilitiesElement, HEPHYCapabilitiesInformation},
+    Element, ElementID, RawIEEE80211ExtElement, ReadElements,
+};
+
+#[bitfield(u16, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The MAC capabilities of an EHT STA.
+pub struct EHTMACCapabilitiesInformation {
+    pub epcs_priority_access_support: bool,
+    pub eht_om_control_support: bool,
+    pub triggered_txop_sharing_mode_1_support: bool,
+    pub triggered_txop_sharing_mode_2_support: bool,
+    pub restricted_twt_support: bool,
+    pub scs_traffic_description_support: bool,
+    #[bits(2)]
+    pub maximum_mpdu_length: u8,
+    pub maximum_ampdu_length_exponent_extension: bool,
+    pub eht_trs_support: bool,
+    pub txop_return_support_in_txop_sharing_mode_2: bool,
+    pub two_bqrs_support: bool,
+    #[bits(2)]
+    pub eht_link_adaptation_support: u8,
+    pub unsolicited_epcs_priority_access_parameter_update: bool,
+    __: bool,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The PHY capabilities of an EHT STA.
+///
+/// Since this field is 72 bits long, it's kept as raw bytes with accessors for the bits relevant to parsing.
+pub struct EHTPHYCapabilitiesInformation(pub [u8; 9]);
+impl EHTPHYCapabilitiesInformation {
+    /// Check if the bit at the specified index is set.
+    pub const fn is_bit_set(&self, bit: usize) -> bool {
+        self.0[bit / 8] & (1 << (bit % 8)) != 0
+    }
+    /// Indicates support for 320 MHz channel width in the 6 GHz band.
+    pub const fn support_for_320mhz_in_6ghz(&self) -> bool {
+        self.is_bit_set(1)
+    }
+    /// Indicates support for transmitting and receiving as an SU beamformer.
+    pub const fn su_beamformer(&self) -> bool {
+        self.is_bit_set(5)
+    }
+    /// Indicates support for receiving as an SU beamformee.
+    pub const fn su_beamformee(&self) -> bool {
+        self.is_bit_set(6)
+    }
+    /// Indicates, that the EHT PPE Thresholds field is present in the EHT Capabilities element.
+    pub const fn ppe_thresholds_present(&self) -> bool {
+        self.is_bit_set(43)
+    }
+}
+
+/// Calculate the length of the EHT PPE Thresholds field from it's first two octets.
+///
+/// The field consists of a nine bit header, followed by a PPETmax and PPET8 subfield of three bits each, for every combination of spatial stream and RU index present. The result is padded to the next octet boundary.
+const fn eht_ppe_thresholds_length(header: u16) -> usize {
+    let nss = (header & 0b1111) as usize + 1;
+    let ru_count = ((header >> 4) & 0b1_1111).count_ones() as usize;
+    (9 + nss * ru_count * 6 + 7) / 8
+}
+
+/// Check if the bytes following a Supported EHT-MCS And NSS Set of the specified length match the EHT PPE Thresholds field.
+fn mcs_set_length_valid(
+    eht_phy_capabilities_information: EHTPHYCapabilitiesInformation,
+    remaining: &[u8],
+    mcs_set_length: usize,
+) -> bool {
+    let Some(eht_ppe_thresholds) = remaining.get(mcs_set_length..) else {
+        return false;
+    };
+    if !eht_phy_capabilities_information.ppe_thresholds_present() {
+        return eht_ppe_thresholds.is_empty();
+    }
+    eht_ppe_thresholds
+        .pread_with(0, Endian::Little)
+        .map(|header| eht_ppe_thresholds_length(header) == eht_ppe_thresholds.len())
+        .unwrap_or_default()
+}
+/// Calculate the length of the Supported EHT-MCS And NSS Set, as specified in IEEE 802.11be-2024 9.4.2.323.4.
+const fn supported_eht_mcs_and_nss_set_length(
+    he_phy_capabilities_information: HEPHYCapabilitiesInformation,
+    eht_phy_capabilities_information: EHTPHYCapabilitiesInformation,
+    is_ap: bool,
+) -> usize {
+    // B0-B3 of the Supported Channel Width Set are bits one to four of the HE PHY Capabilities Information.
+    let twenty_mhz_only = !(he_phy_capabilities_information.is_bit_set(1)
+        || he_phy_capabilities_information.is_bit_set(2)
+        || he_phy_capabilities_information.is_bit_set(3)
+        || he_phy_capabilities_information.is_bit_set(4));
+    if twenty_mhz_only && !is_ap {
+        return 4;
+    }
+    3 + if he_phy_capabilities_information.supports_160mhz() {
+        3
+    } else {
+        0
+    } + if eht_phy_capabilities_information.support_for_320mhz_in_6ghz() {
+        3
+    } else {
+        0
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The EHT Capabilities element advertises the EHT capabilities of a STA.
+///
+/// The length of the Supported EHT-MCS And NSS Set depends on the [HEPHYCapabilitiesInformation] of the STA.
+/// Reading through [Self::read_with_he_phy_capabilities] or [Self::from_elements] determines it exactly.
+/// Without context, the length is inferred from the remaining bytes.
+pub struct EHTCapabilitiesElement<'a> {
+    pub eht_mac_capabilities_information: EHTMACCapabilitiesInformation,
+    pub eht_phy_capabilities_information: EHTPHYCapabilitiesInformation,
+    /// The raw Supported EHT-MCS And NSS Set.
+    ///
+    /// This is four bytes long for 20 MHz-only non-AP STAs. Otherwise it contains three bytes for each of the 80, 160 and 320 MHz bandwidths supported.
+    pub supported_eht_mcs_and_nss_set: &'a [u8],
+    /// The raw EHT PPE Thresholds field, which is present if indicated by the PHY capabilities.
+    pub eht_ppe_thresholds: Option<&'a [u8]>,
+}
+impl<'a> EHTCapabilitiesElement<'a> {
+    /// Read the EHT Capabilities element from the elements, using the [HECapabilitiesElement] to determine the length of the Supported EHT-MCS And NSS Set.
+    pub fn from_elements(elements: ReadElements<'a>, is_ap: bool) -> Option<Self> {
+        let he_capabilities = elements.get_first_element::<HECapabilitiesElement>()?;
+        let ext_element = elements
+            .get_first_element_raw(Self::ELEMENT_ID)?
+            .slice
+            .pread::<RawIEEE80211ExtElement>(0)
+            .ok()?;
+        Self::read_with_he_phy_capabilities(
+            ext_element.slice,
+            he_capabilities.he_phy_capabilities_information,
+            is_ap,
+        )
+        .ok()
+    }
+    /// Read the element from it's body, with the [HEPHYCapabilitiesInformation] of the STA and whether it's an AP determining the length of the Supported EHT-MCS And NSS Set.
+    pub fn read_with_he_phy_capabilities(
+        from: &'a [u8],
+        he_phy_capabilities_information: HEPHYCapabilitiesInformation,
+        is_ap: bool,
+    ) -> Result<Self, scroll::Error> {
+        Self::read_with_mcs_set_length(from, |eht_phy_capabilities_information, _| {
+            Some(supported_eht_mcs_and_nss_set_length(
+                he_phy_capabilities_information,
+                eht_phy_capabilities_information,
+                is_ap,
+            ))
+        })
+        .map(|(eht_capabilities, _)| eht_capabilities)
+    }
+    /// Read the element, with the length of the Supported EHT-MCS And NSS Set being determined by `mcs_set_length`.
+    fn read_with_mcs_set_length(
+        from: &'a [u8],
+        mcs_set_length: impl FnOnce(EHTPHYCapabilitiesInformation, &'a [u8]) -> Option<usize>,
+    ) -> Result<(Self, usize), scroll::Error> {
+        let mut offset = 0;
+
+        let eht_mac_capabilities_information =
+            EHTMACCapabilitiesInformation::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+        let eht_phy_capabilities_information =
+            EHTPHYCapabilitiesInformation(from.gread(&mut offset)?);
+        let remaining = &from[offset..];
+
+        let Some(mcs_set_length) = mcs_set_length(eht_phy_capabilities_information, remaining)
+            .filter(|mcs_set_length| {
+                mcs_set_length_valid(eht_phy_capabilities_information, remaining, *mcs_set_length)
+            })
+        else {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "The length of the Supported EHT-MCS And NSS Set was invalid.",
+            });
+        };
+        let (supported_eht_mcs_and_nss_set, eht_ppe_thresholds) =
+            remaining.split_at(mcs_set_length);
+        let eht_ppe_thresholds = if eht_phy_capabilities_information.ppe_thresholds_present() {
+            Some(eht_ppe_thresholds)
+        } else {
+            None
+        };
+
+        Ok((
+            Self {
+                eht_mac_capabilities_information,
+                eht_phy_capabilities_information,
+                supported_eht_mcs_and_nss_set,
+                eht_ppe_thresholds,
+            },
+            from.len(),
+        ))
+    }
+}
+impl MeasureWith<()> for EHTCapabilitiesElement<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        11 + self.supported_eht_mcs_and_nss_set.len()
+            + self.eht_ppe_thresholds.map_or(0, <[u8]>::len)
+    }
+}
+impl<'a> TryFromCtx<'a> for EHTCapabilitiesElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        Self::read_with_mcs_set_length(from, |eht_phy_capabilities_information, remaining| {
+            // Support for 320 MHz implies support for 160 MHz, so all three maps are present.
+            let candidates: &[usize] =
+                if eht_phy_capabilities_information.support_for_320mhz_in_6ghz() {
+                    &[9]
+                } else {
+                    &[3, 4, 6]
+                };
+            // Find the length, which leaves exactly enough bytes for the PPE Thresholds.
+            candidates.iter().copied().find(|mcs_set_length| {
+                mcs_set_length_valid(eht_phy_capabilities_information, remaining, *mcs_set_length)
+            })
+        })
+    }
+}
+impl TryIntoCtx for EHTCapabilitiesElement<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite_with(
+            self.eht_mac_capabilities_information.into_bits(),
+            &mut offset,
+            Endian::Little,
+        )?;
+        buf.gwrite(self.eht_phy_capabilities_information.0, &mut offset)?;
+        buf.gwrite(self.supported_eht_mcs_and_nss_set, &mut offset)?;
+        if let Some(eht_ppe_thresholds) = self.eht_ppe_thresholds {
+            buf.gwrite(eht_ppe_thresholds, &mut offset)?;
+        }
+
+        Ok(offset)
+    }
+}
+impl Element for EHTCapabilitiesElement<'_> {
+    const ELEMENT_ID: ElementID = ElementID::ExtId(108);
+    type ReadType<'a> = EHTCapabilitiesElement<'a>;
+}
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The EHT Operation Parameters field.
+///
+/// The presence bits are set automatically, based on the fields present in the [EHTOperationElement].
+pub struct EHTOperationParameters {
+    eht_operation_information_present: bool,
+    disabled_subchannel_bitmap_present: bool,
+    pub eht_default_pe_duration: bool,
+    pub group_addressed_bu_indication_limit: bool,
+    #[bits(2)]
+    pub group_addressed_bu_indication_exponent: u8,
+    #[bits(2)]
+    __: u8,
+}
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The channel width of an EHT BSS.
+    pub enum EHTChannelWidth: u8 {
+        #[default]
+        TwentyMHz => 0,
+        FortyMHz => 1,
+        EightyMHz => 2,
+        OneHundredSixtyMHz => 3,
+        ThreeHundredTwentyMHz => 4
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The EHT Operation Information field.
+pub struct EHTOperationInformation {
+    /// The channel width of the BSS.
+    pub channel_width: EHTChannelWidth,
+    /// Channel center frequency segment zero.
+    pub ccfs0: u8,
+    /// Channel center frequency segment one.
+    pub ccfs1: u8,
+    /// A bitmap of the punctured 20 MHz subchannels.
+    pub disabled_subchannel_bitmap: Option<u16>,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The EHT Operation element controls the operation of EHT STAs in the BSS.
+pub struct EHTOperationElement {
+    pub eht_operation_parameters: EHTOperationParameters,
+    /// The EHT-MCSs, which are supported by all EHT STAs in the BSS.
+    pub basic_eht_mcs_and_nss_set: [u8; 4],
+    /// Information about the channel of the BSS, if it differs from the one indicated by the HE Operation element.
+    pub eht_operation_information: Option<EHTOperationInformation>,
+}
+impl MeasureWith<()> for EHTOperationElement {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        5 + match self.eht_operation_information {
+            Some(EHTOperationInformation {
+                disabled_subchannel_bitmap: Some(_),
+                ..
+            }) => 5,
+            Some(_) => 3,
+            None => 0,
+        }
+    }
+}
+impl TryFromCtx<'_> for EHTOperationElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let eht_operation_parameters = EHTOperationParameters::from_bits(from.gread(&mut offset)?);
+        let basic_eht_mcs_and_nss_set = from.gread(&mut offset)?;
+        let eht_operation_information =
+            if eht_operation_parameters.eht_operation_information_present() {
+                let control = from.gread::<u8>(&mut offset)?;
+                let ccfs0 = from.gread(&mut offset)?;
+                let ccfs1 = from.gread(&mut offset)?;
+                let disabled_subchannel_bitmap =
+                    if eht_operation_parameters.disabled_subchannel_bitmap_present() {
+                        Some(from.gread_with(&mut offset, Endian::Little)?)
+                    } else {
+                        None
+                    };
+                Some(EHTOperationInformation {
+                    channel_width: EHTChannelWidth::from_bits(control & 0b111),
+                    ccfs0,
+                    ccfs1,
+                    disabled_subchannel_bitmap,
+                })
+            } else {
+                None
+            };
+
+        Ok((
+            Self {
+                eht_operation_parameters: eht_operation_parameters
+                    .with_eht_operation_information_present(false)
+                    .with_disabled_subchannel_bitmap_present(false),
+                basic_eht_mcs_and_nss_set,
+                eht_operation_information,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for EHTOperationElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(
+            self.eht_operation_parameters
+                .with_eht_operation_information_present(self.eht_operation_information.is_some())
+                .with_disabled_subchannel_bitmap_present(
+                    self.eht_operation_information
+                        .map(|eht_operation_information| {
+                            eht_operation_information
+                                .disabled_subchannel_bitmap
+                                .is_some()
+                        })
+                        .unwrap_or_default(),
+                )
+                .into_bits(),
+            &mut offset,
+        )?;
+        buf.gwrite(self.basic_eht_mcs_and_nss_set, &mut offset)?;
+        if let Some(eht_operation_information) = self.eht_operation_information {
+            buf.gwrite(
+                eht_operation_information.channel_width.into_bits(),
+                &mut offset,
+            )?;
+            buf.gwrite(eht_operation_information.ccfs0, &mut offset)?;
+            buf.gwrite(eht_operation_information.ccfs1, &mut offset)?;
+            if let Some(disabled_subchannel_bitmap) =
+                eht_operation_information.disabled_subchannel_bitmap
+            {
+                buf.gwrite_with(disabled_subchannel_bitmap, &mut offset, Endian::Little)?;
+            }
+        }
+
+        Ok(offset)
+    }
+}
+impl Element for EHTOperationElement {
+    const ELEMENT_ID: ElementID = ElementID::ExtId(106);
+    type ReadType<'a> = EHTOperationElement;
+}
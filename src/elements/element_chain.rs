@@ -20,6 +20,8 @@ use scroll::{
     Endian, Pwrite,
 };
 
+use crate::common::Empty;
+
 use super::{Element, RawIEEE80211Element, WrappedIEEE80211Element};
 
 /// This trait represents a singular element of the chain.
@@ -31,6 +33,15 @@ pub trait ChainElement {
     fn append<T>(self, value: T) -> Self::Appended<T>;
 }
 
+// An empty chain, like the one generated by `element_chain! {}`.
+impl ChainElement for Empty {
+    type Appended<Appendee> = ElementChainEnd<Appendee>;
+    #[inline]
+    fn append<T>(self, value: T) -> Self::Appended<T> {
+        ElementChainEnd { inner: value }
+    }
+}
+
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
 /// This is the end of a chain.
@@ -89,6 +100,29 @@ impl TryIntoCtx for ElementChainEnd<RawIEEE80211Element<'_>> {
         buf.pwrite_with(self.inner, 0, Endian::Little)
     }
 }
+// An optional element at the end of the chain is only written, if it's present.
+impl<Inner> MeasureWith<()> for ElementChainEnd<Option<Inner>>
+where
+    Inner: Element,
+{
+    fn measure_with(&self, ctx: &()) -> usize {
+        self.inner.as_ref().map_or(0, |inner| {
+            Inner::ELEMENT_ID.element_header_length() + inner.measure_with(ctx)
+        })
+    }
+}
+impl<Inner> TryIntoCtx for ElementChainEnd<Option<Inner>>
+where
+    Inner: Element,
+{
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        match self.inner {
+            Some(inner) => buf.pwrite(WrappedIEEE80211Element(inner), 0),
+            None => Ok(0),
+        }
+    }
+}
 
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
@@ -159,6 +193,34 @@ where
         Ok(offset)
     }
 }
+// Like at the end of the chain, an optional element in a link is only written, if it's present.
+impl<Inner, Child> MeasureWith<()> for ElementChainLink<Option<Inner>, Child>
+where
+    Inner: Element,
+    Child: TryIntoCtx<Error = scroll::Error> + MeasureWith<()> + ChainElement,
+{
+    fn measure_with(&self, ctx: &()) -> usize {
+        self.inner.as_ref().map_or(0, |inner| {
+            Inner::ELEMENT_ID.element_header_length() + inner.measure_with(ctx)
+        }) + self.next.measure_with(ctx)
+    }
+}
+impl<Inner, Child> TryIntoCtx for ElementChainLink<Option<Inner>, Child>
+where
+    Inner: Element,
+    Child: TryIntoCtx<Error = scroll::Error> + ChainElement,
+{
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+        if let Some(inner) = self.inner {
+            buf.gwrite(WrappedIEEE80211Element(inner), &mut offset)?;
+        }
+        buf.gwrite(self.next, &mut offset)?;
+
+        Ok(offset)
+    }
+}
 #[macro_export]
 /// Generate an element chain from the provided elements.
 ///
@@ -0,0 +1,50 @@
+use bitfield_struct::bitfield;
+use scroll::{
+    ctx::{MeasureWith, SizeWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+use super::{Element, ElementID};
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The ERP Information element contains information about Clause 15 or Clause 16 STAs in the BSS, that aren't capable of the ERP-OFDM data rates.
+///
+/// It can be combined with the [CapabilitiesInformation](crate::common::CapabilitiesInformation), to determine the protection mode of the BSS.
+pub struct ERPInformationElement {
+    /// Indicates, that non-ERP STAs are associated with the BSS.
+    pub non_erp_present: bool,
+    /// Indicates, that ERP STAs shall use protection mechanisms for ERP-OFDM transmissions.
+    pub use_protection: bool,
+    /// Indicates, that one or more associated non-ERP STAs aren't short preamble capable.
+    pub barker_preamble_mode: bool,
+    #[bits(5)]
+    __: u8,
+}
+impl SizeWith for ERPInformationElement {
+    fn size_with(_ctx: &()) -> usize {
+        1
+    }
+}
+impl MeasureWith<()> for ERPInformationElement {
+    fn measure_with(&self, ctx: &()) -> usize {
+        Self::size_with(ctx)
+    }
+}
+impl TryFromCtx<'_> for ERPInformationElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        Ok((Self::from_bits(from.pread(0)?), 1))
+    }
+}
+impl TryIntoCtx for ERPInformationElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        buf.pwrite(self.into_bits(), 0)
+    }
+}
+
+impl Element for ERPInformationElement {
+    const ELEMENT_ID: ElementID = ElementID::Id(42);
+    type ReadType<'a> = Self;
+}
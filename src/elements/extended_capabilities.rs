@@ -0,0 +1,223 @@
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Pwrite,
+};
+
+use super::{Element, ElementID};
+
+/// Generates the accessors for the single bit capabilities and the list of their names.
+macro_rules! extended_capabilities {
+    ($($(#[doc = $doc:literal])* $name:ident => $bit:literal, $display_name:literal;)*) => {
+        /// The names of the single bit capabilities in the Extended Capabilities element, as specified in Table 9-153 of IEEE 802.11-2020 and IEEE 802.11ax.
+        ///
+        /// Reserved bits and multi bit subfields, like the Service Interval Granularity, aren't included.
+        const CAPABILITY_NAMES: &[(usize, &str)] = &[$(($bit, $display_name)),*];
+        impl ExtendedCapabilitiesElement<'_> {
+            $(
+                $(#[doc = $doc])*
+                #[inline]
+                pub const fn $name(&self) -> bool {
+                    self.is_capability_set($bit)
+                }
+            )*
+        }
+    };
+}
+extended_capabilities! {
+    /// Indicates support for the 20/40 BSS Coexistence Management frame and it's use.
+    bss_coexistence_management_support => 0, "20/40 BSS Coexistence Management";
+    /// Indicates support for switching the operating class and channel with the Extended Channel Switch Announcement.
+    extended_channel_switching => 2, "Extended Channel Switching";
+    /// Indicates support for Power Save Multi-Poll operation.
+    psmp => 4, "PSMP";
+    /// Indicates support for scheduled PSMP.
+    s_psmp => 6, "S-PSMP";
+    /// Indicates support for the reporting of events, like transitions and RSNA failures.
+    event => 7, "Event";
+    /// Indicates support for diagnostic requests and reports.
+    diagnostics => 8, "Diagnostics";
+    /// Indicates support for multicast diagnostic reporting.
+    multicast_diagnostics => 9, "Multicast Diagnostics";
+    /// Indicates support for location tracking through Location Track Notification frames.
+    location_tracking => 10, "Location Tracking";
+    /// Indicates support for the Flexible Multicast Service.
+    fms => 11, "FMS";
+    /// Indicates, that the AP answers ARP and neighbor discovery requests on behalf of associated STAs.
+    proxy_arp => 12, "Proxy ARP";
+    /// Indicates support for reporting interference from collocated radios.
+    collocated_interference_reporting => 13, "Collocated Interference Reporting";
+    /// Indicates support for civic location requests and reports.
+    civic_location => 14, "Civic Location";
+    /// Indicates support for geospatial location requests and reports.
+    geospatial_location => 15, "Geospatial Location";
+    /// Indicates support for the Traffic Filtering Service.
+    tfs => 16, "TFS";
+    /// Indicates support for WNM sleep mode, in which a STA stays associated while sleeping for extended periods.
+    wnm_sleep_mode => 17, "WNM Sleep Mode";
+    /// Indicates support for the TIM Broadcast service.
+    tim_broadcast => 18, "TIM Broadcast";
+    /// Indicates support for BSS Transition Management, as used for 802.11v roaming.
+    bss_transition => 19, "BSS Transition";
+    /// Indicates support for the QoS Traffic Capability update procedure.
+    qos_traffic_capability => 20, "QoS Traffic Capability";
+    /// Indicates support for the per access category station count in the BSS AC Access Delay element.
+    ac_station_count => 21, "AC Station Count";
+    /// Indicates support for the Multiple BSSID element and advertisement of nontransmitted BSSIDs.
+    multiple_bssid => 22, "Multiple BSSID";
+    /// Indicates support for the timing measurement procedure.
+    timing_measurement => 23, "Timing Measurement";
+    /// Indicates support for channel usage requests and reports.
+    channel_usage => 24, "Channel Usage";
+    /// Indicates support for the SSID List element in probe requests.
+    ssid_list => 25, "SSID List";
+    /// Indicates support for the Directed Multicast Service.
+    dms => 26, "DMS";
+    /// Indicates support for providing the offset between UTC and the TSF.
+    utc_tsf_offset => 27, "UTC TSF Offset";
+    /// Indicates support for TDLS Peer U-APSD as the buffer STA.
+    tpu_buffer_sta => 28, "TPU Buffer STA";
+    /// Indicates support for TDLS Peer PSM.
+    tdls_peer_psm => 29, "TDLS Peer PSM";
+    /// Indicates support for switching the channel of a TDLS direct link.
+    tdls_channel_switching => 30, "TDLS Channel Switching";
+    /// Indicates support for interworking with external networks, as used by Hotspot 2.0.
+    interworking => 31, "Interworking";
+    /// Indicates support for mapping DSCP values to user priorities through the QoS Map.
+    qos_map => 32, "QoS Map";
+    /// Indicates support for Expedited Bandwidth Requests.
+    ebr => 33, "EBR";
+    /// Indicates support for the Subscription Service Provider Network interface.
+    sspn_interface => 34, "SSPN Interface";
+    /// Indicates support for the MAC State Generic Convergence Function.
+    msgcf => 36, "MSGCF";
+    /// Indicates support for Tunneled Direct Link Setup.
+    tdls_support => 37, "TDLS Support";
+    /// Indicates, that TDLS isn't allowed in the BSS.
+    tdls_prohibited => 38, "TDLS Prohibited";
+    /// Indicates, that switching the channel of TDLS direct links isn't allowed in the BSS.
+    tdls_channel_switching_prohibited => 39, "TDLS Channel Switching Prohibited";
+    /// Indicates, that frames of access categories with mandatory admission control are dropped, unless admitted.
+    reject_unadmitted_frame => 40, "Reject Unadmitted Frame";
+    /// Indicates support for the Public Identifier URI element in location reports.
+    identifier_location => 44, "Identifier Location";
+    /// Indicates support for U-APSD coexistence.
+    u_apsd_coexistence => 45, "U-APSD Coexistence";
+    /// Indicates support for WNM Notification Request and Response frames.
+    wnm_notification => 46, "WNM Notification";
+    /// Indicates support for the QoS Access Point Bandwidth procedure.
+    qab => 47, "QAB";
+    /// Indicates, that the SSID is UTF-8 encoded.
+    utf8_ssid => 48, "UTF-8 SSID";
+    /// Indicates, that the QoS Management Frame policy is in use.
+    qmf_activated => 49, "QMF Activated";
+    /// Indicates support for reconfiguring the QoS Management Frame policy.
+    qmf_reconfiguration_activated => 50, "QMF Reconfiguration Activated";
+    /// Indicates support for robust audio video streaming.
+    robust_av_streaming => 51, "Robust AV Streaming";
+    /// Indicates support for advanced Groupcast with Retries.
+    advanced_gcr => 52, "Advanced GCR";
+    /// Indicates support for Groupcast with Retries in a mesh BSS.
+    mesh_gcr => 53, "Mesh GCR";
+    /// Indicates support for Stream Classification Service.
+    scs => 54, "SCS";
+    /// Indicates support for QLoad reports.
+    qload_report => 55, "QLoad Report";
+    /// Indicates support for the alternate EDCA transmit queues.
+    alternate_edca => 56, "Alternate EDCA";
+    /// Indicates support for TXOP negotiation through unprotected frames.
+    unprotected_txop_negotiation => 57, "Unprotected TXOP Negotiation";
+    /// Indicates support for TXOP negotiation through protected frames.
+    protected_txop_negotiation => 58, "Protected TXOP Negotiation";
+    /// Indicates support for protected QLoad reports.
+    protected_qload_report => 60, "Protected QLoad Report";
+    /// Indicates support for TDLS direct links wider than the channel of the BSS.
+    tdls_wider_bandwidth => 61, "TDLS Wider Bandwidth";
+    /// Indicates support for the Operating Mode Notification element and frame.
+    operating_mode_notification => 62, "Operating Mode Notification";
+    /// Indicates support for channel schedule management.
+    channel_schedule_management => 65, "Channel Schedule Management";
+    /// Indicates support for the geodatabase inband enabling signal in TV white space bands.
+    geodatabase_inband_enabling_signal => 66, "Geodatabase Inband Enabling Signal";
+    /// Indicates support for network channel control.
+    network_channel_control => 67, "Network Channel Control";
+    /// Indicates support for the White Space Map element.
+    white_space_map => 68, "White Space Map";
+    /// Indicates support for channel availability queries.
+    channel_availability_query => 69, "Channel Availability Query";
+    /// Indicates support for acting as a Fine Timing Measurement responder.
+    ftm_responder => 70, "FTM Responder";
+    /// Indicates support for acting as a Fine Timing Measurement initiator.
+    ftm_initiator => 71, "FTM Initiator";
+    /// Indicates support for Fast Initial Link Setup.
+    fils => 72, "FILS";
+    /// Indicates support for spectrum management of channels wider than 40 MHz.
+    extended_spectrum_management => 73, "Extended Spectrum Management";
+    /// Indicates support for the Future Channel Guidance element.
+    future_channel_guidance => 74, "Future Channel Guidance";
+    /// Indicates the PAD capability, as defined in Table 9-153 of IEEE 802.11-2020.
+    pad => 75, "PAD";
+    /// Indicates support for the TWT requester role.
+    twt_requester => 77, "TWT Requester";
+    /// Indicates support for the TWT responder role.
+    twt_responder => 78, "TWT Responder";
+    /// Indicates tolerance of narrow bandwidth RUs from overlapping BSSs in OFDMA.
+    obss_narrow_bandwidth_ru_in_ofdma_tolerance => 79, "OBSS Narrow Bandwidth RU in OFDMA Tolerance";
+    /// Indicates, that the Multiple BSSID elements contain the profiles of all nontransmitted BSSIDs.
+    complete_list_of_non_tx_bssid_profiles => 80, "Complete List of NonTxBSSID Profiles";
+    /// Indicates, that SAE password identifiers are used for at least one password.
+    sae_password_identifiers_in_use => 81, "SAE Password Identifiers In Use";
+    /// Indicates, that SAE passwords are only used with password identifiers.
+    sae_passwords_used_exclusively => 82, "SAE Passwords Used Exclusively";
+    /// Indicates support for Enhanced Multi-BSSID Advertisement.
+    enhanced_multi_bssid_advertisement => 83, "Enhanced Multi-BSSID Advertisement";
+    /// Indicates, that beacon protection is enabled.
+    beacon_protection_enabled => 84, "Beacon Protection Enabled";
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Extended Capabilities element contains a variable length bit field of optional capabilities.
+///
+/// Since the length of the element grows with every amendment, the raw bits are kept as is and any bits beyond the end are treated as not set.
+pub struct ExtendedCapabilitiesElement<'a> {
+    /// The raw capability bits.
+    pub bytes: &'a [u8],
+}
+impl<'a> ExtendedCapabilitiesElement<'a> {
+    /// Check if the capability with the specified bit index is set.
+    pub const fn is_capability_set(&self, bit: usize) -> bool {
+        let byte_index = bit / 8;
+        byte_index < self.bytes.len() && self.bytes[byte_index] & (1 << (bit % 8)) != 0
+    }
+    /// Returns an [Iterator] over the names of all set capabilities.
+    ///
+    /// Set bits, which are reserved or unknown to this implementation, are skipped.
+    pub fn set_capabilities(&self) -> impl Iterator<Item = &'static str> + 'a {
+        let element = *self;
+        CAPABILITY_NAMES
+            .iter()
+            .filter(move |(bit, _)| element.is_capability_set(*bit))
+            .map(|(_, name)| *name)
+    }
+}
+impl MeasureWith<()> for ExtendedCapabilitiesElement<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        self.bytes.len()
+    }
+}
+impl<'a> TryFromCtx<'a> for ExtendedCapabilitiesElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        Ok((Self { bytes: from }, from.len()))
+    }
+}
+impl TryIntoCtx for ExtendedCapabilitiesElement<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        buf.pwrite(self.bytes, 0)
+    }
+}
+impl Element for ExtendedCapabilitiesElement<'_> {
+    const ELEMENT_ID: ElementID = ElementID::Id(127);
+    type ReadType<'a> = ExtendedCapabilitiesElement<'a>;
+}
@@ -0,0 +1,223 @@
+//! This module contains the elements used for fast BSS transition (FT), as specified in IEEE 802.11r.
+
+use bitfield_struct::bitfield;
+use mac_parser::MACAddress;
+use scroll::{
+    ctx::{MeasureWith, SizeWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use super::{Element, ElementID};
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The FT Capability and Policy field of the [MobilityDomainElement].
+pub struct FTCapabilityAndPolicy {
+    /// Indicates, that FT over the DS is supported.
+    pub ft_over_ds: bool,
+    /// Indicates, that the resource request protocol is supported.
+    pub resource_request_protocol_capability: bool,
+    #[bits(6)]
+    __: u8,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Mobility Domain element identifies a group of APs, between which a STA can use FT.
+pub struct MobilityDomainElement {
+    /// The mobility domain identifier.
+    pub mdid: u16,
+    pub ft_capability_and_policy: FTCapabilityAndPolicy,
+}
+impl SizeWith for MobilityDomainElement {
+    fn size_with(_ctx: &()) -> usize {
+        3
+    }
+}
+impl MeasureWith<()> for MobilityDomainElement {
+    fn measure_with(&self, ctx: &()) -> usize {
+        Self::size_with(ctx)
+    }
+}
+impl TryFromCtx<'_> for MobilityDomainElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let mdid = from.gread_with(&mut offset, Endian::Little)?;
+        let ft_capability_and_policy = FTCapabilityAndPolicy::from_bits(from.gread(&mut offset)?);
+
+        Ok((
+            Self {
+                mdid,
+                ft_capability_and_policy,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for MobilityDomainElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite_with(self.mdid, &mut offset, Endian::Little)?;
+        buf.gwrite(self.ft_capability_and_policy.into_bits(), &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl Element for MobilityDomainElement {
+    const ELEMENT_ID: ElementID = ElementID::Id(54);
+    type ReadType<'a> = Self;
+}
+
+#[bitfield(u16, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The MIC Control field of the [FastBSSTransitionElement].
+pub struct MICControl {
+    /// Indicates, that the RSNXE is included in the MIC calculation.
+    pub rsnxe_used: bool,
+    #[bits(7)]
+    __: u8,
+    /// The number of elements protected by the MIC.
+    pub element_count: u8,
+}
+
+/// The length of the MIC in the [FastBSSTransitionElement].
+pub const FTE_MIC_LENGTH: usize = 16;
+/// The length of the ANonce and SNonce in the [FastBSSTransitionElement].
+pub const FTE_NONCE_LENGTH: usize = 32;
+
+/// The sub-element ID of the R1KH-ID.
+const R1KH_ID_SUB_ELEMENT_ID: u8 = 1;
+/// The sub-element ID of the GTK.
+const GTK_SUB_ELEMENT_ID: u8 = 2;
+/// The sub-element ID of the R0KH-ID.
+const R0KH_ID_SUB_ELEMENT_ID: u8 = 3;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Fast BSS Transition element carries the information required to perform the FT authentication sequence.
+///
+/// Only the 16 byte MIC is supported, which is used by all AKMs except those based on SHA-384.
+/// Of the optional sub-elements, only the R1KH-ID, GTK and R0KH-ID are parsed. All other sub-elements are skipped.
+pub struct FastBSSTransitionElement<'a> {
+    pub mic_control: MICControl,
+    /// The MIC over the FT authentication sequence.
+    ///
+    /// This is all zeroes, if no MIC is present.
+    pub mic: [u8; FTE_MIC_LENGTH],
+    /// The nonce chosen by the R1KH.
+    pub anonce: [u8; FTE_NONCE_LENGTH],
+    /// The nonce chosen by the S1KH.
+    pub snonce: [u8; FTE_NONCE_LENGTH],
+    /// The identifier of the R1KH, which is a MAC address.
+    pub r1kh_id: Option<MACAddress>,
+    /// The raw GTK sub-element, which contains the Key Info, Key Length, RSC and wrapped key.
+    pub gtk: Option<&'a [u8]>,
+    /// The identifier of the R0KH, which is between 1 and 48 bytes long.
+    pub r0kh_id: Option<&'a [u8]>,
+}
+impl FastBSSTransitionElement<'_> {
+    /// Check if the MIC is present, which is the case, if it isn't all zeroes.
+    pub fn has_mic(&self) -> bool {
+        self.mic.iter().any(|byte| *byte != 0x00)
+    }
+}
+impl MeasureWith<()> for FastBSSTransitionElement<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        2 + FTE_MIC_LENGTH
+            + FTE_NONCE_LENGTH * 2
+            + self.r1kh_id.map_or(0, |_| 2 + 6)
+            + self.gtk.map_or(0, |gtk| 2 + gtk.len())
+            + self.r0kh_id.map_or(0, |r0kh_id| 2 + r0kh_id.len())
+    }
+}
+impl<'a> TryFromCtx<'a> for FastBSSTransitionElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let mic_control = MICControl::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+        let mic = from.gread(&mut offset)?;
+        let anonce = from.gread(&mut offset)?;
+        let snonce = from.gread(&mut offset)?;
+
+        let mut r1kh_id = None;
+        let mut gtk = None;
+        let mut r0kh_id = None;
+        while offset < from.len() {
+            let sub_element_id: u8 = from.gread(&mut offset)?;
+            let length = from.gread::<u8>(&mut offset)? as usize;
+            let body = from.gread_with::<&[u8]>(&mut offset, length)?;
+            match sub_element_id {
+                R1KH_ID_SUB_ELEMENT_ID => {
+                    if length != 6 {
+                        return Err(scroll::Error::BadInput {
+                            size: offset,
+                            msg: "The R1KH-ID has to be six bytes long.",
+                        });
+                    }
+                    r1kh_id = Some(body.pread(0)?);
+                }
+                GTK_SUB_ELEMENT_ID => gtk = Some(body),
+                R0KH_ID_SUB_ELEMENT_ID => {
+                    if !(1..=48).contains(&length) {
+                        return Err(scroll::Error::BadInput {
+                            size: offset,
+                            msg: "The R0KH-ID has to be between 1 and 48 bytes long.",
+                        });
+                    }
+                    r0kh_id = Some(body);
+                }
+                _ => {}
+            }
+        }
+
+        Ok((
+            Self {
+                mic_control,
+                mic,
+                anonce,
+                snonce,
+                r1kh_id,
+                gtk,
+                r0kh_id,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for FastBSSTransitionElement<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite_with(self.mic_control.into_bits(), &mut offset, Endian::Little)?;
+        buf.gwrite(self.mic, &mut offset)?;
+        buf.gwrite(self.anonce, &mut offset)?;
+        buf.gwrite(self.snonce, &mut offset)?;
+        if let Some(r1kh_id) = self.r1kh_id {
+            buf.gwrite(R1KH_ID_SUB_ELEMENT_ID, &mut offset)?;
+            buf.gwrite(6u8, &mut offset)?;
+            buf.gwrite(r1kh_id, &mut offset)?;
+        }
+        for (sub_element_id, body) in [
+            (GTK_SUB_ELEMENT_ID, self.gtk),
+            (R0KH_ID_SUB_ELEMENT_ID, self.r0kh_id),
+        ] {
+            if let Some(body) = body {
+                buf.gwrite(sub_element_id, &mut offset)?;
+                buf.gwrite(body.len() as u8, &mut offset)?;
+                buf.gwrite(body, &mut offset)?;
+            }
+        }
+
+        Ok(offset)
+    }
+}
+impl Element for FastBSSTransitionElement<'_> {
+    const ELEMENT_ID: ElementID = ElementID::Id(55);
+    type ReadType<'a> = FastBSSTransitionElement<'a>;
+}
@@ -0,0 +1,332 @@
+use core::fmt::Debug;
+
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use crate::elements::{Element, ElementID};
+
+use super::PPEThresholds;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The MAC capabilities of an HE STA.
+///
+/// Since this field is 48 bits long, it's kept as raw bytes with accessors for commonly used bits.
+pub struct HEMACCapabilitiesInformation(pub [u8; 6]);
+impl HEMACCapabilitiesInformation {
+    /// Check if the bit at the specified index is set.
+    pub const fn is_bit_set(&self, bit: usize) -> bool {
+        self.0[bit / 8] & (1 << (bit % 8)) != 0
+    }
+    /// Indicates support for receiving the HE variant of the HT Control field.
+    pub const fn htc_he_support(&self) -> bool {
+        self.is_bit_set(0)
+    }
+    /// Indicates support for the TWT requester role.
+    pub const fn twt_requester_support(&self) -> bool {
+        self.is_bit_set(1)
+    }
+    /// Indicates support for the TWT responder role.
+    pub const fn twt_responder_support(&self) -> bool {
+        self.is_bit_set(2)
+    }
+    /// Indicates support for broadcast TWT.
+    pub const fn broadcast_twt_support(&self) -> bool {
+        self.is_bit_set(20)
+    }
+    /// Indicates support for OM Control.
+    pub const fn om_control_support(&self) -> bool {
+        self.is_bit_set(25)
+    }
+    /// Returns the Maximum A-MPDU Length Exponent Extension.
+    ///
+    /// This extends the Maximum A-MPDU Length Exponent from the HT or VHT Capabilities element, if that is at it's maximum.
+    pub const fn maximum_ampdu_length_exponent_extension(&self) -> u8 {
+        self.is_bit_set(27) as u8 | (self.is_bit_set(28) as u8) << 1
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The PHY capabilities of an HE STA.
+///
+/// Since this field is 88 bits long, it's kept as raw bytes with accessors for the bits relevant to parsing and commonly used ones.
+pub struct HEPHYCapabilitiesInformation(pub [u8; 11]);
+impl HEPHYCapabilitiesInformation {
+    /// Check if the bit at the specified index is set.
+    pub const fn is_bit_set(&self, bit: usize) -> bool {
+        self.0[bit / 8] & (1 << (bit % 8)) != 0
+    }
+    /// Indicates support for 40 MHz channel width in the 2.4 GHz band.
+    pub const fn supports_40mhz_in_2_4ghz(&self) -> bool {
+        self.is_bit_set(1)
+    }
+    /// Indicates support for 40 and 80 MHz channel width in the 5 and 6 GHz bands.
+    pub const fn supports_40_and_80mhz_in_5ghz(&self) -> bool {
+        self.is_bit_set(2)
+    }
+    /// Indicates support for 160 MHz channel width in the 5 and 6 GHz bands.
+    ///
+    /// If this is set, the HE-MCS maps for 160 MHz are present.
+    pub const fn supports_160mhz(&self) -> bool {
+        self.is_bit_set(3)
+    }
+    /// Indicates support for 80+80 MHz channel width in the 5 and 6 GHz bands.
+    ///
+    /// If this is set, the HE-MCS maps for 80+80 MHz are present.
+    pub const fn supports_80p80mhz(&self) -> bool {
+        self.is_bit_set(4)
+    }
+    /// Indicates support for LDPC encoding of the payload.
+    pub const fn ldpc_coding_in_payload(&self) -> bool {
+        self.is_bit_set(13)
+    }
+    /// Indicates support for transmitting as an SU beamformer.
+    pub const fn su_beamformer(&self) -> bool {
+        self.is_bit_set(31)
+    }
+    /// Indicates support for receiving as an SU beamformee.
+    pub const fn su_beamformee(&self) -> bool {
+        self.is_bit_set(32)
+    }
+    /// Indicates support for transmitting as an MU beamformer.
+    pub const fn mu_beamformer(&self) -> bool {
+        self.is_bit_set(33)
+    }
+    /// Indicates, that the PPE Thresholds field is present in the HE Capabilities element.
+    pub const fn ppe_thresholds_present(&self) -> bool {
+        self.is_bit_set(55)
+    }
+}
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The supported HE-MCS indices.
+    pub enum HEMCSSupport: u8 {
+        ZeroToSeven => 0,
+        ZeroToNine => 1,
+        ZeroToEleven => 2,
+        #[default]
+        NotSupported => 3
+    }
+}
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// The combinations of HE-MCSs and spatial streams supported for one channel width and direction.
+pub struct HEMCSMap(u16);
+impl HEMCSMap {
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+    pub const fn into_bits(self) -> u16 {
+        self.0
+    }
+    /// Returns the supported HE-MCS range for the given number of spatial streams.
+    pub fn he_mcs_support_for_nss(&self, nss: usize) -> Option<HEMCSSupport> {
+        if (1..9).contains(&nss) {
+            Some(HEMCSSupport::from_bits(
+                (self.0 >> ((nss - 1) * 2) & 0b0000_0011) as u8,
+            ))
+        } else {
+            None
+        }
+    }
+    /// Returns an [Iterator] over the HE-MCS ranges.
+    pub fn he_mcs_support_iter(&self) -> impl Iterator<Item = HEMCSSupport> + '_ {
+        (1..9).filter_map(|nss| self.he_mcs_support_for_nss(nss))
+    }
+}
+impl Debug for HEMCSMap {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.he_mcs_support_iter()).finish()
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The receive and transmit HE-MCS maps for one channel width.
+pub struct HEMCSMaps {
+    pub rx_he_mcs_map: HEMCSMap,
+    pub tx_he_mcs_map: HEMCSMap,
+}
+impl TryFromCtx<'_> for HEMCSMaps {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let rx_he_mcs_map = HEMCSMap::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+        let tx_he_mcs_map = HEMCSMap::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+
+        Ok((
+            Self {
+                rx_he_mcs_map,
+                tx_he_mcs_map,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for HEMCSMaps {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite_with(self.rx_he_mcs_map.into_bits(), &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.tx_he_mcs_map.into_bits(), &mut offset, Endian::Little)?;
+
+        Ok(offset)
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Supported HE-MCS And NSS Set field.
+///
+/// The presence of the maps for 160 and 80+80 MHz is indicated by the [HEPHYCapabilitiesInformation].
+pub struct SupportedHEMCSAndNSSSet {
+    pub he_mcs_80mhz: HEMCSMaps,
+    pub he_mcs_160mhz: Option<HEMCSMaps>,
+    pub he_mcs_80p80mhz: Option<HEMCSMaps>,
+}
+impl MeasureWith<()> for SupportedHEMCSAndNSSSet {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        4 + if self.he_mcs_160mhz.is_some() { 4 } else { 0 }
+            + if self.he_mcs_80p80mhz.is_some() { 4 } else { 0 }
+    }
+}
+impl TryFromCtx<'_, HEPHYCapabilitiesInformation> for SupportedHEMCSAndNSSSet {
+    type Error = scroll::Error;
+    fn try_from_ctx(
+        from: &[u8],
+        he_phy_capabilities_information: HEPHYCapabilitiesInformation,
+    ) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let he_mcs_80mhz = from.gread(&mut offset)?;
+        let he_mcs_160mhz = if he_phy_capabilities_information.supports_160mhz() {
+            Some(from.gread(&mut offset)?)
+        } else {
+            None
+        };
+        let he_mcs_80p80mhz = if he_phy_capabilities_information.supports_80p80mhz() {
+            Some(from.gread(&mut offset)?)
+        } else {
+            None
+        };
+
+        Ok((
+            Self {
+                he_mcs_80mhz,
+                he_mcs_160mhz,
+                he_mcs_80p80mhz,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for SupportedHEMCSAndNSSSet {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.he_mcs_80mhz, &mut offset)?;
+        if let Some(he_mcs_160mhz) = self.he_mcs_160mhz {
+            buf.gwrite(he_mcs_160mhz, &mut offset)?;
+        }
+        if let Some(he_mcs_80p80mhz) = self.he_mcs_80p80mhz {
+            buf.gwrite(he_mcs_80p80mhz, &mut offset)?;
+        }
+
+        Ok(offset)
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The HE Capabilities element advertises the HE capabilities of a STA.
+///
+/// When writing, the presence of the optional MCS maps and PPE Thresholds isn't checked against the PHY capabilities, so the user has to make sure they match.
+pub struct HECapabilitiesElement<'a> {
+    pub he_mac_capabilities_information: HEMACCapabilitiesInformation,
+    pub he_phy_capabilities_information: HEPHYCapabilitiesInformation,
+    pub supported_he_mcs_and_nss_set: SupportedHEMCSAndNSSSet,
+    /// The PPE Thresholds field, which is present if indicated by the PHY capabilities.
+    pub ppe_thresholds: Option<PPEThresholds<'a>>,
+}
+impl HECapabilitiesElement<'_> {
+    /// Indicates support for 160 MHz channel width.
+    pub const fn supports_160mhz(&self) -> bool {
+        self.he_phy_capabilities_information.supports_160mhz()
+    }
+    /// Indicates support for 80+80 MHz channel width.
+    pub const fn supports_80p80mhz(&self) -> bool {
+        self.he_phy_capabilities_information.supports_80p80mhz()
+    }
+    /// Indicates support for transmitting as an SU beamformer.
+    pub const fn su_beamformer(&self) -> bool {
+        self.he_phy_capabilities_information.su_beamformer()
+    }
+    /// Indicates support for receiving as an SU beamformee.
+    pub const fn su_beamformee(&self) -> bool {
+        self.he_phy_capabilities_information.su_beamformee()
+    }
+}
+impl MeasureWith<()> for HECapabilitiesElement<'_> {
+    fn measure_with(&self, ctx: &()) -> usize {
+        17 + self.supported_he_mcs_and_nss_set.measure_with(ctx)
+            + self
+                .ppe_thresholds
+                .map_or(0, |ppe_thresholds| ppe_thresholds.measure_with(ctx))
+    }
+}
+impl<'a> TryFromCtx<'a> for HECapabilitiesElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let he_mac_capabilities_information =
+            HEMACCapabilitiesInformation(from.gread(&mut offset)?);
+        let he_phy_capabilities_information =
+            HEPHYCapabilitiesInformation(from.gread(&mut offset)?);
+        let supported_he_mcs_and_nss_set =
+            from.gread_with(&mut offset, he_phy_capabilities_information)?;
+        let ppe_thresholds = if he_phy_capabilities_information.ppe_thresholds_present() {
+            Some(from.gread(&mut offset)?)
+        } else {
+            None
+        };
+
+        Ok((
+            Self {
+                he_mac_capabilities_information,
+                he_phy_capabilities_information,
+                supported_he_mcs_and_nss_set,
+                ppe_thresholds,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for HECapabilitiesElement<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.he_mac_capabilities_information.0, &mut offset)?;
+        buf.gwrite(self.he_phy_capabilities_information.0, &mut offset)?;
+        buf.gwrite(self.supported_he_mcs_and_nss_set, &mut offset)?;
+        if let Some(ppe_thresholds) = self.ppe_thresholds {
+            buf.gwrite(ppe_thresholds, &mut offset)?;
+        }
+
+        Ok(offset)
+    }
+}
+impl Element for HECapabilitiesElement<'_> {
+    const ELEMENT_ID: ElementID = ElementID::ExtId(35);
+    type ReadType<'a> = HECapabilitiesElement<'a>;
+}
@@ -0,0 +1,7 @@
+//! This module contains support for the HE (802.11ax) elements.
+
+mod capabilities;
+pub use capabilities::*;
+
+mod ppe_thresholds;
+pub use ppe_thresholds::*;
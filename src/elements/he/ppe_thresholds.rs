@@ -0,0 +1,102 @@
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Pwrite,
+};
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The PPE Thresholds field, which is located at the end of the HE Capabilities element.
+///
+/// The length of this field isn't fixed, but depends on the NSTS and RU Index Bitmask subfields in the first octet.
+/// When reading, only the amount of bytes indicated by these subfields is consumed, so that any following data is left untouched.
+pub struct PPEThresholds<'a> {
+    bytes: &'a [u8],
+}
+impl<'a> PPEThresholds<'a> {
+    /// Create a new PPE Thresholds field from the raw bytes.
+    ///
+    /// This returns [None], if the length of `bytes` doesn't match the length indicated by the first octet.
+    pub const fn new(bytes: &'a [u8]) -> Option<Self> {
+        if !bytes.is_empty() && bytes.len() == Self::length_from_header(bytes[0]) {
+            Some(Self { bytes })
+        } else {
+            None
+        }
+    }
+    /// Calculate the length in bytes of the PPE Thresholds field from it's first octet.
+    ///
+    /// The field consists of a seven bit header, followed by a PPET16 and PPET8 subfield of three bits each, for every combination of spatial stream and RU index present. The result is padded to the next octet boundary.
+    pub const fn length_from_header(header: u8) -> usize {
+        let nss = (header & 0b0000_0111) as usize + 1;
+        let ru_count = ((header >> 3) & 0b0000_1111).count_ones() as usize;
+        (7 + nss * ru_count * 6 + 7) / 8
+    }
+    /// The number of spatial streams, for which thresholds are present.
+    pub const fn nss(&self) -> u8 {
+        (self.bytes[0] & 0b0000_0111) + 1
+    }
+    /// The bitmask of RU allocation indices, for which thresholds are present.
+    ///
+    /// Bits zero to three correspond to the 242, 484, 996 and 2x996 tone RUs.
+    pub const fn ru_index_bitmask(&self) -> u8 {
+        (self.bytes[0] >> 3) & 0b0000_1111
+    }
+    /// Get the PPET16 and PPET8 values for the specified spatial stream and RU index.
+    ///
+    /// This returns [None], if no thresholds are present for that combination.
+    pub const fn thresholds(&self, nss_index: u8, ru_index: u8) -> Option<(u8, u8)> {
+        if nss_index >= self.nss() || ru_index >= 4 {
+            return None;
+        }
+        let ru_index_bitmask = self.ru_index_bitmask();
+        if ru_index_bitmask & (1 << ru_index) == 0 {
+            return None;
+        }
+        let ru_count = ru_index_bitmask.count_ones() as usize;
+        let preceding_rus = (ru_index_bitmask & ((1 << ru_index) - 1)).count_ones() as usize;
+        let bit_offset = 7 + (nss_index as usize * ru_count + preceding_rus) * 6;
+        Some((self.read_bits(bit_offset), self.read_bits(bit_offset + 3)))
+    }
+    /// Read three bits starting at the specified bit offset.
+    const fn read_bits(&self, bit_offset: usize) -> u8 {
+        let mut value = 0;
+        let mut i = 0;
+        while i < 3 {
+            let bit = bit_offset + i;
+            value |= ((self.bytes[bit / 8] >> (bit % 8)) & 1) << i;
+            i += 1;
+        }
+        value
+    }
+    /// Get the raw bytes of the field.
+    pub const fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+impl MeasureWith<()> for PPEThresholds<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        self.bytes.len()
+    }
+}
+impl<'a> TryFromCtx<'a> for PPEThresholds<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let Some(header) = from.first() else {
+            return Err(scroll::Error::TooBig { size: 1, len: 0 });
+        };
+        let length = Self::length_from_header(*header);
+        let Some(bytes) = from.get(..length) else {
+            return Err(scroll::Error::TooBig {
+                size: length,
+                len: from.len(),
+            });
+        };
+        Ok((Self { bytes }, length))
+    }
+}
+impl TryIntoCtx for PPEThresholds<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        buf.pwrite(self.bytes, 0)
+    }
+}
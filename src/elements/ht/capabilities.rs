@@ -81,6 +81,17 @@ serializable_enum! {
         VeryLarge => 3
     }
 }
+impl MAXAMpduLength {
+    /// Returns the maximum A-MPDU length in bytes.
+    ///
+    /// This returns [None] for unknown values.
+    pub const fn in_bytes(&self) -> Option<usize> {
+        match self {
+            Self::Unknown(_) => None,
+            _ => Some((1 << (13 + self.into_bits())) - 1),
+        }
+    }
+}
 
 serializable_enum! {
     #[cfg_attr(feature = "defmt", derive(defmt::Format))]
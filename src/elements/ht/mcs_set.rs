@@ -66,6 +66,17 @@ impl SupportedMCSSet {
             .into_iter()
             .flat_map(|byte| array::from_fn::<bool, 8, _>(|i| check_bit!(byte, bit!(i))))
     }
+    /// Returns the number of spatial streams, which the STA can receive.
+    ///
+    /// MCS indices 0-7 use one spatial stream, 8-15 two and so on up to four spatial streams.
+    /// The spatial streams are counted from one upwards, until a group of eight MCS indices has no supported MCS.
+    pub const fn rx_spatial_streams(&self) -> u8 {
+        let mut spatial_streams = 0;
+        while spatial_streams < 4 && self.supported_rx_mcs_set[spatial_streams as usize] != 0 {
+            spatial_streams += 1;
+        }
+        spatial_streams
+    }
 }
 impl MeasureWith<()> for SupportedMCSSet {
     fn measure_with(&self, _ctx: &()) -> usize {
@@ -131,7 +131,7 @@ impl TryIntoCtx for HTOperationElement {
 
         buf.gwrite(self.primary_channel, &mut offset)?;
         buf.gwrite(
-            &self.ht_operation_information.into_bits().to_be_bytes()[..5],
+            &self.ht_operation_information.into_bits().to_le_bytes()[..5],
             &mut offset,
         )?;
         buf.gwrite(self.basic_ht_mcs_set, &mut offset)?;
@@ -0,0 +1,61 @@
+use macro_bits::{bit, check_bit};
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+/// The data type of the GTK KDE.
+const GTK_KDE_DATA_TYPE: u8 = 1;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The GTK KDE carries the group temporal key.
+pub struct GTKKDE<'a> {
+    /// The key ID of the GTK, which is in the range one to three, since key ID zero is reserved for the PTK.
+    pub key_id: u8,
+    /// Indicates, that the GTK is used for transmission as well as reception.
+    pub tx: bool,
+    /// The GTK itself.
+    pub gtk: &'a [u8],
+}
+impl MeasureWith<()> for GTKKDE<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        2 + self.gtk.len()
+    }
+}
+impl<'a> TryFromCtx<'a> for GTKKDE<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let flags = from.gread::<u8>(&mut offset)?;
+        // Reserved
+        offset += 1;
+        let gtk = from.get(offset..).ok_or(scroll::Error::BadOffset(offset))?;
+
+        Ok((
+            Self {
+                key_id: flags & 0b11,
+                tx: check_bit!(flags, bit!(2)),
+                gtk,
+            },
+            from.len(),
+        ))
+    }
+}
+impl TryIntoCtx for GTKKDE<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(
+            (self.key_id & 0b11) | if self.tx { bit!(2) } else { 0 },
+            &mut offset,
+        )?;
+        buf.gwrite(0u8, &mut offset)?;
+        buf.gwrite(self.gtk, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+define_kde!(GTKKDE<'a>, GTK_KDE_DATA_TYPE);
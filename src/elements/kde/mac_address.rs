@@ -0,0 +1,37 @@
+use mac_parser::MACAddress;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+/// The data type of the MAC address KDE.
+const MAC_ADDRESS_KDE_DATA_TYPE: u8 = 3;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The MAC address KDE carries the MAC address of a STA.
+pub struct MACAddressKDE {
+    pub mac_address: MACAddress,
+}
+impl MeasureWith<()> for MACAddressKDE {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        6
+    }
+}
+impl TryFromCtx<'_> for MACAddressKDE {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let mac_address = from.gread(&mut offset)?;
+
+        Ok((Self { mac_address }, offset))
+    }
+}
+impl TryIntoCtx for MACAddressKDE {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        buf.pwrite(self.mac_address, 0)
+    }
+}
+define_kde!(MACAddressKDE, MAC_ADDRESS_KDE_DATA_TYPE);
@@ -0,0 +1,43 @@
+//! This module contains the key data encapsulations (KDEs), which are carried in the Key Data field of EAPOL-Key frames.
+//!
+//! KDEs are encoded like vendor specific elements with the [IEEE OUI](crate::common::IEEE_OUI) and a data type, as specified in 12.7.2 of IEEE 802.11-2020.
+//! This allows reading them from the (unwrapped) key data with [ReadElements](super::ReadElements).
+
+/// Implement [Element](super::Element) for a KDE with the specified data type.
+macro_rules! define_kde {
+    ($kde:ident<$lifetime:lifetime>, $data_type:expr) => {
+        impl $crate::elements::Element for $kde<'_> {
+            const ELEMENT_ID: $crate::elements::ElementID =
+                $crate::elements::ElementID::VendorSpecific {
+                    prefix: &[
+                        $crate::common::IEEE_OUI[0],
+                        $crate::common::IEEE_OUI[1],
+                        $crate::common::IEEE_OUI[2],
+                        $data_type,
+                    ],
+                };
+            type ReadType<$lifetime> = $kde<$lifetime>;
+        }
+    };
+    ($kde:ident, $data_type:expr) => {
+        impl $crate::elements::Element for $kde {
+            const ELEMENT_ID: $crate::elements::ElementID =
+                $crate::elements::ElementID::VendorSpecific {
+                    prefix: &[
+                        $crate::common::IEEE_OUI[0],
+                        $crate::common::IEEE_OUI[1],
+                        $crate::common::IEEE_OUI[2],
+                        $data_type,
+                    ],
+                };
+            type ReadType<'a> = Self;
+        }
+    };
+}
+
+mod gtk;
+pub use gtk::GTKKDE;
+mod mac_address;
+pub use mac_address::MACAddressKDE;
+mod pmkid;
+pub use pmkid::PMKIDKDE;
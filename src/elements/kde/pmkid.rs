@@ -0,0 +1,38 @@
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+/// The data type of the PMKID KDE.
+const PMKID_KDE_DATA_TYPE: u8 = 4;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The PMKID KDE carries the identifier of the PMK, which is used in the 4-way handshake.
+///
+/// It's sent by the authenticator in message 1 of the 4-way handshake.
+pub struct PMKIDKDE {
+    pub pmkid: [u8; 16],
+}
+impl MeasureWith<()> for PMKIDKDE {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        16
+    }
+}
+impl TryFromCtx<'_> for PMKIDKDE {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let pmkid = from.gread(&mut offset)?;
+
+        Ok((Self { pmkid }, offset))
+    }
+}
+impl TryIntoCtx for PMKIDKDE {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        buf.pwrite(self.pmkid, 0)
+    }
+}
+define_kde!(PMKIDKDE, PMKID_KDE_DATA_TYPE);
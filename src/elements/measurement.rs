@@ -0,0 +1,195 @@
+//! This module contains the Measurement Request and Measurement Report elements.
+//!
+//! These are carried in [Spectrum Management](crate::mgmt_frame::body::action::MeasurementRequestBody) and Radio Measurement action frames.
+
+use bitfield_struct::bitfield;
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+use super::{Element, ElementID};
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The type of a measurement.
+    pub enum MeasurementType: u8 {
+        #[default]
+        Basic => 0,
+        ClearChannelAssessment => 1,
+        ReceivePowerIndicationHistogram => 2,
+        ChannelLoad => 3,
+        NoiseHistogram => 4,
+        Beacon => 5,
+        Frame => 6,
+        STAStatistics => 7,
+        LCI => 8,
+        TransmitStreamCategory => 9,
+        MulticastDiagnostics => 10,
+        LocationCivic => 11,
+        LocationIdentifier => 12,
+        DirectionalChannelQuality => 13,
+        DirectionalMeasurement => 14,
+        DirectionalStatistics => 15,
+        FineTimingMeasurementRange => 16,
+        MeasurementPause => 255
+    }
+}
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The Measurement Request Mode field of a [MeasurementRequestElement].
+pub struct MeasurementRequestMode {
+    /// Indicates, that this measurement should start at the same time as the previous one.
+    pub parallel: bool,
+    /// Indicates, that the [request](Self::request) and [report](Self::report) bits are valid.
+    pub enable: bool,
+    /// Indicates, that the STA accepts measurement requests of this type from the receiver.
+    pub request: bool,
+    /// Indicates, that the STA accepts autonomous reports of this type.
+    pub report: bool,
+    /// Indicates, that the measurement duration is mandatory.
+    pub duration_mandatory: bool,
+    #[bits(3)]
+    __: u8,
+}
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The Measurement Report Mode field of a [MeasurementReportElement].
+pub struct MeasurementReportMode {
+    /// Indicates, that the request arrived too late, to perform the measurement.
+    pub late: bool,
+    /// Indicates, that the STA is incapable of performing the measurement.
+    pub incapable: bool,
+    /// Indicates, that the STA refused to perform the measurement.
+    pub refused: bool,
+    #[bits(5)]
+    __: u8,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Measurement Request element contains a request for the receiving STA, to perform the specified measurement.
+pub struct MeasurementRequestElement<'a> {
+    /// A number identifying this measurement request.
+    pub measurement_token: u8,
+    pub measurement_request_mode: MeasurementRequestMode,
+    pub measurement_type: MeasurementType,
+    /// The request, which depends on the [measurement type](Self::measurement_type).
+    pub measurement_request: &'a [u8],
+}
+impl MeasureWith<()> for MeasurementRequestElement<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        3 + self.measurement_request.len()
+    }
+}
+impl<'a> TryFromCtx<'a> for MeasurementRequestElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let measurement_token = from.gread(&mut offset)?;
+        let measurement_request_mode = MeasurementRequestMode::from_bits(from.gread(&mut offset)?);
+        let measurement_type = MeasurementType::from_bits(from.gread(&mut offset)?);
+        let measurement_request = &from[offset..];
+
+        Ok((
+            Self {
+                measurement_token,
+                measurement_request_mode,
+                measurement_type,
+                measurement_request,
+            },
+            from.len(),
+        ))
+    }
+}
+impl TryIntoCtx for MeasurementRequestElement<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.measurement_token, &mut offset)?;
+        buf.gwrite(self.measurement_request_mode.into_bits(), &mut offset)?;
+        buf.gwrite(self.measurement_type.into_bits(), &mut offset)?;
+        buf.gwrite(self.measurement_request, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl Element for MeasurementRequestElement<'_> {
+    const ELEMENT_ID: ElementID = ElementID::Id(38);
+    type ReadType<'a> = MeasurementRequestElement<'a>;
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Measurement Report element contains the result of a measurement or a reason, why it wasn't performed.
+pub struct MeasurementReportElement<'a> {
+    /// The token of the corresponding [MeasurementRequestElement].
+    pub measurement_token: u8,
+    pub measurement_report_mode: MeasurementReportMode,
+    pub measurement_type: MeasurementType,
+    /// The report, which depends on the [measurement type](Self::measurement_type).
+    pub measurement_report: &'a [u8],
+}
+impl MeasurementReportElement<'_> {
+    /// Check if radar was detected, which is reported in a basic report.
+    ///
+    /// This returns [None], if this isn't a basic report or it's too short.
+    pub fn radar_detected(&self) -> Option<bool> {
+        if self.measurement_type != MeasurementType::Basic {
+            return None;
+        }
+        // The map field follows the channel number, start time and duration.
+        self.measurement_report
+            .get(11)
+            .map(|map| map & 0b0000_1000 != 0)
+    }
+}
+impl MeasureWith<()> for MeasurementReportElement<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        3 + self.measurement_report.len()
+    }
+}
+impl<'a> TryFromCtx<'a> for MeasurementReportElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let measurement_token = from.gread(&mut offset)?;
+        let measurement_report_mode = MeasurementReportMode::from_bits(from.gread(&mut offset)?);
+        let measurement_type = MeasurementType::from_bits(from.gread(&mut offset)?);
+        let measurement_report = &from[offset..];
+
+        Ok((
+            Self {
+                measurement_token,
+                measurement_report_mode,
+                measurement_type,
+                measurement_report,
+            },
+            from.len(),
+        ))
+    }
+}
+impl TryIntoCtx for MeasurementReportElement<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.measurement_token, &mut offset)?;
+        buf.gwrite(self.measurement_report_mode.into_bits(), &mut offset)?;
+        buf.gwrite(self.measurement_type.into_bits(), &mut offset)?;
+        buf.gwrite(self.measurement_report, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl Element for MeasurementReportElement<'_> {
+    const ELEMENT_ID: ElementID = ElementID::Id(39);
+    type ReadType<'a> = MeasurementReportElement<'a>;
+}
@@ -0,0 +1,371 @@
+use core::marker::PhantomData;
+
+use bitfield_struct::bitfield;
+use mac_parser::MACAddress;
+use scroll::{
+    ctx::{MeasureWith, SizeWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use crate::{
+    common::ReadIterator,
+    elements::{Element, ElementID},
+};
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The flags of a [PREQElement].
+pub struct PREQFlags {
+    /// Indicates, that the originator is connected to a mesh gate.
+    pub gate_announcement: bool,
+    /// Indicates, that the PREQ is individually addressed.
+    /// If this is false, it is group addressed.
+    pub individually_addressed: bool,
+    /// Indicates, that a proactive PREP should be sent in response.
+    pub proactive_prep: bool,
+    #[bits(3)]
+    __: u8,
+    /// This is set automatically while writing, based on the presence of the external address.
+    address_extension: bool,
+    __: bool,
+}
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The flags of a single target in a [PREQElement].
+pub struct PREQTargetFlags {
+    /// Indicates, that only the target mesh STA may respond with a PREP.
+    pub target_only: bool,
+    __: bool,
+    /// Indicates, that the target HWMP sequence number is unknown.
+    pub unknown_target_hwmp_sequence_number: bool,
+    #[bits(5)]
+    __: u8,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// A single target of a [PREQElement].
+pub struct PREQTarget {
+    /// The flags for this target.
+    pub flags: PREQTargetFlags,
+    /// The address of the target mesh STA.
+    pub target_address: MACAddress,
+    /// The latest known HWMP sequence number of the target.
+    pub target_hwmp_sequence_number: u32,
+}
+impl SizeWith for PREQTarget {
+    fn size_with(_ctx: &()) -> usize {
+        11
+    }
+}
+impl MeasureWith<()> for PREQTarget {
+    fn measure_with(&self, ctx: &()) -> usize {
+        Self::size_with(ctx)
+    }
+}
+impl TryFromCtx<'_> for PREQTarget {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let flags = PREQTargetFlags::from_bits(from.gread(&mut offset)?);
+        let target_address = from.gread(&mut offset)?;
+        let target_hwmp_sequence_number = from.gread_with(&mut offset, Endian::Little)?;
+
+        Ok((
+            Self {
+                flags,
+                target_address,
+                target_hwmp_sequence_number,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for PREQTarget {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.flags.into_bits(), &mut offset)?;
+        buf.gwrite(self.target_address, &mut offset)?;
+        buf.gwrite_with(
+            self.target_hwmp_sequence_number,
+            &mut offset,
+            Endian::Little,
+        )?;
+
+        Ok(offset)
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Hash)]
+/// The Path Request (PREQ) element is used by HWMP, to discover a path to one or more target mesh STAs.
+///
+/// The `targets` field is generic, to allow passing targets agnostic of the collection.
+/// When deserializing this struct, it is a [ReadIterator] over [PREQTarget].
+pub struct PREQElement<'a, Targets = ReadIterator<'a, (), PREQTarget>> {
+    pub flags: PREQFlags,
+    /// The number of hops from the originator to the mesh STA transmitting this element.
+    pub hop_count: u8,
+    /// The remaining number of hops, this element may be forwarded.
+    pub element_ttl: u8,
+    /// Unique ID, which identifies this path discovery of the originator.
+    pub path_discovery_id: u32,
+    /// The address of the mesh STA originating the PREQ.
+    pub originator_address: MACAddress,
+    /// The HWMP sequence number of the originator.
+    pub originator_hwmp_sequence_number: u32,
+    /// The external address on behalf of which the PREQ was originated.
+    pub originator_external_address: Option<MACAddress>,
+    /// The time in TUs, for which the forwarding information is valid.
+    pub lifetime: u32,
+    /// The cumulative metric from the originator to the mesh STA transmitting this element.
+    pub metric: u32,
+    /// The targets of the PREQ.
+    pub targets: Targets,
+    pub _phantom: PhantomData<&'a ()>,
+}
+impl<LhsTargets, RhsTargets> PartialEq<PREQElement<'_, RhsTargets>> for PREQElement<'_, LhsTargets>
+where
+    LhsTargets: IntoIterator<Item = PREQTarget> + Clone,
+    RhsTargets: IntoIterator<Item = PREQTarget> + Clone,
+{
+    fn eq(&self, other: &PREQElement<'_, RhsTargets>) -> bool {
+        self.flags == other.flags
+            && self.hop_count == other.hop_count
+            && self.element_ttl == other.element_ttl
+            && self.path_discovery_id == other.path_discovery_id
+            && self.originator_address == other.originator_address
+            && self.originator_hwmp_sequence_number == other.originator_hwmp_sequence_number
+            && self.originator_external_address == other.originator_external_address
+            && self.lifetime == other.lifetime
+            && self.metric == other.metric
+            && self.targets.clone().into_iter().eq(other.targets.clone())
+    }
+}
+impl<Targets: IntoIterator<Item = PREQTarget> + Clone> Eq for PREQElement<'_, Targets> {}
+impl<'a> TryFromCtx<'a> for PREQElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let mut flags = PREQFlags::from_bits(from.gread(&mut offset)?);
+        let hop_count = from.gread(&mut offset)?;
+        let element_ttl = from.gread(&mut offset)?;
+        let path_discovery_id = from.gread_with(&mut offset, Endian::Little)?;
+        let originator_address = from.gread(&mut offset)?;
+        let originator_hwmp_sequence_number = from.gread_with(&mut offset, Endian::Little)?;
+        let originator_external_address = if flags.address_extension() {
+            Some(from.gread(&mut offset)?)
+        } else {
+            None
+        };
+        flags.set_address_extension(false);
+        let lifetime = from.gread_with(&mut offset, Endian::Little)?;
+        let metric = from.gread_with(&mut offset, Endian::Little)?;
+        let target_count = from.gread::<u8>(&mut offset)? as usize;
+        let targets = ReadIterator::new(
+            from.gread_with(&mut offset, target_count * PREQTarget::size_with(&()))?,
+        );
+
+        Ok((
+            Self {
+                flags,
+                hop_count,
+                element_ttl,
+                path_discovery_id,
+                originator_address,
+                originator_hwmp_sequence_number,
+                originator_external_address,
+                lifetime,
+                metric,
+                targets,
+                _phantom: PhantomData,
+            },
+            offset,
+        ))
+    }
+}
+impl<Targets: IntoIterator<Item = PREQTarget> + Clone> MeasureWith<()>
+    for PREQElement<'_, Targets>
+{
+    fn measure_with(&self, ctx: &()) -> usize {
+        26 + if self.originator_external_address.is_some() {
+            6
+        } else {
+            0
+        } + self.targets.clone().into_iter().count() * PREQTarget::size_with(ctx)
+    }
+}
+impl<Targets: IntoIterator<Item = PREQTarget>> TryIntoCtx for PREQElement<'_, Targets> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(
+            self.flags
+                .with_address_extension(self.originator_external_address.is_some())
+                .into_bits(),
+            &mut offset,
+        )?;
+        buf.gwrite(self.hop_count, &mut offset)?;
+        buf.gwrite(self.element_ttl, &mut offset)?;
+        buf.gwrite_with(self.path_discovery_id, &mut offset, Endian::Little)?;
+        buf.gwrite(self.originator_address, &mut offset)?;
+        buf.gwrite_with(
+            self.originator_hwmp_sequence_number,
+            &mut offset,
+            Endian::Little,
+        )?;
+        if let Some(originator_external_address) = self.originator_external_address {
+            buf.gwrite(originator_external_address, &mut offset)?;
+        }
+        buf.gwrite_with(self.lifetime, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.metric, &mut offset, Endian::Little)?;
+        // The target count is written after the targets, since we don't know it in advance.
+        let target_count_offset = offset;
+        offset += 1;
+        let mut target_count = 0usize;
+        for target in self.targets {
+            buf.gwrite(target, &mut offset)?;
+            target_count += 1;
+        }
+        let Ok(target_count) = u8::try_from(target_count) else {
+            return Err(scroll::Error::BadInput {
+                size: target_count,
+                msg: "A PREQ element can't have more than 255 targets.",
+            });
+        };
+        buf.pwrite(target_count, target_count_offset)?;
+
+        Ok(offset)
+    }
+}
+impl<Targets: IntoIterator<Item = PREQTarget> + Clone> Element for PREQElement<'_, Targets> {
+    const ELEMENT_ID: ElementID = ElementID::Id(130);
+    type ReadType<'a> = PREQElement<'a>;
+}
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The flags of a [PREPElement].
+pub struct PREPFlags {
+    #[bits(6)]
+    __: u8,
+    /// This is set automatically while writing, based on the presence of the external address.
+    address_extension: bool,
+    __: bool,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Path Reply (PREP) element is used by HWMP, to establish a forward path to the target and to confirm a reverse path to the originator.
+pub struct PREPElement {
+    pub flags: PREPFlags,
+    /// The number of hops from the target to the mesh STA transmitting this element.
+    pub hop_count: u8,
+    /// The remaining number of hops, this element may be forwarded.
+    pub element_ttl: u8,
+    /// The address of the target mesh STA.
+    pub target_address: MACAddress,
+    /// The HWMP sequence number of the target.
+    pub target_hwmp_sequence_number: u32,
+    /// The external address on behalf of which the PREP was generated.
+    pub target_external_address: Option<MACAddress>,
+    /// The time in TUs, for which the forwarding information is valid.
+    pub lifetime: u32,
+    /// The cumulative metric from the target to the mesh STA transmitting this element.
+    pub metric: u32,
+    /// The address of the mesh STA, which originated the PREQ.
+    pub originator_address: MACAddress,
+    /// The HWMP sequence number of the originator.
+    pub originator_hwmp_sequence_number: u32,
+}
+impl MeasureWith<()> for PREPElement {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        31 + if self.target_external_address.is_some() {
+            6
+        } else {
+            0
+        }
+    }
+}
+impl TryFromCtx<'_> for PREPElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let mut flags = PREPFlags::from_bits(from.gread(&mut offset)?);
+        let hop_count = from.gread(&mut offset)?;
+        let element_ttl = from.gread(&mut offset)?;
+        let target_address = from.gread(&mut offset)?;
+        let target_hwmp_sequence_number = from.gread_with(&mut offset, Endian::Little)?;
+        let target_external_address = if flags.address_extension() {
+            Some(from.gread(&mut offset)?)
+        } else {
+            None
+        };
+        flags.set_address_extension(false);
+        let lifetime = from.gread_with(&mut offset, Endian::Little)?;
+        let metric = from.gread_with(&mut offset, Endian::Little)?;
+        let originator_address = from.gread(&mut offset)?;
+        let originator_hwmp_sequence_number = from.gread_with(&mut offset, Endian::Little)?;
+
+        Ok((
+            Self {
+                flags,
+                hop_count,
+                element_ttl,
+                target_address,
+                target_hwmp_sequence_number,
+                target_external_address,
+                lifetime,
+                metric,
+                originator_address,
+                originator_hwmp_sequence_number,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for PREPElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(
+            self.flags
+                .with_address_extension(self.target_external_address.is_some())
+                .into_bits(),
+            &mut offset,
+        )?;
+        buf.gwrite(self.hop_count, &mut offset)?;
+        buf.gwrite(self.element_ttl, &mut offset)?;
+        buf.gwrite(self.target_address, &mut offset)?;
+        buf.gwrite_with(
+            self.target_hwmp_sequence_number,
+            &mut offset,
+            Endian::Little,
+        )?;
+        if let Some(target_external_address) = self.target_external_address {
+            buf.gwrite(target_external_address, &mut offset)?;
+        }
+        buf.gwrite_with(self.lifetime, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.metric, &mut offset, Endian::Little)?;
+        buf.gwrite(self.originator_address, &mut offset)?;
+        buf.gwrite_with(
+            self.originator_hwmp_sequence_number,
+            &mut offset,
+            Endian::Little,
+        )?;
+
+        Ok(offset)
+    }
+}
+impl Element for PREPElement {
+    const ELEMENT_ID: ElementID = ElementID::Id(131);
+    type ReadType<'a> = Self;
+}
@@ -0,0 +1,179 @@
+use bitfield_struct::bitfield;
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, SizeWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+use crate::elements::{Element, ElementID};
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The path selection protocol, which is active in the MBSS.
+    pub enum PathSelectionProtocol: u8 {
+        #[default]
+        /// The Hybrid Wireless Mesh Protocol.
+        HWMP => 1,
+        VendorSpecific => 255
+    }
+}
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The path selection metric, which is active in the MBSS.
+    pub enum PathSelectionMetric: u8 {
+        #[default]
+        /// The airtime link metric.
+        Airtime => 1,
+        VendorSpecific => 255
+    }
+}
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The congestion control mode, which is active in the MBSS.
+    pub enum CongestionControlMode: u8 {
+        #[default]
+        /// No congestion control is active.
+        NotActivated => 0,
+        /// The congestion control signaling protocol.
+        Signaling => 1,
+        VendorSpecific => 255
+    }
+}
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The synchronization method, which is active in the MBSS.
+    pub enum SynchronizationMethod: u8 {
+        #[default]
+        /// Neighbor offset synchronization.
+        NeighborOffset => 1,
+        VendorSpecific => 255
+    }
+}
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The authentication protocol, which is used by the MBSS.
+    pub enum MeshAuthenticationProtocol: u8 {
+        #[default]
+        /// No authentication is required.
+        None => 0,
+        /// Simultaneous Authentication of Equals.
+        SAE => 1,
+        /// IEEE 802.1X authentication.
+        IEEE8021X => 2,
+        VendorSpecific => 255
+    }
+}
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// Information about the formation of the MBSS.
+pub struct MeshFormationInfo {
+    /// Indicates, that the mesh STA has a mesh path to a mesh gate.
+    pub connected_to_mesh_gate: bool,
+    #[bits(6)]
+    /// The number of mesh peerings, currently maintained by the mesh STA.
+    pub number_of_peerings: u8,
+    /// Indicates, that the mesh STA has a connection to an authentication server.
+    pub connected_to_as: bool,
+}
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The mesh capabilities of the mesh STA.
+pub struct MeshCapability {
+    /// Indicates, that the mesh STA is willing to establish additional mesh peerings.
+    pub accepting_additional_mesh_peerings: bool,
+    pub mcca_supported: bool,
+    pub mcca_enabled: bool,
+    /// Indicates, that the mesh STA forwards MSDUs.
+    pub forwarding: bool,
+    pub mbca_enabled: bool,
+    /// Indicates, that the mesh STA is adjusting it's TBTT.
+    pub tbtt_adjusting: bool,
+    /// Indicates, that at least one of the peer-specific mesh power modes is deep sleep mode.
+    pub mesh_power_save_level: bool,
+    __: bool,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Mesh Configuration element is used to advertise mesh services.
+pub struct MeshConfigurationElement {
+    pub active_path_selection_protocol: PathSelectionProtocol,
+    pub active_path_selection_metric: PathSelectionMetric,
+    pub congestion_control_mode: CongestionControlMode,
+    pub synchronization_method: SynchronizationMethod,
+    pub authentication_protocol: MeshAuthenticationProtocol,
+    pub mesh_formation_info: MeshFormationInfo,
+    pub mesh_capability: MeshCapability,
+}
+impl MeshConfigurationElement {
+    /// Check if the mesh STA accepts additional mesh peerings.
+    pub const fn accepts_additional_peerings(&self) -> bool {
+        self.mesh_capability.accepting_additional_mesh_peerings()
+    }
+}
+impl SizeWith for MeshConfigurationElement {
+    fn size_with(_ctx: &()) -> usize {
+        7
+    }
+}
+impl MeasureWith<()> for MeshConfigurationElement {
+    fn measure_with(&self, ctx: &()) -> usize {
+        Self::size_with(ctx)
+    }
+}
+impl TryFromCtx<'_> for MeshConfigurationElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let active_path_selection_protocol =
+            PathSelectionProtocol::from_bits(from.gread(&mut offset)?);
+        let active_path_selection_metric = PathSelectionMetric::from_bits(from.gread(&mut offset)?);
+        let congestion_control_mode = CongestionControlMode::from_bits(from.gread(&mut offset)?);
+        let synchronization_method = SynchronizationMethod::from_bits(from.gread(&mut offset)?);
+        let authentication_protocol =
+            MeshAuthenticationProtocol::from_bits(from.gread(&mut offset)?);
+        let mesh_formation_info = MeshFormationInfo::from_bits(from.gread(&mut offset)?);
+        let mesh_capability = MeshCapability::from_bits(from.gread(&mut offset)?);
+
+        Ok((
+            Self {
+                active_path_selection_protocol,
+                active_path_selection_metric,
+                congestion_control_mode,
+                synchronization_method,
+                authentication_protocol,
+                mesh_formation_info,
+                mesh_capability,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for MeshConfigurationElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.active_path_selection_protocol.into_bits(), &mut offset)?;
+        buf.gwrite(self.active_path_selection_metric.into_bits(), &mut offset)?;
+        buf.gwrite(self.congestion_control_mode.into_bits(), &mut offset)?;
+        buf.gwrite(self.synchronization_method.into_bits(), &mut offset)?;
+        buf.gwrite(self.authentication_protocol.into_bits(), &mut offset)?;
+        buf.gwrite(self.mesh_formation_info.into_bits(), &mut offset)?;
+        buf.gwrite(self.mesh_capability.into_bits(), &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl Element for MeshConfigurationElement {
+    const ELEMENT_ID: ElementID = ElementID::Id(113);
+    type ReadType<'a> = Self;
+}
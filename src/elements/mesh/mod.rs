@@ -0,0 +1,6 @@
+//! This module contains support for the elements used in mesh BSSs.
+
+mod hwmp;
+pub use hwmp::*;
+mod mesh_configuration;
+pub use mesh_configuration::*;
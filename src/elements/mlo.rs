@@ -0,0 +1,401 @@
+//! This module contains support for the Multi-Link element, which is used for MLO in IEEE 802.11be.
+//!
+//! Currently only the Basic Multi-Link element is supported.
+//! Fragmented Multi-Link elements, which exceed the maximum length of an element, aren't reassembled.
+
+use bitfield_struct::bitfield;
+use mac_parser::MACAddress;
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use super::{Element, ElementID, ReadElements};
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The variant of a Multi-Link element.
+    pub enum MultiLinkType: u8 {
+        #[default]
+        Basic => 0,
+        ProbeRequest => 1,
+        Reconfiguration => 2,
+        TDLS => 3,
+        PriorityAccess => 4
+    }
+}
+
+#[bitfield(u16, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The Multi-Link Control field.
+///
+/// The presence bitmap is set automatically, based on the fields present in the [BasicMultiLinkCommonInfo], and therefore not public.
+pub struct MultiLinkControl {
+    #[bits(3)]
+    pub multi_link_type: MultiLinkType,
+    __: bool,
+    link_id_info_present: bool,
+    bss_parameters_change_count_present: bool,
+    medium_synchronization_delay_information_present: bool,
+    eml_capabilities_present: bool,
+    mld_capabilities_and_operations_present: bool,
+    ap_mld_id_present: bool,
+    extended_mld_capabilities_and_operations_present: bool,
+    #[bits(5)]
+    __: u8,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Common Info field of a Basic Multi-Link element.
+///
+/// It contains information, which is common to all affiliated STAs of the MLD.
+pub struct BasicMultiLinkCommonInfo {
+    /// The MAC address of the MLD.
+    pub mld_mac_address: MACAddress,
+    /// The link ID of the link, on which the frame is transmitted.
+    pub link_id_info: Option<u8>,
+    /// Incremented, when a critical update to the parameters of the BSS occurs.
+    pub bss_parameters_change_count: Option<u8>,
+    pub medium_synchronization_delay_information: Option<u16>,
+    pub eml_capabilities: Option<u16>,
+    pub mld_capabilities_and_operations: Option<u16>,
+    pub ap_mld_id: Option<u8>,
+    pub extended_mld_capabilities_and_operations: Option<u16>,
+}
+impl BasicMultiLinkCommonInfo {
+    /// Generate the [MultiLinkControl] field, with the presence bitmap set according to the fields present.
+    pub const fn multi_link_control(&self) -> MultiLinkControl {
+        MultiLinkControl::new()
+            .with_multi_link_type(MultiLinkType::Basic)
+            .with_link_id_info_present(self.link_id_info.is_some())
+            .with_bss_parameters_change_count_present(self.bss_parameters_change_count.is_some())
+            .with_medium_synchronization_delay_information_present(
+                self.medium_synchronization_delay_information.is_some(),
+            )
+            .with_eml_capabilities_present(self.eml_capabilities.is_some())
+            .with_mld_capabilities_and_operations_present(
+                self.mld_capabilities_and_operations.is_some(),
+            )
+            .with_ap_mld_id_present(self.ap_mld_id.is_some())
+            .with_extended_mld_capabilities_and_operations_present(
+                self.extended_mld_capabilities_and_operations.is_some(),
+            )
+    }
+}
+/// Read an optional field, if it's marked as present.
+fn read_if_present<'a, T: TryFromCtx<'a, Endian, Error = scroll::Error>>(
+    from: &'a [u8],
+    offset: &mut usize,
+    present: bool,
+) -> Result<Option<T>, scroll::Error> {
+    Ok(if present {
+        Some(from.gread_with(offset, Endian::Little)?)
+    } else {
+        None
+    })
+}
+/// Write an optional field, if it's present.
+fn write_if_present<T: TryIntoCtx<Endian, Error = scroll::Error>>(
+    buf: &mut [u8],
+    offset: &mut usize,
+    value: Option<T>,
+) -> Result<(), scroll::Error> {
+    if let Some(value) = value {
+        buf.gwrite_with(value, offset, Endian::Little)?;
+    }
+    Ok(())
+}
+impl MeasureWith<()> for BasicMultiLinkCommonInfo {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        // Common Info Length and MLD MAC Address.
+        7 + self.link_id_info.map_or(0, |_| 1)
+            + self.bss_parameters_change_count.map_or(0, |_| 1)
+            + self
+                .medium_synchronization_delay_information
+                .map_or(0, |_| 2)
+            + self.eml_capabilities.map_or(0, |_| 2)
+            + self.mld_capabilities_and_operations.map_or(0, |_| 2)
+            + self.ap_mld_id.map_or(0, |_| 1)
+            + self
+                .extended_mld_capabilities_and_operations
+                .map_or(0, |_| 2)
+    }
+}
+impl TryFromCtx<'_, MultiLinkControl> for BasicMultiLinkCommonInfo {
+    type Error = scroll::Error;
+    fn try_from_ctx(
+        from: &[u8],
+        multi_link_control: MultiLinkControl,
+    ) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let common_info_length = from.gread::<u8>(&mut offset)? as usize;
+        let mld_mac_address = from.gread(&mut offset)?;
+        let link_id_info =
+            read_if_present(from, &mut offset, multi_link_control.link_id_info_present())?;
+        let bss_parameters_change_count = read_if_present(
+            from,
+            &mut offset,
+            multi_link_control.bss_parameters_change_count_present(),
+        )?;
+        let medium_synchronization_delay_information = read_if_present(
+            from,
+            &mut offset,
+            multi_link_control.medium_synchronization_delay_information_present(),
+        )?;
+        let eml_capabilities = read_if_present(
+            from,
+            &mut offset,
+            multi_link_control.eml_capabilities_present(),
+        )?;
+        let mld_capabilities_and_operations = read_if_present(
+            from,
+            &mut offset,
+            multi_link_control.mld_capabilities_and_operations_present(),
+        )?;
+        let ap_mld_id = read_if_present(from, &mut offset, multi_link_control.ap_mld_id_present())?;
+        let extended_mld_capabilities_and_operations = read_if_present(
+            from,
+            &mut offset,
+            multi_link_control.extended_mld_capabilities_and_operations_present(),
+        )?;
+        if common_info_length < offset {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "The Common Info Length was shorter than the fields indicated as present.",
+            });
+        }
+        if common_info_length > from.len() {
+            return Err(scroll::Error::TooBig {
+                size: common_info_length,
+                len: from.len(),
+            });
+        }
+
+        Ok((
+            Self {
+                mld_mac_address,
+                link_id_info,
+                bss_parameters_change_count,
+                medium_synchronization_delay_information,
+                eml_capabilities,
+                mld_capabilities_and_operations,
+                ap_mld_id,
+                extended_mld_capabilities_and_operations,
+            },
+            // Skip any fields unknown to us.
+            common_info_length,
+        ))
+    }
+}
+impl TryIntoCtx for BasicMultiLinkCommonInfo {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.measure_with(&()) as u8, &mut offset)?;
+        buf.gwrite(self.mld_mac_address, &mut offset)?;
+        write_if_present(buf, &mut offset, self.link_id_info)?;
+        write_if_present(buf, &mut offset, self.bss_parameters_change_count)?;
+        write_if_present(
+            buf,
+            &mut offset,
+            self.medium_synchronization_delay_information,
+        )?;
+        write_if_present(buf, &mut offset, self.eml_capabilities)?;
+        write_if_present(buf, &mut offset, self.mld_capabilities_and_operations)?;
+        write_if_present(buf, &mut offset, self.ap_mld_id)?;
+        write_if_present(
+            buf,
+            &mut offset,
+            self.extended_mld_capabilities_and_operations,
+        )?;
+
+        Ok(offset)
+    }
+}
+
+#[bitfield(u16, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The STA Control field of a Per-STA Profile subelement in a Basic Multi-Link element.
+pub struct PerSTAControl {
+    #[bits(4)]
+    pub link_id: u8,
+    pub complete_profile: bool,
+    pub sta_mac_address_present: bool,
+    pub beacon_interval_present: bool,
+    pub tsf_offset_present: bool,
+    pub dtim_info_present: bool,
+    pub nstr_link_pair_present: bool,
+    pub nstr_bitmap_size: bool,
+    pub bss_parameters_change_count_present: bool,
+    #[bits(4)]
+    __: u8,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// A Per-STA Profile subelement of a Basic Multi-Link element.
+///
+/// It carries the information of one STA affiliated with the MLD.
+pub struct PerSTAProfile<'a> {
+    /// Indicates, which fields of the STA Info are present.
+    pub sta_control: PerSTAControl,
+    /// The STA Info field, without the leading length octet.
+    pub sta_info: &'a [u8],
+    /// The STA Profile, which consists of the fixed fields and elements of the frame carrying the Multi-Link element.
+    pub sta_profile: &'a [u8],
+}
+impl<'a> PerSTAProfile<'a> {
+    /// The subelement ID of the Per-STA Profile subelement.
+    pub const SUBELEMENT_ID: u8 = 0;
+
+    /// Returns the MAC address of the affiliated STA, if it's present.
+    pub fn sta_mac_address(&self) -> Option<MACAddress> {
+        if self.sta_control.sta_mac_address_present() {
+            self.sta_info.pread(0).ok()
+        } else {
+            None
+        }
+    }
+    /// Returns the elements of the STA Profile, after skipping the specified amount of fixed fields.
+    ///
+    /// The length of the fixed fields depends on the frame carrying the Multi-Link element. For beacons and probe responses, it's two bytes for the Capability Information.
+    pub fn sta_profile_elements(&self, fixed_fields_length: usize) -> Option<ReadElements<'a>> {
+        self.sta_profile
+            .get(fixed_fields_length..)
+            .map(|bytes| ReadElements { bytes })
+    }
+}
+impl<'a> TryFromCtx<'a> for PerSTAProfile<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let sta_control = PerSTAControl::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+        let sta_info_length = from.gread::<u8>(&mut offset)? as usize;
+        let Some(sta_info) = sta_info_length
+            .checked_sub(1)
+            .and_then(|sta_info_length| from.get(offset..offset + sta_info_length))
+        else {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "The STA Info Length was invalid.",
+            });
+        };
+        offset += sta_info.len();
+        let sta_profile = &from[offset..];
+
+        Ok((
+            Self {
+                sta_control,
+                sta_info,
+                sta_profile,
+            },
+            from.len(),
+        ))
+    }
+}
+impl MeasureWith<()> for PerSTAProfile<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        3 + self.sta_info.len() + self.sta_profile.len()
+    }
+}
+impl TryIntoCtx for PerSTAProfile<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite_with(self.sta_control.into_bits(), &mut offset, Endian::Little)?;
+        buf.gwrite(self.sta_info.len() as u8 + 1, &mut offset)?;
+        buf.gwrite(self.sta_info, &mut offset)?;
+        buf.gwrite(self.sta_profile, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Basic Multi-Link element.
+///
+/// It advertises the MLD, to which the transmitting STA is affiliated, and optionally the other STAs affiliated with that MLD.
+pub struct MultiLinkElement<'a> {
+    /// Information common to all STAs affiliated with the MLD.
+    pub common_info: BasicMultiLinkCommonInfo,
+    /// The raw subelements of the Link Info field.
+    ///
+    /// Use [Self::per_sta_profiles] to iterate over the Per-STA Profiles.
+    pub link_info: &'a [u8],
+}
+impl<'a> MultiLinkElement<'a> {
+    /// Returns the [MultiLinkControl] field of the element.
+    pub const fn multi_link_control(&self) -> MultiLinkControl {
+        self.common_info.multi_link_control()
+    }
+    /// Returns an [Iterator] over the [PerSTAProfiles](PerSTAProfile) in the Link Info field.
+    ///
+    /// Other subelements and malformed profiles are skipped.
+    pub fn per_sta_profiles(&self) -> impl Iterator<Item = PerSTAProfile<'a>> + 'a {
+        ReadElements {
+            bytes: self.link_info,
+        }
+        .raw_element_iterator()
+        .filter(|subelement| subelement.tlv_type == PerSTAProfile::SUBELEMENT_ID)
+        .filter_map(|subelement| subelement.slice.pread(0).ok())
+    }
+}
+impl MeasureWith<()> for MultiLinkElement<'_> {
+    fn measure_with(&self, ctx: &()) -> usize {
+        2 + self.common_info.measure_with(ctx) + self.link_info.len()
+    }
+}
+impl<'a> TryFromCtx<'a> for MultiLinkElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let multi_link_control =
+            MultiLinkControl::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+        if multi_link_control.multi_link_type() != MultiLinkType::Basic {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "Only the Basic Multi-Link element is supported.",
+            });
+        }
+        let common_info = from.gread_with(&mut offset, multi_link_control)?;
+        let link_info = &from[offset..];
+
+        Ok((
+            Self {
+                common_info,
+                link_info,
+            },
+            from.len(),
+        ))
+    }
+}
+impl TryIntoCtx for MultiLinkElement<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite_with(
+            self.multi_link_control().into_bits(),
+            &mut offset,
+            Endian::Little,
+        )?;
+        buf.gwrite(self.common_info, &mut offset)?;
+        buf.gwrite(self.link_info, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl Element for MultiLinkElement<'_> {
+    const ELEMENT_ID: ElementID = ElementID::ExtId(107);
+    const FRAGMENTABLE: bool = true;
+    type ReadType<'a> = MultiLinkElement<'a>;
+}
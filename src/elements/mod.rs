@@ -16,6 +16,8 @@ use tlv_rs::{raw_tlv::RawTLV, TLV};
 
 use crate::common::ReadIterator;
 
+mod ap_channel_report;
+pub use ap_channel_report::APChannelReportElement;
 mod dsss_parameter_set;
 pub use dsss_parameter_set::DSSSParameterSetElement;
 pub mod rates;
@@ -23,18 +25,58 @@ mod ssid;
 pub use ssid::SSIDElement;
 mod bss_load;
 pub use bss_load::BSSLoadElement;
+mod bss_max_idle;
+pub use bss_max_idle::{BSSMaxIdlePeriodElement, IdleOptions};
+mod extended_capabilities;
+pub use extended_capabilities::ExtendedCapabilitiesElement;
+pub mod country;
+mod csa;
+pub use csa::{ChannelSwitchAnnouncementElement, ExtendedChannelSwitchAnnouncementElement};
+pub mod eht;
+mod erp;
+pub use erp::ERPInformationElement;
+pub mod ft;
+pub mod he;
 pub mod ht;
 mod ibss_parameter_set;
 pub use ibss_parameter_set::IBSSParameterSetElement;
+pub mod kde;
 pub mod rsn;
 mod vendor_specific_element;
-pub use vendor_specific_element::VendorSpecificElement;
+pub use vendor_specific_element::{VendorElement, VendorSpecificElement};
 mod owe_transition;
 pub mod vht;
 pub use owe_transition::OWETransitionModeElement;
+mod opmode;
+pub use opmode::{OperatingModeChannelWidth, OperatingModeNotificationElement};
+pub mod measurement;
+pub mod mesh;
+pub mod mlo;
+mod multiple_bssid;
+pub use multiple_bssid::MultipleBSSIDElement;
+pub mod neighbor_report;
+pub mod operating_classes;
+mod quiet;
+pub use quiet::QuietElement;
+mod rm_enabled;
+pub use rm_enabled::RMEnabledCapabilitiesElement;
+pub mod rnr;
 pub mod tim;
+pub mod tspec;
+pub mod twt;
+pub mod wmm;
+pub mod wpa;
 
+#[cfg(feature = "alloc")]
+mod editor;
 pub mod element_chain;
+#[cfg(feature = "alloc")]
+pub use editor::ElementEditor;
+
+/// The maximum length of the body of an element.
+const MAX_ELEMENT_LENGTH: usize = 255;
+/// The ID of the Fragment element, which carries the remainder of a fragmented element.
+const FRAGMENT_ELEMENT_ID: u8 = 242;
 
 /// A raw TLV.
 pub type RawIEEE80211Element<'a> = RawTLV<'a, u8, u8>;
@@ -226,25 +268,58 @@ impl<'bytes> ReadElements<'bytes> {
     pub fn parse_raw_element<ElementType: Element>(
         raw_element: RawIEEE80211Element<'bytes>,
     ) -> Option<ElementType::ReadType<'bytes>> {
-        match ElementType::ELEMENT_ID {
+        Self::raw_element_payload::<ElementType>(raw_element)
+            .ok()?
+            .pread(0)
+            .ok()
+    }
+    /// Strip the extension ID or vendor prefix of the element type from the [RawIEEE80211Element].
+    fn raw_element_payload<ElementType: Element>(
+        raw_element: RawIEEE80211Element<'bytes>,
+    ) -> Result<&'bytes [u8], scroll::Error> {
+        Ok(match ElementType::ELEMENT_ID {
             ElementID::Id(_) => raw_element.slice,
             ElementID::ExtId(_) => {
-                let ext_element: RawIEEE80211ExtElement = raw_element.slice.pread(0).ok()?;
+                let ext_element: RawIEEE80211ExtElement = raw_element.slice.pread(0)?;
                 ext_element.slice
             }
             ElementID::VendorSpecific { prefix } => {
-                let vendor_specific_element: VendorSpecificElement =
-                    raw_element.slice.pread(0).ok()?;
-                &vendor_specific_element.get_payload()[prefix.len()..]
+                let vendor_specific_element: VendorSpecificElement = raw_element.slice.pread(0)?;
+                vendor_specific_element
+                    .get_payload()
+                    .get(prefix.len()..)
+                    .ok_or(scroll::Error::TooBig {
+                        size: prefix.len(),
+                        len: vendor_specific_element.get_payload().len(),
+                    })?
             }
-        }
-        .pread(0)
-        .ok()
+        })
     }
     /// Returns an iterator over [RawIEEE80211Elements](RawIEEE80211Element).
     pub fn raw_element_iterator(self) -> ReadIterator<'bytes, Endian, RawIEEE80211Element<'bytes>> {
         ReadIterator::<Endian, RawIEEE80211Element<'bytes>>::new(self.bytes)
     }
+    /// Returns the number of elements.
+    ///
+    /// Only the element headers are read, so this is cheaper than parsing the elements.
+    pub fn count(self) -> usize {
+        self.raw_element_iterator().count()
+    }
+    /// Returns the number of bytes spanned by the elements, including their headers.
+    ///
+    /// Trailing bytes, which don't form a complete element, aren't included.
+    pub fn byte_len(self) -> usize {
+        self.raw_element_iterator()
+            .map(|raw_element| 2 + raw_element.slice.len())
+            .sum()
+    }
+    /// Returns an [Iterator] over the IDs of the elements, in the order they appear in.
+    ///
+    /// For extension elements, this is 255 and not the extension ID.
+    pub fn element_ids(self) -> impl Iterator<Item = u8> + 'bytes {
+        self.raw_element_iterator()
+            .map(|raw_element| raw_element.tlv_type)
+    }
 
     /// Returns an iterator over [RawIEEE80211Elements](RawIEEE80211Element), which match the specified [ElementID].
     pub fn get_matching_elements_raw(
@@ -258,7 +333,9 @@ impl<'bytes> ReadElements<'bytes> {
     pub fn get_matching_elements<ElementType: Element>(
         self,
     ) -> impl Iterator<Item = ElementType::ReadType<'bytes>> + 'bytes {
-        self.raw_element_iterator().filter_map(|raw_element| {
+        // Only the bytes are moved into the iterator, so the returned elements borrow them directly.
+        let Self { bytes } = self;
+        ReadIterator::<Endian, RawIEEE80211Element<'bytes>>::new(bytes).filter_map(|raw_element| {
             if Self::element_id_matches(&raw_element, ElementType::ELEMENT_ID) {
                 Self::parse_raw_element::<ElementType>(raw_element)
             } else {
@@ -278,6 +355,97 @@ impl<'bytes> ReadElements<'bytes> {
     pub fn get_first_element<ElementType: Element>(self) -> Option<ElementType::ReadType<'bytes>> {
         self.get_matching_elements::<ElementType>().next()
     }
+    /// This returns the first element, matching the specified element type, and validates it's length.
+    ///
+    /// Unlike [Self::get_first_element], this returns an error, if the element couldn't be parsed,
+    /// if the parser didn't consume the entire element body or if the length of the element doesn't
+    /// match the length returned by [MeasureWith] for the parsed element. This is useful for
+    /// rejecting malformed fixed size elements, which would otherwise be parsed from a prefix of
+    /// the element body.
+    pub fn get_first_element_strict<ElementType: Element>(
+        self,
+    ) -> Result<Option<ElementType::ReadType<'bytes>>, scroll::Error>
+    where
+        ElementType::ReadType<'bytes>: MeasureWith<()>,
+    {
+        let Some(raw_element) = self.get_first_element_raw(ElementType::ELEMENT_ID) else {
+            return Ok(None);
+        };
+        let payload = Self::raw_element_payload::<ElementType>(raw_element)?;
+        let mut offset = 0;
+        let element = payload.gread::<ElementType::ReadType<'bytes>>(&mut offset)?;
+        if offset != payload.len() || element.measure_with(&()) != payload.len() {
+            return Err(scroll::Error::BadInput {
+                size: payload.len(),
+                msg: "The length of the element didn't match the expected length.",
+            });
+        }
+        Ok(Some(element))
+    }
+    /// This returns the first element, matching the specified element type, after reassembling it from it's fragments.
+    ///
+    /// Elements with a body longer than 255 bytes are split into the element itself and one or more subsequent Fragment elements.
+    /// If the element type is [fragmentable](Element::FRAGMENTABLE), the payload of the element and all it's fragments are copied into `buffer`, from which the element is then parsed.
+    /// For all other element types, only the payload of the element itself is copied.
+    /// An error is returned, if the payload doesn't fit into `buffer` or the element couldn't be parsed.
+    pub fn get_first_element_defragmented<'buf, ElementType: Element>(
+        self,
+        buffer: &'buf mut [u8],
+    ) -> Result<Option<ElementType::ReadType<'buf>>, scroll::Error> {
+        let mut raw_elements = self.raw_element_iterator();
+        let Some(raw_element) = raw_elements
+            .by_ref()
+            .find(|raw_element| Self::element_id_matches(raw_element, ElementType::ELEMENT_ID))
+        else {
+            return Ok(None);
+        };
+
+        let mut length = 0;
+        let mut append = |payload: &[u8]| {
+            let buffer_length = buffer.len();
+            buffer
+                .get_mut(length..length + payload.len())
+                .ok_or(scroll::Error::TooBig {
+                    size: length + payload.len(),
+                    len: buffer_length,
+                })?
+                .copy_from_slice(payload);
+            length += payload.len();
+            Ok::<_, scroll::Error>(())
+        };
+        append(Self::raw_element_payload::<ElementType>(raw_element)?)?;
+        // Only elements with the maximum length can be followed by fragments.
+        let mut fragmented = raw_element.slice.len() == MAX_ELEMENT_LENGTH;
+        if ElementType::FRAGMENTABLE {
+            for fragment in raw_elements {
+                if !fragmented || fragment.tlv_type != FRAGMENT_ELEMENT_ID {
+                    break;
+                }
+                append(fragment.slice)?;
+                // The last fragment is shorter than the maximum length.
+                fragmented = fragment.slice.len() == MAX_ELEMENT_LENGTH;
+            }
+        }
+
+        buffer[..length].pread(0).map(Some)
+    }
+    /// Returns an [Iterator] over all vendor specific elements, dispatched by their OUI.
+    pub fn vendor_elements(self) -> impl Iterator<Item = VendorElement<'bytes>> + 'bytes {
+        self.get_matching_elements::<VendorSpecificElement>()
+            .map(VendorElement::from_vendor_specific_element)
+    }
+    /// Returns the primary channel of the BSS.
+    ///
+    /// This first checks the [DSSSParameterSetElement] and then the [HTOperationElement](ht::HTOperationElement), since APs operating in the 5 GHz band commonly omit the former.
+    /// The 6 GHz operation information of the HE Operation element isn't checked yet, since that element isn't implemented.
+    pub fn primary_channel(self) -> Option<u8> {
+        self.get_first_element::<DSSSParameterSetElement>()
+            .map(|dsss_parameter_set| dsss_parameter_set.current_channel)
+            .or_else(|| {
+                self.get_first_element::<ht::HTOperationElement>()
+                    .map(|ht_operation| ht_operation.primary_channel)
+            })
+    }
 }
 impl<'a> TryFromCtx<'a> for ReadElements<'a> {
     type Error = scroll::Error;
@@ -297,6 +465,62 @@ impl MeasureWith<()> for ReadElements<'_> {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A container of elements backed by a fixed capacity buffer.
+///
+/// This allows assembling a list of elements incrementally, without requiring an allocator or a separate frame buffer.
+/// It can be used as the element container of a frame body, like [BeaconBody](crate::mgmt_frame::body::BeaconBody).
+pub struct OwnedElements<const N: usize> {
+    buffer: [u8; N],
+    length: usize,
+}
+impl<const N: usize> OwnedElements<N> {
+    /// Create a new empty container.
+    pub const fn new() -> Self {
+        Self {
+            buffer: [0x00; N],
+            length: 0,
+        }
+    }
+    /// Append an element to the container.
+    ///
+    /// If the element doesn't fit into the remaining capacity, an error is returned and the container is left unchanged.
+    pub fn push_element(&mut self, element: impl Element) -> Result<(), scroll::Error> {
+        let element = WrappedIEEE80211Element(element);
+        let element_length = element.measure_with(&());
+        if element_length > N - self.length {
+            return Err(scroll::Error::TooBig {
+                size: element_length,
+                len: N - self.length,
+            });
+        }
+        self.length += self.buffer.pwrite(element, self.length)?;
+        Ok(())
+    }
+    /// Returns the elements, which were pushed so far, as [ReadElements].
+    pub fn as_read_elements(&self) -> ReadElements<'_> {
+        ReadElements {
+            bytes: &self.buffer[..self.length],
+        }
+    }
+}
+impl<const N: usize> Default for OwnedElements<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<const N: usize> MeasureWith<()> for OwnedElements<N> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        self.length
+    }
+}
+impl<const N: usize> TryIntoCtx for OwnedElements<N> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        buf.pwrite(&self.buffer[..self.length], 0)
+    }
+}
+
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
 /// A wrapper for any type implementing the [Element] trait.
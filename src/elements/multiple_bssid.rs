@@ -0,0 +1,108 @@
+use mac_parser::MACAddress;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+use super::{Element, ElementID, ReadElements};
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Multiple BSSID element advertises the nontransmitted BSSIDs of a multiple BSSID set.
+///
+/// This is used by APs, which operate multiple BSSs, but only transmit beacons for one of them.
+pub struct MultipleBSSIDElement<'a> {
+    /// The maximum number of BSSIDs in the multiple BSSID set is two to the power of this value.
+    pub max_bssid_indicator: u8,
+    /// The raw subelements of the element.
+    ///
+    /// Use [Self::nontransmitted_profiles] to iterate over the Nontransmitted BSSID Profiles.
+    pub sub_elements: &'a [u8],
+}
+impl<'a> MultipleBSSIDElement<'a> {
+    /// The subelement ID of the Nontransmitted BSSID Profile subelement.
+    pub const NONTRANSMITTED_BSSID_PROFILE_SUBELEMENT_ID: u8 = 0;
+
+    /// Returns the maximum number of BSSIDs in the multiple BSSID set, including the transmitted BSSID.
+    ///
+    /// Since a BSSID is 48 bits long, the MaxBSSID Indicator is capped at 48.
+    pub const fn max_bssids(&self) -> u64 {
+        1 << if self.max_bssid_indicator > 48 {
+            48
+        } else {
+            self.max_bssid_indicator
+        }
+    }
+    /// Returns an [Iterator] over the Nontransmitted BSSID Profiles.
+    ///
+    /// Each profile consists of the elements of the nontransmitted BSSID, like the SSID and the Multiple BSSID-Index element.
+    /// Other subelements are skipped.
+    pub fn nontransmitted_profiles(&self) -> impl Iterator<Item = ReadElements<'a>> + 'a {
+        ReadElements {
+            bytes: self.sub_elements,
+        }
+        .raw_element_iterator()
+        .filter(|sub_element| {
+            sub_element.tlv_type == Self::NONTRANSMITTED_BSSID_PROFILE_SUBELEMENT_ID
+        })
+        .map(|sub_element| ReadElements {
+            bytes: sub_element.slice,
+        })
+    }
+    /// Derive the nontransmitted BSSID with the specified BSSID index from the transmitted BSSID, as specified in 9.4.2.45 of IEEE 802.11-2020.
+    ///
+    /// The BSSID index is carried in the Multiple BSSID-Index element of the Nontransmitted BSSID Profile.
+    pub fn nontransmitted_bssid(
+        &self,
+        transmitted_bssid: MACAddress,
+        bssid_index: u8,
+    ) -> MACAddress {
+        let mut bssid = [0x00; 8];
+        bssid[2..].copy_from_slice(transmitted_bssid.as_slice());
+        let bssid = u64::from_be_bytes(bssid);
+
+        let mask = self.max_bssids() - 1;
+        let bssid = (bssid & !mask) | ((bssid & mask).wrapping_add(bssid_index as u64) & mask);
+
+        let mut nontransmitted_bssid = [0x00; 6];
+        nontransmitted_bssid.copy_from_slice(&bssid.to_be_bytes()[2..]);
+        MACAddress::new(nontransmitted_bssid)
+    }
+}
+impl MeasureWith<()> for MultipleBSSIDElement<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        1 + self.sub_elements.len()
+    }
+}
+impl<'a> TryFromCtx<'a> for MultipleBSSIDElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let max_bssid_indicator = from.gread(&mut offset)?;
+        let sub_elements = &from[offset..];
+
+        Ok((
+            Self {
+                max_bssid_indicator,
+                sub_elements,
+            },
+            from.len(),
+        ))
+    }
+}
+impl TryIntoCtx for MultipleBSSIDElement<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.max_bssid_indicator, &mut offset)?;
+        buf.gwrite(self.sub_elements, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl Element for MultipleBSSIDElement<'_> {
+    const ELEMENT_ID: ElementID = ElementID::Id(71);
+    type ReadType<'a> = MultipleBSSIDElement<'a>;
+}
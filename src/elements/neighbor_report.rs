@@ -0,0 +1,140 @@
+//! This module contains support for the Neighbor Report element.
+//!
+//! Neighbor reports were introduced in IEEE 802.11k and describe APs, which are candidates for a BSS transition.
+
+use bitfield_struct::bitfield;
+use mac_parser::MACAddress;
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use super::{Element, ElementID, RawIEEE80211Element, ReadElements};
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// Indicates, if the reported AP is reachable by the STA requesting the neighbor report.
+    pub enum APReachability: u8 {
+        NotReachable => 1,
+        /// The reachability of the AP is unknown.
+        #[default]
+        Undetermined => 2,
+        Reachable => 3
+    }
+}
+
+#[bitfield(u32, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The BSSID Information field of a [NeighborReportElement].
+pub struct BSSIDInformation {
+    #[bits(2)]
+    pub ap_reachability: APReachability,
+    /// Indicates, that the reported AP supports the same security as the current association.
+    pub security: bool,
+    /// Indicates, that the reported AP has the same authenticator as the reporting AP.
+    pub key_scope: bool,
+    pub spectrum_management: bool,
+    pub qos: bool,
+    pub apsd: bool,
+    pub radio_measurement: bool,
+    pub delayed_block_ack: bool,
+    pub immediate_block_ack: bool,
+    /// Indicates, that the reported AP is in the same mobility domain as the reporting AP.
+    pub mobility_domain: bool,
+    pub high_throughput: bool,
+    pub very_high_throughput: bool,
+    pub ftm: bool,
+    pub high_efficiency: bool,
+    pub er_bss: bool,
+    pub colocated_ap: bool,
+    pub unsolicited_probe_responses_active: bool,
+    pub member_of_ess_with_2g_5g_colocated_ap: bool,
+    pub oct_supported_with_reporting_ap: bool,
+    pub colocated_6ghz_ap: bool,
+    pub extremely_high_throughput: bool,
+    #[bits(10)]
+    __: u16,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Neighbor Report element describes a neighboring AP.
+pub struct NeighborReportElement<'a> {
+    pub bssid: MACAddress,
+    pub bssid_information: BSSIDInformation,
+    /// The operating class, in which the reported AP is operating.
+    pub operating_class: u8,
+    /// The channel number of the primary channel of the reported AP.
+    pub channel_number: u8,
+    /// The PHY type of the reported AP, as specified in Annex C of IEEE 802.11-2020.
+    pub phy_type: u8,
+    /// The raw optional subelements.
+    ///
+    /// Use [Self::sub_elements] to iterate over them.
+    pub optional_sub_elements: &'a [u8],
+}
+impl<'a> NeighborReportElement<'a> {
+    /// Returns an [Iterator] over the optional subelements.
+    pub fn sub_elements(&self) -> impl Iterator<Item = RawIEEE80211Element<'a>> + 'a {
+        ReadElements {
+            bytes: self.optional_sub_elements,
+        }
+        .raw_element_iterator()
+    }
+}
+impl MeasureWith<()> for NeighborReportElement<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        13 + self.optional_sub_elements.len()
+    }
+}
+impl<'a> TryFromCtx<'a> for NeighborReportElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let bssid = from.gread(&mut offset)?;
+        let bssid_information =
+            BSSIDInformation::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+        let operating_class = from.gread(&mut offset)?;
+        let channel_number = from.gread(&mut offset)?;
+        let phy_type = from.gread(&mut offset)?;
+        let optional_sub_elements = &from[offset..];
+
+        Ok((
+            Self {
+                bssid,
+                bssid_information,
+                operating_class,
+                channel_number,
+                phy_type,
+                optional_sub_elements,
+            },
+            from.len(),
+        ))
+    }
+}
+impl TryIntoCtx for NeighborReportElement<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.bssid, &mut offset)?;
+        buf.gwrite_with(
+            self.bssid_information.into_bits(),
+            &mut offset,
+            Endian::Little,
+        )?;
+        buf.gwrite(self.operating_class, &mut offset)?;
+        buf.gwrite(self.channel_number, &mut offset)?;
+        buf.gwrite(self.phy_type, &mut offset)?;
+        buf.gwrite(self.optional_sub_elements, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl Element for NeighborReportElement<'_> {
+    const ELEMENT_ID: ElementID = ElementID::Id(52);
+    type ReadType<'a> = NeighborReportElement<'a>;
+}
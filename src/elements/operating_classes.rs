@@ -0,0 +1,135 @@
+//! This module contains support for the Supported Operating Classes element.
+
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+use super::{Element, ElementID};
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The frequency band, in which an operating class is located.
+pub enum Band {
+    TwoPointFourGHz,
+    FiveGHz,
+    SixGHz,
+    SixtyGHz,
+}
+impl Band {
+    /// Get the band of a global operating class, as specified in Table E-4 of IEEE 802.11-2020.
+    ///
+    /// This returns [None] for operating classes, which aren't located in one of the bands above.
+    pub const fn from_operating_class(operating_class: u8) -> Option<Self> {
+        Some(match operating_class {
+            81..=84 => Self::TwoPointFourGHz,
+            115..=130 => Self::FiveGHz,
+            131..=137 => Self::SixGHz,
+            180..=184 => Self::SixtyGHz,
+            _ => return None,
+        })
+    }
+}
+
+/// The delimiter, which precedes the Current Operating Class Extension Sequence.
+pub const CURRENT_OPERATING_CLASS_EXTENSION_DELIMITER: u8 = 130;
+/// The delimiter, which precedes the Operating Class Duple Sequence.
+pub const OPERATING_CLASS_DUPLE_SEQUENCE_DELIMITER: u8 = 0;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Supported Operating Classes element lists the operating classes, in which the STA is capable of operating.
+pub struct SupportedOperatingClassesElement<'a> {
+    /// The operating class, in which the STA is currently operating.
+    pub current_operating_class: u8,
+    /// The raw list of alternate operating classes.
+    ///
+    /// This may include the Current Operating Class Extension and Operating Class Duple sequences, each introduced by their delimiter.
+    pub operating_classes: &'a [u8],
+}
+impl<'a> SupportedOperatingClassesElement<'a> {
+    /// Returns the operating class, in which the STA is currently operating.
+    pub const fn current_operating_class(&self) -> u8 {
+        self.current_operating_class
+    }
+    /// Returns an [Iterator] over the alternate operating classes.
+    ///
+    /// This stops at the first delimiter, so neither the Current Operating Class Extension Sequence, nor the Operating Class Duple Sequence are included.
+    pub fn alternate_operating_classes(&self) -> impl Iterator<Item = u8> + 'a {
+        self.operating_classes
+            .iter()
+            .copied()
+            .take_while(|operating_class| {
+                !matches!(
+                    *operating_class,
+                    CURRENT_OPERATING_CLASS_EXTENSION_DELIMITER
+                        | OPERATING_CLASS_DUPLE_SEQUENCE_DELIMITER
+                )
+            })
+    }
+    /// Returns the Current Operating Class Extension Sequence, if present.
+    ///
+    /// This is the sequence of bytes between the [130 delimiter](CURRENT_OPERATING_CLASS_EXTENSION_DELIMITER) and either the [zero delimiter](OPERATING_CLASS_DUPLE_SEQUENCE_DELIMITER) or the end of the element.
+    pub fn current_operating_class_extension(&self) -> Option<&'a [u8]> {
+        let operating_classes = self.operating_classes;
+        let start = operating_classes.iter().position(|operating_class| {
+            matches!(
+                *operating_class,
+                CURRENT_OPERATING_CLASS_EXTENSION_DELIMITER
+                    | OPERATING_CLASS_DUPLE_SEQUENCE_DELIMITER
+            )
+        })?;
+        if operating_classes[start] != CURRENT_OPERATING_CLASS_EXTENSION_DELIMITER {
+            return None;
+        }
+        let extension = &operating_classes[start + 1..];
+        let end = extension
+            .iter()
+            .position(|operating_class| {
+                *operating_class == OPERATING_CLASS_DUPLE_SEQUENCE_DELIMITER
+            })
+            .unwrap_or(extension.len());
+        Some(&extension[..end])
+    }
+    /// Returns the [Band], in which the STA is currently operating.
+    pub const fn current_band(&self) -> Option<Band> {
+        Band::from_operating_class(self.current_operating_class)
+    }
+}
+impl MeasureWith<()> for SupportedOperatingClassesElement<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        1 + self.operating_classes.len()
+    }
+}
+impl<'a> TryFromCtx<'a> for SupportedOperatingClassesElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let current_operating_class = from.gread(&mut offset)?;
+        let operating_classes = &from[offset..];
+
+        Ok((
+            Self {
+                current_operating_class,
+                operating_classes,
+            },
+            from.len(),
+        ))
+    }
+}
+impl TryIntoCtx for SupportedOperatingClassesElement<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.current_operating_class, &mut offset)?;
+        buf.gwrite(self.operating_classes, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl Element for SupportedOperatingClassesElement<'_> {
+    const ELEMENT_ID: ElementID = ElementID::Id(59);
+    type ReadType<'a> = SupportedOperatingClassesElement<'a>;
+}
@@ -0,0 +1,78 @@
+use bitfield_struct::bitfield;
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, SizeWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+use super::{Element, ElementID};
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The channel width, which the STA is able to receive.
+    pub enum OperatingModeChannelWidth: u8 {
+        #[default]
+        TwentyMHz => 0,
+        FortyMHz => 1,
+        /// 80 MHz, or 160 and 80+80 MHz, if the [160/80+80 BW](OperatingModeNotificationElement::bw_160_or_80p80) bit is set.
+        EightyMHz => 2,
+        /// 160 or 80+80 MHz, which is only used by STAs, that don't set the [160/80+80 BW](OperatingModeNotificationElement::bw_160_or_80p80) bit.
+        OneSixtyOrEightyPlusEightyMHz => 3
+    }
+}
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The Operating Mode Notification element is used by a STA, to notify other STAs, that it's changing one or more of it's operating parameters.
+pub struct OperatingModeNotificationElement {
+    #[bits(2)]
+    pub channel_width: OperatingModeChannelWidth,
+    /// Indicates, that the STA supports 160 or 80+80 MHz, in combination with [OperatingModeChannelWidth::EightyMHz].
+    pub bw_160_or_80p80: bool,
+    /// Indicates, that the STA can't receive LDPC encoded PPDUs.
+    pub no_ldpc: bool,
+    #[bits(3)]
+    encoded_rx_nss: u8,
+    /// Indicates, that the [Rx NSS](Self::rx_nss) only applies to PPDUs transmitted using SU or MU beamforming.
+    pub rx_nss_type: bool,
+}
+impl OperatingModeNotificationElement {
+    /// Returns the maximum number of spatial streams, that the STA can receive.
+    pub const fn rx_nss(&self) -> u8 {
+        self.encoded_rx_nss() + 1
+    }
+    /// Set the maximum number of spatial streams, that the STA can receive.
+    ///
+    /// The value has to be in the range 1-8.
+    pub const fn with_rx_nss(self, rx_nss: u8) -> Self {
+        self.with_encoded_rx_nss(rx_nss.saturating_sub(1))
+    }
+}
+impl SizeWith for OperatingModeNotificationElement {
+    fn size_with(_ctx: &()) -> usize {
+        1
+    }
+}
+impl MeasureWith<()> for OperatingModeNotificationElement {
+    fn measure_with(&self, ctx: &()) -> usize {
+        Self::size_with(ctx)
+    }
+}
+impl TryFromCtx<'_> for OperatingModeNotificationElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        Ok((Self::from_bits(from.pread(0)?), 1))
+    }
+}
+impl TryIntoCtx for OperatingModeNotificationElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        buf.pwrite(self.into_bits(), 0)
+    }
+}
+
+impl Element for OperatingModeNotificationElement {
+    const ELEMENT_ID: ElementID = ElementID::Id(199);
+    type ReadType<'a> = Self;
+}
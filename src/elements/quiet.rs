@@ -0,0 +1,90 @@
+use core::time::Duration;
+
+use scroll::{
+    ctx::{MeasureWith, SizeWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use crate::common::TU;
+
+use super::{Element, ElementID};
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Quiet element defines an interval, during which no transmissions occur in the current channel.
+///
+/// This is used by an AP for radar detection in DFS channels.
+pub struct QuietElement {
+    /// The number of TBTTs until the beacon interval, during which the next quiet interval starts.
+    pub quiet_count: u8,
+    /// The number of beacon intervals between successive quiet intervals.
+    ///
+    /// A value of zero indicates, that no periodic quiet interval is defined.
+    pub quiet_period: u8,
+    /// The duration of the quiet interval in [TUs](TU).
+    ///
+    /// Use [Self::duration] to get a [Duration].
+    pub quiet_duration: u16,
+    /// The offset of the start of the quiet interval from the TBTT in [TUs](TU).
+    ///
+    /// Use [Self::offset] to get a [Duration].
+    pub quiet_offset: u16,
+}
+impl QuietElement {
+    /// Returns a [Duration] for the quiet duration.
+    pub const fn duration(&self) -> Duration {
+        Duration::from_micros(TU.as_micros() as u64 * self.quiet_duration as u64)
+    }
+    /// Returns a [Duration] for the quiet offset.
+    pub const fn offset(&self) -> Duration {
+        Duration::from_micros(TU.as_micros() as u64 * self.quiet_offset as u64)
+    }
+}
+impl SizeWith for QuietElement {
+    fn size_with(_ctx: &()) -> usize {
+        6
+    }
+}
+impl MeasureWith<()> for QuietElement {
+    fn measure_with(&self, ctx: &()) -> usize {
+        Self::size_with(ctx)
+    }
+}
+impl TryFromCtx<'_> for QuietElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let quiet_count = from.gread(&mut offset)?;
+        let quiet_period = from.gread(&mut offset)?;
+        let quiet_duration = from.gread_with(&mut offset, Endian::Little)?;
+        let quiet_offset = from.gread_with(&mut offset, Endian::Little)?;
+
+        Ok((
+            Self {
+                quiet_count,
+                quiet_period,
+                quiet_duration,
+                quiet_offset,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for QuietElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.quiet_count, &mut offset)?;
+        buf.gwrite(self.quiet_period, &mut offset)?;
+        buf.gwrite_with(self.quiet_duration, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.quiet_offset, &mut offset, Endian::Little)?;
+
+        Ok(offset)
+    }
+}
+impl Element for QuietElement {
+    const ELEMENT_ID: ElementID = ElementID::Id(40);
+    type ReadType<'a> = Self;
+}
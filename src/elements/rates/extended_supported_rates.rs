@@ -48,6 +48,15 @@ where
         }
     }
 }
+#[cfg(feature = "alloc")]
+impl FromIterator<EncodedRate> for ExtendedSupportedRatesElement<'_, alloc::vec::Vec<EncodedRate>> {
+    /// Collect the rates into a [Vec](alloc::vec::Vec).
+    ///
+    /// No more than 251 rates are retained, since anything after that would be truncated anyway.
+    fn from_iter<T: IntoIterator<Item = EncodedRate>>(iter: T) -> Self {
+        Self::new_unchecked(iter.into_iter().take(251).collect())
+    }
+}
 impl<LhsIterator, RhsIterator> PartialEq<ExtendedSupportedRatesElement<'_, RhsIterator>>
     for ExtendedSupportedRatesElement<'_, LhsIterator>
 where
@@ -36,6 +36,51 @@ impl EncodedRate {
     pub const fn from_rate_in_kbps(rate: usize, is_b: bool) -> Self {
         Self::new().with_rate((rate / 500) as u8).with_is_b(is_b)
     }
+    #[inline]
+    /// Returns the data rate in units of 500 kbps.
+    ///
+    /// This is equivalent to [Self::rate], but makes the unit explicit.
+    pub const fn rate_half_mbps(&self) -> u8 {
+        self.rate()
+    }
+    #[inline]
+    /// Returns the data rate in Mbps.
+    pub fn rate_in_mbps(&self) -> f32 {
+        self.rate() as f32 / 2.0
+    }
+}
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for EncodedRate {
+    type Err = scroll::Error;
+    /// Parses a rate in Mbps, like `54` or `5.5(B)`.
+    ///
+    /// The optional `(B)` suffix marks the rate as IEEE 802.11b. Only whole and half Mbps are accepted, since the rate is encoded in units of 500 kbps.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const INVALID_RATE: scroll::Error = scroll::Error::BadInput {
+            size: 0,
+            msg: "The rate wasn't a valid number of whole or half Mbps.",
+        };
+
+        let s = s.trim();
+        let (s, is_b) = match s.strip_suffix("(B)") {
+            Some(s) => (s.trim_end(), true),
+            None => (s, false),
+        };
+        let (whole, half) = match s.split_once('.') {
+            Some((whole, "5")) => (whole, 1),
+            Some((whole, "0")) => (whole, 0),
+            Some(_) => return Err(INVALID_RATE),
+            None => (s, 0),
+        };
+        let rate = whole
+            .parse::<u8>()
+            .ok()
+            .and_then(|whole| whole.checked_mul(2))
+            .map(|rate| rate + half)
+            .filter(|rate| (1..=0x7f).contains(rate))
+            .ok_or(INVALID_RATE)?;
+        Ok(Self::new().with_rate(rate).with_is_b(is_b))
+    }
 }
 #[cfg(feature = "alloc")]
 impl ::alloc::fmt::Display for EncodedRate {
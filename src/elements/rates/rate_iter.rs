@@ -15,6 +15,11 @@ impl<'a> RatesReadIterator<'a> {
             bytes: ReadIterator::new(bytes),
         }
     }
+    #[cfg(feature = "alloc")]
+    /// Collect the remaining rates into a [Vec](alloc::vec::Vec).
+    pub fn to_vec(&self) -> alloc::vec::Vec<EncodedRate> {
+        self.collect()
+    }
 }
 impl Iterator for RatesReadIterator<'_> {
     type Item = EncodedRate;
@@ -49,6 +49,15 @@ where
         }
     }
 }
+#[cfg(feature = "alloc")]
+impl FromIterator<EncodedRate> for SupportedRatesElement<'_, alloc::vec::Vec<EncodedRate>> {
+    /// Collect the rates into a [Vec](alloc::vec::Vec).
+    ///
+    /// No more than 8 rates are retained, since anything after that would be truncated anyway.
+    fn from_iter<T: IntoIterator<Item = EncodedRate>>(iter: T) -> Self {
+        Self::new_unchecked(iter.into_iter().take(8).collect())
+    }
+}
 impl<LhsIterator, RhsIterator> PartialEq<SupportedRatesElement<'_, RhsIterator>>
     for SupportedRatesElement<'_, LhsIterator>
 where
@@ -0,0 +1,85 @@
+use bitfield_struct::bitfield;
+use scroll::{
+    ctx::{MeasureWith, SizeWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+use super::{Element, ElementID};
+
+#[bitfield(u64, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The RM Enabled Capabilities element advertises, which radio measurements a STA supports, as specified in 9.4.2.44 of IEEE 802.11-2020.
+///
+/// Only the lower 40 bits are transmitted.
+pub struct RMEnabledCapabilitiesElement {
+    pub link_measurement: bool,
+    /// Indicates support for neighbor reports, as introduced by IEEE 802.11k.
+    pub neighbor_report: bool,
+    pub parallel_measurements: bool,
+    pub repeated_measurements: bool,
+    pub beacon_passive_measurement: bool,
+    pub beacon_active_measurement: bool,
+    pub beacon_table_measurement: bool,
+    pub beacon_measurement_reporting_conditions: bool,
+    pub frame_measurement: bool,
+    pub channel_load_measurement: bool,
+    pub noise_histogram_measurement: bool,
+    pub statistics_measurement: bool,
+    pub lci_measurement: bool,
+    pub lci_azimuth: bool,
+    pub transmit_stream_category_measurement: bool,
+    pub triggered_transmit_stream_category_measurement: bool,
+    pub ap_channel_report: bool,
+    pub rm_mib: bool,
+    #[bits(3)]
+    pub operating_channel_max_measurement_duration: u8,
+    #[bits(3)]
+    pub nonoperating_channel_max_measurement_duration: u8,
+    #[bits(3)]
+    pub measurement_pilot: u8,
+    pub measurement_pilot_transmission_information: bool,
+    pub neighbor_report_tsf_offset: bool,
+    pub rcpi_measurement: bool,
+    pub rsni_measurement: bool,
+    pub bss_average_access_delay: bool,
+    pub bss_available_admission_capacity: bool,
+    pub antenna: bool,
+    pub ftm_range_report: bool,
+    pub civic_location_measurement: bool,
+    #[bits(28)]
+    __: u32,
+}
+impl SizeWith for RMEnabledCapabilitiesElement {
+    fn size_with(_ctx: &()) -> usize {
+        5
+    }
+}
+impl MeasureWith<()> for RMEnabledCapabilitiesElement {
+    fn measure_with(&self, ctx: &()) -> usize {
+        Self::size_with(ctx)
+    }
+}
+impl TryFromCtx<'_> for RMEnabledCapabilitiesElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let mut rm_enabled_capabilities = [0x00; 8];
+        rm_enabled_capabilities[..5].copy_from_slice(from.gread_with(&mut offset, 5)?);
+
+        Ok((
+            Self::from_bits(u64::from_le_bytes(rm_enabled_capabilities)),
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for RMEnabledCapabilitiesElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        buf.pwrite(&self.into_bits().to_le_bytes()[..5], 0)
+    }
+}
+impl Element for RMEnabledCapabilitiesElement {
+    const ELEMENT_ID: ElementID = ElementID::Id(70);
+    type ReadType<'a> = Self;
+}
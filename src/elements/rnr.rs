@@ -0,0 +1,425 @@
+//! This module contains support for the Reduced Neighbor Report element.
+//!
+//! The Reduced Neighbor Report is mostly used in beacons and probe responses on 2.4 and 5 GHz, to advertise APs operating in the 6 GHz band.
+
+use core::marker::PhantomData;
+
+use bitfield_struct::bitfield;
+use mac_parser::MACAddress;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use super::{Element, ElementID};
+
+#[bitfield(u16, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The TBTT Information Header of a [NeighborAPInformation] field.
+pub struct TBTTInformationHeader {
+    /// The type of the TBTT Information fields. Only type zero is currently defined.
+    #[bits(2)]
+    pub tbtt_information_field_type: u8,
+    /// Indicates, that only APs with an SSID matching the one in the probe request are reported.
+    pub filtered_neighbor_ap: bool,
+    __: bool,
+    /// The amount of TBTT Information fields minus one.
+    #[bits(4)]
+    pub tbtt_information_count: u8,
+    /// The length of each TBTT Information field.
+    #[bits(8)]
+    pub tbtt_information_length: u8,
+}
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The BSS Parameters subfield of a [TBTTInformation] field.
+pub struct BSSParameters {
+    /// Indicates, that the reporting AP recommends on-channel tunneling to the reported AP.
+    pub oct_recommended: bool,
+    /// Indicates, that the reported AP has the same SSID as the reporting AP.
+    pub same_ssid: bool,
+    /// Indicates, that the reported AP is part of a multiple BSSID set.
+    pub multiple_bssid: bool,
+    /// Indicates, that the reported AP is the transmitted BSSID of it's multiple BSSID set.
+    pub transmitted_bssid: bool,
+    /// Indicates, that the reported AP is a member of an ESS with co-located APs on 2.4 or 5 GHz.
+    pub member_of_ess_with_2g_5g_colocated_ap: bool,
+    /// Indicates, that the reported AP sends unsolicited probe responses.
+    pub unsolicited_probe_responses_active: bool,
+    /// Indicates, that the reported AP is co-located with the reporting AP.
+    pub colocated_ap: bool,
+    __: bool,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// A TBTT Information field of type zero.
+///
+/// The subfields present are determined by the length of the field, as specified in Table 9-281 of IEEE 802.11-2020 and IEEE 802.11be.
+/// When reading, subfields unknown to this implementation are skipped. When writing, the subfields are written in order, so the combination of present subfields has to correspond to a valid length.
+pub struct TBTTInformation {
+    /// The offset in TUs of the next TBTT of the reported AP, relative to the reporting AP.
+    pub tbtt_offset: u8,
+    pub bssid: Option<MACAddress>,
+    /// The short SSID, which is the CRC-32 of the SSID.
+    pub short_ssid: Option<u32>,
+    pub bss_parameters: Option<BSSParameters>,
+    /// The maximum transmit power spectral density in units of 0.5 dBm/MHz.
+    pub psd_20mhz: Option<i8>,
+    /// The raw MLD Parameters subfield.
+    pub mld_parameters: Option<[u8; 3]>,
+}
+impl MeasureWith<()> for TBTTInformation {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        1 + if self.bssid.is_some() { 6 } else { 0 }
+            + if self.short_ssid.is_some() { 4 } else { 0 }
+            + if self.bss_parameters.is_some() { 1 } else { 0 }
+            + if self.psd_20mhz.is_some() { 1 } else { 0 }
+            + if self.mld_parameters.is_some() { 3 } else { 0 }
+    }
+}
+impl TryFromCtx<'_, u8> for TBTTInformation {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], length: u8) -> Result<(Self, usize), Self::Error> {
+        let length = length as usize;
+        let from = from.get(..length).ok_or(scroll::Error::TooBig {
+            size: length,
+            len: from.len(),
+        })?;
+        let mut offset = 0;
+
+        let (has_bssid, has_short_ssid, has_bss_parameters, has_psd_20mhz, has_mld_parameters) =
+            match length {
+                1 => (false, false, false, false, false),
+                2 => (false, false, true, false, false),
+                5 => (false, true, false, false, false),
+                6 => (false, true, true, false, false),
+                7 => (true, false, false, false, false),
+                8 => (true, false, true, false, false),
+                9 => (true, false, true, true, false),
+                11 => (true, true, false, false, false),
+                12 => (true, true, true, false, false),
+                13..=15 => (true, true, true, true, false),
+                16.. => (true, true, true, true, true),
+                _ => {
+                    return Err(scroll::Error::BadInput {
+                        size: length,
+                        msg: "The length of the TBTT Information field is reserved.",
+                    })
+                }
+            };
+        let tbtt_offset = from.gread(&mut offset)?;
+        let bssid = if has_bssid {
+            Some(from.gread(&mut offset)?)
+        } else {
+            None
+        };
+        let short_ssid = if has_short_ssid {
+            Some(from.gread_with(&mut offset, Endian::Little)?)
+        } else {
+            None
+        };
+        let bss_parameters = if has_bss_parameters {
+            Some(BSSParameters::from_bits(from.gread(&mut offset)?))
+        } else {
+            None
+        };
+        let psd_20mhz = if has_psd_20mhz {
+            Some(from.gread(&mut offset)?)
+        } else {
+            None
+        };
+        let mld_parameters = if has_mld_parameters {
+            Some(from.gread(&mut offset)?)
+        } else {
+            None
+        };
+
+        Ok((
+            Self {
+                tbtt_offset,
+                bssid,
+                short_ssid,
+                bss_parameters,
+                psd_20mhz,
+                mld_parameters,
+            },
+            length,
+        ))
+    }
+}
+impl TryIntoCtx for TBTTInformation {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.tbtt_offset, &mut offset)?;
+        if let Some(bssid) = self.bssid {
+            buf.gwrite(bssid, &mut offset)?;
+        }
+        if let Some(short_ssid) = self.short_ssid {
+            buf.gwrite_with(short_ssid, &mut offset, Endian::Little)?;
+        }
+        if let Some(bss_parameters) = self.bss_parameters {
+            buf.gwrite(bss_parameters.into_bits(), &mut offset)?;
+        }
+        if let Some(psd_20mhz) = self.psd_20mhz {
+            buf.gwrite(psd_20mhz, &mut offset)?;
+        }
+        if let Some(mld_parameters) = self.mld_parameters {
+            buf.gwrite(mld_parameters, &mut offset)?;
+        }
+
+        Ok(offset)
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// An [Iterator] over the [TBTTInformation] fields of a [NeighborAPInformation] field.
+///
+/// Fields with a reserved length are skipped.
+pub struct TBTTInformationIterator<'a> {
+    bytes: &'a [u8],
+    tbtt_information_length: u8,
+}
+impl Iterator for TBTTInformationIterator<'_> {
+    type Item = TBTTInformation;
+    fn next(&mut self) -> Option<Self::Item> {
+        let length = self.tbtt_information_length as usize;
+        if length == 0 {
+            return None;
+        }
+        loop {
+            let field = self.bytes.get(..length)?;
+            self.bytes = &self.bytes[length..];
+            if let Ok(tbtt_information) = field.pread_with(0, self.tbtt_information_length) {
+                return Some(tbtt_information);
+            }
+        }
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// A Neighbor AP Information field, which contains information about one or more APs operating on the same channel.
+///
+/// The TBTT Information fields are kept as raw bytes, since their layout depends on the TBTT Information Length.
+pub struct NeighborAPInformation<'a> {
+    /// The TBTT Information Header.
+    ///
+    /// The TBTT Information Count is ignored when writing and calculated from the length of [Self::tbtt_information_fields] instead.
+    pub tbtt_information_header: TBTTInformationHeader,
+    pub operating_class: u8,
+    pub channel_number: u8,
+    /// The raw TBTT Information fields.
+    pub tbtt_information_fields: &'a [u8],
+}
+impl<'a> NeighborAPInformation<'a> {
+    /// Returns an [Iterator] over the TBTT Information fields.
+    ///
+    /// If the field type isn't zero, the iterator is empty.
+    pub const fn tbtt_information(&self) -> TBTTInformationIterator<'a> {
+        TBTTInformationIterator {
+            bytes: if self.tbtt_information_header.tbtt_information_field_type() == 0 {
+                self.tbtt_information_fields
+            } else {
+                &[]
+            },
+            tbtt_information_length: self.tbtt_information_header.tbtt_information_length(),
+        }
+    }
+}
+impl MeasureWith<()> for NeighborAPInformation<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        4 + self.tbtt_information_fields.len()
+    }
+}
+impl<'a> TryFromCtx<'a> for NeighborAPInformation<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let tbtt_information_header =
+            TBTTInformationHeader::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+        let operating_class = from.gread(&mut offset)?;
+        let channel_number = from.gread(&mut offset)?;
+        if tbtt_information_header.tbtt_information_length() == 0 {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "The TBTT Information Length can't be zero.",
+            });
+        }
+        let tbtt_information_fields_length =
+            (tbtt_information_header.tbtt_information_count() as usize + 1)
+                * tbtt_information_header.tbtt_information_length() as usize;
+        let tbtt_information_fields =
+            from.gread_with(&mut offset, tbtt_information_fields_length)?;
+
+        Ok((
+            Self {
+                tbtt_information_header,
+                operating_class,
+                channel_number,
+                tbtt_information_fields,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for NeighborAPInformation<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        let tbtt_information_count = self
+            .tbtt_information_fields
+            .len()
+            .checked_div(self.tbtt_information_header.tbtt_information_length() as usize)
+            .unwrap_or_default()
+            .saturating_sub(1) as u8;
+        buf.gwrite_with(
+            self.tbtt_information_header
+                .with_tbtt_information_count(tbtt_information_count)
+                .into_bits(),
+            &mut offset,
+            Endian::Little,
+        )?;
+        buf.gwrite(self.operating_class, &mut offset)?;
+        buf.gwrite(self.channel_number, &mut offset)?;
+        buf.gwrite(self.tbtt_information_fields, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// An [Iterator] over the [NeighborAPInformation] fields of a [ReducedNeighborReportElement].
+pub struct NeighborAPInformationIterator<'a> {
+    bytes: &'a [u8],
+}
+impl<'a> NeighborAPInformationIterator<'a> {
+    /// Create a new iterator over the raw bytes.
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+}
+impl<'a> Iterator for NeighborAPInformationIterator<'a> {
+    type Item = NeighborAPInformation<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut offset = 0;
+        let neighbor_ap_information = self.bytes.gread(&mut offset).ok()?;
+        self.bytes = &self.bytes[offset..];
+        Some(neighbor_ap_information)
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, Hash)]
+/// The Reduced Neighbor Report element contains channel and other information about neighboring APs.
+pub struct ReducedNeighborReportElement<
+    'a,
+    NeighborAPInformations = NeighborAPInformationIterator<'a>,
+> where
+    NeighborAPInformations: IntoIterator<Item = NeighborAPInformation<'a>>,
+{
+    pub neighbor_ap_information: NeighborAPInformations,
+    pub _phantom: PhantomData<&'a ()>,
+}
+impl<'a, NeighborAPInformations: IntoIterator<Item = NeighborAPInformation<'a>>>
+    ReducedNeighborReportElement<'a, NeighborAPInformations>
+{
+    pub const fn new(neighbor_ap_information: NeighborAPInformations) -> Self {
+        Self {
+            neighbor_ap_information,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<'a, NeighborAPInformations: IntoIterator<Item = NeighborAPInformation<'a>> + Clone>
+    ReducedNeighborReportElement<'a, NeighborAPInformations>
+{
+    /// Returns an [Iterator] over the Neighbor AP Information fields.
+    pub fn neighbor_ap_information(&self) -> NeighborAPInformations::IntoIter {
+        self.neighbor_ap_information.clone().into_iter()
+    }
+    /// Returns an [Iterator] over the operating class, channel and BSSID of all reported APs.
+    ///
+    /// APs, for which no BSSID is included, are skipped.
+    pub fn neighbor_bssids(&self) -> impl Iterator<Item = (u8, u8, MACAddress)> + 'a
+    where
+        NeighborAPInformations::IntoIter: 'a,
+    {
+        self.neighbor_ap_information()
+            .flat_map(|neighbor_ap_information| {
+                neighbor_ap_information
+                    .tbtt_information()
+                    .filter_map(move |tbtt_information| {
+                        Some((
+                            neighbor_ap_information.operating_class,
+                            neighbor_ap_information.channel_number,
+                            tbtt_information.bssid?,
+                        ))
+                    })
+            })
+    }
+}
+impl<'a, LhsNeighborAPInformations, RhsNeighborAPInformations>
+    PartialEq<ReducedNeighborReportElement<'a, RhsNeighborAPInformations>>
+    for ReducedNeighborReportElement<'a, LhsNeighborAPInformations>
+where
+    LhsNeighborAPInformations: IntoIterator<Item = NeighborAPInformation<'a>> + Clone,
+    RhsNeighborAPInformations: IntoIterator<Item = NeighborAPInformation<'a>> + Clone,
+{
+    fn eq(&self, other: &ReducedNeighborReportElement<'a, RhsNeighborAPInformations>) -> bool {
+        self.neighbor_ap_information()
+            .eq(other.neighbor_ap_information())
+    }
+}
+impl<'a, NeighborAPInformations> Eq for ReducedNeighborReportElement<'a, NeighborAPInformations> where
+    NeighborAPInformations: IntoIterator<Item = NeighborAPInformation<'a>> + Clone
+{
+}
+impl<'a, NeighborAPInformations: IntoIterator<Item = NeighborAPInformation<'a>> + Clone>
+    MeasureWith<()> for ReducedNeighborReportElement<'a, NeighborAPInformations>
+{
+    fn measure_with(&self, ctx: &()) -> usize {
+        self.neighbor_ap_information()
+            .map(|neighbor_ap_information| neighbor_ap_information.measure_with(ctx))
+            .sum()
+    }
+}
+impl<'a> TryFromCtx<'a> for ReducedNeighborReportElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        // Validate all fields, so the iterator doesn't silently stop early.
+        let mut offset = 0;
+        while offset < from.len() {
+            from.gread::<NeighborAPInformation>(&mut offset)?;
+        }
+
+        Ok((Self::new(NeighborAPInformationIterator::new(from)), offset))
+    }
+}
+impl<'a, NeighborAPInformations: IntoIterator<Item = NeighborAPInformation<'a>>> TryIntoCtx
+    for ReducedNeighborReportElement<'a, NeighborAPInformations>
+{
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        for neighbor_ap_information in self.neighbor_ap_information {
+            buf.gwrite(neighbor_ap_information, &mut offset)?;
+        }
+
+        Ok(offset)
+    }
+}
+impl<'a, NeighborAPInformations: IntoIterator<Item = NeighborAPInformation<'a>> + Clone> Element
+    for ReducedNeighborReportElement<'a, NeighborAPInformations>
+{
+    const ELEMENT_ID: ElementID = ElementID::Id(201);
+    type ReadType<'b> = ReducedNeighborReportElement<'b>;
+}
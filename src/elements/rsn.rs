@@ -1,4 +1,4 @@
-//! This module contains support for the RSN element.
+//! This module contains support for the RSN and RSN extension elements.
 
 use core::{fmt::Display, marker::PhantomData};
 
@@ -12,7 +12,7 @@ use crate::common::{ReadIterator, IEEE_OUI};
 
 use super::{Element, ElementID};
 
-const fn merge_oui_and_suite_type(oui: [u8; 3], suite_type: u8) -> u32 {
+pub(crate) const fn merge_oui_and_suite_type(oui: [u8; 3], suite_type: u8) -> u32 {
     let mut cipher_suite_selector = [0x00u8; 4];
 
     // We can't use copy_from_slice here, since it's not const, due to the mutable reference.
@@ -23,7 +23,7 @@ const fn merge_oui_and_suite_type(oui: [u8; 3], suite_type: u8) -> u32 {
 
     u32::from_le_bytes(cipher_suite_selector)
 }
-const fn split_cipher_suite_selector(cipher_suite_selector: u32) -> ([u8; 3], u8) {
+pub(crate) const fn split_cipher_suite_selector(cipher_suite_selector: u32) -> ([u8; 3], u8) {
     let cipher_suite_selector = cipher_suite_selector.to_le_bytes();
     let mut oui = [0x00u8; 3];
     oui[0] = cipher_suite_selector[0];
@@ -117,6 +117,7 @@ macro_rules! cipher_suite_selectors {
         }
     };
 }
+pub(crate) use cipher_suite_selectors;
 cipher_suite_selectors! {
     #[cfg_attr(feature = "defmt", derive(defmt::Format))]
     #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
@@ -138,6 +139,52 @@ cipher_suite_selectors! {
         BIPCcmp256 => (IEEE_OUI, 13)
     }
 }
+impl IEEE80211CipherSuiteSelector {
+    /// Returns the relative strength of a data cipher suite.
+    ///
+    /// This returns [None] for cipher suites, which can't be used for protecting data frames.
+    const fn data_cipher_strength(&self) -> Option<u8> {
+        Some(match self {
+            Self::Wep40 => 0,
+            Self::Wep104 => 1,
+            Self::Tkip => 2,
+            Self::Ccmp128 => 3,
+            Self::Gcmp128 => 4,
+            Self::Ccmp256 => 5,
+            Self::Gcmp256 => 6,
+            _ => return None,
+        })
+    }
+    /// Returns the length of the temporal key in bytes, as specified in Table 12-8 of IEEE 802.11-2020.
+    ///
+    /// This returns [None] for cipher suites, which don't use a temporal key.
+    pub const fn tk_len(&self) -> Option<usize> {
+        Some(match self {
+            Self::Wep40 => 5,
+            Self::Wep104 => 13,
+            Self::Tkip | Self::Gcmp256 | Self::Ccmp256 | Self::BIPGcmp256 | Self::BIPCcmp256 => 32,
+            Self::Ccmp128 | Self::BipCmac128 | Self::Gcmp128 | Self::BIPGcmp128 => 16,
+            _ => return None,
+        })
+    }
+    /// Infer the group data cipher suite from a list of pairwise cipher suites.
+    ///
+    /// Since all STAs in the BSS have to be able to use the group cipher suite, this is the weakest data cipher suite from the list.
+    /// If no data cipher suite is in the list, this returns [Self::Ccmp128], which is the default for RSNAs.
+    pub fn implied_group_cipher(pairwise: &[Self]) -> Self {
+        pairwise
+            .iter()
+            .copied()
+            .filter_map(|cipher_suite| {
+                cipher_suite
+                    .data_cipher_strength()
+                    .map(|strength| (strength, cipher_suite))
+            })
+            .min_by_key(|(strength, _)| *strength)
+            .map(|(_, cipher_suite)| cipher_suite)
+            .unwrap_or(Self::Ccmp128)
+    }
+}
 cipher_suite_selectors! {
     #[cfg_attr(feature = "defmt", derive(defmt::Format))]
     #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
@@ -203,6 +250,9 @@ impl RSNCapabilities {
         !self.mfp_required() || self.mfp_enabled()
     }
     /// Check if the own management frame protection (MFP) policy is compatible with the other provided one.
+    ///
+    /// This implements the MFPC/MFPR compatibility matrix from 12.6.3 of IEEE 802.11-2020.
+    /// Two policies are incompatible, if either of them is invalid or one side requires MFP, while the other one isn't capable of it.
     pub const fn is_mfp_compatible(&self, other: RSNCapabilities) -> bool {
         // Associations aren't allowed with invalid MFP policies, so rule them out directly.
         if !self.is_mfp_valid() || !other.is_mfp_valid() {
@@ -523,6 +573,48 @@ impl<PairwiseCipherSuiteList: Default, AKMList: Default, PMKIDList: Default>
         }
     }
 }
+impl<
+        PairwiseCipherSuiteList: IntoIterator<Item = IEEE80211CipherSuiteSelector> + Clone,
+        AKMList: IntoIterator<Item = IEEE80211AKMType> + Clone,
+        PMKIDList,
+    > RSNElement<'_, PairwiseCipherSuiteList, AKMList, PMKIDList>
+{
+    /// Returns an [Iterator] over the AKM suites.
+    ///
+    /// The list is cloned, so this can be called repeatedly. If the AKM list isn't present, the iterator is empty.
+    pub fn akm_suites(&self) -> impl Iterator<Item = IEEE80211AKMType> + '_ {
+        self.akm_list.clone().into_iter().flatten()
+    }
+    /// Returns an [Iterator] over the pairwise cipher suites.
+    ///
+    /// The list is cloned, so this can be called repeatedly. If the pairwise cipher suite list isn't present, the iterator is empty.
+    pub fn pairwise_ciphers(&self) -> impl Iterator<Item = IEEE80211CipherSuiteSelector> + '_ {
+        self.pairwise_cipher_suite_list
+            .clone()
+            .into_iter()
+            .flatten()
+    }
+    /// Check if the specified AKM suite is supported.
+    ///
+    /// If the AKM list isn't present, the default of 00-0F-AC:1 is assumed, as specified in 9.4.2.24.1 of IEEE 802.11-2020.
+    pub fn supports_akm(&self, akm: IEEE80211AKMType) -> bool {
+        match self.akm_list {
+            Some(_) => self.akm_suites().any(|akm_suite| akm_suite == akm),
+            None => akm == IEEE80211AKMType::Wpa,
+        }
+    }
+    /// Check if the specified pairwise cipher suite is supported.
+    ///
+    /// If the pairwise cipher suite list isn't present, the default of CCMP-128 is assumed, as specified in 9.4.2.24.1 of IEEE 802.11-2020.
+    pub fn supports_pairwise(&self, cipher: IEEE80211CipherSuiteSelector) -> bool {
+        match self.pairwise_cipher_suite_list {
+            Some(_) => self
+                .pairwise_ciphers()
+                .any(|pairwise_cipher| pairwise_cipher == cipher),
+            None => cipher == IEEE80211CipherSuiteSelector::Ccmp128,
+        }
+    }
+}
 macro_rules! compare_list_option {
     ($lhs:expr, $rhs:expr, $field_name:ident) => {
         match ($lhs.$field_name.clone(), $rhs.$field_name.clone()) {
@@ -579,6 +671,7 @@ macro_rules! read_list {
         }
     };
 }
+pub(crate) use read_list;
 impl<'a> TryFromCtx<'a> for RSNElement<'a> {
     type Error = scroll::Error;
     fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
@@ -656,6 +749,7 @@ macro_rules! write_list {
         )?;
     };
 }
+pub(crate) use write_list;
 // The additional `TryIntoCtx` bounds are present, because doing this using an iterator is horribly inefficent.
 impl<
         PairwiseCipherSuiteList: TryIntoCtx<(), Error = scroll::Error>,
@@ -712,3 +806,98 @@ where
     const ELEMENT_ID: ElementID = ElementID::Id(0x30);
     type ReadType<'a> = RSNElement<'a>;
 }
+
+#[bitfield(u16, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The extended RSN capabilities of the transmitting STA.
+pub struct RSNExtendedCapabilities {
+    /// The length of the field minus one.
+    ///
+    /// This is set automatically while writing.
+    #[bits(4)]
+    field_length: u8,
+    pub protected_twt_operations_support: bool,
+    /// SAE hash-to-element is supported.
+    pub sae_hash_to_element: bool,
+    /// SAE public key is enabled.
+    pub sae_pk: bool,
+    pub __: bool,
+    pub secure_ltf_support: bool,
+    pub secure_rtt_support: bool,
+    pub urnm_mfpr_x20: bool,
+    #[bits(5)]
+    pub __: u8,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The RSN extension element (RSNXE) contains further security capabilities, which didn't fit into the [RSNElement].
+///
+/// Only the first two octets of the extended RSN capabilities are parsed, all further octets are skipped.
+/// The length of the field is chosen automatically while writing.
+pub struct RSNExtensionElement {
+    pub extended_rsn_capabilities: RSNExtendedCapabilities,
+}
+impl RSNExtensionElement {
+    /// Returns the number of octets, which the extended RSN capabilities occupy when written.
+    const fn capabilities_length(&self) -> usize {
+        if self.extended_rsn_capabilities.into_bits() >> 8 != 0 {
+            2
+        } else {
+            1
+        }
+    }
+}
+impl MeasureWith<()> for RSNExtensionElement {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        self.capabilities_length()
+    }
+}
+impl TryFromCtx<'_> for RSNExtensionElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let first_octet = from.gread::<u8>(&mut offset)?;
+        let field_length = (first_octet & 0x0f) as usize + 1;
+        let second_octet = if field_length >= 2 {
+            from.gread::<u8>(&mut offset)?
+        } else {
+            0
+        };
+        // The remaining octets are currently all reserved.
+        offset += field_length.saturating_sub(2);
+        if offset > from.len() {
+            return Err(scroll::Error::TooBig {
+                size: offset,
+                len: from.len(),
+            });
+        }
+
+        Ok((
+            Self {
+                extended_rsn_capabilities: RSNExtendedCapabilities::from_bits(u16::from_le_bytes(
+                    [first_octet, second_octet],
+                ))
+                .with_field_length(0),
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for RSNExtensionElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let capabilities_length = self.capabilities_length();
+        let extended_rsn_capabilities = self
+            .extended_rsn_capabilities
+            .with_field_length(capabilities_length as u8 - 1)
+            .into_bits()
+            .to_le_bytes();
+        buf.pwrite(&extended_rsn_capabilities[..capabilities_length], 0)
+    }
+}
+impl Element for RSNExtensionElement {
+    const ELEMENT_ID: ElementID = ElementID::Id(244);
+    type ReadType<'a> = Self;
+}
@@ -220,6 +220,23 @@ impl<Bitmap> TIMBitmap<Bitmap> {
         })
     }
 }
+impl<'a> TIMBitmap<&'a [u8]> {
+    /// Creates a new [TIMBitmap] from a partial virtual bitmap, which starts at byte `n1` of the traffic indication virtual bitmap.
+    ///
+    /// # Returns
+    /// This returns [None], if `n1` is odd, the bitmap is empty or the length of the bitmap plus `n1` is larger than 251, and [Some] in all other cases.
+    pub fn from_partial_bitmap(traffic_indicator: bool, n1: u8, bitmap: &'a [u8]) -> Option<Self> {
+        if n1 % 2 != 0 || bitmap.is_empty() {
+            return None;
+        }
+        Self::new_raw(
+            TIMBitmapControl::new()
+                .with_traffic_indicator(traffic_indicator)
+                .with_n1(n1),
+            Some(bitmap),
+        )
+    }
+}
 impl TIMBitmap<StaticBitmap> {
     /// Create a static [TIMBitmap].
     ///
@@ -500,6 +517,24 @@ pub struct TIMElement<'a, Bitmap = &'a [u8]> {
     pub _phantom: PhantomData<&'a ()>,
 }
 impl<Bitmap> TIMElement<'_, Bitmap> {
+    /// Check if the beacon carrying this element is a DTIM beacon.
+    ///
+    /// This is the case, if [Self::dtim_count] is zero.
+    pub const fn is_dtim(&self) -> bool {
+        self.dtim_count == 0
+    }
+    /// Returns an [Iterator] over the [AssociationIDs](AssociationID), for which traffic is buffered.
+    ///
+    /// This is a shortcut for [TIMBitmap::aid_iter] and the iterator is empty, if no bitmap is present.
+    pub fn buffered_aids(&self) -> impl Iterator<Item = AssociationID> + '_
+    where
+        Bitmap: Deref<Target = [u8]>,
+    {
+        self.bitmap
+            .iter()
+            .flat_map(|bitmap| bitmap.aid_iter())
+            .flatten()
+    }
     /// Check if the DTIM parameters are valid.
     const fn check_dtim_parameters(dtim_period: u8, dtim_count: u8) -> Result<(), scroll::Error> {
         if dtim_period < dtim_count {
@@ -561,7 +596,14 @@ impl<'a> TryFromCtx<'a> for TIMElement<'a> {
         let dtim_period = from.gread(&mut offset)?;
         Self::check_dtim_parameters(dtim_period, dtim_count)?;
 
-        let bitmap = from.gread(&mut offset).ok();
+        // The bitmap control and at least one byte of the partial virtual bitmap are always present.
+        if from.len() < 4 {
+            return Err(scroll::Error::BadInput {
+                size: from.len(),
+                msg: "The TIM element is missing the bitmap.",
+            });
+        }
+        let bitmap = Some(from.gread(&mut offset)?);
         Ok((
             Self {
                 dtim_count,
@@ -596,7 +638,7 @@ impl<Bitmap: TryIntoCtx<Error = scroll::Error>> TryIntoCtx for TIMElement<'_, Bi
             buf.gwrite(bitmap, &mut offset)?;
         } else {
             // Empty bitmap control and partial virtual bitmap.
-            offset += 2;
+            buf.gwrite([0x00u8; 2].as_slice(), &mut offset)?;
         }
 
         Ok(offset)
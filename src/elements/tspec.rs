@@ -0,0 +1,324 @@
+//! This module contains support for the TSPEC and TCLAS elements, which are used for QoS admission control.
+
+use bitfield_struct::bitfield;
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use super::{Element, ElementID};
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The direction of a traffic stream.
+    pub enum TSDirection: u8 {
+        #[default]
+        Uplink => 0b00,
+        Downlink => 0b01,
+        DirectLink => 0b10,
+        Bidirectional => 0b11
+    }
+}
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The access policy of a traffic stream.
+    pub enum TSAccessPolicy: u8 {
+        Reserved => 0b00,
+        HCCA => 0b01,
+        #[default]
+        EDCA => 0b10,
+        HCCAAndEDCA => 0b11
+    }
+}
+
+#[bitfield(u32, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// Information about the traffic stream.
+///
+/// Only the lower three octets are transmitted.
+pub struct TSInfo {
+    /// Indicates, that the traffic is periodic.
+    pub periodic_traffic: bool,
+    /// The traffic stream identifier.
+    #[bits(4)]
+    pub tsid: u8,
+    #[bits(2)]
+    pub direction: TSDirection,
+    #[bits(2)]
+    pub access_policy: TSAccessPolicy,
+    pub aggregation: bool,
+    /// Indicates, that automatic power save delivery is used for this traffic stream.
+    pub apsd: bool,
+    /// The user priority of the MSDUs belonging to this traffic stream.
+    #[bits(3)]
+    pub user_priority: u8,
+    #[bits(2)]
+    pub ack_policy: u8,
+    pub schedule: bool,
+    #[bits(15)]
+    __: u32,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The TSPEC element describes the traffic characteristics and QoS expectations of a traffic stream.
+///
+/// All intervals are specified in µs and all data rates in bits per second.
+pub struct TSPECElement {
+    pub ts_info: TSInfo,
+    /// The nominal size of MSDUs in octets.
+    ///
+    /// The most significant bit indicates, that the size is fixed.
+    pub nominal_msdu_size: u16,
+    /// The maximum size of MSDUs in octets.
+    pub maximum_msdu_size: u16,
+    pub minimum_service_interval: u32,
+    pub maximum_service_interval: u32,
+    pub inactivity_interval: u32,
+    pub suspension_interval: u32,
+    /// The lower four octets of the TSF timer, at which the service period starts.
+    pub service_start_time: u32,
+    pub minimum_data_rate: u32,
+    pub mean_data_rate: u32,
+    pub peak_data_rate: u32,
+    /// The maximum burst size in octets.
+    pub burst_size: u32,
+    /// The maximum amount of time allowed to transport an MSDU.
+    pub delay_bound: u32,
+    pub minimum_phy_rate: u32,
+    /// The excess allocation of time and bandwidth over the application rate, encoded as a binary fixed point number, with 13 fractional bits.
+    pub surplus_bandwidth_allowance: u16,
+    /// The amount of time admitted to access the medium, in units of 32 µs/s.
+    pub medium_time: u16,
+}
+impl MeasureWith<()> for TSPECElement {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        55
+    }
+}
+impl TryFromCtx<'_> for TSPECElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let mut ts_info = [0x00u8; 4];
+        ts_info[..3].copy_from_slice(from.gread_with(&mut offset, 3)?);
+        let ts_info = TSInfo::from_bits(u32::from_le_bytes(ts_info));
+        let nominal_msdu_size = from.gread_with(&mut offset, Endian::Little)?;
+        let maximum_msdu_size = from.gread_with(&mut offset, Endian::Little)?;
+        let minimum_service_interval = from.gread_with(&mut offset, Endian::Little)?;
+        let maximum_service_interval = from.gread_with(&mut offset, Endian::Little)?;
+        let inactivity_interval = from.gread_with(&mut offset, Endian::Little)?;
+        let suspension_interval = from.gread_with(&mut offset, Endian::Little)?;
+        let service_start_time = from.gread_with(&mut offset, Endian::Little)?;
+        let minimum_data_rate = from.gread_with(&mut offset, Endian::Little)?;
+        let mean_data_rate = from.gread_with(&mut offset, Endian::Little)?;
+        let peak_data_rate = from.gread_with(&mut offset, Endian::Little)?;
+        let burst_size = from.gread_with(&mut offset, Endian::Little)?;
+        let delay_bound = from.gread_with(&mut offset, Endian::Little)?;
+        let minimum_phy_rate = from.gread_with(&mut offset, Endian::Little)?;
+        let surplus_bandwidth_allowance = from.gread_with(&mut offset, Endian::Little)?;
+        let medium_time = from.gread_with(&mut offset, Endian::Little)?;
+
+        Ok((
+            Self {
+                ts_info,
+                nominal_msdu_size,
+                maximum_msdu_size,
+                minimum_service_interval,
+                maximum_service_interval,
+                inactivity_interval,
+                suspension_interval,
+                service_start_time,
+                minimum_data_rate,
+                mean_data_rate,
+                peak_data_rate,
+                burst_size,
+                delay_bound,
+                minimum_phy_rate,
+                surplus_bandwidth_allowance,
+                medium_time,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for TSPECElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(&self.ts_info.into_bits().to_le_bytes()[..3], &mut offset)?;
+        buf.gwrite_with(self.nominal_msdu_size, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.maximum_msdu_size, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.minimum_service_interval, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.maximum_service_interval, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.inactivity_interval, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.suspension_interval, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.service_start_time, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.minimum_data_rate, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.mean_data_rate, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.peak_data_rate, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.burst_size, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.delay_bound, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.minimum_phy_rate, &mut offset, Endian::Little)?;
+        buf.gwrite_with(
+            self.surplus_bandwidth_allowance,
+            &mut offset,
+            Endian::Little,
+        )?;
+        buf.gwrite_with(self.medium_time, &mut offset, Endian::Little)?;
+
+        Ok(offset)
+    }
+}
+impl Element for TSPECElement {
+    const ELEMENT_ID: ElementID = ElementID::Id(13);
+    type ReadType<'a> = Self;
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The parameters of an IPv4 classifier, i.e. classifier type four with version four.
+///
+/// The addresses and ports are in network byte order.
+pub struct IPv4ClassifierParameters {
+    pub source_address: [u8; 4],
+    pub destination_address: [u8; 4],
+    pub source_port: u16,
+    pub destination_port: u16,
+    pub dscp: u8,
+    pub protocol: u8,
+}
+impl IPv4ClassifierParameters {
+    /// The type of the IP classifier.
+    pub const CLASSIFIER_TYPE: u8 = 4;
+}
+impl MeasureWith<()> for IPv4ClassifierParameters {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        16
+    }
+}
+impl TryFromCtx<'_> for IPv4ClassifierParameters {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        if from.gread::<u8>(&mut offset)? != 4 {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "The IP version of the classifier wasn't four.",
+            });
+        }
+        let source_address = from.gread(&mut offset)?;
+        let destination_address = from.gread(&mut offset)?;
+        let source_port = from.gread_with(&mut offset, Endian::Big)?;
+        let destination_port = from.gread_with(&mut offset, Endian::Big)?;
+        let dscp = from.gread(&mut offset)?;
+        let protocol = from.gread(&mut offset)?;
+        // Reserved
+        offset += 1;
+
+        Ok((
+            Self {
+                source_address,
+                destination_address,
+                source_port,
+                destination_port,
+                dscp,
+                protocol,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for IPv4ClassifierParameters {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(4u8, &mut offset)?;
+        buf.gwrite(self.source_address, &mut offset)?;
+        buf.gwrite(self.destination_address, &mut offset)?;
+        buf.gwrite_with(self.source_port, &mut offset, Endian::Big)?;
+        buf.gwrite_with(self.destination_port, &mut offset, Endian::Big)?;
+        buf.gwrite(self.dscp, &mut offset)?;
+        buf.gwrite(self.protocol, &mut offset)?;
+        buf.gwrite(0u8, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The TCLAS element specifies how incoming MSDUs are classified into a traffic stream.
+///
+/// The classifier parameters depend on the classifier type and are therefore kept as a raw slice.
+/// For the IP classifier, they can be parsed with [Self::ipv4_classifier_parameters].
+pub struct TCLASElement<'a> {
+    /// The user priority, with which matching MSDUs are transmitted.
+    pub user_priority: u8,
+    pub classifier_type: u8,
+    /// A bitmask specifying, which of the classifier parameters are used for the classification.
+    pub classifier_mask: u8,
+    pub classifier_parameters: &'a [u8],
+}
+impl TCLASElement<'_> {
+    /// Parse the classifier parameters as [IPv4ClassifierParameters].
+    ///
+    /// This returns [None], if the classifier isn't an IPv4 classifier.
+    pub fn ipv4_classifier_parameters(&self) -> Option<IPv4ClassifierParameters> {
+        if self.classifier_type != IPv4ClassifierParameters::CLASSIFIER_TYPE {
+            return None;
+        }
+        self.classifier_parameters.pread(0).ok()
+    }
+}
+impl MeasureWith<()> for TCLASElement<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        3 + self.classifier_parameters.len()
+    }
+}
+impl<'a> TryFromCtx<'a> for TCLASElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let user_priority = from.gread(&mut offset)?;
+        let classifier_type = from.gread(&mut offset)?;
+        let classifier_mask = from.gread(&mut offset)?;
+        let classifier_parameters = &from[offset..];
+
+        Ok((
+            Self {
+                user_priority,
+                classifier_type,
+                classifier_mask,
+                classifier_parameters,
+            },
+            from.len(),
+        ))
+    }
+}
+impl TryIntoCtx for TCLASElement<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.user_priority, &mut offset)?;
+        buf.gwrite(self.classifier_type, &mut offset)?;
+        buf.gwrite(self.classifier_mask, &mut offset)?;
+        buf.gwrite(self.classifier_parameters, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl Element for TCLASElement<'_> {
+    const ELEMENT_ID: ElementID = ElementID::Id(14);
+    type ReadType<'a> = TCLASElement<'a>;
+}
@@ -0,0 +1,199 @@
+//! This module contains support for the TWT element, as specified in IEEE 802.11ax.
+
+use core::time::Duration;
+
+use bitfield_struct::bitfield;
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use crate::common::TU;
+
+use super::{Element, ElementID};
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The type of TWT negotiation.
+    pub enum TWTNegotiationType: u8 {
+        /// An individual TWT agreement between two STAs.
+        #[default]
+        Individual => 0b00,
+        /// The next wake TBTT and listen interval are negotiated.
+        WakeTBTT => 0b01,
+        /// A broadcast TWT schedule is advertised or negotiated.
+        Broadcast => 0b10,
+        /// The broadcast TWT schedule, which the STA will be a member of, is managed.
+        BroadcastSchedule => 0b11
+    }
+}
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The control field of the [TWTElement].
+pub struct TWTControl {
+    /// Indicates, that the NDP Paging field is present.
+    pub ndp_paging_indicator: bool,
+    /// The power management mode of the responder.
+    pub responder_pm_mode: bool,
+    #[bits(2)]
+    pub negotiation_type: TWTNegotiationType,
+    /// Indicates, that reception of TWT Information frames is disabled.
+    pub twt_information_frame_disabled: bool,
+    /// The unit of the nominal minimum TWT wake duration.
+    ///
+    /// If this is false, the unit is 256µs, otherwise it's one [TU](crate::common::TU).
+    pub wake_duration_unit: bool,
+    #[bits(2)]
+    __: u8,
+}
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The type of TWT command.
+    pub enum TWTSetupCommand: u8 {
+        #[default]
+        Request => 0,
+        Suggest => 1,
+        Demand => 2,
+        Grouping => 3,
+        Accept => 4,
+        Alternate => 5,
+        Dictate => 6,
+        Reject => 7
+    }
+}
+
+#[bitfield(u16, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The request type field of an individual TWT parameter set.
+pub struct TWTRequestType {
+    /// Indicates, that the transmitting STA is the TWT requesting STA.
+    pub twt_request: bool,
+    #[bits(3)]
+    pub twt_setup_command: TWTSetupCommand,
+    /// Indicates, that the TWT SP includes trigger frames.
+    pub trigger: bool,
+    /// Indicates, that the TWT is implicit.
+    pub implicit: bool,
+    /// Indicates an unannounced TWT.
+    pub flow_type: bool,
+    #[bits(3)]
+    pub twt_flow_identifier: u8,
+    /// The exponent of the wake interval, which is a power of two.
+    #[bits(5)]
+    pub twt_wake_interval_exponent: u8,
+    /// Indicates, that the TWT SP is protected by the NAV or RTS/CTS.
+    pub twt_protection: bool,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The TWT element is used to negotiate a schedule, at which a STA is awake.
+///
+/// Only individual TWT parameter sets are supported and an error is returned for broadcast TWT.
+pub struct TWTElement {
+    pub control: TWTControl,
+    pub request_type: TWTRequestType,
+    /// The time in microseconds, at which the STA wakes up for the first TWT SP.
+    ///
+    /// This is relative to the TSF.
+    pub target_wake_time: u64,
+    /// The minimum time, that the STA will be awake during a TWT SP.
+    ///
+    /// The unit depends on [TWTControl::wake_duration_unit].
+    pub nominal_minimum_wake_duration: u8,
+    /// The mantissa of the wake interval in microseconds.
+    pub twt_wake_interval_mantissa: u16,
+    /// A bitmap of the channels, that the STA will use during the TWT SP.
+    pub twt_channel: u8,
+    /// The NDP paging field, which is present if [TWTControl::ndp_paging_indicator] is set.
+    pub ndp_paging: Option<u32>,
+}
+impl TWTElement {
+    /// Returns the interval between two TWT SPs.
+    ///
+    /// This is the mantissa multiplied by two to the power of the exponent, in microseconds.
+    pub const fn wake_interval(&self) -> Duration {
+        Duration::from_micros(
+            (self.twt_wake_interval_mantissa as u64)
+                << self.request_type.twt_wake_interval_exponent(),
+        )
+    }
+    /// Returns the nominal minimum wake duration, taking the unit into account.
+    pub const fn minimum_wake_duration(&self) -> Duration {
+        let unit = if self.control.wake_duration_unit() {
+            TU.as_micros() as u64
+        } else {
+            256
+        };
+        Duration::from_micros(self.nominal_minimum_wake_duration as u64 * unit)
+    }
+}
+impl MeasureWith<()> for TWTElement {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        1 + 2 + 8 + 1 + 2 + 1 + if self.ndp_paging.is_some() { 4 } else { 0 }
+    }
+}
+impl TryFromCtx<'_> for TWTElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let control = TWTControl::from_bits(from.gread(&mut offset)?);
+        if control.negotiation_type() != TWTNegotiationType::Individual {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "Only individual TWT parameter sets are supported.",
+            });
+        }
+        let request_type = TWTRequestType::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+        let target_wake_time = from.gread_with(&mut offset, Endian::Little)?;
+        let nominal_minimum_wake_duration = from.gread(&mut offset)?;
+        let twt_wake_interval_mantissa = from.gread_with(&mut offset, Endian::Little)?;
+        let twt_channel = from.gread(&mut offset)?;
+        let ndp_paging = if control.ndp_paging_indicator() {
+            Some(from.gread_with(&mut offset, Endian::Little)?)
+        } else {
+            None
+        };
+
+        Ok((
+            Self {
+                control,
+                request_type,
+                target_wake_time,
+                nominal_minimum_wake_duration,
+                twt_wake_interval_mantissa,
+                twt_channel,
+                ndp_paging,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for TWTElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.control.into_bits(), &mut offset)?;
+        buf.gwrite_with(self.request_type.into_bits(), &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.target_wake_time, &mut offset, Endian::Little)?;
+        buf.gwrite(self.nominal_minimum_wake_duration, &mut offset)?;
+        buf.gwrite_with(self.twt_wake_interval_mantissa, &mut offset, Endian::Little)?;
+        buf.gwrite(self.twt_channel, &mut offset)?;
+        if let Some(ndp_paging) = self.ndp_paging {
+            buf.gwrite_with(ndp_paging, &mut offset, Endian::Little)?;
+        }
+
+        Ok(offset)
+    }
+}
+impl Element for TWTElement {
+    const ELEMENT_ID: ElementID = ElementID::Id(216);
+    type ReadType<'a> = Self;
+}
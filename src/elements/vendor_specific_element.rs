@@ -2,10 +2,16 @@ use core::marker::PhantomData;
 
 use scroll::{
     ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
-    Pwrite,
+    Pread, Pwrite,
 };
 
-use super::{Element, ElementID};
+use crate::common::{APPLE_OUI, ARUBA_OUI, CISCO_OUI, MICROSOFT_OUI};
+
+use super::{
+    wmm::{WMMInfoElement, WMMParameterElement},
+    wpa::WPAElement,
+    Element, ElementID, OWETransitionModeElement,
+};
 
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
@@ -29,6 +35,22 @@ impl<'a> VendorSpecificElement<'a> {
             None
         }
     }
+    /// Returns the OUI, which are the first three bytes of the payload.
+    ///
+    /// This returns [None], if the payload is shorter than three bytes.
+    pub fn oui(&self) -> Option<[u8; 3]> {
+        self.payload.pread(0).ok()
+    }
+    /// Returns the vendor specific type, which is the byte following the OUI.
+    ///
+    /// This returns [None], if the payload is shorter than four bytes.
+    pub fn vendor_type(&self) -> Option<u8> {
+        self.payload.get(3).copied()
+    }
+    /// Check if the OUI and vendor specific type match the specified ones.
+    pub fn matches_oui_type(&self, oui: [u8; 3], vendor_type: u8) -> bool {
+        self.oui() == Some(oui) && self.vendor_type() == Some(vendor_type)
+    }
 }
 impl<Payload> VendorSpecificElement<'_, Payload> {
     pub const fn get_payload(&self) -> &Payload {
@@ -105,3 +127,70 @@ impl<Payload: TryIntoCtx<Error = scroll::Error>> TryIntoCtx for PrefixedVendorPa
         Ok(offset)
     }
 }
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A [VendorSpecificElement] dispatched by it's OUI and type.
+///
+/// Elements, for which a typed parser exists, are parsed. For all others the payload following the
+/// OUI and type is returned. If the OUI isn't known or the element couldn't be parsed, the raw
+/// [VendorSpecificElement] is returned.
+pub enum VendorElement<'a> {
+    /// The legacy WPA element.
+    Wpa(WPAElement<'a>),
+    WmmInfo(WMMInfoElement),
+    WmmParameter(WMMParameterElement),
+    /// The Wi-Fi Protected Setup element, with the attributes following the OUI and type.
+    Wps(&'a [u8]),
+    OWETransitionMode(OWETransitionModeElement<'a>),
+    /// An element with Apple's OUI.
+    Apple(&'a [u8]),
+    /// An element with Cisco's OUI.
+    Cisco(&'a [u8]),
+    /// An element with Aruba's OUI.
+    Aruba(&'a [u8]),
+    Unknown(VendorSpecificElement<'a>),
+}
+impl<'a> VendorElement<'a> {
+    /// Parse the payload of the [VendorSpecificElement] as the specified element type, if the vendor prefix matches.
+    fn parse_typed<ElementType: Element>(
+        vendor_specific_element: &VendorSpecificElement<'a>,
+    ) -> Option<ElementType::ReadType<'a>> {
+        vendor_specific_element
+            .get_payload_if_prefix_matches(ElementType::ELEMENT_ID.vendor_prefix()?)?
+            .pread(0)
+            .ok()
+    }
+    /// Dispatch the [VendorSpecificElement] by it's OUI.
+    pub fn from_vendor_specific_element(
+        vendor_specific_element: VendorSpecificElement<'a>,
+    ) -> Self {
+        let Some(oui) = vendor_specific_element.oui() else {
+            return Self::Unknown(vendor_specific_element);
+        };
+        match (oui, &vendor_specific_element.get_payload()[3..]) {
+            (MICROSOFT_OUI, [0x01, ..]) => {
+                Self::parse_typed::<WPAElement>(&vendor_specific_element)
+                    .map(Self::Wpa)
+                    .unwrap_or(Self::Unknown(vendor_specific_element))
+            }
+            (MICROSOFT_OUI, [0x02, 0x00, ..]) => {
+                Self::parse_typed::<WMMInfoElement>(&vendor_specific_element)
+                    .map(Self::WmmInfo)
+                    .unwrap_or(Self::Unknown(vendor_specific_element))
+            }
+            (MICROSOFT_OUI, [0x02, 0x01, ..]) => {
+                Self::parse_typed::<WMMParameterElement>(&vendor_specific_element)
+                    .map(Self::WmmParameter)
+                    .unwrap_or(Self::Unknown(vendor_specific_element))
+            }
+            (MICROSOFT_OUI, [0x04, payload @ ..]) => Self::Wps(payload),
+            (APPLE_OUI, payload) => Self::Apple(payload),
+            (CISCO_OUI, payload) => Self::Cisco(payload),
+            (ARUBA_OUI, payload) => Self::Aruba(payload),
+            _ => Self::parse_typed::<OWETransitionModeElement>(&vendor_specific_element)
+                .map(Self::OWETransitionMode)
+                .unwrap_or(Self::Unknown(vendor_specific_element)),
+        }
+    }
+}
@@ -67,6 +67,10 @@ impl VHTCapabilitiesInfo {
     pub fn set_maximum_mpdu_length_in_bytes(&mut self, maximum_mpdu_length_in_bytes: u16) {
         *self = self.with_maximum_mpdu_length_in_bytes(maximum_mpdu_length_in_bytes);
     }
+    /// Returns the maximum A-MPDU length in bytes, as specified by the exponent.
+    pub const fn maximum_ampdu_length_in_bytes(&self) -> usize {
+        (1 << (13 + self.maximum_ampdu_length_exponent())) - 1
+    }
 }
 serializable_enum! {
     #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
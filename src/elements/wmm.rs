@@ -0,0 +1,274 @@
+//! This module contains support for the WMM Information and Parameter elements.
+//!
+//! WMM is the Wi-Fi Alliance's subset of IEEE 802.11e and is advertised in a vendor specific element with Microsoft's OUI.
+
+use bitfield_struct::bitfield;
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use crate::common::MICROSOFT_OUI;
+
+use super::{Element, ElementID};
+
+/// The OUI type of all WMM elements.
+const WMM_OUI_TYPE: u8 = 0x02;
+/// The OUI subtype of the WMM Information element.
+const WMM_INFO_OUI_SUBTYPE: u8 = 0x00;
+/// The OUI subtype of the WMM Parameter element.
+const WMM_PARAMETER_OUI_SUBTYPE: u8 = 0x01;
+/// The only defined version of the WMM Information and Parameter elements.
+const WMM_VERSION: u8 = 0x01;
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// An access category.
+    pub enum AccessCategory: u8 {
+        #[default]
+        BestEffort => 0,
+        Background => 1,
+        Video => 2,
+        Voice => 3
+    }
+}
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The ACI/AIFSN field of an [EDCAParameterRecord].
+pub struct ACIAndAIFSN {
+    /// The number of slots after a SIFS, which a STA defers before transmitting.
+    #[bits(4)]
+    pub aifsn: u8,
+    /// Indicates, that admission control is mandatory for this access category.
+    pub acm: bool,
+    #[bits(2)]
+    pub aci: AccessCategory,
+    __: bool,
+}
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The ECWmin and ECWmax values of an [EDCAParameterRecord].
+///
+/// The contention window is encoded as an exponent, so CW = 2^ECW - 1.
+pub struct ECWMinMax {
+    #[bits(4)]
+    pub ecw_min: u8,
+    #[bits(4)]
+    pub ecw_max: u8,
+}
+impl ECWMinMax {
+    /// Returns the minimum contention window in slots.
+    pub const fn cw_min(&self) -> u16 {
+        (1 << self.ecw_min()) - 1
+    }
+    /// Returns the maximum contention window in slots.
+    pub const fn cw_max(&self) -> u16 {
+        (1 << self.ecw_max()) - 1
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The contention parameters for one access category.
+pub struct EDCAParameterRecord {
+    pub aci_aifsn: ACIAndAIFSN,
+    pub ecw: ECWMinMax,
+    /// The TXOP limit in units of 32 µs. A value of zero means, that only one MSDU may be sent per TXOP.
+    pub txop_limit: u16,
+}
+impl EDCAParameterRecord {
+    /// Returns the TXOP limit in µs.
+    pub const fn txop_limit_in_us(&self) -> u32 {
+        self.txop_limit as u32 * 32
+    }
+}
+impl TryFromCtx<'_> for EDCAParameterRecord {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let aci_aifsn = ACIAndAIFSN::from_bits(from.gread(&mut offset)?);
+        let ecw = ECWMinMax::from_bits(from.gread(&mut offset)?);
+        let txop_limit = from.gread_with(&mut offset, Endian::Little)?;
+
+        Ok((
+            Self {
+                aci_aifsn,
+                ecw,
+                txop_limit,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for EDCAParameterRecord {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.aci_aifsn.into_bits(), &mut offset)?;
+        buf.gwrite(self.ecw.into_bits(), &mut offset)?;
+        buf.gwrite_with(self.txop_limit, &mut offset, Endian::Little)?;
+
+        Ok(offset)
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The WMM Information element, which advertises WMM support without the EDCA parameters.
+///
+/// The OUI, type, subtype and version are checked while reading and aren't part of this struct.
+pub struct WMMInfoElement {
+    /// The QoS Info field, which contains the parameter set count and the U-APSD bit, when sent by an AP,
+    /// or the U-APSD flags and maximum service period length, when sent by a non-AP STA.
+    pub qos_info: u8,
+}
+impl MeasureWith<()> for WMMInfoElement {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        2
+    }
+}
+impl TryFromCtx<'_> for WMMInfoElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        if from.gread::<u8>(&mut offset)? != WMM_VERSION {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "The WMM version wasn't one.",
+            });
+        }
+        let qos_info = from.gread(&mut offset)?;
+
+        Ok((Self { qos_info }, offset))
+    }
+}
+impl TryIntoCtx for WMMInfoElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(WMM_VERSION, &mut offset)?;
+        buf.gwrite(self.qos_info, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl Element for WMMInfoElement {
+    const ELEMENT_ID: ElementID = ElementID::VendorSpecific {
+        prefix: &[
+            MICROSOFT_OUI[0],
+            MICROSOFT_OUI[1],
+            MICROSOFT_OUI[2],
+            WMM_OUI_TYPE,
+            WMM_INFO_OUI_SUBTYPE,
+        ],
+    };
+    type ReadType<'a> = Self;
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The WMM Parameter element, which contains the EDCA parameters used in the BSS.
+///
+/// The OUI, type, subtype and version are checked while reading and aren't part of this struct.
+pub struct WMMParameterElement {
+    /// The QoS Info field, which contains the parameter set count and the U-APSD bit, when sent by an AP.
+    pub qos_info: u8,
+    /// The parameter records for best effort, background, video and voice, in this order.
+    pub ac_parameters: [EDCAParameterRecord; 4],
+}
+impl WMMParameterElement {
+    /// Returns the parameters for the specified access category.
+    ///
+    /// The records are looked up by their ACI, falling back to the position in the element, if no record has a matching ACI.
+    pub fn parameters_for(&self, access_category: AccessCategory) -> EDCAParameterRecord {
+        self.ac_parameters
+            .iter()
+            .find(|record| record.aci_aifsn.aci() == access_category)
+            .copied()
+            .unwrap_or(self.ac_parameters[access_category.into_bits() as usize])
+    }
+    /// Returns the parameters for best effort traffic.
+    pub fn best_effort(&self) -> EDCAParameterRecord {
+        self.parameters_for(AccessCategory::BestEffort)
+    }
+    /// Returns the parameters for background traffic.
+    pub fn background(&self) -> EDCAParameterRecord {
+        self.parameters_for(AccessCategory::Background)
+    }
+    /// Returns the parameters for video traffic.
+    pub fn video(&self) -> EDCAParameterRecord {
+        self.parameters_for(AccessCategory::Video)
+    }
+    /// Returns the parameters for voice traffic.
+    pub fn voice(&self) -> EDCAParameterRecord {
+        self.parameters_for(AccessCategory::Voice)
+    }
+}
+impl MeasureWith<()> for WMMParameterElement {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        19
+    }
+}
+impl TryFromCtx<'_> for WMMParameterElement {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        if from.gread::<u8>(&mut offset)? != WMM_VERSION {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "The WMM version wasn't one.",
+            });
+        }
+        let qos_info = from.gread(&mut offset)?;
+        // Reserved
+        offset += 1;
+        let mut ac_parameters = [EDCAParameterRecord::default(); 4];
+        for record in ac_parameters.iter_mut() {
+            *record = from.gread(&mut offset)?;
+        }
+
+        Ok((
+            Self {
+                qos_info,
+                ac_parameters,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for WMMParameterElement {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(WMM_VERSION, &mut offset)?;
+        buf.gwrite(self.qos_info, &mut offset)?;
+        buf.gwrite(0u8, &mut offset)?;
+        for record in self.ac_parameters {
+            buf.gwrite(record, &mut offset)?;
+        }
+
+        Ok(offset)
+    }
+}
+impl Element for WMMParameterElement {
+    const ELEMENT_ID: ElementID = ElementID::VendorSpecific {
+        prefix: &[
+            MICROSOFT_OUI[0],
+            MICROSOFT_OUI[1],
+            MICROSOFT_OUI[2],
+            WMM_OUI_TYPE,
+            WMM_PARAMETER_OUI_SUBTYPE,
+        ],
+    };
+    type ReadType<'a> = Self;
+}
@@ -0,0 +1,213 @@
+//! This module contains support for the legacy WPA element.
+//!
+//! WPA (also known as WPA1) predates the RSN element and is advertised in a vendor specific element with Microsoft's OUI.
+//! It's structure is a subset of the [RSN element](super::rsn::RSNElement), but the cipher and AKM suites use Microsoft's OUI.
+
+use core::marker::PhantomData;
+
+use scroll::{
+    ctx::{MeasureWith, SizeWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use crate::common::{ReadIterator, MICROSOFT_OUI};
+
+use super::{
+    rsn::{
+        cipher_suite_selectors, merge_oui_and_suite_type, read_list, split_cipher_suite_selector,
+        write_list,
+    },
+    Element, ElementID,
+};
+
+/// The OUI type of the WPA element.
+const WPA_OUI_TYPE: u8 = 0x01;
+
+cipher_suite_selectors! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The cipher suites used by WPA.
+    pub enum WPACipherSuiteSelector {
+        #[default]
+        UseGroupCipherSuite => (MICROSOFT_OUI, 0),
+        Wep40 => (MICROSOFT_OUI, 1),
+        Tkip => (MICROSOFT_OUI, 2),
+        Ccmp128 => (MICROSOFT_OUI, 4),
+        Wep104 => (MICROSOFT_OUI, 5)
+    }
+}
+cipher_suite_selectors! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The authentication and key-management types used by WPA.
+    pub enum WPAAKMType {
+        #[default]
+        None => (MICROSOFT_OUI, 0),
+        /// Authentication using IEEE 802.1X, which is also known as WPA-Enterprise.
+        IEEE8021X => (MICROSOFT_OUI, 1),
+        /// Authentication using a pre-shared key, which is also known as WPA-Personal.
+        Psk => (MICROSOFT_OUI, 2)
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Hash)]
+/// The WPA element contains information about the security characteristics of a BSS using WPA.
+///
+/// # Note
+/// Like for the [RSN element](super::rsn::RSNElement), all fields after the version are only present, if there are enough bytes left for them.
+/// This means, that if you want to use the `akm_list` field, all prior fields need to be [Option::Some].
+/// This is not validated while writing and can cause invalid outputs.
+pub struct WPAElement<
+    'a,
+    UnicastCipherSuiteList = ReadIterator<'a, (), WPACipherSuiteSelector>,
+    AKMList = ReadIterator<'a, (), WPAAKMType>,
+> {
+    /// The cipher suite used for group addressed traffic.
+    pub multicast_cipher_suite: Option<WPACipherSuiteSelector>,
+    /// The list of cipher suites supported for individually addressed traffic.
+    pub unicast_cipher_suite_list: Option<UnicastCipherSuiteList>,
+    /// The list of supported authentication and key-management suites.
+    pub akm_list: Option<AKMList>,
+    pub _phantom: PhantomData<&'a ()>,
+}
+impl<
+        UnicastCipherSuiteList: IntoIterator<Item = WPACipherSuiteSelector> + Clone,
+        AKMList: IntoIterator<Item = WPAAKMType> + Clone,
+    > WPAElement<'_, UnicastCipherSuiteList, AKMList>
+{
+    /// Returns an [Iterator] over the AKM suites.
+    ///
+    /// The list is cloned, so this can be called repeatedly. If the AKM list isn't present, the iterator is empty.
+    pub fn akm_suites(&self) -> impl Iterator<Item = WPAAKMType> + '_ {
+        self.akm_list.clone().into_iter().flatten()
+    }
+    /// Returns an [Iterator] over the unicast cipher suites.
+    ///
+    /// The list is cloned, so this can be called repeatedly. If the unicast cipher suite list isn't present, the iterator is empty.
+    pub fn unicast_ciphers(&self) -> impl Iterator<Item = WPACipherSuiteSelector> + '_ {
+        self.unicast_cipher_suite_list.clone().into_iter().flatten()
+    }
+}
+impl<
+        'a,
+        LUnicastCipherSuiteList: IntoIterator<Item = WPACipherSuiteSelector> + Clone,
+        LAKMList: IntoIterator<Item = WPAAKMType> + Clone,
+        RUnicastCipherSuiteList: IntoIterator<Item = WPACipherSuiteSelector> + Clone,
+        RAKMList: IntoIterator<Item = WPAAKMType> + Clone,
+    > PartialEq<WPAElement<'a, RUnicastCipherSuiteList, RAKMList>>
+    for WPAElement<'a, LUnicastCipherSuiteList, LAKMList>
+{
+    fn eq(&self, other: &WPAElement<'a, RUnicastCipherSuiteList, RAKMList>) -> bool {
+        self.multicast_cipher_suite == other.multicast_cipher_suite
+            && match (
+                self.unicast_cipher_suite_list.clone(),
+                other.unicast_cipher_suite_list.clone(),
+            ) {
+                (Some(lhs), Some(rhs)) => lhs.into_iter().eq(rhs),
+                (None, None) => true,
+                _ => false,
+            }
+            && match (self.akm_list.clone(), other.akm_list.clone()) {
+                (Some(lhs), Some(rhs)) => lhs.into_iter().eq(rhs),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+impl Eq for WPAElement<'_> {}
+impl<UnicastCipherSuiteList, AKMList> Default for WPAElement<'_, UnicastCipherSuiteList, AKMList> {
+    fn default() -> Self {
+        Self {
+            multicast_cipher_suite: None,
+            unicast_cipher_suite_list: None,
+            akm_list: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<'a> TryFromCtx<'a> for WPAElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let mut wpa_element = WPAElement::default();
+        if from.gread_with::<u16>(&mut offset, Endian::Little)? != 1 {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "WPA versions other than one are unsupported.",
+            });
+        }
+        if let Ok(multicast_cipher_suite) = from.gread(&mut offset) {
+            wpa_element.multicast_cipher_suite = Some(multicast_cipher_suite);
+        } else {
+            return Ok((wpa_element, offset));
+        }
+        read_list!(wpa_element, from, offset, unicast_cipher_suite_list);
+        read_list!(wpa_element, from, offset, akm_list);
+
+        Ok((wpa_element, offset))
+    }
+}
+impl<
+        UnicastCipherSuiteList: IntoIterator<Item = WPACipherSuiteSelector> + Clone,
+        AKMList: IntoIterator<Item = WPAAKMType> + Clone,
+    > MeasureWith<()> for WPAElement<'_, UnicastCipherSuiteList, AKMList>
+{
+    fn measure_with(&self, _ctx: &()) -> usize {
+        2 + if self.multicast_cipher_suite.is_some() {
+            4
+        } else {
+            0
+        } + if let Some(unicast_cipher_suite_list) = &self.unicast_cipher_suite_list {
+            2 + unicast_cipher_suite_list.clone().into_iter().count() * 4
+        } else {
+            0
+        } + if let Some(akm_list) = &self.akm_list {
+            2 + akm_list.clone().into_iter().count() * 4
+        } else {
+            0
+        }
+    }
+}
+impl<
+        UnicastCipherSuiteList: TryIntoCtx<(), Error = scroll::Error>,
+        AKMList: TryIntoCtx<(), Error = scroll::Error>,
+    > TryIntoCtx for WPAElement<'_, UnicastCipherSuiteList, AKMList>
+{
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite_with(1u16, &mut offset, Endian::Little)?;
+        if let Some(multicast_cipher_suite) = self.multicast_cipher_suite {
+            buf.gwrite(multicast_cipher_suite, &mut offset)?;
+        } else {
+            return Ok(offset);
+        }
+        if let Some(unicast_cipher_suite_list) = self.unicast_cipher_suite_list {
+            write_list!(buf, offset, unicast_cipher_suite_list);
+        } else {
+            return Ok(offset);
+        }
+        if let Some(akm_list) = self.akm_list {
+            write_list!(buf, offset, akm_list);
+        }
+
+        Ok(offset)
+    }
+}
+impl<UnicastCipherSuiteList, AKMList> Element for WPAElement<'_, UnicastCipherSuiteList, AKMList>
+where
+    Self: MeasureWith<()> + TryIntoCtx<Error = scroll::Error>,
+{
+    const ELEMENT_ID: ElementID = ElementID::VendorSpecific {
+        prefix: &[
+            MICROSOFT_OUI[0],
+            MICROSOFT_OUI[1],
+            MICROSOFT_OUI[2],
+            WPA_OUI_TYPE,
+        ],
+    };
+    type ReadType<'a> = WPAElement<'a>;
+}
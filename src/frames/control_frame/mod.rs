@@ -1,13 +1,56 @@
+use bitfield_struct::bitfield;
 use mac_parser::MACAddress;
+use macro_bits::{check_bit, serializable_enum};
 use scroll::{
     ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
     Endian, Pread, Pwrite,
 };
 
-use crate::common::{ControlFrameSubtype, FCFFlags, FrameControlField, FrameType};
+use crate::common::{
+    AssociationID, ControlFrameSubtype, FCFFlags, FrameControlField, FrameType, SequenceControl,
+};
 
 use super::IEEE80211Frame;
 
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The variant of a Block Ack Request or Block Ack frame.
+    pub enum BlockAckType: u8 {
+        /// The bitmap contains 64 MSDUs with 16 fragments each.
+        #[default]
+        Basic => 0,
+        ExtendedCompressed => 1,
+        /// The bitmap contains one bit per MSDU.
+        Compressed => 2,
+        MultiTID => 3
+    }
+}
+impl BlockAckType {
+    /// Check if the Block Ack Request or Block Ack information of this type is supported.
+    pub const fn is_supported(&self) -> bool {
+        matches!(self, Self::Basic | Self::Compressed)
+    }
+}
+
+#[bitfield(u16, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The BAR Control or BA Control field of a Block Ack Request or Block Ack frame.
+pub struct BlockAckControl {
+    /// If this is set, no immediate acknowledgement is expected.
+    pub no_ack: bool,
+    #[bits(4)]
+    pub block_ack_type: BlockAckType,
+    #[bits(7)]
+    __: u8,
+    #[bits(4)]
+    /// The TID, for which the Block Ack Request or Block Ack is sent.
+    pub tid_info: u8,
+}
+
+/// The length of the bitmap of a basic Block Ack.
+const BASIC_BLOCK_ACK_BITMAP_LENGTH: usize = 128;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 /// This is the body of a control frame.
 pub enum ControlFrame<'a> {
@@ -27,6 +70,44 @@ pub enum ControlFrame<'a> {
         duration: u16,
         receiver_address: MACAddress,
     },
+    /// A PS-Poll frame.
+    ///
+    /// The duration field of this frame carries the AID of the transmitter.
+    PSPoll {
+        fcf_flags: FCFFlags,
+        association_id: AssociationID,
+        /// The BSSID, which is also the receiver address.
+        bssid: MACAddress,
+        transmitter_address: MACAddress,
+    },
+    /// A Block Ack Request frame.
+    ///
+    /// Only the [Basic](BlockAckType::Basic) and [Compressed](BlockAckType::Compressed) variants are parsed, all others are returned as [Unknown](Self::Unknown).
+    BlockAckRequest {
+        fcf_flags: FCFFlags,
+        duration: u16,
+        receiver_address: MACAddress,
+        transmitter_address: MACAddress,
+        bar_control: BlockAckControl,
+        /// The sequence number of the first MSDU, for which a Block Ack is requested.
+        starting_sequence_control: SequenceControl,
+    },
+    /// A Block Ack frame.
+    ///
+    /// Only the [Basic](BlockAckType::Basic) and [Compressed](BlockAckType::Compressed) variants are parsed, all others are returned as [Unknown](Self::Unknown).
+    BlockAck {
+        fcf_flags: FCFFlags,
+        duration: u16,
+        receiver_address: MACAddress,
+        transmitter_address: MACAddress,
+        ba_control: BlockAckControl,
+        /// The sequence number of the first MSDU in the bitmap.
+        starting_sequence_control: SequenceControl,
+        /// The Block Ack bitmap.
+        ///
+        /// For a basic Block Ack, this is 128 bytes long, with 16 bits of fragments per MSDU. For a compressed Block Ack, this is one bit per MSDU and usually eight bytes long.
+        bitmap: &'a [u8],
+    },
     Unknown {
         subtype: ControlFrameSubtype,
         fcf_flags: FCFFlags,
@@ -40,6 +121,9 @@ impl ControlFrame<'_> {
             ControlFrame::RTS { .. } => 14,
             ControlFrame::CTS { .. } => 8,
             ControlFrame::Ack { .. } => 14,
+            ControlFrame::PSPoll { .. } => 14,
+            ControlFrame::BlockAckRequest { .. } => 18,
+            ControlFrame::BlockAck { bitmap, .. } => 18 + bitmap.len(),
             ControlFrame::Unknown { body, .. } => body.len(),
         }
     }
@@ -49,6 +133,9 @@ impl ControlFrame<'_> {
             ControlFrame::RTS { .. } => ControlFrameSubtype::RTS,
             ControlFrame::CTS { .. } => ControlFrameSubtype::CTS,
             ControlFrame::Ack { .. } => ControlFrameSubtype::Ack,
+            ControlFrame::PSPoll { .. } => ControlFrameSubtype::PSPoll,
+            ControlFrame::BlockAckRequest { .. } => ControlFrameSubtype::BlockAckRequest,
+            ControlFrame::BlockAck { .. } => ControlFrameSubtype::BlockAck,
             ControlFrame::Unknown { subtype, .. } => *subtype,
         }
     }
@@ -58,6 +145,9 @@ impl ControlFrame<'_> {
             ControlFrame::RTS { fcf_flags, .. }
             | ControlFrame::CTS { fcf_flags, .. }
             | ControlFrame::Ack { fcf_flags, .. }
+            | ControlFrame::PSPoll { fcf_flags, .. }
+            | ControlFrame::BlockAckRequest { fcf_flags, .. }
+            | ControlFrame::BlockAck { fcf_flags, .. }
             | ControlFrame::Unknown { fcf_flags, .. } => *fcf_flags,
         }
     }
@@ -78,7 +168,14 @@ impl ControlFrame<'_> {
             }
             | Self::Ack {
                 receiver_address, ..
+            }
+            | Self::BlockAckRequest {
+                receiver_address, ..
+            }
+            | Self::BlockAck {
+                receiver_address, ..
             } => *receiver_address,
+            Self::PSPoll { bssid, .. } => *bssid,
             Self::Unknown { body, .. } => body.pread(2).unwrap_or_default(),
         }
     }
@@ -88,10 +185,65 @@ impl ControlFrame<'_> {
             Self::RTS {
                 transmitter_address,
                 ..
+            }
+            | Self::PSPoll {
+                transmitter_address,
+                ..
+            }
+            | Self::BlockAckRequest {
+                transmitter_address,
+                ..
+            }
+            | Self::BlockAck {
+                transmitter_address,
+                ..
             } => Some(*transmitter_address),
             _ => None,
         }
     }
+    /// Returns the association ID, if this is a PS-Poll frame.
+    pub const fn association_id(&self) -> Option<AssociationID> {
+        match self {
+            Self::PSPoll { association_id, .. } => Some(*association_id),
+            _ => None,
+        }
+    }
+}
+impl<'a> ControlFrame<'a> {
+    /// Returns an [Iterator] over the sequence numbers acknowledged by a Block Ack.
+    ///
+    /// For a basic Block Ack, an MSDU is considered acknowledged, if any of it's fragments was acknowledged.
+    /// This returns [None], if the frame isn't a Block Ack.
+    pub fn acknowledged_sequence_numbers(&self) -> Option<impl Iterator<Item = u16> + 'a> {
+        let Self::BlockAck {
+            ba_control,
+            starting_sequence_control,
+            bitmap,
+            ..
+        } = *self
+        else {
+            return None;
+        };
+        let is_basic = ba_control.block_ack_type() == BlockAckType::Basic;
+        let msdu_count = if is_basic {
+            bitmap.len() / 2
+        } else {
+            bitmap.len() * 8
+        };
+        let starting_sequence_number = starting_sequence_control.sequence_number();
+        Some(
+            (0..msdu_count)
+                .filter(move |&i| {
+                    if is_basic {
+                        bitmap[i * 2] != 0 || bitmap[i * 2 + 1] != 0
+                    } else {
+                        check_bit!(bitmap[i / 8], 1 << (i % 8))
+                    }
+                })
+                // Sequence numbers are twelve bits long.
+                .map(move |i| (starting_sequence_number + i as u16) & 0x0fff),
+        )
+    }
 }
 impl<'a> TryFromCtx<'a, (ControlFrameSubtype, FCFFlags)> for ControlFrame<'a> {
     type Error = scroll::Error;
@@ -117,6 +269,61 @@ impl<'a> TryFromCtx<'a, (ControlFrameSubtype, FCFFlags)> for ControlFrame<'a> {
                 duration: from.gread_with(&mut offset, Endian::Little)?,
                 receiver_address: from.gread(&mut offset)?,
             },
+            ControlFrameSubtype::PSPoll => Self::PSPoll {
+                fcf_flags,
+                association_id: AssociationID::new_checked(
+                    from.gread_with(&mut offset, Endian::Little)?,
+                )
+                .ok_or(scroll::Error::BadInput {
+                    size: offset,
+                    msg: "Association ID is out of bounds.",
+                })?,
+                bssid: from.gread(&mut offset)?,
+                transmitter_address: from.gread(&mut offset)?,
+            },
+            ControlFrameSubtype::BlockAckRequest | ControlFrameSubtype::BlockAck
+                if from
+                    .pread_with::<u16>(14, Endian::Little)
+                    .map(|control| {
+                        BlockAckControl::from_bits(control)
+                            .block_ack_type()
+                            .is_supported()
+                    })
+                    .unwrap_or_default() =>
+            {
+                let duration = from.gread_with(&mut offset, Endian::Little)?;
+                let receiver_address = from.gread(&mut offset)?;
+                let transmitter_address = from.gread(&mut offset)?;
+                let control =
+                    BlockAckControl::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+                let starting_sequence_control =
+                    SequenceControl::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+                if subtype == ControlFrameSubtype::BlockAckRequest {
+                    Self::BlockAckRequest {
+                        fcf_flags,
+                        duration,
+                        receiver_address,
+                        transmitter_address,
+                        bar_control: control,
+                        starting_sequence_control,
+                    }
+                } else {
+                    let bitmap_length = if control.block_ack_type() == BlockAckType::Basic {
+                        BASIC_BLOCK_ACK_BITMAP_LENGTH
+                    } else {
+                        from.len() - offset
+                    };
+                    Self::BlockAck {
+                        fcf_flags,
+                        duration,
+                        receiver_address,
+                        transmitter_address,
+                        ba_control: control,
+                        starting_sequence_control,
+                        bitmap: from.gread_with(&mut offset, bitmap_length)?,
+                    }
+                }
+            }
             _ => {
                 offset = from.len();
                 Self::Unknown {
@@ -165,6 +372,55 @@ impl TryIntoCtx for ControlFrame<'_> {
                 buf.gwrite_with(duration, &mut offset, Endian::Little)?;
                 buf.gwrite(receiver_address, &mut offset)?;
             }
+            ControlFrame::PSPoll {
+                association_id,
+                bssid,
+                transmitter_address,
+                ..
+            } => {
+                // The two most significant bits are always set, when the AID is carried in the duration field.
+                buf.gwrite_with(association_id.aid() | 0xc000, &mut offset, Endian::Little)?;
+                buf.gwrite(bssid, &mut offset)?;
+                buf.gwrite(transmitter_address, &mut offset)?;
+            }
+            ControlFrame::BlockAckRequest {
+                duration,
+                receiver_address,
+                transmitter_address,
+                bar_control,
+                starting_sequence_control,
+                ..
+            } => {
+                buf.gwrite_with(duration, &mut offset, Endian::Little)?;
+                buf.gwrite(receiver_address, &mut offset)?;
+                buf.gwrite(transmitter_address, &mut offset)?;
+                buf.gwrite_with(bar_control.into_bits(), &mut offset, Endian::Little)?;
+                buf.gwrite_with(
+                    starting_sequence_control.into_bits(),
+                    &mut offset,
+                    Endian::Little,
+                )?;
+            }
+            ControlFrame::BlockAck {
+                duration,
+                receiver_address,
+                transmitter_address,
+                ba_control,
+                starting_sequence_control,
+                bitmap,
+                ..
+            } => {
+                buf.gwrite_with(duration, &mut offset, Endian::Little)?;
+                buf.gwrite(receiver_address, &mut offset)?;
+                buf.gwrite(transmitter_address, &mut offset)?;
+                buf.gwrite_with(ba_control.into_bits(), &mut offset, Endian::Little)?;
+                buf.gwrite_with(
+                    starting_sequence_control.into_bits(),
+                    &mut offset,
+                    Endian::Little,
+                )?;
+                buf.gwrite(bitmap, &mut offset)?;
+            }
             ControlFrame::Unknown { body, .. } => {
                 buf.gwrite(body, &mut offset)?;
             }
@@ -10,6 +10,7 @@ use scroll::{
 ///
 /// The payload is generic, to avoid the need for an intermediate step when writing.
 /// It can be any type, which implements [`TryIntoCtx<Ctx = (), Error = scroll::Error>`](TryIntoCtx).
+/// When written, the subframe isn't padded, since that is taken care of by [AMSDUPayload].
 pub struct AMSDUSubframe<Payload> {
     pub destination_address: MACAddress,
     pub source_address: MACAddress,
@@ -41,6 +42,8 @@ impl<'a> TryFromCtx<'a> for AMSDUSubframe<&'a [u8]> {
         // Round to the nearest multiple of four.
         offset += 3;
         offset &= !0b0000_0011;
+        // The last subframe isn't padded.
+        offset = offset.min(from.len());
         Ok((
             Self {
                 destination_address,
@@ -58,17 +61,16 @@ impl<Payload: TryIntoCtx<Error = scroll::Error> + MeasureWith<()>> TryIntoCtx
     fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
         let mut offset = 0;
 
+        let Ok(length) = u16::try_from(self.payload.measure_with(&())) else {
+            return Err(scroll::Error::BadInput {
+                size: self.payload.measure_with(&()),
+                msg: "The MSDU is longer than the length field allows.",
+            });
+        };
         buf.gwrite(self.destination_address, &mut offset)?;
         buf.gwrite(self.source_address, &mut offset)?;
-        buf.gwrite_with(
-            self.payload.measure_with(&()) as u16,
-            &mut offset,
-            Endian::Little,
-        )?;
+        buf.gwrite_with(length, &mut offset, Endian::Little)?;
         buf.gwrite(self.payload, &mut offset)?;
-        // Round to the nearest multiple of four.
-        offset += 3;
-        offset &= !0b0000_0011;
 
         Ok(offset)
     }
@@ -80,6 +82,7 @@ impl<Payload: TryIntoCtx<Error = scroll::Error> + MeasureWith<()>> TryIntoCtx
 ///
 /// This internally keeps the bytes slice and the offset and returns [Some] until [scroll] returns an error.
 /// This has the side effect, that if an error is encoutered while reading, the iterator may stop early, even if data is still left.
+/// To detect malformed subframes, use [validate](Self::validate) or [try_for_each_subframe](Self::try_for_each_subframe).
 pub struct AMSDUSubframeIterator<'a> {
     // Making this an option comes with the advantage, that after encoutering an error, subsequent iterations will be almost instant.
     pub(crate) bytes: Option<&'a [u8]>,
@@ -96,6 +99,26 @@ impl<'a> AMSDUSubframeIterator<'a> {
             None => 0,
         }
     }
+    /// Call `f` for every remaining subframe and stop at the first malformed one.
+    ///
+    /// Unlike the [Iterator] implementation, this returns an error, if a subframe is malformed, for example because it's declared length exceeds the remaining bytes.
+    pub fn try_for_each_subframe(
+        &self,
+        mut f: impl FnMut(AMSDUSubframe<&'a [u8]>),
+    ) -> Result<(), scroll::Error> {
+        let Some(bytes) = self.bytes else {
+            return Ok(());
+        };
+        let mut offset = 0;
+        while offset < bytes.len() {
+            f(bytes.gread(&mut offset)?);
+        }
+        Ok(())
+    }
+    /// Check if all remaining subframes are well formed.
+    pub fn validate(&self) -> bool {
+        self.try_for_each_subframe(|_| {}).is_ok()
+    }
 }
 impl<'a> Iterator for AMSDUSubframeIterator<'a> {
     type Item = AMSDUSubframe<&'a [u8]>;
@@ -115,11 +138,21 @@ impl<'a> Iterator for AMSDUSubframeIterator<'a> {
         }
     }
 }
+/// Calculate the length of an A-MSDU from the lengths of it's subframes.
+///
+/// Every subframe, except for the last one, is padded to a multiple of four bytes.
+fn amsdu_length(subframe_lengths: impl Iterator<Item = usize>) -> usize {
+    subframe_lengths.fold(0, |length, subframe_length| {
+        ((length + 3) & !0b0000_0011) + subframe_length
+    })
+}
+
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 /// This can be used for writing an aggregate MSDU.
 ///
 /// The generic paramter can be any type, which implements [IntoIterator].
+/// Every subframe, except for the last one, is padded with zeros to a multiple of four bytes, so the written A-MSDU can be parsed with [AMSDUSubframeIterator] as is.
 /// It can only be written.
 pub struct AMSDUPayload<Frames> {
     pub sub_frames: Frames,
@@ -130,7 +163,7 @@ impl<'a> AMSDUPayload<&'a [AMSDUSubframe<&'a [u8]>]> {
         let mut size = 0;
         let mut i = 0;
         while i != self.sub_frames.len() {
-            size += self.sub_frames[i].length_in_bytes();
+            size = ((size + 3) & !0b0000_0011) + self.sub_frames[i].length_in_bytes();
             i += 1;
         }
         size
@@ -139,12 +172,13 @@ impl<'a> AMSDUPayload<&'a [AMSDUSubframe<&'a [u8]>]> {
 impl<Frames: IntoIterator<Item = Payload> + Clone, Payload: MeasureWith<()>> MeasureWith<()>
     for AMSDUPayload<Frames>
 {
-    fn measure_with(&self, _ctx: &()) -> usize {
-        self.sub_frames
-            .clone()
-            .into_iter()
-            .map(|sub_frame| sub_frame.measure_with(&()))
-            .sum()
+    fn measure_with(&self, ctx: &()) -> usize {
+        amsdu_length(
+            self.sub_frames
+                .clone()
+                .into_iter()
+                .map(|sub_frame| sub_frame.measure_with(ctx)),
+        )
     }
 }
 impl<Frames: IntoIterator<Item = Payload>, Payload: Copy + TryIntoCtx<Error = scroll::Error>>
@@ -154,8 +188,60 @@ impl<Frames: IntoIterator<Item = Payload>, Payload: Copy + TryIntoCtx<Error = sc
     fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
         let mut offset = 0;
         for sub_frame in self.sub_frames.into_iter() {
+            // Pad the previous subframe to a multiple of four bytes.
+            while offset % 4 != 0 {
+                buf.gwrite(0u8, &mut offset)?;
+            }
             buf.gwrite(sub_frame, &mut offset)?;
         }
         Ok(offset)
     }
 }
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// A builder for an aggregate MSDU, which takes care of the subframe headers and the padding between the subframes.
+///
+/// The generic parameter can be any type, which implements [IntoIterator] over tuples of the destination address, the source address and the MSDU.
+/// The subframes are written as an [AMSDUPayload], so every subframe, except for the last one, is padded with zeros to a multiple of four bytes.
+/// To build a data frame carrying an A-MSDU, use [payload_amsdu](super::builder::DataFrameBuilderInner::payload_amsdu) of the data frame builder, which also sets A-MSDU Present in the QoS control field.
+/// If this is passed to [payload](super::builder::DataFrameBuilderInner::payload) instead, A-MSDU Present has to be set with [qos_control](super::builder::DataFrameBuilderInner::qos_control), otherwise the subframes are parsed as a single MSDU.
+pub struct AMSDUBuilder<Subframes> {
+    subframes: Subframes,
+}
+impl<Subframes> AMSDUBuilder<Subframes> {
+    /// Create a new A-MSDU from the subframes.
+    pub const fn new(subframes: Subframes) -> Self {
+        Self { subframes }
+    }
+}
+impl<'a, Subframes: IntoIterator<Item = (MACAddress, MACAddress, &'a [u8])> + Clone> MeasureWith<()>
+    for AMSDUBuilder<Subframes>
+{
+    fn measure_with(&self, _ctx: &()) -> usize {
+        amsdu_length(
+            self.subframes
+                .clone()
+                .into_iter()
+                .map(|(_, _, msdu)| 14 + msdu.len()),
+        )
+    }
+}
+impl<'a, Subframes: IntoIterator<Item = (MACAddress, MACAddress, &'a [u8])>> TryIntoCtx
+    for AMSDUBuilder<Subframes>
+{
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        buf.pwrite(
+            AMSDUPayload {
+                sub_frames: self.subframes.into_iter().map(
+                    |(destination_address, source_address, payload)| AMSDUSubframe {
+                        destination_address,
+                        source_address,
+                        payload,
+                    },
+                ),
+            },
+            0,
+        )
+    }
+}
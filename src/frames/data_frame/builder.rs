@@ -6,7 +6,8 @@
 //! 1. Create a [DataFrameBuilder] with [new](DataFrameBuilder::new)
 //! 2. Specify `ToDS` and `FromDS`, using [neither_to_nor_from_ds](DataFrameBuilder::neither_to_nor_from_ds), [to_ds](DataFrameBuilder::to_ds), [from_ds](DataFrameBuilder::from_ds) or [to_and_from_ds](DataFrameBuilder::to_and_from_ds).
 //! 3. Specify a category, using [category_data](DataFrameBuilder::category_data), [category_data_null](DataFrameBuilder::category_data_null), [category_qos](DataFrameBuilder::category_qos) or [category_qos_null](DataFrameBuilder::category_qos_null). These are equivalent to [DataFrameCF], just as type state.
-//!     3.5. If your selected category is not [DataNull] or [QoSNull], specify a payload using [payload](DataFrameBuilder::payload) or [payload_amsdu](DataFrameBuilder::payload_amsdu).
+//!    3.5. If your selected category is not [DataNull] or [QoSNull], specify a payload using [payload](DataFrameBuilder::payload) or [payload_amsdu](DataFrameBuilder::payload_amsdu).
+//!    3.6. If your selected category is [QoS] or [QoSNull], you can specify the TID and ack policy using [qos_control](DataFrameBuilder::qos_control).
 //! 4. Specify your addresses using [destination_address](DataFrameBuilder::destination_address), [source_address](DataFrameBuilder::source_address) and [bssid](DataFrameBuilder::bssid).
 //! 5. Call [build](DataFrameBuilder::build).
 //! ## Example
@@ -37,9 +38,13 @@ use scroll::ctx::TryIntoCtx;
 
 use crate::common::*;
 
-use self::type_state::{Data, DataFrameCategory, DataNull, HasPayload, QoS, QoSNull};
+use self::type_state::{Data, DataFrameCategory, DataNull, HasPayload, QoS, QoSCategory, QoSNull};
 
-use super::{amsdu::AMSDUPayload, header::DataFrameHeader, DataFrame};
+use super::{
+    amsdu::AMSDUPayload,
+    header::{AckPolicy, DataFrameHeader},
+    DataFrame,
+};
 
 pub mod type_state {
 
@@ -48,6 +53,7 @@ pub mod type_state {
     }
     pub trait HasPayload {}
     pub trait NoPayload {}
+    pub trait QoSCategory {}
     macro_rules! data_frame_category {
         ($category_name:ident, $trait_name:ident, $upper_two_bits:expr) => {
             #[doc(hidden)]
@@ -62,6 +68,8 @@ pub mod type_state {
     data_frame_category!(DataNull, NoPayload, 0b01);
     data_frame_category!(QoS, HasPayload, 0b10);
     data_frame_category!(QoSNull, NoPayload, 0b11);
+    impl QoSCategory for QoS {}
+    impl QoSCategory for QoSNull {}
 }
 /// A type state based data frame builder.
 pub struct DataFrameBuilderInner<
@@ -80,6 +88,7 @@ pub struct DataFrameBuilderInner<
     address_4: Option<MACAddress>,
     payload: Option<PayloadType>,
     fcf_flags: FCFFlags,
+    qos: [u8; 2],
     _phantom: PhantomData<(&'a (), DS, Category, Address4)>,
 }
 impl<
@@ -113,6 +122,7 @@ impl<
             address_4: self.address_4,
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -127,6 +137,7 @@ impl<'a> DataFrameBuilderInner<'a, (), (), (), (), (), (), ()> {
             address_4: None,
             payload: None,
             fcf_flags: FCFFlags::new(),
+            qos: [0x00; 2],
             _phantom: PhantomData,
         }
     }
@@ -164,6 +175,7 @@ impl<'a, DS> DataFrameBuilderInner<'a, DS, (), (), (), (), (), ()> {
             address_4: self.address_4,
             payload: None,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -180,6 +192,7 @@ impl<'a, DS> DataFrameBuilderInner<'a, DS, (), (), (), (), (), ()> {
             address_4: self.address_4,
             payload: None,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -198,11 +211,15 @@ impl<'a, DS, Category: HasPayload + DataFrameCategory>
             address_4: None,
             payload: Some(payload),
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
 }
 impl<'a, DS> DataFrameBuilderInner<'a, DS, QoS, (), (), (), (), ()> {
+    /// Set an A-MSDU as the payload.
+    ///
+    /// This also sets A-MSDU Present in the QoS control field, so the subframes are parsed as such.
     pub const fn payload_amsdu<SubFrames>(
         self,
         sub_frames: SubFrames,
@@ -214,6 +231,7 @@ impl<'a, DS> DataFrameBuilderInner<'a, DS, QoS, (), (), (), (), ()> {
             address_4: None,
             payload: Some(AMSDUPayload { sub_frames }),
             fcf_flags: self.fcf_flags,
+            qos: [self.qos[0] | 0b1000_0000, self.qos[1]],
             _phantom: PhantomData,
         }
     }
@@ -241,6 +259,7 @@ impl<'a, DS, Category, PayloadType: Copy, Address2: Copy, Address3: Copy, Addres
             address_4: self.address_4,
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -268,6 +287,7 @@ impl<'a, DS, Category, PayloadType: Copy, Address1: Copy, Address3: Copy, Addres
             address_4: self.address_4,
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -311,6 +331,7 @@ impl<
             address_4: self.address_4,
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -334,6 +355,7 @@ impl<
             address_4: self.address_4,
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -357,6 +379,7 @@ impl<
             address_4: self.address_4,
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -390,6 +413,7 @@ impl<
             address_4: self.address_4,
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -409,6 +433,7 @@ impl<'a, Category: DataFrameCategory, Address1: Copy, Address2: Copy, Address3:
             address_4: self.address_4,
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -424,6 +449,7 @@ impl<'a, Category: DataFrameCategory, Address1: Copy, Address2: Copy, Address3:
             address_4: self.address_4,
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -467,6 +493,7 @@ impl<
             address_4: self.address_4,
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -492,6 +519,7 @@ impl<
             address_4: self.address_4,
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -511,6 +539,7 @@ impl<'a, Category: DataFrameCategory, Address1: Copy, Address2: Copy, Address3:
             address_4: self.address_4,
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -526,6 +555,7 @@ impl<'a, Category: DataFrameCategory, Address1: Copy, Address2: Copy, Address3:
             address_4: self.address_4,
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -569,6 +599,7 @@ impl<
             address_4: self.address_4,
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -612,6 +643,7 @@ impl<
             address_4: self.address_4,
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -635,6 +667,7 @@ impl<
             address_4: Some(source_address),
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -679,6 +712,7 @@ impl<
             address_4: Some(bssid),
             payload: self.payload,
             fcf_flags: self.fcf_flags,
+            qos: self.qos,
             _phantom: PhantomData,
         }
     }
@@ -711,6 +745,29 @@ impl<DS, Category, Payload, Address1, Address2, Address3, Address4>
         self
     }
 }
+impl<DS, Category: QoSCategory, Payload, Address1, Address2, Address3, Address4>
+    DataFrameBuilderInner<'_, DS, Category, Payload, Address1, Address2, Address3, Address4>
+{
+    /// Set the QoS control field.
+    ///
+    /// The `tid` is truncated to four bits. If this isn't called for a QoS category, the QoS
+    /// control field will be all zeroes, except for A-MSDU Present, which is set by [payload_amsdu](DataFrameBuilderInner::payload_amsdu).
+    /// This overwrites A-MSDU Present with `amsdu`.
+    pub const fn qos_control(mut self, tid: u8, ack_policy: AckPolicy, amsdu: bool) -> Self {
+        self.qos = [
+            (tid & 0x0f) | (ack_policy.into_bits() << 5) | ((amsdu as u8) << 7),
+            0x00,
+        ];
+        self
+    }
+    /// Set the TID in the QoS control field.
+    ///
+    /// The `tid` is truncated to four bits. All other bits of the QoS control field are left untouched.
+    pub const fn tid(mut self, tid: u8) -> Self {
+        self.qos[0] = (self.qos[0] & 0xf0) | (tid & 0x0f);
+        self
+    }
+}
 impl<'a, DS: DSField, Category: DataFrameCategory, PayloadType: Copy>
     DataFrameBuilderInner<
         'a,
@@ -725,8 +782,9 @@ impl<'a, DS: DSField, Category: DataFrameCategory, PayloadType: Copy>
 {
     #[inline]
     pub const fn build(self) -> DataFrame<'a, PayloadType> {
+        let subtype = DataFrameSubtype::from_bits(Category::UPPER_TWO_BITS << 2);
         let header = DataFrameHeader {
-            subtype: DataFrameSubtype::from_bits(Category::UPPER_TWO_BITS << 2),
+            subtype,
             address_1: self.address_1,
             address_2: self.address_2,
             address_3: self.address_3,
@@ -734,7 +792,12 @@ impl<'a, DS: DSField, Category: DataFrameCategory, PayloadType: Copy>
             fcf_flags: self.fcf_flags,
             duration: 0,
             sequence_control: SequenceControl::new(),
-            qos: None,
+            // QoS data frames always carry a QoS control field.
+            qos: if subtype.is_qos() {
+                Some(self.qos)
+            } else {
+                None
+            },
             ht_control: None,
         };
         DataFrame::<'a, PayloadType> {
@@ -749,8 +812,9 @@ impl<'a, DS: DSField, Category: DataFrameCategory, PayloadType: Copy>
 {
     #[inline]
     pub const fn build(self) -> DataFrame<'a, PayloadType> {
+        let subtype = DataFrameSubtype::from_bits(Category::UPPER_TWO_BITS << 2);
         let header = DataFrameHeader {
-            subtype: DataFrameSubtype::from_bits(Category::UPPER_TWO_BITS << 2),
+            subtype,
             address_1: self.address_1,
             address_2: self.address_2,
             address_3: self.address_3,
@@ -758,7 +822,12 @@ impl<'a, DS: DSField, Category: DataFrameCategory, PayloadType: Copy>
             fcf_flags: self.fcf_flags,
             duration: 0,
             sequence_control: SequenceControl::new(),
-            qos: None,
+            // QoS data frames always carry a QoS control field.
+            qos: if subtype.is_qos() {
+                Some(self.qos)
+            } else {
+                None
+            },
             ht_control: None,
         };
         DataFrame::<'a, PayloadType> {
@@ -774,25 +843,3 @@ impl Default for DataFrameBuilder<'_> {
     }
 }
 pub type DataFrameBuilder<'a> = DataFrameBuilderInner<'a, (), (), (), (), (), (), ()>;
-
-#[test]
-fn test() {
-    use crate::frames::data_frame::amsdu::AMSDUSubframe;
-    use mac_parser::ZERO;
-    let _data_frame = DataFrameBuilder::new()
-        .neither_to_nor_from_ds()
-        .category_data()
-        .payload::<&[u8]>(&[])
-        .destination_address(ZERO)
-        .source_address(ZERO)
-        .bssid(ZERO)
-        .build();
-    let _data_frame = DataFrameBuilder::new()
-        .to_and_from_ds()
-        .category_qos()
-        .payload_amsdu::<&[AMSDUSubframe<&[u8]>]>(&[])
-        .receiver_address(ZERO)
-        .transmitter_address(ZERO)
-        .bssid(ZERO)
-        .build();
-}
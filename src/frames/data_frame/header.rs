@@ -1,5 +1,6 @@
+use bitfield_struct::bitfield;
 use mac_parser::MACAddress;
-use macro_bits::bit;
+use macro_bits::serializable_enum;
 use scroll::{
     ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
     Endian, Pread, Pwrite,
@@ -7,6 +8,95 @@ use scroll::{
 
 use crate::common::*;
 
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The ack policy of a QoS data frame.
+    pub enum AckPolicy: u8 {
+        /// The recipient acknowledges the frame with an Ack or BlockAck.
+        #[default]
+        NormalAck => 0b00,
+        /// The recipient doesn't acknowledge the frame.
+        NoAck => 0b01,
+        /// The frame is acknowledged implicitly or through a PSMP Ack.
+        NoExplicitAck => 0b10,
+        /// The frame is acknowledged through a later BlockAck.
+        BlockAck => 0b11
+    }
+}
+
+#[bitfield(u16, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The QoS Control field of a QoS data frame.
+pub struct QoSControl {
+    #[bits(4)]
+    /// The traffic identifier of the frame.
+    pub tid: u8,
+    /// End of service period.
+    ///
+    /// In frames transmitted by a non-AP STA, this bit instead indicates, whether the upper octet contains the [queue size](Self::queue_size) or the [requested TXOP duration](Self::txop_duration_requested).
+    pub eosp: bool,
+    #[bits(2)]
+    /// The ack policy of the frame.
+    pub ack_policy: AckPolicy,
+    /// Indicates, that the payload is an A-MSDU.
+    pub amsdu_present: bool,
+    /// The upper octet of the QoS Control field.
+    ///
+    /// It's meaning depends on the subtype of the frame and the sender.
+    pub upper_octet: u8,
+}
+impl QoSControl {
+    /// Returns the requested TXOP duration in units of 32 µs.
+    ///
+    /// This is only meaningful for frames transmitted by a non-AP STA and returns [None], if the upper octet contains the queue size.
+    pub const fn txop_duration_requested(&self) -> Option<u8> {
+        if !self.eosp() {
+            Some(self.upper_octet())
+        } else {
+            None
+        }
+    }
+    /// Returns the queue size in units of 256 octets.
+    ///
+    /// This is only meaningful for frames transmitted by a non-AP STA and returns [None], if the upper octet contains the requested TXOP duration.
+    pub const fn queue_size(&self) -> Option<u8> {
+        if self.eosp() {
+            Some(self.upper_octet())
+        } else {
+            None
+        }
+    }
+    /// Check if the Mesh Control Present bit is set.
+    ///
+    /// This bit is only defined for frames transmitted in a mesh BSS.
+    pub const fn mesh_control_present(&self) -> bool {
+        self.upper_octet() & 0b1 != 0
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The direction of a data frame, relative to the DS.
+///
+/// # Mapping
+/// To DS | From DS | Direction
+/// -- | -- | --
+/// No | No | [Ibss](Self::Ibss)
+/// Yes | No | [ToAp](Self::ToAp)
+/// No | Yes | [FromAp](Self::FromAp)
+/// Yes | Yes | [Wds](Self::Wds)
+pub enum FrameDirection {
+    /// The frame is exchanged directly between two STAs, without passing the DS.
+    Ibss,
+    /// The frame is sent from a STA to the AP.
+    ToAp,
+    /// The frame is sent from the AP to a STA.
+    FromAp,
+    /// The frame is sent through a wireless distribution system, like a mesh network.
+    Wds,
+}
+
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 /// A generic data frame header.
@@ -56,10 +146,28 @@ impl DataFrameHeader {
         }
         size
     }
+    /// Returns the [QoSControl] field, if present.
+    pub const fn qos_control(&self) -> Option<QoSControl> {
+        if let Some(qos) = self.qos {
+            Some(QoSControl::from_bits(u16::from_le_bytes(qos)))
+        } else {
+            None
+        }
+    }
     /// Check if the data frame is an A-MSDU.
     pub const fn is_amsdu(&self) -> bool {
-        if let Some(qos) = self.qos {
-            qos[0] & bit!(7) != 0 && self.subtype.has_payload()
+        if let Some(qos_control) = self.qos_control() {
+            qos_control.amsdu_present() && self.subtype.has_payload()
+        } else {
+            false
+        }
+    }
+    /// Check if the Mesh Control Present bit in the QoS control field is set.
+    ///
+    /// This bit is only defined for frames transmitted in a mesh BSS.
+    pub const fn is_mesh_control_present(&self) -> bool {
+        if let Some(qos_control) = self.qos_control() {
+            qos_control.mesh_control_present() && self.subtype.has_payload()
         } else {
             false
         }
@@ -132,6 +240,16 @@ impl DataFrameHeader {
         &self.address_2
     }
 
+    /// Returns the [FrameDirection] derived from the To DS and From DS flags.
+    pub const fn direction(&self) -> FrameDirection {
+        match (self.fcf_flags.to_ds(), self.fcf_flags.from_ds()) {
+            (false, false) => FrameDirection::Ibss,
+            (true, false) => FrameDirection::ToAp,
+            (false, true) => FrameDirection::FromAp,
+            (true, true) => FrameDirection::Wds,
+        }
+    }
+
     /// Returns an optional reference to the destination address.
     ///
     /// # Mapping
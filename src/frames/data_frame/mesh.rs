@@ -0,0 +1,139 @@
+use bitfield_struct::bitfield;
+use mac_parser::MACAddress;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The flags of the [MeshControl] field.
+pub struct MeshControlFlags {
+    /// This is set automatically while writing, based on the [MeshAddressExtension].
+    #[bits(2)]
+    address_extension_mode: u8,
+    #[bits(6)]
+    __: u8,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The extended addresses carried in the [MeshControl] field.
+pub enum MeshAddressExtension {
+    #[default]
+    None,
+    /// Used for group addressed frames, which originated outside of the mesh BSS.
+    Address4(MACAddress),
+    /// Used for individually addressed frames, with an end station outside of the mesh BSS.
+    Addresses5And6 {
+        address_5: MACAddress,
+        address_6: MACAddress,
+    },
+}
+impl MeshAddressExtension {
+    /// Returns the address extension mode, as encoded in the [MeshControlFlags].
+    pub const fn address_extension_mode(&self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Address4(_) => 1,
+            Self::Addresses5And6 { .. } => 2,
+        }
+    }
+    /// The total length in bytes.
+    pub const fn length_in_bytes(&self) -> usize {
+        match self {
+            Self::None => 0,
+            Self::Address4(_) => 6,
+            Self::Addresses5And6 { .. } => 12,
+        }
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The Mesh Control field, which is prepended to the payload of mesh data frames.
+pub struct MeshControl {
+    pub flags: MeshControlFlags,
+    /// The remaining number of hops, this frame may be forwarded.
+    pub mesh_ttl: u8,
+    /// Used for eliminating duplicate group addressed frames.
+    pub mesh_sequence_number: u32,
+    /// The extended addresses.
+    pub mesh_address_extension: MeshAddressExtension,
+}
+impl MeshControl {
+    /// The total length in bytes.
+    pub const fn length_in_bytes(&self) -> usize {
+        6 + self.mesh_address_extension.length_in_bytes()
+    }
+}
+impl MeasureWith<()> for MeshControl {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        self.length_in_bytes()
+    }
+}
+impl TryFromCtx<'_> for MeshControl {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let mut flags = MeshControlFlags::from_bits(from.gread(&mut offset)?);
+        let mesh_ttl = from.gread(&mut offset)?;
+        let mesh_sequence_number = from.gread_with(&mut offset, Endian::Little)?;
+        let mesh_address_extension = match flags.address_extension_mode() {
+            0 => MeshAddressExtension::None,
+            1 => MeshAddressExtension::Address4(from.gread(&mut offset)?),
+            2 => MeshAddressExtension::Addresses5And6 {
+                address_5: from.gread(&mut offset)?,
+                address_6: from.gread(&mut offset)?,
+            },
+            _ => {
+                return Err(scroll::Error::BadInput {
+                    size: 1,
+                    msg: "The address extension mode is reserved.",
+                })
+            }
+        };
+        flags.set_address_extension_mode(0);
+
+        Ok((
+            Self {
+                flags,
+                mesh_ttl,
+                mesh_sequence_number,
+                mesh_address_extension,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for MeshControl {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(
+            self.flags
+                .with_address_extension_mode(self.mesh_address_extension.address_extension_mode())
+                .into_bits(),
+            &mut offset,
+        )?;
+        buf.gwrite(self.mesh_ttl, &mut offset)?;
+        buf.gwrite_with(self.mesh_sequence_number, &mut offset, Endian::Little)?;
+        match self.mesh_address_extension {
+            MeshAddressExtension::None => {}
+            MeshAddressExtension::Address4(address_4) => {
+                buf.gwrite(address_4, &mut offset)?;
+            }
+            MeshAddressExtension::Addresses5And6 {
+                address_5,
+                address_6,
+            } => {
+                buf.gwrite(address_5, &mut offset)?;
+                buf.gwrite(address_6, &mut offset)?;
+            }
+        }
+
+        Ok(offset)
+    }
+}
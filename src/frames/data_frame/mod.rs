@@ -9,7 +9,7 @@ use crate::common::{
     attach_fcs, strip_and_validate_fcs, DataFrameSubtype, FrameControlField, FrameType,
 };
 
-use self::{amsdu::AMSDUSubframeIterator, header::DataFrameHeader};
+use self::{amsdu::AMSDUSubframeIterator, header::DataFrameHeader, mesh::MeshControl};
 
 use super::IEEE80211Frame;
 
@@ -18,6 +18,8 @@ pub mod amsdu;
 pub mod builder;
 /// This contains the header.
 pub mod header;
+/// This contains the mesh control field.
+pub mod mesh;
 
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -46,9 +48,9 @@ impl<'a> TryFromCtx<'a, bool> for DataFrameReadPayload<'a> {
     fn try_from_ctx(from: &'a [u8], is_amsdu: bool) -> Result<(Self, usize), Self::Error> {
         Ok((
             if is_amsdu {
-                Self::Single(from)
-            } else {
                 Self::AMSDU(AMSDUSubframeIterator::from_bytes(from))
+            } else {
+                Self::Single(from)
             },
             from.len(),
         ))
@@ -87,6 +89,20 @@ impl DataFrame<'_> {
             }
     }
 }
+impl DataFrame<'_> {
+    /// Parse the [MeshControl] field from the start of the payload.
+    ///
+    /// This returns [None], if the Mesh Control Present bit in the QoS control field isn't set, there is no payload or the field couldn't be parsed.
+    pub fn mesh_control(&self) -> Option<MeshControl> {
+        if !self.header.is_mesh_control_present() {
+            return None;
+        }
+        let Some(DataFrameReadPayload::Single(payload)) = self.payload else {
+            return None;
+        };
+        payload.pread(0).ok()
+    }
+}
 impl<DataFramePayload: MeasureWith<()>> MeasureWith<bool> for DataFrame<'_, DataFramePayload> {
     fn measure_with(&self, with_fcs: &bool) -> usize {
         self.header.length_in_bytes()
@@ -110,10 +126,7 @@ impl<'a> TryFromCtx<'a, bool> for DataFrame<'a, DataFrameReadPayload<'a>> {
         };
         let header: DataFrameHeader = from.gread(&mut offset)?;
         let payload = if header.subtype.has_payload() {
-            let len = from.len() - offset;
-            Some(DataFrameReadPayload::Single(
-                from.gread_with(&mut offset, len)?,
-            ))
+            Some(from.gread_with(&mut offset, header.is_amsdu())?)
         } else {
             None
         };
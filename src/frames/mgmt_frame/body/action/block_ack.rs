@@ -0,0 +1,317 @@
+use core::marker::PhantomData;
+
+use bitfield_struct::bitfield;
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use crate::{
+    common::{IEEE80211Reason, IEEE80211StatusCode, SequenceControl},
+    elements::ReadElements,
+    mgmt_frame::ManagementFrame,
+};
+
+use super::{
+    action_body_matches, append_action_header, strip_and_check_action_header, ActionBody,
+    CategoryCode, RawActionBody,
+};
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The action code of a Block Ack action frame.
+    pub enum BlockAckActionCode: u8 {
+        #[default]
+        AddBARequest => 0,
+        AddBAResponse => 1,
+        DelBA => 2
+    }
+}
+
+#[bitfield(u16, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The Block Ack Parameter Set field of an ADDBA Request or Response frame.
+pub struct BlockAckParameterSet {
+    /// Indicates, that A-MSDUs may be carried in A-MPDUs under this agreement.
+    pub amsdu_supported: bool,
+    /// Set for immediate Block Ack and cleared for delayed Block Ack.
+    pub immediate_block_ack: bool,
+    #[bits(4)]
+    pub tid: u8,
+    #[bits(10)]
+    /// The amount of MSDUs, which can be buffered for this TID.
+    pub buffer_size: u16,
+}
+#[bitfield(u16, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The DELBA Parameter Set field of a DELBA frame.
+pub struct DelBAParameterSet {
+    #[bits(11)]
+    __: u16,
+    /// Indicates, that the originator of the agreement sent the DELBA.
+    pub initiator: bool,
+    #[bits(4)]
+    pub tid: u8,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The body of an ADDBA Request frame.
+///
+/// This is sent by the originator to set up a Block Ack agreement for a TID.
+pub struct AddBARequestBody<'a, ElementContainer = ReadElements<'a>> {
+    pub dialog_token: u8,
+    pub parameters: BlockAckParameterSet,
+    /// The timeout of the agreement in TUs. A value of zero disables the timeout.
+    pub timeout: u16,
+    /// The sequence number of the first MSDU, which will be sent under this agreement.
+    pub starting_sequence_control: SequenceControl,
+    /// These contain the optional GCR Group Address, Multi-band, TCLAS and ADDBA Extension elements.
+    pub elements: ElementContainer,
+    pub _phantom: PhantomData<&'a ()>,
+}
+impl<'a> TryFromCtx<'a> for AddBARequestBody<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        strip_and_check_action_header(
+            from,
+            &mut offset,
+            CategoryCode::BlockAck,
+            BlockAckActionCode::AddBARequest,
+        )?;
+        let dialog_token = from.gread(&mut offset)?;
+        let parameters =
+            BlockAckParameterSet::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+        let timeout = from.gread_with(&mut offset, Endian::Little)?;
+        let starting_sequence_control =
+            SequenceControl::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+        let elements = from.gread(&mut offset)?;
+
+        Ok((
+            Self {
+                dialog_token,
+                parameters,
+                timeout,
+                starting_sequence_control,
+                elements,
+                _phantom: PhantomData,
+            },
+            offset,
+        ))
+    }
+}
+impl<ElementContainer: MeasureWith<()>> MeasureWith<()> for AddBARequestBody<'_, ElementContainer> {
+    fn measure_with(&self, ctx: &()) -> usize {
+        9 + self.elements.measure_with(ctx)
+    }
+}
+impl<ElementContainer: TryIntoCtx<Error = scroll::Error>> TryIntoCtx
+    for AddBARequestBody<'_, ElementContainer>
+{
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        append_action_header(
+            buf,
+            &mut offset,
+            CategoryCode::BlockAck,
+            BlockAckActionCode::AddBARequest,
+        )?;
+        buf.gwrite(self.dialog_token, &mut offset)?;
+        buf.gwrite_with(self.parameters.into_bits(), &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.timeout, &mut offset, Endian::Little)?;
+        buf.gwrite_with(
+            self.starting_sequence_control.into_bits(),
+            &mut offset,
+            Endian::Little,
+        )?;
+        buf.gwrite(self.elements, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl<ElementContainer> ActionBody for AddBARequestBody<'_, ElementContainer> {
+    const CATEGORY_CODE: CategoryCode = CategoryCode::BlockAck;
+    fn matches(action_body: RawActionBody<'_>) -> bool {
+        action_body_matches(
+            action_body,
+            CategoryCode::BlockAck,
+            BlockAckActionCode::AddBARequest,
+        )
+    }
+}
+pub type AddBARequestFrame<'a, ElementContainer = ReadElements<'a>> =
+    ManagementFrame<AddBARequestBody<'a, ElementContainer>>;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The body of an ADDBA Response frame.
+///
+/// This is sent by the recipient in response to an [ADDBA Request](AddBARequestBody).
+pub struct AddBAResponseBody<'a, ElementContainer = ReadElements<'a>> {
+    /// The dialog token of the corresponding request.
+    pub dialog_token: u8,
+    pub status_code: IEEE80211StatusCode,
+    /// The parameters accepted by the recipient, which may differ from the requested ones.
+    pub parameters: BlockAckParameterSet,
+    /// The timeout of the agreement in TUs. A value of zero disables the timeout.
+    pub timeout: u16,
+    /// These contain the optional GCR Group Address, Multi-band, TCLAS and ADDBA Extension elements.
+    pub elements: ElementContainer,
+    pub _phantom: PhantomData<&'a ()>,
+}
+impl<'a> TryFromCtx<'a> for AddBAResponseBody<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        strip_and_check_action_header(
+            from,
+            &mut offset,
+            CategoryCode::BlockAck,
+            BlockAckActionCode::AddBAResponse,
+        )?;
+        let dialog_token = from.gread(&mut offset)?;
+        let status_code =
+            IEEE80211StatusCode::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+        let parameters =
+            BlockAckParameterSet::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+        let timeout = from.gread_with(&mut offset, Endian::Little)?;
+        let elements = from.gread(&mut offset)?;
+
+        Ok((
+            Self {
+                dialog_token,
+                status_code,
+                parameters,
+                timeout,
+                elements,
+                _phantom: PhantomData,
+            },
+            offset,
+        ))
+    }
+}
+impl<ElementContainer: MeasureWith<()>> MeasureWith<()>
+    for AddBAResponseBody<'_, ElementContainer>
+{
+    fn measure_with(&self, ctx: &()) -> usize {
+        9 + self.elements.measure_with(ctx)
+    }
+}
+impl<ElementContainer: TryIntoCtx<Error = scroll::Error>> TryIntoCtx
+    for AddBAResponseBody<'_, ElementContainer>
+{
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        append_action_header(
+            buf,
+            &mut offset,
+            CategoryCode::BlockAck,
+            BlockAckActionCode::AddBAResponse,
+        )?;
+        buf.gwrite(self.dialog_token, &mut offset)?;
+        buf.gwrite_with(self.status_code.into_bits(), &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.parameters.into_bits(), &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.timeout, &mut offset, Endian::Little)?;
+        buf.gwrite(self.elements, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl<ElementContainer> ActionBody for AddBAResponseBody<'_, ElementContainer> {
+    const CATEGORY_CODE: CategoryCode = CategoryCode::BlockAck;
+    fn matches(action_body: RawActionBody<'_>) -> bool {
+        action_body_matches(
+            action_body,
+            CategoryCode::BlockAck,
+            BlockAckActionCode::AddBAResponse,
+        )
+    }
+}
+pub type AddBAResponseFrame<'a, ElementContainer = ReadElements<'a>> =
+    ManagementFrame<AddBAResponseBody<'a, ElementContainer>>;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The body of a DELBA frame.
+///
+/// This is sent by either party to tear down a Block Ack agreement.
+pub struct DelBABody<'a, ElementContainer = ReadElements<'a>> {
+    pub parameters: DelBAParameterSet,
+    pub reason: IEEE80211Reason,
+    /// These contain the optional GCR Group Address and Multi-band elements.
+    pub elements: ElementContainer,
+    pub _phantom: PhantomData<&'a ()>,
+}
+impl<'a> TryFromCtx<'a> for DelBABody<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        strip_and_check_action_header(
+            from,
+            &mut offset,
+            CategoryCode::BlockAck,
+            BlockAckActionCode::DelBA,
+        )?;
+        let parameters =
+            DelBAParameterSet::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+        let reason = IEEE80211Reason::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+        let elements = from.gread(&mut offset)?;
+
+        Ok((
+            Self {
+                parameters,
+                reason,
+                elements,
+                _phantom: PhantomData,
+            },
+            offset,
+        ))
+    }
+}
+impl<ElementContainer: MeasureWith<()>> MeasureWith<()> for DelBABody<'_, ElementContainer> {
+    fn measure_with(&self, ctx: &()) -> usize {
+        6 + self.elements.measure_with(ctx)
+    }
+}
+impl<ElementContainer: TryIntoCtx<Error = scroll::Error>> TryIntoCtx
+    for DelBABody<'_, ElementContainer>
+{
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        append_action_header(
+            buf,
+            &mut offset,
+            CategoryCode::BlockAck,
+            BlockAckActionCode::DelBA,
+        )?;
+        buf.gwrite_with(self.parameters.into_bits(), &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.reason.into_bits(), &mut offset, Endian::Little)?;
+        buf.gwrite(self.elements, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl<ElementContainer> ActionBody for DelBABody<'_, ElementContainer> {
+    const CATEGORY_CODE: CategoryCode = CategoryCode::BlockAck;
+    fn matches(action_body: RawActionBody<'_>) -> bool {
+        action_body_matches(
+            action_body,
+            CategoryCode::BlockAck,
+            BlockAckActionCode::DelBA,
+        )
+    }
+}
+pub type DelBAFrame<'a, ElementContainer = ReadElements<'a>> =
+    ManagementFrame<DelBABody<'a, ElementContainer>>;
@@ -17,17 +17,47 @@ use scroll::{
     Pread, Pwrite,
 };
 
+mod block_ack;
+pub use block_ack::{
+    AddBARequestBody, AddBARequestFrame, AddBAResponseBody, AddBAResponseFrame, BlockAckActionCode,
+    BlockAckParameterSet, DelBABody, DelBAFrame, DelBAParameterSet,
+};
+mod public;
+pub use public::{FTMBody, FTMFrame, FTMRequestBody, FTMRequestFrame, PublicActionCode};
+mod sa_query;
+pub use sa_query::{
+    SAQueryActionCode, SAQueryRequestBody, SAQueryRequestFrame, SAQueryResponseBody,
+    SAQueryResponseFrame,
+};
+mod spectrum_management;
+pub use spectrum_management::{
+    ChannelSwitchAnnouncementBody, ChannelSwitchAnnouncementFrame, MeasurementReportBody,
+    MeasurementReportFrame, MeasurementRequestBody, MeasurementRequestFrame,
+    SpectrumManagementActionCode,
+};
 mod vendor;
 pub use vendor::{
     append_vendor_action_header, strip_and_check_vendor_action_header, RawVendorSpecificActionBody,
     RawVendorSpecificActionFrame, VENDOR_SPECIFIC_ACTION_HEADER_LENGTH,
 };
+mod wnm;
+pub use wnm::{
+    BSSTerminationDuration, BSSTransitionManagementQueryBody, BSSTransitionManagementQueryFrame,
+    BSSTransitionManagementRequestBody, BSSTransitionManagementRequestFrame,
+    BSSTransitionManagementRequestMode, BSSTransitionManagementResponseBody,
+    BSSTransitionManagementResponseFrame, BTMStatusCode, WNMActionCode,
+};
 
 serializable_enum! {
     #[cfg_attr(feature = "defmt", derive(defmt::Format))]
     #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
     /// This enum contains the category code specified in the body of an [Action Frame](ActionBody).
     pub enum CategoryCode: u8 {
+        SpectrumManagement => 0,
+        BlockAck => 3,
+        Public => 4,
+        SAQuery => 8,
+        WNM => 10,
         #[default]
         VendorSpecific => 127
     }
@@ -41,6 +71,48 @@ pub trait ActionBody {
     fn matches(action_body: RawActionBody<'_>) -> bool;
 }
 
+/// Check the category code and action code of the body and advance the `offset`.
+fn strip_and_check_action_header(
+    buf: &[u8],
+    offset: &mut usize,
+    category_code: CategoryCode,
+    expected_action_code: impl Into<u8>,
+) -> Result<(), scroll::Error> {
+    if CategoryCode::from_bits(buf.gread(offset)?) != category_code {
+        return Err(scroll::Error::BadInput {
+            size: *offset,
+            msg: "The category code didn't match, what was expected.",
+        });
+    }
+    if buf.gread::<u8>(offset)? != expected_action_code.into() {
+        return Err(scroll::Error::BadInput {
+            size: *offset,
+            msg: "The action code didn't match, what was expected.",
+        });
+    }
+    Ok(())
+}
+/// Append the category code and action code to the buffer.
+fn append_action_header(
+    buf: &mut [u8],
+    offset: &mut usize,
+    category_code: CategoryCode,
+    action_code: impl Into<u8>,
+) -> Result<(), scroll::Error> {
+    buf.gwrite(category_code.into_bits(), offset)?;
+    buf.gwrite(action_code.into(), offset)?;
+    Ok(())
+}
+/// Check if the raw action body has the specified category and action code.
+fn action_body_matches(
+    action_body: RawActionBody<'_>,
+    category_code: CategoryCode,
+    action_code: impl Into<u8>,
+) -> bool {
+    action_body.category_code == category_code
+        && action_body.payload.first().copied() == Some(action_code.into())
+}
+
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 /// An unparsed action frame body.
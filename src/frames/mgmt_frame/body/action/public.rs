@@ -0,0 +1,198 @@
+use core::marker::PhantomData;
+
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use crate::{elements::ReadElements, mgmt_frame::ManagementFrame};
+
+use super::{
+    action_body_matches, append_action_header, strip_and_check_action_header, ActionBody,
+    CategoryCode, RawActionBody,
+};
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The action code of a public action frame.
+    pub enum PublicActionCode: u8 {
+        #[default]
+        FTMRequest => 32,
+        FTM => 33
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The body of a Fine Timing Measurement Request frame.
+///
+/// This is sent by the initiating STA to start or stop an FTM session.
+pub struct FTMRequestBody<'a, ElementContainer = ReadElements<'a>> {
+    /// Indicates, that the initiating STA requests the responder to start (1) or stop (0) sending FTM frames.
+    pub trigger: u8,
+    /// These contain the optional LCI and Location Civic Measurement Requests and the FTM Parameters.
+    pub elements: ElementContainer,
+    pub _phantom: PhantomData<&'a ()>,
+}
+impl<'a> TryFromCtx<'a> for FTMRequestBody<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        strip_and_check_action_header(
+            from,
+            &mut offset,
+            CategoryCode::Public,
+            PublicActionCode::FTMRequest,
+        )?;
+        let trigger = from.gread(&mut offset)?;
+        let elements = from.gread(&mut offset)?;
+
+        Ok((
+            Self {
+                trigger,
+                elements,
+                _phantom: PhantomData,
+            },
+            offset,
+        ))
+    }
+}
+impl<ElementContainer: MeasureWith<()>> MeasureWith<()> for FTMRequestBody<'_, ElementContainer> {
+    fn measure_with(&self, ctx: &()) -> usize {
+        3 + self.elements.measure_with(ctx)
+    }
+}
+impl<ElementContainer: TryIntoCtx<Error = scroll::Error>> TryIntoCtx
+    for FTMRequestBody<'_, ElementContainer>
+{
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        append_action_header(
+            buf,
+            &mut offset,
+            CategoryCode::Public,
+            PublicActionCode::FTMRequest,
+        )?;
+        buf.gwrite(self.trigger, &mut offset)?;
+        buf.gwrite(self.elements, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl<ElementContainer> ActionBody for FTMRequestBody<'_, ElementContainer> {
+    const CATEGORY_CODE: CategoryCode = CategoryCode::Public;
+    fn matches(action_body: RawActionBody<'_>) -> bool {
+        action_body_matches(
+            action_body,
+            CategoryCode::Public,
+            PublicActionCode::FTMRequest,
+        )
+    }
+}
+pub type FTMRequestFrame<'a, ElementContainer = ReadElements<'a>> =
+    ManagementFrame<FTMRequestBody<'a, ElementContainer>>;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The body of a Fine Timing Measurement frame.
+///
+/// The timestamps are in units of picoseconds and 48 bits long, so the upper 16 bits are truncated when writing.
+pub struct FTMBody<'a, ElementContainer = ReadElements<'a>> {
+    /// Identifies this FTM frame. A value of zero indicates the end of the FTM session.
+    pub dialog_token: u8,
+    /// The dialog token of the previous FTM frame, to which the timestamps refer.
+    pub follow_up_dialog_token: u8,
+    /// The time of departure of the previous FTM frame.
+    pub tod: u64,
+    /// The time of arrival of the Ack to the previous FTM frame.
+    pub toa: u64,
+    /// The maximum error of the TOD.
+    pub tod_error: u16,
+    /// The maximum error of the TOA.
+    pub toa_error: u16,
+    /// These contain the optional LCI and Location Civic Reports, FTM Parameters and FTM Synchronization Information.
+    pub elements: ElementContainer,
+    pub _phantom: PhantomData<&'a ()>,
+}
+/// Read a 48 bit timestamp.
+fn read_timestamp(from: &[u8], offset: &mut usize) -> Result<u64, scroll::Error> {
+    let mut bytes = [0x00; 8];
+    bytes[..6].copy_from_slice(&from.gread::<[u8; 6]>(offset)?);
+    Ok(u64::from_le_bytes(bytes))
+}
+impl<'a> TryFromCtx<'a> for FTMBody<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        strip_and_check_action_header(
+            from,
+            &mut offset,
+            CategoryCode::Public,
+            PublicActionCode::FTM,
+        )?;
+        let dialog_token = from.gread(&mut offset)?;
+        let follow_up_dialog_token = from.gread(&mut offset)?;
+        let tod = read_timestamp(from, &mut offset)?;
+        let toa = read_timestamp(from, &mut offset)?;
+        let tod_error = from.gread_with(&mut offset, Endian::Little)?;
+        let toa_error = from.gread_with(&mut offset, Endian::Little)?;
+        let elements = from.gread(&mut offset)?;
+
+        Ok((
+            Self {
+                dialog_token,
+                follow_up_dialog_token,
+                tod,
+                toa,
+                tod_error,
+                toa_error,
+                elements,
+                _phantom: PhantomData,
+            },
+            offset,
+        ))
+    }
+}
+impl<ElementContainer: MeasureWith<()>> MeasureWith<()> for FTMBody<'_, ElementContainer> {
+    fn measure_with(&self, ctx: &()) -> usize {
+        20 + self.elements.measure_with(ctx)
+    }
+}
+impl<ElementContainer: TryIntoCtx<Error = scroll::Error>> TryIntoCtx
+    for FTMBody<'_, ElementContainer>
+{
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        append_action_header(
+            buf,
+            &mut offset,
+            CategoryCode::Public,
+            PublicActionCode::FTM,
+        )?;
+        buf.gwrite(self.dialog_token, &mut offset)?;
+        buf.gwrite(self.follow_up_dialog_token, &mut offset)?;
+        buf.gwrite(&self.tod.to_le_bytes()[..6], &mut offset)?;
+        buf.gwrite(&self.toa.to_le_bytes()[..6], &mut offset)?;
+        buf.gwrite_with(self.tod_error, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.toa_error, &mut offset, Endian::Little)?;
+        buf.gwrite(self.elements, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl<ElementContainer> ActionBody for FTMBody<'_, ElementContainer> {
+    const CATEGORY_CODE: CategoryCode = CategoryCode::Public;
+    fn matches(action_body: RawActionBody<'_>) -> bool {
+        action_body_matches(action_body, CategoryCode::Public, PublicActionCode::FTM)
+    }
+}
+pub type FTMFrame<'a, ElementContainer = ReadElements<'a>> =
+    ManagementFrame<FTMBody<'a, ElementContainer>>;
@@ -0,0 +1,136 @@
+use core::marker::PhantomData;
+
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use crate::{elements::ReadElements, mgmt_frame::ManagementFrame};
+
+use super::{
+    action_body_matches, append_action_header, strip_and_check_action_header, ActionBody,
+    CategoryCode, RawActionBody,
+};
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The action code of an SA Query action frame.
+    pub enum SAQueryActionCode: u8 {
+        #[default]
+        Request => 0,
+        Response => 1
+    }
+}
+
+macro_rules! sa_query_bodies {
+    (
+        $(
+            $(
+                #[$meta:meta]
+            )*
+            $body:ident, $frame:ident => $action_code:ident
+        ),*
+    ) => {
+        $(
+            #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+            #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+            $(
+                #[$meta]
+            )*
+            pub struct $body<'a, ElementContainer = ReadElements<'a>> {
+                /// The transaction identifier, which is echoed in the response.
+                pub transaction_identifier: u16,
+                /// These contain the optional OCI element.
+                pub elements: ElementContainer,
+                pub _phantom: PhantomData<&'a ()>,
+            }
+            impl<'a> TryFromCtx<'a> for $body<'a> {
+                type Error = scroll::Error;
+                fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+                    let mut offset = 0;
+
+                    strip_and_check_action_header(
+                        from,
+                        &mut offset,
+                        CategoryCode::SAQuery,
+                        SAQueryActionCode::$action_code,
+                    )?;
+                    let transaction_identifier = from.gread_with(&mut offset, Endian::Little)?;
+                    let elements = from.gread(&mut offset)?;
+
+                    Ok((
+                        Self {
+                            transaction_identifier,
+                            elements,
+                            _phantom: PhantomData,
+                        },
+                        offset,
+                    ))
+                }
+            }
+            impl<ElementContainer: MeasureWith<()>> MeasureWith<()> for $body<'_, ElementContainer> {
+                fn measure_with(&self, ctx: &()) -> usize {
+                    4 + self.elements.measure_with(ctx)
+                }
+            }
+            impl<ElementContainer: TryIntoCtx<Error = scroll::Error>> TryIntoCtx
+                for $body<'_, ElementContainer>
+            {
+                type Error = scroll::Error;
+                fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+                    let mut offset = 0;
+
+                    append_action_header(
+                        buf,
+                        &mut offset,
+                        CategoryCode::SAQuery,
+                        SAQueryActionCode::$action_code,
+                    )?;
+                    buf.gwrite_with(self.transaction_identifier, &mut offset, Endian::Little)?;
+                    buf.gwrite(self.elements, &mut offset)?;
+
+                    Ok(offset)
+                }
+            }
+            impl<ElementContainer> ActionBody for $body<'_, ElementContainer> {
+                const CATEGORY_CODE: CategoryCode = CategoryCode::SAQuery;
+                fn matches(action_body: RawActionBody<'_>) -> bool {
+                    action_body_matches(action_body, CategoryCode::SAQuery, SAQueryActionCode::$action_code)
+                }
+            }
+            pub type $frame<'a, ElementContainer = ReadElements<'a>> =
+                ManagementFrame<$body<'a, ElementContainer>>;
+        )*
+    };
+}
+sa_query_bodies! {
+    /// The body of an SA Query Request frame.
+    ///
+    /// This is sent to verify, that the peer still has a valid security association, when MFP is used.
+    SAQueryRequestBody, SAQueryRequestFrame => Request,
+    /// The body of an SA Query Response frame.
+    ///
+    /// This is sent in response to an [SA Query Request](SAQueryRequestBody).
+    SAQueryResponseBody, SAQueryResponseFrame => Response
+}
+impl<ElementContainer> SAQueryRequestBody<'_, ElementContainer> {
+    /// Generate the response to this request, which echoes the transaction identifier.
+    pub const fn response<'a>(&self) -> SAQueryResponseBody<'a, ReadElements<'a>> {
+        SAQueryResponseBody {
+            transaction_identifier: self.transaction_identifier,
+            elements: ReadElements { bytes: &[] },
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<ElementContainer> SAQueryResponseBody<'_, ElementContainer> {
+    /// Check if this response answers the specified request.
+    pub const fn answers<RequestElementContainer>(
+        &self,
+        request: &SAQueryRequestBody<'_, RequestElementContainer>,
+    ) -> bool {
+        self.transaction_identifier == request.transaction_identifier
+    }
+}
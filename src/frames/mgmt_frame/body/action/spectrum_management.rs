@@ -0,0 +1,209 @@
+use core::marker::PhantomData;
+
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use crate::{
+    elements::{
+        measurement::{MeasurementReportElement, MeasurementRequestElement},
+        ChannelSwitchAnnouncementElement, Element, ElementID, RawIEEE80211Element, ReadElements,
+    },
+    mgmt_frame::ManagementFrame,
+};
+
+use super::{
+    action_body_matches, append_action_header, strip_and_check_action_header, ActionBody,
+    CategoryCode, RawActionBody,
+};
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The action code of a Spectrum Management action frame.
+    pub enum SpectrumManagementActionCode: u8 {
+        #[default]
+        MeasurementRequest => 0,
+        MeasurementReport => 1,
+        TPCRequest => 2,
+        TPCReport => 3,
+        ChannelSwitchAnnouncement => 4
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The body of a Channel Switch Announcement frame.
+///
+/// This is sent by an AP, to announce a channel switch, for example after radar was detected.
+pub struct ChannelSwitchAnnouncementBody<'a, ElementContainer = ReadElements<'a>> {
+    pub channel_switch_announcement: ChannelSwitchAnnouncementElement,
+    /// These contain the optional Secondary Channel Offset, Mesh Channel Switch Parameters, Wide Bandwidth Channel Switch and Transmit Power Envelope elements.
+    pub elements: ElementContainer,
+    pub _phantom: PhantomData<&'a ()>,
+}
+impl<'a> TryFromCtx<'a> for ChannelSwitchAnnouncementBody<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        strip_and_check_action_header(
+            from,
+            &mut offset,
+            CategoryCode::SpectrumManagement,
+            SpectrumManagementActionCode::ChannelSwitchAnnouncement,
+        )?;
+        let raw_element = from.gread_with::<RawIEEE80211Element>(&mut offset, Endian::Little)?;
+        if ElementID::Id(raw_element.tlv_type) != ChannelSwitchAnnouncementElement::ELEMENT_ID {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "The first element wasn't a Channel Switch Announcement element.",
+            });
+        }
+        let channel_switch_announcement = raw_element.slice.pread(0)?;
+        let elements = from.gread(&mut offset)?;
+
+        Ok((
+            Self {
+                channel_switch_announcement,
+                elements,
+                _phantom: PhantomData,
+            },
+            offset,
+        ))
+    }
+}
+impl<ElementContainer: MeasureWith<()>> MeasureWith<()>
+    for ChannelSwitchAnnouncementBody<'_, ElementContainer>
+{
+    fn measure_with(&self, ctx: &()) -> usize {
+        2 + self.channel_switch_announcement.wrap().measure_with(ctx)
+            + self.elements.measure_with(ctx)
+    }
+}
+impl<ElementContainer: TryIntoCtx<Error = scroll::Error>> TryIntoCtx
+    for ChannelSwitchAnnouncementBody<'_, ElementContainer>
+{
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        append_action_header(
+            buf,
+            &mut offset,
+            CategoryCode::SpectrumManagement,
+            SpectrumManagementActionCode::ChannelSwitchAnnouncement,
+        )?;
+        buf.gwrite(self.channel_switch_announcement.wrap(), &mut offset)?;
+        buf.gwrite(self.elements, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl<ElementContainer> ActionBody for ChannelSwitchAnnouncementBody<'_, ElementContainer> {
+    const CATEGORY_CODE: CategoryCode = CategoryCode::SpectrumManagement;
+    fn matches(action_body: RawActionBody<'_>) -> bool {
+        action_body_matches(
+            action_body,
+            CategoryCode::SpectrumManagement,
+            SpectrumManagementActionCode::ChannelSwitchAnnouncement,
+        )
+    }
+}
+pub type ChannelSwitchAnnouncementFrame<'a, ElementContainer = ReadElements<'a>> =
+    ManagementFrame<ChannelSwitchAnnouncementBody<'a, ElementContainer>>;
+
+macro_rules! measurement_bodies {
+    (
+        $(
+            #[doc = $doc:literal]
+            $body:ident, $frame:ident => $action_code:ident, $element:ident, $accessor:ident
+        );*
+    ) => {
+        $(
+            #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+            #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+            #[doc = $doc]
+            pub struct $body<'a, ElementContainer = ReadElements<'a>> {
+                pub dialog_token: u8,
+                #[doc = concat!("These contain one or more [", stringify!($element), "]s.")]
+                pub elements: ElementContainer,
+                pub _phantom: PhantomData<&'a ()>,
+            }
+            impl<'a> $body<'a> {
+                #[doc = concat!("Returns an iterator over the [", stringify!($element), "]s in the body.")]
+                pub fn $accessor(&self) -> impl Iterator<Item = $element<'a>> + 'a {
+                    self.elements.get_matching_elements::<$element>()
+                }
+            }
+            impl<'a> TryFromCtx<'a> for $body<'a> {
+                type Error = scroll::Error;
+                fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+                    let mut offset = 0;
+
+                    strip_and_check_action_header(
+                        from,
+                        &mut offset,
+                        CategoryCode::SpectrumManagement,
+                        SpectrumManagementActionCode::$action_code,
+                    )?;
+                    let dialog_token = from.gread(&mut offset)?;
+                    let elements = from.gread(&mut offset)?;
+
+                    Ok((
+                        Self {
+                            dialog_token,
+                            elements,
+                            _phantom: PhantomData,
+                        },
+                        offset,
+                    ))
+                }
+            }
+            impl<ElementContainer: MeasureWith<()>> MeasureWith<()> for $body<'_, ElementContainer> {
+                fn measure_with(&self, ctx: &()) -> usize {
+                    3 + self.elements.measure_with(ctx)
+                }
+            }
+            impl<ElementContainer: TryIntoCtx<Error = scroll::Error>> TryIntoCtx
+                for $body<'_, ElementContainer>
+            {
+                type Error = scroll::Error;
+                fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+                    let mut offset = 0;
+
+                    append_action_header(
+                        buf,
+                        &mut offset,
+                        CategoryCode::SpectrumManagement,
+                        SpectrumManagementActionCode::$action_code,
+                    )?;
+                    buf.gwrite(self.dialog_token, &mut offset)?;
+                    buf.gwrite(self.elements, &mut offset)?;
+
+                    Ok(offset)
+                }
+            }
+            impl<ElementContainer> ActionBody for $body<'_, ElementContainer> {
+                const CATEGORY_CODE: CategoryCode = CategoryCode::SpectrumManagement;
+                fn matches(action_body: RawActionBody<'_>) -> bool {
+                    action_body_matches(
+                        action_body,
+                        CategoryCode::SpectrumManagement,
+                        SpectrumManagementActionCode::$action_code,
+                    )
+                }
+            }
+            pub type $frame<'a, ElementContainer = ReadElements<'a>> =
+                ManagementFrame<$body<'a, ElementContainer>>;
+        )*
+    };
+}
+measurement_bodies! {
+    /// The body of a Measurement Request frame.
+    MeasurementRequestBody, MeasurementRequestFrame => MeasurementRequest, MeasurementRequestElement, measurement_requests;
+    /// The body of a Measurement Report frame.
+    MeasurementReportBody, MeasurementReportFrame => MeasurementReport, MeasurementReportElement, measurement_reports
+}
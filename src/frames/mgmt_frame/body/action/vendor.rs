@@ -17,6 +17,20 @@ pub struct RawVendorSpecificActionBody<'a, Payload = &'a [u8]> {
     pub payload: Payload,
     pub _phantom: PhantomData<&'a ()>,
 }
+impl<'a> RawVendorSpecificActionBody<'a> {
+    /// Returns the payload following the OUI type, if both the OUI and OUI type match.
+    ///
+    /// This is useful for vendor action frames, which carry a type byte after the OUI.
+    pub fn payload_if_matches(&self, oui: [u8; 3], oui_type: u8) -> Option<&'a [u8]> {
+        if self.oui != oui {
+            return None;
+        }
+        match self.payload.split_first() {
+            Some((read_oui_type, payload)) if *read_oui_type == oui_type => Some(payload),
+            _ => None,
+        }
+    }
+}
 impl<'a> TryFromCtx<'a> for RawVendorSpecificActionBody<'a> {
     type Error = scroll::Error;
     fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
@@ -0,0 +1,439 @@
+use core::marker::PhantomData;
+
+use bitfield_struct::bitfield;
+use mac_parser::MACAddress;
+use macro_bits::serializable_enum;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+use crate::{
+    elements::{neighbor_report::NeighborReportElement, ReadElements},
+    mgmt_frame::ManagementFrame,
+};
+
+use super::{
+    action_body_matches, append_action_header, strip_and_check_action_header, ActionBody,
+    CategoryCode, RawActionBody,
+};
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The action code of a WNM action frame.
+    pub enum WNMActionCode: u8 {
+        #[default]
+        EventRequest => 0,
+        EventReport => 1,
+        DiagnosticRequest => 2,
+        DiagnosticReport => 3,
+        LocationConfigurationRequest => 4,
+        LocationConfigurationResponse => 5,
+        BSSTransitionManagementQuery => 6,
+        BSSTransitionManagementRequest => 7,
+        BSSTransitionManagementResponse => 8
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The body of a BSS Transition Management Query frame.
+///
+/// This is sent by a non-AP STA, to request a [BSS Transition Management Request](BSSTransitionManagementRequestBody) from the AP.
+pub struct BSSTransitionManagementQueryBody<'a, ElementContainer = ReadElements<'a>> {
+    pub dialog_token: u8,
+    /// The reason for the query, as specified in Table 9-198 of IEEE 802.11-2020.
+    pub query_reason: u8,
+    /// The candidates, which the STA would like to transition to, as [Neighbor Report elements](NeighborReportElement).
+    pub candidate_list: ElementContainer,
+    pub _phantom: PhantomData<&'a ()>,
+}
+impl<'a> TryFromCtx<'a> for BSSTransitionManagementQueryBody<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        strip_and_check_action_header(
+            from,
+            &mut offset,
+            CategoryCode::WNM,
+            WNMActionCode::BSSTransitionManagementQuery,
+        )?;
+        let dialog_token = from.gread(&mut offset)?;
+        let query_reason = from.gread(&mut offset)?;
+        let candidate_list = from.gread(&mut offset)?;
+
+        Ok((
+            Self {
+                dialog_token,
+                query_reason,
+                candidate_list,
+                _phantom: PhantomData,
+            },
+            offset,
+        ))
+    }
+}
+impl<ElementContainer: MeasureWith<()>> MeasureWith<()>
+    for BSSTransitionManagementQueryBody<'_, ElementContainer>
+{
+    fn measure_with(&self, ctx: &()) -> usize {
+        4 + self.candidate_list.measure_with(ctx)
+    }
+}
+impl<ElementContainer: TryIntoCtx<Error = scroll::Error>> TryIntoCtx
+    for BSSTransitionManagementQueryBody<'_, ElementContainer>
+{
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        append_action_header(
+            buf,
+            &mut offset,
+            CategoryCode::WNM,
+            WNMActionCode::BSSTransitionManagementQuery,
+        )?;
+        buf.gwrite(self.dialog_token, &mut offset)?;
+        buf.gwrite(self.query_reason, &mut offset)?;
+        buf.gwrite(self.candidate_list, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl<ElementContainer> ActionBody for BSSTransitionManagementQueryBody<'_, ElementContainer> {
+    const CATEGORY_CODE: CategoryCode = CategoryCode::WNM;
+    fn matches(action_body: RawActionBody<'_>) -> bool {
+        action_body_matches(
+            action_body,
+            CategoryCode::WNM,
+            WNMActionCode::BSSTransitionManagementQuery,
+        )
+    }
+}
+pub type BSSTransitionManagementQueryFrame<'a, ElementContainer = ReadElements<'a>> =
+    ManagementFrame<BSSTransitionManagementQueryBody<'a, ElementContainer>>;
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The Request Mode field of a [BSS Transition Management Request](BSSTransitionManagementRequestBody).
+pub struct BSSTransitionManagementRequestMode {
+    /// Indicates, that the candidate list is included.
+    pub preferred_candidate_list_included: bool,
+    /// Indicates, that BSSs not included in the candidate list aren't recommended.
+    pub abridged: bool,
+    /// Indicates, that the STA will be disassociated from the current AP.
+    pub disassociation_imminent: bool,
+    /// Indicates, that the BSS Termination Duration field is present.
+    pub bss_termination_included: bool,
+    /// Indicates, that the STA will be disassociated from the ESS and that the Session Information URL field is present.
+    pub ess_disassociation_imminent: bool,
+    /// Indicates, that the link will be removed from the AP MLD.
+    pub link_removal_imminent: bool,
+    #[bits(2)]
+    __: u8,
+}
+
+/// The subelement ID of the BSS Termination Duration subelement.
+const BSS_TERMINATION_DURATION_SUBELEMENT_ID: u8 = 4;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The BSS Termination Duration subelement, which indicates when and for how long the BSS will be shut down.
+pub struct BSSTerminationDuration {
+    /// The TSF value, at which the BSS will be shut down.
+    pub bss_termination_tsf: u64,
+    /// The time in minutes, for which the BSS will be unavailable.
+    pub duration: u16,
+}
+impl MeasureWith<()> for BSSTerminationDuration {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        12
+    }
+}
+impl TryFromCtx<'_> for BSSTerminationDuration {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        if from.gread::<u8>(&mut offset)? != BSS_TERMINATION_DURATION_SUBELEMENT_ID
+            || from.gread::<u8>(&mut offset)? != 10
+        {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "The BSS Termination Duration subelement was malformed.",
+            });
+        }
+        let bss_termination_tsf = from.gread_with(&mut offset, Endian::Little)?;
+        let duration = from.gread_with(&mut offset, Endian::Little)?;
+
+        Ok((
+            Self {
+                bss_termination_tsf,
+                duration,
+            },
+            offset,
+        ))
+    }
+}
+impl TryIntoCtx for BSSTerminationDuration {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(BSS_TERMINATION_DURATION_SUBELEMENT_ID, &mut offset)?;
+        buf.gwrite(10u8, &mut offset)?;
+        buf.gwrite_with(self.bss_termination_tsf, &mut offset, Endian::Little)?;
+        buf.gwrite_with(self.duration, &mut offset, Endian::Little)?;
+
+        Ok(offset)
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The body of a BSS Transition Management Request frame.
+///
+/// This is sent by an AP, to request a STA to transition to another BSS, for example because the AP is overloaded or about to disassociate the STA.
+///
+/// # Note
+/// When reading, the presence of the optional fields is determined by the [request mode](BSSTransitionManagementRequestMode).
+/// When writing, the optional fields are written if they are [Some], which isn't validated against the request mode.
+pub struct BSSTransitionManagementRequestBody<'a, ElementContainer = ReadElements<'a>> {
+    pub dialog_token: u8,
+    pub request_mode: BSSTransitionManagementRequestMode,
+    /// The number of TBTTs until the AP disassociates the STA.
+    pub disassociation_timer: u16,
+    /// The number of TBTTs, for which the candidate list is valid.
+    pub validity_interval: u8,
+    pub bss_termination_duration: Option<BSSTerminationDuration>,
+    /// A URL with information about the disassociation from the ESS.
+    pub session_information_url: Option<&'a [u8]>,
+    /// The candidates for the transition, as [Neighbor Report elements](NeighborReportElement).
+    pub candidate_list: ElementContainer,
+    pub _phantom: PhantomData<&'a ()>,
+}
+impl<'a> BSSTransitionManagementRequestBody<'a> {
+    /// Returns an [Iterator] over the candidates for the transition.
+    pub fn candidates(&self) -> impl Iterator<Item = NeighborReportElement<'a>> + 'a {
+        self.candidate_list
+            .get_matching_elements::<NeighborReportElement>()
+    }
+}
+impl<'a> TryFromCtx<'a> for BSSTransitionManagementRequestBody<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        strip_and_check_action_header(
+            from,
+            &mut offset,
+            CategoryCode::WNM,
+            WNMActionCode::BSSTransitionManagementRequest,
+        )?;
+        let dialog_token = from.gread(&mut offset)?;
+        let request_mode = BSSTransitionManagementRequestMode::from_bits(from.gread(&mut offset)?);
+        let disassociation_timer = from.gread_with(&mut offset, Endian::Little)?;
+        let validity_interval = from.gread(&mut offset)?;
+        let bss_termination_duration = if request_mode.bss_termination_included() {
+            Some(from.gread(&mut offset)?)
+        } else {
+            None
+        };
+        let session_information_url = if request_mode.ess_disassociation_imminent() {
+            let url_length = from.gread::<u8>(&mut offset)?;
+            Some(from.gread_with(&mut offset, url_length as usize)?)
+        } else {
+            None
+        };
+        let candidate_list = from.gread(&mut offset)?;
+
+        Ok((
+            Self {
+                dialog_token,
+                request_mode,
+                disassociation_timer,
+                validity_interval,
+                bss_termination_duration,
+                session_information_url,
+                candidate_list,
+                _phantom: PhantomData,
+            },
+            offset,
+        ))
+    }
+}
+impl<ElementContainer: MeasureWith<()>> MeasureWith<()>
+    for BSSTransitionManagementRequestBody<'_, ElementContainer>
+{
+    fn measure_with(&self, ctx: &()) -> usize {
+        7 + if self.bss_termination_duration.is_some() {
+            12
+        } else {
+            0
+        } + self
+            .session_information_url
+            .map_or(0, |session_information_url| {
+                1 + session_information_url.len()
+            })
+            + self.candidate_list.measure_with(ctx)
+    }
+}
+impl<ElementContainer: TryIntoCtx<Error = scroll::Error>> TryIntoCtx
+    for BSSTransitionManagementRequestBody<'_, ElementContainer>
+{
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        append_action_header(
+            buf,
+            &mut offset,
+            CategoryCode::WNM,
+            WNMActionCode::BSSTransitionManagementRequest,
+        )?;
+        buf.gwrite(self.dialog_token, &mut offset)?;
+        buf.gwrite(self.request_mode.into_bits(), &mut offset)?;
+        buf.gwrite_with(self.disassociation_timer, &mut offset, Endian::Little)?;
+        buf.gwrite(self.validity_interval, &mut offset)?;
+        if let Some(bss_termination_duration) = self.bss_termination_duration {
+            buf.gwrite(bss_termination_duration, &mut offset)?;
+        }
+        if let Some(session_information_url) = self.session_information_url {
+            buf.gwrite(session_information_url.len() as u8, &mut offset)?;
+            buf.gwrite(session_information_url, &mut offset)?;
+        }
+        buf.gwrite(self.candidate_list, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl<ElementContainer> ActionBody for BSSTransitionManagementRequestBody<'_, ElementContainer> {
+    const CATEGORY_CODE: CategoryCode = CategoryCode::WNM;
+    fn matches(action_body: RawActionBody<'_>) -> bool {
+        action_body_matches(
+            action_body,
+            CategoryCode::WNM,
+            WNMActionCode::BSSTransitionManagementRequest,
+        )
+    }
+}
+pub type BSSTransitionManagementRequestFrame<'a, ElementContainer = ReadElements<'a>> =
+    ManagementFrame<BSSTransitionManagementRequestBody<'a, ElementContainer>>;
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// The status code of a [BSS Transition Management Response](BSSTransitionManagementResponseBody).
+    pub enum BTMStatusCode: u8 {
+        #[default]
+        Accept => 0,
+        RejectUnspecified => 1,
+        RejectInsufficientBeacons => 2,
+        RejectInsufficientCapacity => 3,
+        RejectBSSTerminationUndesired => 4,
+        RejectBSSTerminationDelayRequest => 5,
+        RejectSTABTMCandidateListProvided => 6,
+        RejectNoSuitableCandidates => 7,
+        RejectLeavingESS => 8
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The body of a BSS Transition Management Response frame.
+///
+/// This is sent by a non-AP STA in response to a [BSS Transition Management Request](BSSTransitionManagementRequestBody).
+///
+/// # Note
+/// When reading, the target BSSID is only present, if the status code is [BTMStatusCode::Accept].
+/// When writing, it's written if it's [Some], which isn't validated against the status code.
+pub struct BSSTransitionManagementResponseBody<'a, ElementContainer = ReadElements<'a>> {
+    pub dialog_token: u8,
+    pub status_code: BTMStatusCode,
+    /// The number of minutes, that the STA requests the BSS to delay termination.
+    pub bss_termination_delay: u8,
+    /// The BSSID of the BSS, to which the STA will transition.
+    pub target_bssid: Option<MACAddress>,
+    /// The candidates preferred by the STA, as [Neighbor Report elements](NeighborReportElement).
+    pub candidate_list: ElementContainer,
+    pub _phantom: PhantomData<&'a ()>,
+}
+impl<'a> TryFromCtx<'a> for BSSTransitionManagementResponseBody<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        strip_and_check_action_header(
+            from,
+            &mut offset,
+            CategoryCode::WNM,
+            WNMActionCode::BSSTransitionManagementResponse,
+        )?;
+        let dialog_token = from.gread(&mut offset)?;
+        let status_code = BTMStatusCode::from_bits(from.gread(&mut offset)?);
+        let bss_termination_delay = from.gread(&mut offset)?;
+        let target_bssid = if status_code == BTMStatusCode::Accept {
+            Some(from.gread(&mut offset)?)
+        } else {
+            None
+        };
+        let candidate_list = from.gread(&mut offset)?;
+
+        Ok((
+            Self {
+                dialog_token,
+                status_code,
+                bss_termination_delay,
+                target_bssid,
+                candidate_list,
+                _phantom: PhantomData,
+            },
+            offset,
+        ))
+    }
+}
+impl<ElementContainer: MeasureWith<()>> MeasureWith<()>
+    for BSSTransitionManagementResponseBody<'_, ElementContainer>
+{
+    fn measure_with(&self, ctx: &()) -> usize {
+        5 + if self.target_bssid.is_some() { 6 } else { 0 } + self.candidate_list.measure_with(ctx)
+    }
+}
+impl<ElementContainer: TryIntoCtx<Error = scroll::Error>> TryIntoCtx
+    for BSSTransitionManagementResponseBody<'_, ElementContainer>
+{
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        append_action_header(
+            buf,
+            &mut offset,
+            CategoryCode::WNM,
+            WNMActionCode::BSSTransitionManagementResponse,
+        )?;
+        buf.gwrite(self.dialog_token, &mut offset)?;
+        buf.gwrite(self.status_code.into_bits(), &mut offset)?;
+        buf.gwrite(self.bss_termination_delay, &mut offset)?;
+        if let Some(target_bssid) = self.target_bssid {
+            buf.gwrite(target_bssid, &mut offset)?;
+        }
+        buf.gwrite(self.candidate_list, &mut offset)?;
+
+        Ok(offset)
+    }
+}
+impl<ElementContainer> ActionBody for BSSTransitionManagementResponseBody<'_, ElementContainer> {
+    const CATEGORY_CODE: CategoryCode = CategoryCode::WNM;
+    fn matches(action_body: RawActionBody<'_>) -> bool {
+        action_body_matches(
+            action_body,
+            CategoryCode::WNM,
+            WNMActionCode::BSSTransitionManagementResponse,
+        )
+    }
+}
+pub type BSSTransitionManagementResponseFrame<'a, ElementContainer = ReadElements<'a>> =
+    ManagementFrame<BSSTransitionManagementResponseBody<'a, ElementContainer>>;
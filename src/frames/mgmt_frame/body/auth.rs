@@ -1,5 +1,6 @@
 use core::marker::PhantomData;
 
+use macro_bits::serializable_enum;
 use scroll::{
     ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
     Endian, Pread, Pwrite,
@@ -15,7 +16,8 @@ use crate::{
 /// This is the body of an authentication frame.
 ///
 /// # Note
-/// This is currently only valid for open system authentication, since I haven't worked out a good way for other options yet.
+/// For SAE, the fields following the status code aren't elements, but an [SAEMessage].
+/// When reading, it can be obtained with [sae_message](AuthenticationBody::sae_message) and when writing, it can be used as the `ElementContainer`.
 pub struct AuthenticationBody<'a, ElementContainer = ReadElements<'a>> {
     pub authentication_algorithm_number: IEEE80211AuthenticationAlgorithmNumber,
     pub authentication_transaction_sequence_number: u16,
@@ -23,11 +25,29 @@ pub struct AuthenticationBody<'a, ElementContainer = ReadElements<'a>> {
     pub elements: ElementContainer,
     pub _phantom: PhantomData<&'a ()>,
 }
-impl AuthenticationBody<'_> {
+impl<'a> AuthenticationBody<'a> {
     /// Returns the total length in bytes.
     pub const fn length_in_bytes(&self) -> usize {
         6 + self.elements.bytes.len()
     }
+    /// Parse the SAE message contained in the body.
+    ///
+    /// This returns [None], if the authentication algorithm isn't SAE.
+    /// The returned message borrows the bytes of the frame and not the body.
+    pub fn sae_message(&self) -> Option<Result<SAEMessage<'a>, scroll::Error>> {
+        if self.authentication_algorithm_number
+            != IEEE80211AuthenticationAlgorithmNumber::SimultaneousAuthenticationOfEquals
+        {
+            return None;
+        }
+        Some(self.elements.bytes.pread_with(
+            0,
+            (
+                self.authentication_transaction_sequence_number,
+                self.status_code,
+            ),
+        ))
+    }
 }
 impl<'a> TryFromCtx<'a> for AuthenticationBody<'a> {
     type Error = scroll::Error;
@@ -85,3 +105,211 @@ impl<ElementContainer: MeasureWith<()>> MeasureWith<()>
         6 + self.elements.measure_with(ctx)
     }
 }
+
+serializable_enum! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    /// A finite cyclic group used by SAE, as registered with IANA.
+    pub enum SAEGroup: u16 {
+        #[default]
+        /// 256-bit random ECP group.
+        ECP256 => 19,
+        /// 384-bit random ECP group.
+        ECP384 => 20,
+        /// 521-bit random ECP group.
+        ECP521 => 21
+    }
+}
+impl SAEGroup {
+    /// Returns the length of the prime of the group in bytes.
+    ///
+    /// This is the length of the scalar and half of the length of the finite field element.
+    pub const fn prime_length(&self) -> Option<usize> {
+        match self {
+            Self::ECP256 => Some(32),
+            Self::ECP384 => Some(48),
+            Self::ECP521 => Some(66),
+            Self::Unknown(_) => None,
+        }
+    }
+}
+
+/// The authentication transaction sequence number of an SAE commit message.
+const SAE_COMMIT_SEQUENCE_NUMBER: u16 = 1;
+/// The authentication transaction sequence number of an SAE confirm message.
+const SAE_CONFIRM_SEQUENCE_NUMBER: u16 = 2;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// An SAE commit message.
+pub struct SAECommit<'a> {
+    /// The group used for this SAE exchange.
+    pub group: SAEGroup,
+    /// The anti-clogging token, which was previously requested by the peer.
+    ///
+    /// For H2E, the anti-clogging token is carried in the Anti-Clogging Token Container element instead.
+    pub anti_clogging_token: Option<&'a [u8]>,
+    pub scalar: &'a [u8],
+    /// The element, which is encoded as the x and y coordinates for ECC groups.
+    pub finite_field_element: &'a [u8],
+    /// The elements following the finite field element.
+    ///
+    /// For H2E, these are the Password Identifier, Rejected Groups and Anti-Clogging Token Container elements.
+    pub elements: ReadElements<'a>,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// An SAE confirm message.
+pub struct SAEConfirm<'a> {
+    /// The value of the send-confirm counter.
+    pub send_confirm: u16,
+    /// The confirm hash, which has the length of the digest of the hash function used by the group.
+    pub confirm: &'a [u8],
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The payload of an authentication frame using SAE, as specified in 9.3.3.12 of IEEE 802.11-2020.
+///
+/// Which message is contained, depends on the authentication transaction sequence number and the status code, which are passed as the context while reading.
+/// For H2E, which is indicated by the status code being [SaeHashToElement](IEEE80211StatusCode::SaeHashToElement), the elements following the commit message are available through [SAECommit::elements].
+/// Otherwise, the optional elements following the commit message, like the Password Identifier element, aren't supported and will be treated as part of the anti-clogging token.
+pub enum SAEMessage<'a> {
+    Commit(SAECommit<'a>),
+    /// A request by the peer, to retransmit the commit message with the contained anti-clogging token.
+    AntiCloggingTokenRequest {
+        group: SAEGroup,
+        anti_clogging_token: &'a [u8],
+    },
+    Confirm(SAEConfirm<'a>),
+}
+impl MeasureWith<()> for SAEMessage<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        match self {
+            Self::Commit(commit) => {
+                2 + commit.anti_clogging_token.map_or(0, <[u8]>::len)
+                    + commit.scalar.len()
+                    + commit.finite_field_element.len()
+                    + commit.elements.bytes.len()
+            }
+            Self::AntiCloggingTokenRequest {
+                anti_clogging_token,
+                ..
+            } => 2 + anti_clogging_token.len(),
+            Self::Confirm(confirm) => 2 + confirm.confirm.len(),
+        }
+    }
+}
+impl<'a> TryFromCtx<'a, (u16, IEEE80211StatusCode)> for SAEMessage<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(
+        from: &'a [u8],
+        (authentication_transaction_sequence_number, status_code): (u16, IEEE80211StatusCode),
+    ) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let sae_message = match (authentication_transaction_sequence_number, status_code) {
+            (SAE_COMMIT_SEQUENCE_NUMBER, IEEE80211StatusCode::AntiCloggingTokenRequired) => {
+                let group = SAEGroup::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+                let anti_clogging_token = &from[offset..];
+                offset = from.len();
+                Self::AntiCloggingTokenRequest {
+                    group,
+                    anti_clogging_token,
+                }
+            }
+            (
+                SAE_COMMIT_SEQUENCE_NUMBER,
+                IEEE80211StatusCode::Success | IEEE80211StatusCode::SaeHashToElement,
+            ) => {
+                let group = SAEGroup::from_bits(from.gread_with(&mut offset, Endian::Little)?);
+                let Some(prime_length) = group.prime_length() else {
+                    return Err(scroll::Error::BadInput {
+                        size: offset,
+                        msg: "The SAE group isn't supported.",
+                    });
+                };
+                // For H2E, the anti-clogging token is carried in an element following the commit message.
+                let anti_clogging_token = if status_code == IEEE80211StatusCode::Success {
+                    let remaining = from.len() - offset;
+                    let Some(anti_clogging_token_length) = remaining.checked_sub(3 * prime_length)
+                    else {
+                        return Err(scroll::Error::TooBig {
+                            size: 3 * prime_length,
+                            len: remaining,
+                        });
+                    };
+                    if anti_clogging_token_length != 0 {
+                        Some(from.gread_with(&mut offset, anti_clogging_token_length)?)
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                };
+                let scalar = from.gread_with(&mut offset, prime_length)?;
+                let finite_field_element = from.gread_with(&mut offset, 2 * prime_length)?;
+                let elements = ReadElements {
+                    bytes: &from[offset..],
+                };
+                offset = from.len();
+                Self::Commit(SAECommit {
+                    group,
+                    anti_clogging_token,
+                    scalar,
+                    finite_field_element,
+                    elements,
+                })
+            }
+            (SAE_CONFIRM_SEQUENCE_NUMBER, IEEE80211StatusCode::Success) => {
+                let send_confirm = from.gread_with(&mut offset, Endian::Little)?;
+                let confirm = &from[offset..];
+                offset = from.len();
+                Self::Confirm(SAEConfirm {
+                    send_confirm,
+                    confirm,
+                })
+            }
+            _ => {
+                return Err(scroll::Error::BadInput {
+                    size: 0,
+                    msg: "The sequence number and status code don't indicate an SAE message.",
+                })
+            }
+        };
+
+        Ok((sae_message, offset))
+    }
+}
+impl TryIntoCtx for SAEMessage<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        match self {
+            Self::Commit(commit) => {
+                buf.gwrite_with(commit.group.into_bits(), &mut offset, Endian::Little)?;
+                if let Some(anti_clogging_token) = commit.anti_clogging_token {
+                    buf.gwrite(anti_clogging_token, &mut offset)?;
+                }
+                buf.gwrite(commit.scalar, &mut offset)?;
+                buf.gwrite(commit.finite_field_element, &mut offset)?;
+                buf.gwrite(commit.elements, &mut offset)?;
+            }
+            Self::AntiCloggingTokenRequest {
+                group,
+                anti_clogging_token,
+            } => {
+                buf.gwrite_with(group.into_bits(), &mut offset, Endian::Little)?;
+                buf.gwrite(anti_clogging_token, &mut offset)?;
+            }
+            Self::Confirm(confirm) => {
+                buf.gwrite_with(confirm.send_confirm, &mut offset, Endian::Little)?;
+                buf.gwrite(confirm.confirm, &mut offset)?;
+            }
+        }
+
+        Ok(offset)
+    }
+}
@@ -6,8 +6,15 @@ use scroll::{
 };
 
 use crate::{
-    common::{CapabilitiesInformation, TU},
-    elements::{ReadElements, SSIDElement},
+    common::{BeaconInterval, CapabilitiesInformation},
+    elements::{
+        he::HECapabilitiesElement,
+        ht::{HTCapabilitiesElement, MAXAMpduLength},
+        rsn::{IEEE80211AKMType, RSNElement},
+        tim::TIMElement,
+        vht::VHTCapabilitiesElement,
+        Element, OWETransitionModeElement, RawIEEE80211Element, ReadElements, SSIDElement,
+    },
 };
 
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
@@ -15,13 +22,25 @@ pub struct BeaconSubtype;
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
 pub struct ProbeResponseSubtype;
 
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A transition mode, in which a network allows both legacy and modern STAs to connect.
+pub enum TransitionMode {
+    /// The network accepts both PSK and SAE, with MFP being capable but not required.
+    Wpa2Wpa3,
+    /// The network is the open part of an OWE transition mode pair.
+    ///
+    /// This is also returned for the OWE part of the pair, since it carries the same element.
+    Owe,
+}
+
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
 /// This is a generic body of a beacon like frame. This includes beacons and probe responses.
 pub struct BeaconLikeBody<'a, Subtype, ElementContainer = ReadElements<'a>> {
     /// The amount of µs since the BSS was started.
     /// Use [Self::timestamp_as_duration] to get a [Duration].
     pub timestamp: u64,
-    /// The time that passes, between two consecutive beacons in [TU]s.
+    /// The time that passes, between two consecutive beacons in [TU](crate::common::TU)s.
     /// This is almost always set to `100 TUs`, which is 102.4 ms.
     pub beacon_interval: u16,
     /// The capabilities of the BSS.
@@ -45,11 +64,109 @@ impl<'a, Subtype> BeaconLikeBody<'a, Subtype> {
             .get_first_element::<SSIDElement>()
             .map(SSIDElement::take_ssid)
     }
+    /// Returns the maximum A-MPDU length in bytes, which the BSS can receive.
+    ///
+    /// This is read from the [VHTCapabilitiesElement] if present and otherwise from the [HTCapabilitiesElement].
+    /// If the exponent is at it's maximum, it is extended by the Maximum A-MPDU Length Exponent Extension from the [HECapabilitiesElement].
+    pub fn max_ampdu_length(&self) -> Option<usize> {
+        let exponent_extension = self
+            .elements
+            .get_first_element::<HECapabilitiesElement>()
+            .map(|he_capabilities| {
+                he_capabilities
+                    .he_mac_capabilities_information
+                    .maximum_ampdu_length_exponent_extension()
+            })
+            .unwrap_or_default();
+        let exponent = if let Some(vht_capabilities) =
+            self.elements.get_first_element::<VHTCapabilitiesElement>()
+        {
+            let exponent = vht_capabilities
+                .vht_capabilities_info
+                .maximum_ampdu_length_exponent();
+            if exponent == 7 {
+                exponent + exponent_extension
+            } else {
+                exponent
+            }
+        } else {
+            let max_a_mpdu_length = self
+                .elements
+                .get_first_element::<HTCapabilitiesElement>()?
+                .ampdu_parameters
+                .max_a_mpdu_length();
+            match max_a_mpdu_length {
+                MAXAMpduLength::VeryLarge => max_a_mpdu_length.into_bits() + exponent_extension,
+                MAXAMpduLength::Unknown(_) => return None,
+                _ => max_a_mpdu_length.into_bits(),
+            }
+        };
+        Some((1 << (13 + exponent as usize)) - 1)
+    }
+    /// Detect, if the network is operating in a [TransitionMode].
+    ///
+    /// OWE transition mode is detected through the presence of the [OWETransitionModeElement].
+    /// WPA2/WPA3 transition mode requires the [RSNElement] to advertise both a PSK and an SAE AKM, with MFP being enabled but not required.
+    pub fn transition_mode(&self) -> Option<TransitionMode> {
+        if self
+            .elements
+            .get_first_element::<OWETransitionModeElement>()
+            .is_some()
+        {
+            return Some(TransitionMode::Owe);
+        }
+        let rsn = self.elements.get_first_element::<RSNElement>()?;
+        let rsn_capabilities = rsn.rsn_capbilities?;
+        let akm_list = rsn.akm_list?;
+        let has_psk = akm_list.clone().any(|akm| {
+            matches!(
+                akm,
+                IEEE80211AKMType::Psk | IEEE80211AKMType::PskSha256 | IEEE80211AKMType::FTUsingPsk
+            )
+        });
+        let has_sae = akm_list.clone().any(|akm| {
+            matches!(
+                akm,
+                IEEE80211AKMType::Sae
+                    | IEEE80211AKMType::FTUsingSae
+                    | IEEE80211AKMType::SaeGroupDefend
+                    | IEEE80211AKMType::FTUsingSaeGroupDefend
+            )
+        });
+        if has_psk && has_sae && rsn_capabilities.mfp_enabled() && !rsn_capabilities.mfp_required()
+        {
+            Some(TransitionMode::Wpa2Wpa3)
+        } else {
+            None
+        }
+    }
+    /// Compare the contents of two beacon like bodies, while ignoring volatile fields.
+    ///
+    /// This ignores the [Self::timestamp] and the [TIMElement], since both change with every beacon.
+    /// It can be used to detect, if the configuration advertised by an AP has changed.
+    pub fn content_eq(&self, other: &Self) -> bool {
+        let is_not_tim = |raw_element: &RawIEEE80211Element<'_>| {
+            !ReadElements::element_id_matches(raw_element, <TIMElement as Element>::ELEMENT_ID)
+        };
+        self.beacon_interval == other.beacon_interval
+            && self.capabilities_info == other.capabilities_info
+            && self
+                .elements
+                .raw_element_iterator()
+                .filter(is_not_tim)
+                .eq(other.elements.raw_element_iterator().filter(is_not_tim))
+    }
 }
 impl<Subtype, ElementContainer> BeaconLikeBody<'_, Subtype, ElementContainer> {
-    /// Returns the [Self::beacon_interval] as a [Duration],
+    /// Returns the beacon interval in [TU](crate::common::TU)s.
+    ///
+    /// This is the raw value, as it's transmitted in the frame. Use [Self::beacon_interval_as_duration] to get a [Duration].
+    pub const fn beacon_interval(&self) -> u16 {
+        self.beacon_interval
+    }
+    /// Returns the beacon interval as a [Duration],
     pub const fn beacon_interval_as_duration(&self) -> Duration {
-        Duration::from_micros(self.beacon_interval as u64 * TU.as_micros() as u64)
+        BeaconInterval(self.beacon_interval).as_duration()
     }
     /// Returns the [Self::timestamp] as a [Duration].
     pub const fn timestamp_as_duration(&self) -> Duration {
@@ -70,7 +187,7 @@ impl<Subtype, ElementContainer: defmt::Format> defmt::Format
                 elements: {}
             }}",
             self.timestamp,
-            (self.beacon_interval as u32 * TU.as_micros() as u32),
+            (self.beacon_interval_as_duration().as_micros() as u32),
             self.capabilities_info,
             self.elements
         )
@@ -141,3 +258,23 @@ impl<ElementContainer: TryIntoCtx<Error = scroll::Error>, Subtype> TryIntoCtx
 /// This is derived from a [generic type](BeaconLikeBody) over beacon like frames, since Beacons and Probe Responses have exactly the same frame format.
 pub type BeaconBody<'a, ElementContainer = ReadElements<'a>> =
     BeaconLikeBody<'a, BeaconSubtype, ElementContainer>;
+
+impl BeaconBody<'_> {
+    /// Extract the DTIM period from the [TIMElement].
+    ///
+    /// This returns [None], if no [TIMElement] is present.
+    pub fn dtim_period(&self) -> Option<u8> {
+        self.elements
+            .get_first_element::<TIMElement>()
+            .map(|tim_element| tim_element.dtim_period)
+    }
+    /// Calculate the time until the next DTIM beacon, relative to this beacon.
+    ///
+    /// This is the [DTIM count](TIMElement::dtim_count) multiplied by the beacon interval, so it will be zero, if this beacon is a DTIM beacon.
+    /// This returns [None], if no [TIMElement] is present.
+    pub fn time_to_next_dtim(&self) -> Option<Duration> {
+        self.elements
+            .get_first_element::<TIMElement>()
+            .map(|tim_element| self.beacon_interval_as_duration() * tim_element.dtim_count as u32)
+    }
+}
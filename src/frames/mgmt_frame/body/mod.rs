@@ -2,13 +2,16 @@ pub mod action;
 
 mod beacon;
 use action::{ActionBody, RawActionBody};
-pub use beacon::{BeaconBody, BeaconLikeBody, BeaconSubtype, ProbeResponseSubtype};
+pub use beacon::{BeaconBody, BeaconLikeBody, BeaconSubtype, ProbeResponseSubtype, TransitionMode};
 
 mod disassoc;
 pub use disassoc::DisassociationBody;
 
 mod probe;
-pub use probe::{ProbeRequestBody, ProbeResponseBody};
+pub use probe::{
+    BssConfig, ProbeRequestBody, ProbeRequestBuilder, ProbeRequestElements, ProbeResponseBody,
+    ProbeResponseElements,
+};
 
 mod assoc;
 pub use assoc::{AssociationRequestBody, AssociationResponseBody};
@@ -17,7 +20,7 @@ mod deauth;
 pub use deauth::DeauthenticationBody;
 
 mod auth;
-pub use auth::AuthenticationBody;
+pub use auth::{AuthenticationBody, SAECommit, SAEConfirm, SAEGroup, SAEMessage};
 
 use crate::common::ManagementFrameSubtype;
 
@@ -1,11 +1,29 @@
-use core::marker::PhantomData;
+use core::{
+    iter::{Skip, Take},
+    marker::PhantomData,
+};
+
+use mac_parser::{MACAddress, BROADCAST};
 
 use scroll::{
     ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
     Pread,
 };
 
-use crate::elements::{ReadElements, SSIDElement};
+use crate::{
+    common::CapabilitiesInformation,
+    elements::{
+        element_chain::{ElementChainEnd, ElementChainLink},
+        ht::HTCapabilitiesElement,
+        rates::{
+            EncodedRate, ExtendedSupportedRatesElement, RatesReadIterator, SupportedRatesElement,
+        },
+        rsn::RSNElement,
+        vht::VHTCapabilitiesElement,
+        DSSSParameterSetElement, Element, ReadElements, SSIDElement,
+    },
+    mgmt_frame::{ManagementFrameHeader, ProbeRequestFrame},
+};
 
 use super::{beacon::ProbeResponseSubtype, BeaconLikeBody};
 
@@ -57,5 +75,195 @@ impl<ElementContainer: TryIntoCtx<Error = scroll::Error>> TryIntoCtx
         <ElementContainer as TryIntoCtx>::try_into_ctx(self.elements, buf, ctx)
     }
 }
+/// The elements of a probe request generated by [ProbeRequestBuilder].
+pub type ProbeRequestElements<'a, Rates> = ElementChainLink<
+    SSIDElement<'a>,
+    ElementChainLink<
+        SupportedRatesElement<'a, Take<Rates>>,
+        ElementChainLink<
+            Option<ExtendedSupportedRatesElement<'a, Skip<Rates>>>,
+            ElementChainLink<
+                Option<DSSSParameterSetElement>,
+                ElementChainLink<
+                    Option<HTCapabilitiesElement>,
+                    ElementChainEnd<Option<VHTCapabilitiesElement>>,
+                >,
+            >,
+        >,
+    >,
+>;
+
+#[derive(Clone, Copy, Debug, Hash)]
+/// A builder for probe requests.
+///
+/// The elements are always written in the order specified in Table 9-33 of IEEE 802.11-2020, since some APs don't respond to probe requests with an unexpected element order.
+/// The rate set is split into the Supported Rates element, which holds the first eight rates, and the Extended Supported Rates element, which holds the rest and is only present, if there are more than eight rates.
+pub struct ProbeRequestBuilder<'a, Rates: IntoIterator<Item = EncodedRate>> {
+    transmitter_address: MACAddress,
+    bssid: MACAddress,
+    ssid: SSIDElement<'a>,
+    rates: Rates,
+    channel: Option<u8>,
+    ht_capabilities: Option<HTCapabilitiesElement>,
+    vht_capabilities: Option<VHTCapabilitiesElement>,
+}
+impl<'a, Rates> ProbeRequestBuilder<'a, Rates>
+where
+    Rates: IntoIterator<Item = EncodedRate>,
+    Rates::IntoIter: Clone,
+{
+    /// Create a new builder for a broadcast probe request with the wildcard SSID.
+    pub const fn new(transmitter_address: MACAddress, rates: Rates) -> Self {
+        Self {
+            transmitter_address,
+            bssid: BROADCAST,
+            ssid: SSIDElement::new_unchecked(""),
+            rates,
+            channel: None,
+            ht_capabilities: None,
+            vht_capabilities: None,
+        }
+    }
+    /// Request a specific SSID, instead of the wildcard SSID.
+    pub fn ssid(mut self, ssid: SSIDElement<'a>) -> Self {
+        self.ssid = ssid;
+        self
+    }
+    /// Direct the probe request at a specific BSS.
+    ///
+    /// This sets both the receiver address and the BSSID.
+    pub fn bssid(mut self, bssid: MACAddress) -> Self {
+        self.bssid = bssid;
+        self
+    }
+    /// Include a DSSS Parameter Set element with the channel, on which the probe request is transmitted.
+    pub fn channel(mut self, channel: u8) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+    /// Include the HT capabilities of the STA.
+    pub fn ht_capabilities(mut self, ht_capabilities: HTCapabilitiesElement) -> Self {
+        self.ht_capabilities = Some(ht_capabilities);
+        self
+    }
+    /// Include the VHT capabilities of the STA.
+    pub fn vht_capabilities(mut self, vht_capabilities: VHTCapabilitiesElement) -> Self {
+        self.vht_capabilities = Some(vht_capabilities);
+        self
+    }
+    /// Build the probe request.
+    pub fn build(self) -> ProbeRequestFrame<'a, ProbeRequestElements<'a, Rates::IntoIter>> {
+        let rates = self.rates.into_iter();
+        let extended_supported_rates = if rates.clone().nth(8).is_some() {
+            Some(ExtendedSupportedRatesElement::new_unchecked(
+                rates.clone().skip(8),
+            ))
+        } else {
+            None
+        };
+        ProbeRequestFrame {
+            header: ManagementFrameHeader {
+                receiver_address: self.bssid,
+                transmitter_address: self.transmitter_address,
+                bssid: self.bssid,
+                ..Default::default()
+            },
+            body: ProbeRequestBody {
+                elements: ElementChainLink {
+                    inner: self.ssid,
+                    next: ElementChainLink {
+                        inner: SupportedRatesElement::new_unchecked(rates.take(8)),
+                        next: ElementChainLink {
+                            inner: extended_supported_rates,
+                            next: ElementChainLink {
+                                inner: self.channel.map(|current_channel| {
+                                    DSSSParameterSetElement { current_channel }
+                                }),
+                                next: ElementChainLink {
+                                    inner: self.ht_capabilities,
+                                    next: ElementChainEnd {
+                                        inner: self.vht_capabilities,
+                                    },
+                                },
+                            },
+                        },
+                    },
+                },
+                _phantom: PhantomData,
+            },
+        }
+    }
+}
+
 pub type ProbeResponseBody<'a, ElementContainer = ReadElements<'a>> =
     BeaconLikeBody<'a, ProbeResponseSubtype, ElementContainer>;
+
+#[derive(Clone, Copy, Debug, Default, Hash)]
+/// The parameters of a BSS, which are required to respond to a probe request.
+pub struct BssConfig<'a, Rates = RatesReadIterator<'a>, RSN = RSNElement<'a>>
+where
+    Rates: IntoIterator<Item = EncodedRate>,
+{
+    /// The SSID of the BSS.
+    pub ssid: SSIDElement<'a>,
+    /// The channel, on which the BSS is operating.
+    pub channel: u8,
+    /// The time between two consecutive beacons in TUs.
+    pub beacon_interval: u16,
+    /// The capabilities of the BSS.
+    pub capabilities_info: CapabilitiesInformation,
+    /// The rates supported by the BSS.
+    pub supported_rates: SupportedRatesElement<'a, Rates>,
+    /// The RSN element of the BSS, if the BSS is protected.
+    pub rsn: Option<RSN>,
+}
+
+/// The elements of a probe response generated by [ProbeResponseBody::respond_to].
+pub type ProbeResponseElements<'a, Rates = RatesReadIterator<'a>, RSN = RSNElement<'a>> =
+    ElementChainLink<
+        SSIDElement<'a>,
+        ElementChainLink<
+            SupportedRatesElement<'a, Rates>,
+            ElementChainLink<DSSSParameterSetElement, ElementChainEnd<Option<RSN>>>,
+        >,
+    >;
+
+impl<'a, Rates, RSN> ProbeResponseBody<'a, ProbeResponseElements<'a, Rates, RSN>>
+where
+    Rates: IntoIterator<Item = EncodedRate> + Clone,
+    RSN: Element + Clone,
+{
+    /// Generate a probe response to the probe request, based on the configuration of the BSS.
+    ///
+    /// This returns [None], if the requested SSID is neither the wildcard SSID nor the SSID of the BSS.
+    /// The timestamp is set to zero, since it's usually filled in by the hardware.
+    pub fn respond_to(
+        request: &ProbeRequestBody<'_>,
+        config: &BssConfig<'a, Rates, RSN>,
+    ) -> Option<Self> {
+        let requested_ssid = request.ssid()?;
+        if !requested_ssid.is_empty() && requested_ssid != config.ssid.ssid() {
+            return None;
+        }
+        Some(Self {
+            timestamp: 0,
+            beacon_interval: config.beacon_interval,
+            capabilities_info: config.capabilities_info,
+            elements: ElementChainLink {
+                inner: config.ssid,
+                next: ElementChainLink {
+                    inner: config.supported_rates.clone(),
+                    next: ElementChainLink {
+                        inner: DSSSParameterSetElement {
+                            current_channel: config.channel,
+                        },
+                        next: ElementChainEnd {
+                            inner: config.rsn.clone(),
+                        },
+                    },
+                },
+            },
+            _phantom: PhantomData,
+        })
+    }
+}
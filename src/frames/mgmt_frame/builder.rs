@@ -0,0 +1,176 @@
+//! This module contains a type state based builder for beacons.
+//!
+//! The type state ensures, that the BSSID and SSID are set, before the beacon can be built.
+//! ## Usage
+//! To create a [BeaconFrame] with the builder you can use the following scheme:
+//! 1. Create a [BeaconBuilder] with [new](BeaconBuilder::new).
+//! 2. Specify the BSSID and SSID using [bssid](BeaconBuilderInner::bssid) and [ssid](BeaconBuilderInner::ssid).
+//! 3. Optionally specify the elements following the SSID using [elements](BeaconBuilderInner::elements), as well as the [beacon_interval](BeaconBuilderInner::beacon_interval), [capabilities_info](BeaconBuilderInner::capabilities_info) and [timestamp](BeaconBuilderInner::timestamp).
+//! 4. Call [build](BeaconBuilderInner::build).
+//!
+//! By default, the beacon interval is 100 TUs, only the ESS bit of the capabilities is set and the timestamp is zero.
+//! ## Example
+//! ```
+//! use ieee80211::{
+//!     element_chain,
+//!     elements::DSSSParameterSetElement,
+//!     mac_parser::MACAddress,
+//!     mgmt_frame::builder::BeaconBuilder,
+//!     ssid, supported_rates,
+//! };
+//!
+//! const AP_MAC_ADDRESS: MACAddress = MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x01]);
+//!
+//! let _beacon = BeaconBuilder::new()
+//!     .bssid(AP_MAC_ADDRESS)
+//!     .ssid(ssid!("OpenRF"))
+//!     .elements(element_chain! {
+//!         supported_rates![1 B, 2 B, 5.5 B, 11 B],
+//!         DSSSParameterSetElement {
+//!             current_channel: 6
+//!         }
+//!     })
+//!     .build();
+//! ```
+
+use core::marker::PhantomData;
+
+use mac_parser::{MACAddress, BROADCAST};
+
+use crate::{
+    common::{CapabilitiesInformation, Empty, FCFFlags, SequenceControl},
+    elements::{
+        element_chain::{ChainElement, ElementChainLink},
+        SSIDElement,
+    },
+};
+
+use super::{body::BeaconBody, BeaconFrame, ManagementFrame, ManagementFrameHeader};
+
+/// A type state based beacon builder.
+pub struct BeaconBuilderInner<'a, BSSID, SSID, Elements> {
+    bssid: BSSID,
+    ssid: SSID,
+    elements: Elements,
+    timestamp: u64,
+    beacon_interval: u16,
+    capabilities_info: CapabilitiesInformation,
+    _phantom: PhantomData<&'a ()>,
+}
+impl<'a> BeaconBuilderInner<'a, (), (), Empty> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            bssid: (),
+            ssid: (),
+            elements: Empty,
+            timestamp: 0,
+            beacon_interval: 100,
+            capabilities_info: CapabilitiesInformation::new().with_is_ess(true),
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<'a, SSID, Elements> BeaconBuilderInner<'a, (), SSID, Elements> {
+    /// Set the BSSID, which is also used as the transmitter address.
+    pub fn bssid(self, bssid: MACAddress) -> BeaconBuilderInner<'a, MACAddress, SSID, Elements> {
+        BeaconBuilderInner {
+            bssid,
+            ssid: self.ssid,
+            elements: self.elements,
+            timestamp: self.timestamp,
+            beacon_interval: self.beacon_interval,
+            capabilities_info: self.capabilities_info,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<'a, BSSID, Elements> BeaconBuilderInner<'a, BSSID, (), Elements> {
+    /// Set the SSID, which will be the first element of the beacon.
+    pub fn ssid<SSID: AsRef<str>>(
+        self,
+        ssid: SSIDElement<'a, SSID>,
+    ) -> BeaconBuilderInner<'a, BSSID, SSIDElement<'a, SSID>, Elements> {
+        BeaconBuilderInner {
+            bssid: self.bssid,
+            ssid,
+            elements: self.elements,
+            timestamp: self.timestamp,
+            beacon_interval: self.beacon_interval,
+            capabilities_info: self.capabilities_info,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<'a, BSSID, SSID> BeaconBuilderInner<'a, BSSID, SSID, Empty> {
+    /// Set the elements, which follow the SSID.
+    ///
+    /// These are usually generated with [element_chain](crate::element_chain).
+    pub fn elements<Elements: ChainElement>(
+        self,
+        elements: Elements,
+    ) -> BeaconBuilderInner<'a, BSSID, SSID, Elements> {
+        BeaconBuilderInner {
+            bssid: self.bssid,
+            ssid: self.ssid,
+            elements,
+            timestamp: self.timestamp,
+            beacon_interval: self.beacon_interval,
+            capabilities_info: self.capabilities_info,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<BSSID, SSID, Elements> BeaconBuilderInner<'_, BSSID, SSID, Elements> {
+    /// Set the timestamp in µs.
+    pub fn timestamp(mut self, timestamp: u64) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+    /// Set the beacon interval in TUs.
+    pub fn beacon_interval(mut self, beacon_interval: u16) -> Self {
+        self.beacon_interval = beacon_interval;
+        self
+    }
+    /// Set the capabilities of the BSS.
+    pub fn capabilities_info(mut self, capabilities_info: CapabilitiesInformation) -> Self {
+        self.capabilities_info = capabilities_info;
+        self
+    }
+}
+impl<'a, SSID: AsRef<str>, Elements: ChainElement>
+    BeaconBuilderInner<'a, MACAddress, SSIDElement<'a, SSID>, Elements>
+{
+    /// Build the beacon.
+    ///
+    /// The beacon is addressed to the broadcast address.
+    pub fn build(self) -> BeaconFrame<'a, ElementChainLink<SSIDElement<'a, SSID>, Elements>> {
+        ManagementFrame {
+            header: ManagementFrameHeader {
+                fcf_flags: FCFFlags::new(),
+                duration: 0,
+                receiver_address: BROADCAST,
+                transmitter_address: self.bssid,
+                bssid: self.bssid,
+                sequence_control: SequenceControl::new(),
+                ht_control: None,
+            },
+            body: BeaconBody {
+                timestamp: self.timestamp,
+                beacon_interval: self.beacon_interval,
+                capabilities_info: self.capabilities_info,
+                elements: ElementChainLink {
+                    inner: self.ssid,
+                    next: self.elements,
+                },
+                _phantom: PhantomData,
+            },
+        }
+    }
+}
+impl Default for BeaconBuilder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+pub type BeaconBuilder<'a> = BeaconBuilderInner<'a, (), (), Empty>;
@@ -83,3 +83,112 @@ impl TryIntoCtx for ManagementFrameHeader {
         Ok(offset)
     }
 }
+
+/// A type state based builder for a [ManagementFrameHeader].
+///
+/// In management frames, the receiver address is the destination address (DA) and the transmitter address is the source address (SA).
+/// The type state ensures, that the DA, SA and BSSID are set, before the header can be built.
+/// By default, the duration and sequence control are zero and no flags are set.
+pub struct ManagementFrameHeaderBuilderInner<DA, SA, BSSID> {
+    destination_address: DA,
+    source_address: SA,
+    bssid: BSSID,
+    fcf_flags: FCFFlags,
+    duration: u16,
+    sequence_control: SequenceControl,
+}
+impl ManagementFrameHeaderBuilderInner<(), (), ()> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            destination_address: (),
+            source_address: (),
+            bssid: (),
+            fcf_flags: FCFFlags::new(),
+            duration: 0,
+            sequence_control: SequenceControl::new(),
+        }
+    }
+}
+impl<SA, BSSID> ManagementFrameHeaderBuilderInner<(), SA, BSSID> {
+    /// Set the destination address (DA), which is used as the receiver address.
+    pub fn destination_address(
+        self,
+        destination_address: MACAddress,
+    ) -> ManagementFrameHeaderBuilderInner<MACAddress, SA, BSSID> {
+        ManagementFrameHeaderBuilderInner {
+            destination_address,
+            source_address: self.source_address,
+            bssid: self.bssid,
+            fcf_flags: self.fcf_flags,
+            duration: self.duration,
+            sequence_control: self.sequence_control,
+        }
+    }
+}
+impl<DA, BSSID> ManagementFrameHeaderBuilderInner<DA, (), BSSID> {
+    /// Set the source address (SA), which is used as the transmitter address.
+    pub fn source_address(
+        self,
+        source_address: MACAddress,
+    ) -> ManagementFrameHeaderBuilderInner<DA, MACAddress, BSSID> {
+        ManagementFrameHeaderBuilderInner {
+            destination_address: self.destination_address,
+            source_address,
+            bssid: self.bssid,
+            fcf_flags: self.fcf_flags,
+            duration: self.duration,
+            sequence_control: self.sequence_control,
+        }
+    }
+}
+impl<DA, SA> ManagementFrameHeaderBuilderInner<DA, SA, ()> {
+    /// Set the BSSID.
+    pub fn bssid(self, bssid: MACAddress) -> ManagementFrameHeaderBuilderInner<DA, SA, MACAddress> {
+        ManagementFrameHeaderBuilderInner {
+            destination_address: self.destination_address,
+            source_address: self.source_address,
+            bssid,
+            fcf_flags: self.fcf_flags,
+            duration: self.duration,
+            sequence_control: self.sequence_control,
+        }
+    }
+}
+impl<DA, SA, BSSID> ManagementFrameHeaderBuilderInner<DA, SA, BSSID> {
+    /// Set the flags of the frame control field.
+    pub const fn fcf_flags(mut self, fcf_flags: FCFFlags) -> Self {
+        self.fcf_flags = fcf_flags;
+        self
+    }
+    /// Set the duration in µs.
+    pub const fn duration(mut self, duration: u16) -> Self {
+        self.duration = duration;
+        self
+    }
+    /// Set the sequence control.
+    pub const fn sequence_control(mut self, sequence_control: SequenceControl) -> Self {
+        self.sequence_control = sequence_control;
+        self
+    }
+}
+impl ManagementFrameHeaderBuilderInner<MACAddress, MACAddress, MACAddress> {
+    /// Build the header.
+    pub const fn build(self) -> ManagementFrameHeader {
+        ManagementFrameHeader {
+            fcf_flags: self.fcf_flags,
+            duration: self.duration,
+            receiver_address: self.destination_address,
+            transmitter_address: self.source_address,
+            bssid: self.bssid,
+            sequence_control: self.sequence_control,
+            ht_control: None,
+        }
+    }
+}
+impl Default for ManagementFrameHeaderBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+pub type ManagementFrameHeaderBuilder = ManagementFrameHeaderBuilderInner<(), (), ()>;
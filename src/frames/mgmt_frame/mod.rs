@@ -31,15 +31,25 @@ use scroll::{
     Endian, Pread, Pwrite,
 };
 
+#[cfg(feature = "alloc")]
+use crate::elements::ElementEditor;
 use crate::{
-    common::{attach_fcs, strip_and_validate_fcs, FrameControlField, FrameType},
+    common::{
+        attach_fcs, strip_and_validate_fcs, Fcs, FrameControlField, FrameType,
+        ManagementFrameSubtype,
+    },
     elements::{Element, ReadElements, WrappedIEEE80211Element},
     IEEE80211Frame,
 };
+#[cfg(feature = "alloc")]
+use body::HasElements;
 
 pub mod body;
+pub mod builder;
 mod header;
-pub use header::ManagementFrameHeader;
+pub use header::{
+    ManagementFrameHeader, ManagementFrameHeaderBuilder, ManagementFrameHeaderBuilderInner,
+};
 
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
@@ -57,6 +67,71 @@ impl<Body: TryIntoCtx<Error = scroll::Error> + ManagementFrameBody> ManagementFr
         DynamicManagementFrame::new(self, buffer)
     }
 }
+#[cfg(feature = "alloc")]
+impl<'a, Body> ManagementFrame<Body>
+where
+    Body: TryIntoCtx<Error = scroll::Error>
+        + MeasureWith<()>
+        + ManagementFrameBody
+        + HasElements<ReadElements<'a>>
+        + Copy,
+{
+    /// Create a new frame with modified elements.
+    ///
+    /// The elements of the frame are copied into an [ElementEditor], which is then passed to `f`.
+    /// The returned [EditedManagementFrame] contains the header and fixed fields of the frame, followed by the edited elements, and can be parsed as the same frame type again.
+    pub fn edit_elements(
+        &self,
+        f: impl FnOnce(&mut ElementEditor),
+    ) -> Result<EditedManagementFrame, scroll::Error> {
+        let mut editor = ElementEditor::new(*self.body.get_elements());
+        f(&mut editor);
+
+        let mut bytes = alloc::vec![0x00; self.measure_with(&false)];
+        let written = bytes.pwrite_with(*self, 0, false)?;
+        bytes.truncate(written - self.body.get_elements().bytes.len());
+        bytes.extend(editor.into_bytes());
+        Ok(EditedManagementFrame { bytes })
+    }
+}
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// A management frame, which was created by [ManagementFrame::edit_elements].
+///
+/// It owns the serialized frame, from which a [ManagementFrame] borrowing it can be parsed with [Self::frame].
+/// No FCS is attached.
+pub struct EditedManagementFrame {
+    bytes: alloc::vec::Vec<u8>,
+}
+#[cfg(feature = "alloc")]
+impl EditedManagementFrame {
+    /// Parse the frame.
+    ///
+    /// The body type should be the same as that of the frame, which was edited.
+    pub fn frame<'a, Body: TryFromCtx<'a, (), Error = scroll::Error>>(
+        &'a self,
+    ) -> Result<ManagementFrame<Body>, scroll::Error> {
+        self.bytes.pread_with(0, false)
+    }
+    /// Get the serialized frame.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+    /// Take the serialized frame out.
+    pub fn into_bytes(self) -> alloc::vec::Vec<u8> {
+        self.bytes
+    }
+}
+impl<Body: ManagementFrameBody> ManagementFrame<Body> {
+    /// Returns the [ManagementFrameSubtype] of the body.
+    pub const fn subtype(&self) -> ManagementFrameSubtype {
+        Body::SUBTYPE
+    }
+    /// Returns the [FrameType] of the frame.
+    pub const fn frame_type(&self) -> FrameType {
+        FrameType::Management(Body::SUBTYPE)
+    }
+}
 impl<Body: ManagementFrameBody> IEEE80211Frame for ManagementFrame<Body> {
     const TYPE: FrameType = FrameType::Management(Body::SUBTYPE);
     fn read_action_body_matches(action_body: RawActionBody<'_>) -> bool {
@@ -192,12 +267,28 @@ impl<'a> DynamicManagementFrame<'a> {
     /// # Returns
     /// If `with_fcs` is `true` and the remaining length of the buffer is less then four, an error will be returned.
     /// Otherwise, this will always return [Ok].
-    pub fn finish(mut self, with_fcs: bool) -> Result<usize, scroll::Error> {
-        if with_fcs {
-            self.buffer.gwrite(
-                crc32fast::hash(&self.buffer[..self.offset]),
-                &mut self.offset,
-            )?;
+    pub fn finish(self, with_fcs: bool) -> Result<usize, scroll::Error> {
+        let fcs = if with_fcs {
+            let mut fcs = Fcs::new();
+            fcs.update(&self.buffer[..self.offset]);
+            Some(fcs.finalize())
+        } else {
+            None
+        };
+        self.finish_with_fcs(fcs)
+    }
+    /// Finish writing the dynamic frame, with a precomputed FCS.
+    ///
+    /// If `fcs` is [Some], it's attached to the end of the frame as is, without being validated.
+    /// This is useful, if the FCS was already calculated incrementally with an [Fcs] accumulator, while the frame was assembled.
+    ///
+    /// # Returns
+    /// If `fcs` is [Some] and the remaining length of the buffer is less then four, an error will be returned.
+    /// Otherwise, this will always return [Ok].
+    pub fn finish_with_fcs(mut self, fcs: Option<u32>) -> Result<usize, scroll::Error> {
+        if let Some(fcs) = fcs {
+            self.buffer
+                .gwrite_with(fcs, &mut self.offset, Endian::Little)?;
         }
         Ok(self.offset)
     }
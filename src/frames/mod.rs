@@ -1,9 +1,14 @@
+use core::hash::{Hash, Hasher};
+
+use control_frame::{BlockAckControl, BlockAckType};
+use data_frame::header::QoSControl;
 use mac_parser::MACAddress;
 use mgmt_frame::{body::action::RawActionBody, RawActionFrame};
-use scroll::{ctx::TryFromCtx, Endian, Pread};
+use scroll::{ctx::TryFromCtx, Endian, Pread, Pwrite};
 
 use crate::common::{
-    strip_and_validate_fcs, FrameControlField, FrameType, ManagementFrameSubtype, SequenceControl,
+    attach_fcs, strip_and_validate_fcs, strip_fcs, ControlFrameSubtype, FrameControlField,
+    FrameType, ManagementFrameSubtype, SequenceControl,
 };
 
 /// Support for control frames.
@@ -26,31 +31,188 @@ pub trait IEEE80211Frame {
         false
     }
 }
+#[cfg(feature = "radiotap")]
+/// Calculate the length of the MAC header from the raw frame control field.
+///
+/// This returns [None] for control frames, since they don't have a frame body.
+fn mac_header_length(fcf: u16) -> Option<usize> {
+    let fcf = FrameControlField::from_bits(fcf);
+    let flags = fcf.flags();
+    match fcf.frame_type() {
+        FrameType::Management(_) => Some(if flags.order() { 28 } else { 24 }),
+        FrameType::Data(subtype) => {
+            let mut length = 24;
+            if flags.to_ds() && flags.from_ds() {
+                length += 6;
+            }
+            if subtype.is_qos() {
+                length += 2;
+                if flags.order() {
+                    length += 4;
+                }
+            }
+            Some(length)
+        }
+        _ => None,
+    }
+}
 /// A generic IEEE 802.11 frame.
 ///
 /// This allows extraction of certain fields, without knowing the actual type.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Equality and hashing only take the bytes of the frame into account, so the same frame compares equal, regardless of whether it was created with an FCS or not.
+#[derive(Clone, Copy, Debug)]
 pub struct GenericFrame<'a> {
     bytes: &'a [u8],
+    fcs: Option<u32>,
+}
+impl PartialEq for GenericFrame<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+impl Eq for GenericFrame<'_> {}
+impl Hash for GenericFrame<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bytes.hash(state);
+    }
 }
 impl<'a> GenericFrame<'a> {
     /// Create a new [GenericFrame].
     ///
     /// If `with_fcs` is true, the fcs will be validated and internally stripped from the bytes
-    /// slice.
+    /// slice. It can still be retrieved through [Self::fcs].
+    /// The shortest valid frames are CTS and Ack frames, which are 10 bytes long, so any slice
+    /// shorter than that (after stripping the FCS) is rejected.
     pub fn new(bytes: &'a [u8], with_fcs: bool) -> Result<Self, scroll::Error> {
-        let bytes = if with_fcs {
-            strip_and_validate_fcs(bytes)?
+        let (bytes, fcs) = if with_fcs {
+            let stripped_bytes = strip_and_validate_fcs(bytes)?;
+            (
+                stripped_bytes,
+                Some(bytes.pread_with(stripped_bytes.len(), Endian::Little)?),
+            )
         } else {
-            bytes
+            (bytes, None)
         };
+        Self::from_stripped_bytes(bytes, fcs)
+    }
+    /// Create a new [GenericFrame] from bytes with an FCS, without validating the FCS.
+    ///
+    /// The FCS is stripped from the bytes slice and returned alongside the frame, so that frames with an invalid FCS can still be inspected.
+    /// Use [Self::fcs_valid] on the original bytes, to check the FCS.
+    pub fn new_keep_fcs(bytes: &'a [u8]) -> Result<(Self, u32), scroll::Error> {
+        let (bytes, fcs) = strip_fcs(bytes)?;
+        Ok((Self::from_stripped_bytes(bytes, Some(fcs))?, fcs))
+    }
+    #[cfg(feature = "radiotap")]
+    /// Create a new [GenericFrame] from bytes, which start with a radiotap header.
+    ///
+    /// If the flags in the radiotap header indicate, that the FCS is present, it will be validated and stripped, like for [Self::new].
+    /// If the data pad flag is set and the MPDU actually has padding between the MAC header and the frame body, an error is returned, since the padding can't be removed from an immutable slice. Use [Self::from_radiotap_mut] for those frames.
+    pub fn from_radiotap(
+        bytes: &'a [u8],
+    ) -> Result<(crate::radiotap::RadiotapHeader, Self), scroll::Error> {
+        let (radiotap_header, padding) = Self::parse_radiotap_header(bytes)?;
+        if padding != 0 {
+            return Err(scroll::Error::BadInput {
+                size: radiotap_header.mpdu_offset(),
+                msg:
+                    "The MPDU has padding after the MAC header, which can only be removed in place.",
+            });
+        }
+        let generic_frame = Self::new(
+            &bytes[radiotap_header.mpdu_offset()..],
+            radiotap_header.fcs_present(),
+        )?;
+        Ok((radiotap_header, generic_frame))
+    }
+    #[cfg(feature = "radiotap")]
+    /// Create a new [GenericFrame] from bytes, which start with a radiotap header, and remove the padding after the MAC header in place.
+    ///
+    /// If the data pad flag is set, the MAC header is moved forward over the padding, so that it directly precedes the frame body. The frame then starts at [RadiotapHeader::mpdu_offset](crate::radiotap::RadiotapHeader::mpdu_offset) plus the padding.
+    /// Apart from that, this behaves like [Self::from_radiotap].
+    pub fn from_radiotap_mut(
+        bytes: &'a mut [u8],
+    ) -> Result<(crate::radiotap::RadiotapHeader, Self), scroll::Error> {
+        let (radiotap_header, padding) = Self::parse_radiotap_header(bytes)?;
+        let mpdu_offset = radiotap_header.mpdu_offset();
+        if padding != 0 {
+            let mac_header_length =
+                mac_header_length(bytes.pread_with(mpdu_offset, Endian::Little)?).unwrap_or(0);
+            bytes.copy_within(
+                mpdu_offset..mpdu_offset + mac_header_length,
+                mpdu_offset + padding,
+            );
+        }
+        let bytes: &'a [u8] = bytes;
+        let generic_frame = Self::new(
+            &bytes[mpdu_offset + padding..],
+            radiotap_header.fcs_present(),
+        )?;
+        Ok((radiotap_header, generic_frame))
+    }
+    #[cfg(feature = "radiotap")]
+    /// Parse the radiotap header and determine the length of the padding after the MAC header.
+    ///
+    /// This also checks, that the MAC header and the padding are in bounds.
+    fn parse_radiotap_header(
+        bytes: &[u8],
+    ) -> Result<(crate::radiotap::RadiotapHeader, usize), scroll::Error> {
+        let radiotap_header: crate::radiotap::RadiotapHeader = bytes.pread(0)?;
+        let mpdu_offset = radiotap_header.mpdu_offset();
+        let Ok(fcf) = bytes.pread_with::<u16>(mpdu_offset, Endian::Little) else {
+            // The MPDU is too short, which is reported when creating the GenericFrame.
+            return Ok((radiotap_header, 0));
+        };
+        let Some(mac_header_length) = mac_header_length(fcf) else {
+            return Ok((radiotap_header, 0));
+        };
+        let padding = radiotap_header.data_padding(mac_header_length);
+        if padding != 0 && bytes.len() < mpdu_offset + mac_header_length + padding {
+            return Err(scroll::Error::TooBig {
+                size: mpdu_offset + mac_header_length + padding,
+                len: bytes.len(),
+            });
+        }
+        Ok((radiotap_header, padding))
+    }
+    /// Check the length of the frame and create the [GenericFrame].
+    fn from_stripped_bytes(bytes: &'a [u8], fcs: Option<u32>) -> Result<Self, scroll::Error> {
         if bytes.len() < 10 {
             return Err(scroll::Error::BadInput {
                 size: 0,
                 msg: "Byte slice for generic frame was shorter than 10.",
             });
         }
-        Ok(Self { bytes })
+        Ok(Self { bytes, fcs })
+    }
+    /// Check if the FCS at the end of the bytes matches the CRC of the preceding bytes.
+    ///
+    /// This returns false, if the slice is shorter than the FCS.
+    pub fn fcs_valid(bytes: &[u8]) -> bool {
+        strip_and_validate_fcs(bytes).is_ok()
+    }
+    /// Get the FCS.
+    ///
+    /// This returns [None], if the [GenericFrame] was created without an FCS. Frames created with [Self::new_keep_fcs] return the FCS, as it was transmitted, even if it's invalid.
+    pub const fn fcs(&self) -> Option<u32> {
+        self.fcs
+    }
+    /// Get the bytes of the frame.
+    ///
+    /// If the [GenericFrame] was created with `with_fcs` set to true, the FCS isn't included.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+    /// Write the frame to the buffer and append a freshly computed FCS.
+    ///
+    /// This returns the number of bytes written, which includes the FCS.
+    pub fn write_with_fcs(&self, out: &mut [u8]) -> Result<usize, scroll::Error> {
+        let mut offset = 0;
+
+        out.gwrite(self.bytes, &mut offset)?;
+        attach_fcs(out, &mut offset)?;
+
+        Ok(offset)
     }
     /// Get the frame control field.
     ///
@@ -70,6 +232,9 @@ impl<'a> GenericFrame<'a> {
     ///
     /// This can't fail, since all frames have this and we validate it's presence when creating a
     /// [GenericFrame].
+    ///
+    /// All other fields past the first ten bytes are optional and their accessors return [None],
+    /// if the byte slice is truncated.
     pub fn address_1(&self) -> MACAddress {
         self.bytes.pread(4).unwrap()
     }
@@ -84,7 +249,15 @@ impl<'a> GenericFrame<'a> {
             None
         }
     }
-    /// Get the second address.
+    /// Get the transmitter address.
+    ///
+    /// For all frames, which have a transmitter address, this is the second address. This will
+    /// return [None] for frames without a transmitter address, like CTS and Ack, or if the byte
+    /// slice ends early.
+    pub fn transmitter_address(&self) -> Option<MACAddress> {
+        self.address_2()
+    }
+    /// Get the third address.
     ///
     /// This may return [None], if the frame type doesn't have a third address, or the byte slice
     /// ends early.
@@ -95,7 +268,97 @@ impl<'a> GenericFrame<'a> {
             None
         }
     }
-    /// Get the second address.
+    /// Get the fourth address.
+    ///
+    /// This may return [None], if the frame isn't a data frame with both ToDS and FromDS set, or
+    /// the byte slice ends early.
+    pub fn address_4(&self) -> Option<MACAddress> {
+        let fcf = self.frame_control_field();
+        if matches!(fcf.frame_type(), FrameType::Data(_))
+            && fcf.flags().to_ds()
+            && fcf.flags().from_ds()
+        {
+            self.bytes.pread(24).ok()
+        } else {
+            None
+        }
+    }
+    /// Get the QoS control field.
+    ///
+    /// This may return [None], if the frame isn't a QoS data frame, or the byte slice ends early.
+    pub fn qos_control(&self) -> Option<QoSControl> {
+        let fcf = self.frame_control_field();
+        let FrameType::Data(subtype) = fcf.frame_type() else {
+            return None;
+        };
+        if !subtype.is_qos() {
+            return None;
+        }
+        let qos_offset = if fcf.flags().to_ds() && fcf.flags().from_ds() {
+            30
+        } else {
+            24
+        };
+        self.bytes
+            .pread_with(qos_offset, Endian::Little)
+            .ok()
+            .map(QoSControl::from_bits)
+    }
+    /// Check if the frame is a data frame carrying an A-MSDU.
+    ///
+    /// This returns false, if the QoS control field is missing or truncated.
+    fn is_amsdu(&self) -> bool {
+        let FrameType::Data(subtype) = self.frame_control_field().frame_type() else {
+            return false;
+        };
+        subtype.has_payload()
+            && self
+                .qos_control()
+                .map_or(false, |qos_control| qos_control.amsdu_present())
+    }
+    /// Get the destination address.
+    ///
+    /// For management frames, this is the first address. For data frames, the same mapping as in [DataFrameHeader::destination_address](data_frame::header::DataFrameHeader::destination_address) is used.
+    /// This returns [None] for control frames, if the address isn't present for the ToDS/FromDS combination, or if the byte slice ends early.
+    pub fn destination_address(&self) -> Option<MACAddress> {
+        let fcf = self.frame_control_field();
+        match fcf.frame_type() {
+            FrameType::Management(_) => Some(self.address_1()),
+            FrameType::Data(_) if !fcf.flags().to_ds() => Some(self.address_1()),
+            FrameType::Data(_) if !self.is_amsdu() => self.address_3(),
+            _ => None,
+        }
+    }
+    /// Get the source address.
+    ///
+    /// For management frames, this is the second address. For data frames, the same mapping as in [DataFrameHeader::source_address](data_frame::header::DataFrameHeader::source_address) is used.
+    /// This returns [None] for control frames, if the address isn't present for the ToDS/FromDS combination, or if the byte slice ends early.
+    pub fn source_address(&self) -> Option<MACAddress> {
+        let fcf = self.frame_control_field();
+        match fcf.frame_type() {
+            FrameType::Management(_) => self.address_2(),
+            FrameType::Data(_) if !fcf.flags().from_ds() => self.address_2(),
+            FrameType::Data(_) if self.is_amsdu() => None,
+            FrameType::Data(_) if !fcf.flags().to_ds() => self.address_3(),
+            FrameType::Data(_) => self.address_4(),
+            _ => None,
+        }
+    }
+    /// Get the BSSID.
+    ///
+    /// For management frames, this is the third address. For data frames, the same mapping as in [DataFrameHeader::bssid](data_frame::header::DataFrameHeader::bssid) is used.
+    /// This returns [None] for control frames, or if the byte slice ends early.
+    pub fn bssid(&self) -> Option<MACAddress> {
+        let fcf = self.frame_control_field();
+        match fcf.frame_type() {
+            FrameType::Management(_) => self.address_3(),
+            FrameType::Data(_) if fcf.flags().from_ds() => self.address_2(),
+            FrameType::Data(_) if fcf.flags().to_ds() => Some(self.address_1()),
+            FrameType::Data(_) => self.address_3(),
+            _ => None,
+        }
+    }
+    /// Get the sequence control field.
     ///
     /// This may return [None], if the frame type doesn't have a sequence control field, or the byte slice
     /// ends early.
@@ -141,21 +404,182 @@ impl<'a> GenericFrame<'a> {
         }
     }
 }
+/// The length of the FCS in bytes.
+const FCS_LENGTH: usize = 4;
+
+/// Try to determine the length of the frame at the start of `bytes`, without any external information.
+///
+/// This only works for control frames with a fixed length, since all other frames aren't self-delimiting.
+/// For Block Acks, a compressed bitmap is assumed to be eight bytes long.
+/// The FCS isn't included in the returned length.
+pub fn best_effort_frame_length(bytes: &[u8]) -> Option<usize> {
+    let fcf = FrameControlField::from_bits(bytes.pread_with(0, Endian::Little).ok()?);
+    let FrameType::Control(subtype) = fcf.frame_type() else {
+        return None;
+    };
+    Some(match subtype {
+        ControlFrameSubtype::CTS | ControlFrameSubtype::Ack => 10,
+        ControlFrameSubtype::RTS
+        | ControlFrameSubtype::PSPoll
+        | ControlFrameSubtype::CFEnd
+        | ControlFrameSubtype::CFEndAck => 16,
+        ControlFrameSubtype::BlockAckRequest | ControlFrameSubtype::BlockAck => {
+            let block_ack_control =
+                BlockAckControl::from_bits(bytes.pread_with(16, Endian::Little).ok()?);
+            match (subtype, block_ack_control.block_ack_type()) {
+                (ControlFrameSubtype::BlockAckRequest, BlockAckType::Basic)
+                | (ControlFrameSubtype::BlockAckRequest, BlockAckType::Compressed) => 20,
+                (ControlFrameSubtype::BlockAck, BlockAckType::Basic) => 148,
+                (ControlFrameSubtype::BlockAck, BlockAckType::Compressed) => 28,
+                _ => return None,
+            }
+        }
+        _ => return None,
+    })
+}
+
+/// An [Iterator] over multiple frames, which are concatenated in one buffer.
+///
+/// Since IEEE 802.11 frames aren't self-delimiting, the length of every frame has to be supplied by a length provider.
+/// The length provider gets passed the remaining bytes and returns the length of the next frame, including the FCS if present, which could for example come from a radiotap header or a length prefix.
+/// If the length provider returns [None], the iteration ends.
+/// If a frame couldn't be created, because it was too short or the FCS was invalid, the error is returned and the iteration ends.
+///
+/// For buffers only containing control frames, [FrameIterator::best_effort] can be used, which uses [best_effort_frame_length].
+/// Any frame with a length, which can't be determined that way, like management and data frames, ends the iteration.
+pub struct FrameIterator<'a, LengthProvider> {
+    bytes: &'a [u8],
+    with_fcs: bool,
+    length_provider: LengthProvider,
+}
+impl<'a, LengthProvider: FnMut(&'a [u8]) -> Option<usize>> FrameIterator<'a, LengthProvider> {
+    /// Create a new [FrameIterator] with a custom length provider.
+    pub const fn new(bytes: &'a [u8], with_fcs: bool, length_provider: LengthProvider) -> Self {
+        Self {
+            bytes,
+            with_fcs,
+            length_provider,
+        }
+    }
+}
+impl<'a> FrameIterator<'a, fn(&'a [u8]) -> Option<usize>> {
+    /// Create a new [FrameIterator], which determines the frame lengths using [best_effort_frame_length].
+    pub fn best_effort(bytes: &'a [u8], with_fcs: bool) -> Self {
+        if with_fcs {
+            Self::new(bytes, with_fcs, |bytes| {
+                best_effort_frame_length(bytes).map(|length| length + FCS_LENGTH)
+            })
+        } else {
+            Self::new(bytes, with_fcs, best_effort_frame_length)
+        }
+    }
+}
+impl<'a, LengthProvider: FnMut(&'a [u8]) -> Option<usize>> Iterator
+    for FrameIterator<'a, LengthProvider>
+{
+    type Item = Result<GenericFrame<'a>, scroll::Error>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+        let length = (self.length_provider)(self.bytes)?;
+        let Some(frame_bytes) = self.bytes.get(..length) else {
+            let len = self.bytes.len();
+            self.bytes = &[];
+            return Some(Err(scroll::Error::TooBig { size: length, len }));
+        };
+        self.bytes = &self.bytes[length..];
+        let generic_frame = GenericFrame::new(frame_bytes, self.with_fcs);
+        if generic_frame.is_err() {
+            self.bytes = &[];
+        }
+        Some(generic_frame)
+    }
+}
+#[cfg(feature = "defmt")]
+impl defmt::Format for GenericFrame<'_> {
+    fn format(&self, fmt: defmt::Formatter) {
+        let frame_type = self.frame_control_field().frame_type();
+        defmt::write!(fmt, "{} | RA={}", frame_type, self.address_1());
+        if let Some(transmitter_address) = self.transmitter_address() {
+            defmt::write!(fmt, " TA={}", transmitter_address);
+        }
+        if let Some(bssid) = self.bssid() {
+            defmt::write!(fmt, " BSSID={}", bssid);
+        }
+    }
+}
 #[macro_export]
 /// This macro allows matching a strongly typed frame from a byte slice.
 ///
+/// If no arm matches, a [BadInput](scroll::Error::BadInput) error is returned.
+/// To handle unmatched frames yourself, an arm for [GenericFrame] can be added, which always matches and binds the generic frame.
+/// This arm has to be the last one.
+///
 /// # Notes
 /// If you match for action frames in this macro and include a [RawActionFrame](crate::mgmt_frame::RawActionFrame), it will always be matched for an action frame, as long as there are no strongly typed action frames before it.
+/// # Example
+/// ```
+/// use ieee80211::{
+///     common::{ControlFrameSubtype, FrameType},
+///     match_frames,
+///     mgmt_frame::BeaconFrame,
+/// };
+///
+/// let bytes = [0xd4, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+/// let frame_type = match_frames! {
+///     bytes.as_slice(),
+///     _beacon = BeaconFrame => {
+///         None
+///     }
+///     generic_frame = GenericFrame => {
+///         Some(generic_frame.frame_control_field().frame_type())
+///     }
+/// }
+/// .unwrap();
+/// assert_eq!(frame_type, Some(FrameType::Control(ControlFrameSubtype::Ack)));
+/// ```
+/// The [GenericFrame] arm can't be followed by other arms.
+/// ```compile_fail
+/// use ieee80211::{match_frames, mgmt_frame::BeaconFrame};
+///
+/// let bytes = [0xd4, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+/// let _ = match_frames! {
+///     bytes.as_slice(),
+///     _generic_frame = GenericFrame => {}
+///     _beacon = BeaconFrame => {}
+/// };
+/// ```
 macro_rules! match_frames {
+    (@arms $generic_frame:ident;) => {
+        Err($crate::scroll::Error::BadInput { size: 0, msg: "Frame type not matched." })
+    };
+    (@arms $generic_frame:ident; $binding:pat = GenericFrame => $block:block) => {
+        {
+            let $binding = $generic_frame;
+            Ok($block)
+        }
+    };
+    (@arms $generic_frame:ident; $binding:pat = GenericFrame => $block:block $($rest:tt)+) => {
+        compile_error!("The GenericFrame arm of match_frames has to be the last one.")
+    };
+    (@arms $generic_frame:ident; $binding:pat = $frame_type:ty => $block:block $($rest:tt)*) => {
+        if let Some(frame_res) = $generic_frame.parse_to_typed::<$frame_type>() {
+            match frame_res {
+                Ok($binding) => Ok($block),
+                Err(err) => Err(err)
+            }
+        } else {
+            $crate::match_frames!(@arms $generic_frame; $($rest)*)
+        }
+    };
     (
         $bytes:expr,
         $(with_fcs: $ctx:expr,)?
-        $(
-            $binding:pat = $frame_type:ty => $block:block
-        )+
+        $($arms:tt)+
     ) => {
         {
-            use ieee80211::{GenericFrame, scroll};
+            use ieee80211::GenericFrame;
             const WITH_FCS: bool = {
                 let mut with_fcs = false;
 
@@ -169,22 +593,7 @@ macro_rules! match_frames {
             let generic_frame = GenericFrame::new($bytes, WITH_FCS);
             assert!(generic_frame.is_ok());
             match generic_frame {
-                Ok(generic_frame) => {
-                    if false {
-                        unreachable!()
-                    }
-                    $(
-                        else if let Some(frame_res) = generic_frame.parse_to_typed::<$frame_type>() {
-                            match frame_res {
-                                Ok($binding) => Ok($block),
-                                Err(err) => Err(err)
-                            }
-                        }
-                    )*
-                    else {
-                        Err(scroll::Error::BadInput { size: 0, msg: "Frame type not matched." })
-                    }
-                }
+                Ok(generic_frame) => $crate::match_frames!(@arms generic_frame; $($arms)+),
                 Err(err) => Err(err)
             }
         }
@@ -33,3 +33,5 @@ pub use scroll;
 #[cfg(feature = "crypto")]
 /// Implementations of cryptographic primitives.
 pub mod crypto;
+#[cfg(feature = "radiotap")]
+pub mod radiotap;
@@ -0,0 +1,174 @@
+//! This module contains a minimal parser for the radiotap header, which precedes IEEE 802.11 frames in most captures.
+//!
+//! Only the fields, which are commonly needed for inspecting captured frames, are parsed. For full radiotap support, see [Rtap](https://github.com/Frostie314159/rtap).
+//! Extended presence bitmaps are skipped and only the fields indicated in the first one are parsed.
+//! Fields following the antenna noise aren't parsed.
+
+use bitfield_struct::bitfield;
+use scroll::{ctx::TryFromCtx, Endian, Pread};
+
+/// The bit in the presence bitmap, which indicates, that another presence bitmap follows.
+const EXT_BIT: u32 = 31;
+// The presence bits of all fields supported by this parser, in ascending order.
+const TSFT_BIT: u32 = 0;
+const FLAGS_BIT: u32 = 1;
+const RATE_BIT: u32 = 2;
+const CHANNEL_BIT: u32 = 3;
+const FHSS_BIT: u32 = 4;
+const ANTENNA_SIGNAL_BIT: u32 = 5;
+const ANTENNA_NOISE_BIT: u32 = 6;
+
+#[bitfield(u8, defmt = cfg(feature = "defmt"))]
+#[derive(PartialEq, Eq, Hash)]
+/// The flags field of the [RadiotapHeader].
+pub struct RadiotapFlags {
+    /// The frame was sent or received during the CFP.
+    pub cfp: bool,
+    /// The frame was sent or received with the short preamble.
+    pub short_preamble: bool,
+    /// The frame was sent or received with WEP encryption.
+    pub wep: bool,
+    /// The frame was sent or received with fragmentation.
+    pub fragmentation: bool,
+    /// The frame includes the FCS.
+    pub fcs_at_end: bool,
+    /// The frame has padding between the header and the payload.
+    pub data_pad: bool,
+    /// The frame failed the FCS check.
+    pub bad_fcs: bool,
+    /// The frame used the short guard interval.
+    pub short_gi: bool,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The channel field of the [RadiotapHeader].
+pub struct RadiotapChannel {
+    /// The center frequency in MHz.
+    pub frequency: u16,
+    /// The raw channel flags.
+    pub flags: u16,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// A radiotap header.
+///
+/// Fields, which aren't present in the header or weren't parsed, are [None].
+pub struct RadiotapHeader {
+    /// The length of the entire radiotap header, which is the offset to the MPDU.
+    pub length: u16,
+    /// The first presence bitmap.
+    pub present: u32,
+    /// The value of the TSF in microseconds, when the first bit of the MPDU arrived at the MAC.
+    pub tsft: Option<u64>,
+    pub flags: Option<RadiotapFlags>,
+    /// The data rate in units of 500kbps.
+    pub rate: Option<u8>,
+    pub channel: Option<RadiotapChannel>,
+    /// The signal power at the antenna in dBm.
+    pub antenna_signal: Option<i8>,
+    /// The noise power at the antenna in dBm.
+    pub antenna_noise: Option<i8>,
+}
+impl RadiotapHeader {
+    /// Returns the offset of the MPDU from the start of the radiotap header.
+    pub const fn mpdu_offset(&self) -> usize {
+        self.length as usize
+    }
+    /// Check if the MPDU following the header includes the FCS.
+    ///
+    /// This returns false, if the flags field isn't present.
+    pub const fn fcs_present(&self) -> bool {
+        match self.flags {
+            Some(flags) => flags.fcs_at_end(),
+            None => false,
+        }
+    }
+    /// Returns the number of padding bytes between the MAC header and the frame body of the MPDU.
+    ///
+    /// If the data pad flag is set, the frame body is aligned to a multiple of four bytes from the start of the MPDU, otherwise there is no padding.
+    /// The MAC header itself always starts at [Self::mpdu_offset].
+    pub const fn data_padding(&self, mac_header_length: usize) -> usize {
+        match self.flags {
+            Some(flags) if flags.data_pad() => (4 - mac_header_length % 4) % 4,
+            _ => 0,
+        }
+    }
+    /// Returns the data rate in kbps.
+    pub const fn rate_in_kbps(&self) -> Option<u32> {
+        match self.rate {
+            Some(rate) => Some(rate as u32 * 500),
+            None => None,
+        }
+    }
+}
+/// Align the offset to the specified alignment, which is relative to the start of the header.
+fn align(offset: &mut usize, alignment: usize) {
+    *offset = (*offset + alignment - 1) & !(alignment - 1);
+}
+impl TryFromCtx<'_> for RadiotapHeader {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        if from.gread::<u8>(&mut offset)? != 0 {
+            return Err(scroll::Error::BadInput {
+                size: offset,
+                msg: "Radiotap versions other than zero are unsupported.",
+            });
+        }
+        // Padding
+        offset += 1;
+        let length: u16 = from.gread_with(&mut offset, Endian::Little)?;
+        // The fields are only read from the header, so we don't accidentally read from the MPDU.
+        let Some(header) = from.get(..length as usize) else {
+            return Err(scroll::Error::TooBig {
+                size: length as usize,
+                len: from.len(),
+            });
+        };
+        let present: u32 = header.gread_with(&mut offset, Endian::Little)?;
+        // Skip the extended presence bitmaps.
+        let mut current_present = present;
+        while current_present & (1 << EXT_BIT) != 0 {
+            current_present = header.gread_with(&mut offset, Endian::Little)?;
+        }
+
+        let mut radiotap_header = Self {
+            length,
+            present,
+            ..Default::default()
+        };
+        let is_present = |bit: u32| present & (1 << bit) != 0;
+        if is_present(TSFT_BIT) {
+            align(&mut offset, 8);
+            radiotap_header.tsft = Some(header.gread_with(&mut offset, Endian::Little)?);
+        }
+        if is_present(FLAGS_BIT) {
+            radiotap_header.flags = Some(RadiotapFlags::from_bits(header.gread(&mut offset)?));
+        }
+        if is_present(RATE_BIT) {
+            radiotap_header.rate = Some(header.gread(&mut offset)?);
+        }
+        if is_present(CHANNEL_BIT) {
+            align(&mut offset, 2);
+            radiotap_header.channel = Some(RadiotapChannel {
+                frequency: header.gread_with(&mut offset, Endian::Little)?,
+                flags: header.gread_with(&mut offset, Endian::Little)?,
+            });
+        }
+        if is_present(FHSS_BIT) {
+            // The hop set and pattern aren't exposed.
+            offset += 2;
+        }
+        if is_present(ANTENNA_SIGNAL_BIT) {
+            radiotap_header.antenna_signal = Some(header.gread(&mut offset)?);
+        }
+        if is_present(ANTENNA_NOISE_BIT) {
+            radiotap_header.antenna_noise = Some(header.gread(&mut offset)?);
+        }
+
+        Ok((radiotap_header, length as usize))
+    }
+}
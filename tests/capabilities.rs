@@ -0,0 +1,75 @@
+use ieee80211::{
+    common::{CapabilitiesInformation, ProtectionMode},
+    elements::{ht::HTProtectionMode, ERPInformationElement},
+};
+
+#[test]
+fn test_protection_mode() {
+    assert_eq!(
+        CapabilitiesInformation::protection_mode(None, None),
+        ProtectionMode::None
+    );
+    assert_eq!(
+        CapabilitiesInformation::protection_mode(Some(ERPInformationElement::new()), None),
+        ProtectionMode::None
+    );
+    assert_eq!(
+        CapabilitiesInformation::protection_mode(
+            Some(ERPInformationElement::new().with_non_erp_present(true)),
+            None
+        ),
+        ProtectionMode::Capable
+    );
+    assert_eq!(
+        CapabilitiesInformation::protection_mode(
+            Some(
+                ERPInformationElement::new()
+                    .with_non_erp_present(true)
+                    .with_use_protection(true)
+            ),
+            Some(HTProtectionMode::None)
+        ),
+        ProtectionMode::Required
+    );
+    assert_eq!(
+        CapabilitiesInformation::protection_mode(None, Some(HTProtectionMode::TwentyMHz)),
+        ProtectionMode::Required
+    );
+    assert_eq!(
+        CapabilitiesInformation::protection_mode(
+            Some(ERPInformationElement::new()),
+            Some(HTProtectionMode::NonHTMixed)
+        ),
+        ProtectionMode::Required
+    );
+    assert!(CapabilitiesInformation::requires_protection(
+        None,
+        Some(HTProtectionMode::NonMember)
+    ));
+    assert!(!CapabilitiesInformation::requires_protection(
+        None,
+        Some(HTProtectionMode::None)
+    ));
+    assert!(CapabilitiesInformation::requires_protection(
+        Some(ERPInformationElement::new().with_use_protection(true)),
+        None
+    ));
+}
+#[test]
+fn test_short_preamble_and_slot_time() {
+    let capabilities_info = CapabilitiesInformation::new()
+        .with_is_short_preamble_allowed(true)
+        .with_is_short_time_slot_in_use(true);
+
+    assert!(capabilities_info.uses_short_preamble(None));
+    assert!(capabilities_info.uses_short_slot_time(None));
+    assert!(capabilities_info.uses_short_preamble(Some(ERPInformationElement::new())));
+    assert!(!capabilities_info.uses_short_preamble(Some(
+        ERPInformationElement::new().with_barker_preamble_mode(true)
+    )));
+    assert!(!capabilities_info.uses_short_slot_time(Some(
+        ERPInformationElement::new().with_non_erp_present(true)
+    )));
+    assert!(!CapabilitiesInformation::new().uses_short_preamble(None));
+    assert!(!CapabilitiesInformation::new().uses_short_slot_time(None));
+}
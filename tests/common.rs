@@ -1,11 +1,18 @@
 #![deny(unused)]
 
 mod aid;
+mod capabilities;
 #[cfg(feature = "crypto")]
 mod crypto;
 mod elements;
+mod fcs;
+mod frame_type;
 mod frames;
 mod issues;
+#[cfg(feature = "radiotap")]
+mod radiotap;
+mod sequence_control;
+mod status_code;
 #[macro_export]
 macro_rules! roundtrip_test {
     ($test_name:ident, $read_type:ty, $expected_read:expr, $expected_bytes:expr) => {
@@ -0,0 +1,65 @@
+use ieee80211::{
+    crypto::{
+        ccmp_decrypt, ccmp_encrypt, ccmp_output_len, CCMP_128_MIC_LENGTH, CCMP_HEADER_LENGTH,
+    },
+    data_frame::DataFrame,
+};
+use scroll::Pread;
+
+#[test]
+fn test_ccmp_output_len() {
+    assert_eq!(ccmp_output_len(0, 8), CCMP_HEADER_LENGTH + 8);
+    assert_eq!(ccmp_output_len(100, 8), 116);
+    assert_eq!(ccmp_output_len(100, 16), 124);
+}
+
+// The CCMP test vector from J.6.4.
+const TK: [u8; 16] = [
+    0xc9, 0x7c, 0x1f, 0x67, 0xce, 0x37, 0x11, 0x85, 0x51, 0x4a, 0x8a, 0x19, 0xf2, 0xbd, 0xd5, 0x2f,
+];
+const PN: u64 = 0xb5039776e70c;
+const PLAINTEXT_MPDU: &[u8] = &[
+    0x08, 0x48, 0xc3, 0x2c, 0x0f, 0xd2, 0xe1, 0x28, 0xa5, 0x7c, 0x50, 0x30, 0xf1, 0x84, 0x44, 0x08,
+    0xab, 0xae, 0xa5, 0xb8, 0xfc, 0xba, 0x80, 0x33, 0xf8, 0xba, 0x1a, 0x55, 0xd0, 0x2f, 0x85, 0xae,
+    0x96, 0x7b, 0xb6, 0x2f, 0xb6, 0xcd, 0xa8, 0xeb, 0x7e, 0x78, 0xa0, 0x50,
+];
+const ENCRYPTED_MPDU: &[u8] = &[
+    0x08, 0x48, 0xc3, 0x2c, 0x0f, 0xd2, 0xe1, 0x28, 0xa5, 0x7c, 0x50, 0x30, 0xf1, 0x84, 0x44, 0x08,
+    0xab, 0xae, 0xa5, 0xb8, 0xfc, 0xba, 0x80, 0x33, 0x0c, 0xe7, 0x00, 0x20, 0x76, 0x97, 0x03, 0xb5,
+    0xf3, 0xd0, 0xa2, 0xfe, 0x9a, 0x3d, 0xbf, 0x23, 0x42, 0xa6, 0x43, 0xe4, 0x32, 0x46, 0xe8, 0x0c,
+    0x3c, 0x04, 0xd0, 0x19, 0x78, 0x45, 0xce, 0x0b, 0x16, 0xf9, 0x76, 0x23,
+];
+const HEADER_LENGTH: usize = 24;
+
+#[test]
+fn test_ccmp_encrypt() {
+    let header = PLAINTEXT_MPDU.pread::<DataFrame>(0).unwrap().header;
+    let plaintext = &PLAINTEXT_MPDU[HEADER_LENGTH..];
+
+    let mut buf = [0x00; 64];
+    let written = ccmp_encrypt(&TK, &header, PN, plaintext, &mut buf).unwrap();
+    assert_eq!(
+        written,
+        ccmp_output_len(plaintext.len(), CCMP_128_MIC_LENGTH)
+    );
+    assert_eq!(&buf[..written], &ENCRYPTED_MPDU[HEADER_LENGTH..]);
+
+    assert!(ccmp_encrypt(&TK, &header, PN, plaintext, &mut buf[..written - 1]).is_err());
+}
+#[test]
+fn test_ccmp_decrypt() {
+    let header = ENCRYPTED_MPDU.pread::<DataFrame>(0).unwrap().header;
+    let protected_payload = &ENCRYPTED_MPDU[HEADER_LENGTH..];
+
+    let mut buf = [0x00; 64];
+    let plaintext_len = ccmp_decrypt(&TK, &header, protected_payload, &mut buf).unwrap();
+    assert_eq!(&buf[..plaintext_len], &PLAINTEXT_MPDU[HEADER_LENGTH..]);
+
+    // A modified MIC must be rejected.
+    let mut tampered = [0x00; 36];
+    tampered.copy_from_slice(protected_payload);
+    tampered[35] ^= 0x01;
+    assert!(ccmp_decrypt(&TK, &header, &tampered, &mut buf).is_err());
+    // Too short to contain the CCMP header and MIC.
+    assert!(ccmp_decrypt(&TK, &header, &protected_payload[..15], &mut buf).is_err());
+}
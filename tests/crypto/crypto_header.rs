@@ -0,0 +1,35 @@
+use ieee80211::crypto::CryptoHeader;
+
+use crate::roundtrip_test;
+
+// The CCMP header from the test vector in J.6.4.
+const EXPECTED_CRYPTO_HEADER_BYTES: [u8; 8] = [0x0c, 0xe7, 0x00, 0x20, 0x76, 0x97, 0x03, 0xb5];
+const EXPECTED_CRYPTO_HEADER: CryptoHeader = CryptoHeader::new_ccmp(0xb5039776e70c, 0);
+
+roundtrip_test!(
+    test_crypto_header_rw,
+    CryptoHeader,
+    EXPECTED_CRYPTO_HEADER,
+    EXPECTED_CRYPTO_HEADER_BYTES
+);
+#[test]
+fn test_crypto_header_fields() {
+    let crypto_header = CryptoHeader::from_bytes(EXPECTED_CRYPTO_HEADER_BYTES);
+    assert_eq!(crypto_header.packet_number(), 0xb5039776e70c);
+    assert_eq!(crypto_header.key_id(), 0);
+    assert!(crypto_header.ext_iv());
+
+    let crypto_header = CryptoHeader::new_ccmp(0x1234_5678_9abc_def0, 2);
+    assert_eq!(
+        crypto_header.into_bytes(),
+        [0xf0, 0xde, 0x00, 0xa0, 0xbc, 0x9a, 0x78, 0x56]
+    );
+    // Only the lower 48 bits are kept.
+    assert_eq!(crypto_header.packet_number(), 0x5678_9abc_def0);
+    assert_eq!(crypto_header.key_id(), 2);
+    assert_eq!(
+        CryptoHeader::new_ccmp(1, 3),
+        CryptoHeader::from_bytes([0x01, 0x00, 0x00, 0xe0, 0x00, 0x00, 0x00, 0x00])
+    );
+    assert!(!CryptoHeader::default().ext_iv());
+}
@@ -0,0 +1,141 @@
+use ieee80211::{
+    crypto::{gcmp_decrypt, gcmp_encrypt, gcmp_output_len, GCMP_HEADER_LENGTH, GCMP_MIC_LENGTH},
+    data_frame::DataFrame,
+    elements::rsn::IEEE80211CipherSuiteSelector,
+};
+use scroll::Pread;
+
+// The MPDU and PN are those of the CCMP test vector from J.6.4.
+// The expected outputs were generated with an independent AES-GCM implementation, from the AAD and nonce in test_gcmp_expected_outputs.
+const TK_128: [u8; 16] = [
+    0xc9, 0x7c, 0x1f, 0x67, 0xce, 0x37, 0x11, 0x85, 0x51, 0x4a, 0x8a, 0x19, 0xf2, 0xbd, 0xd5, 0x2f,
+];
+const TK_256: [u8; 32] = [
+    0xc9, 0x7c, 0x1f, 0x67, 0xce, 0x37, 0x11, 0x85, 0x51, 0x4a, 0x8a, 0x19, 0xf2, 0xbd, 0xd5, 0x2f,
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+const PN: u64 = 0xb5039776e70c;
+const PLAINTEXT_MPDU: &[u8] = &[
+    0x08, 0x48, 0xc3, 0x2c, 0x0f, 0xd2, 0xe1, 0x28, 0xa5, 0x7c, 0x50, 0x30, 0xf1, 0x84, 0x44, 0x08,
+    0xab, 0xae, 0xa5, 0xb8, 0xfc, 0xba, 0x80, 0x33, 0xf8, 0xba, 0x1a, 0x55, 0xd0, 0x2f, 0x85, 0xae,
+    0x96, 0x7b, 0xb6, 0x2f, 0xb6, 0xcd, 0xa8, 0xeb, 0x7e, 0x78, 0xa0, 0x50,
+];
+const GCMP_128_PROTECTED_PAYLOAD: &[u8] = &[
+    0x0c, 0xe7, 0x00, 0x20, 0x76, 0x97, 0x03, 0xb5, 0xc5, 0x94, 0x94, 0x5c, 0x1b, 0x95, 0xf5, 0x12,
+    0xad, 0xda, 0xa2, 0xce, 0xfb, 0x00, 0xb0, 0x9f, 0x70, 0x8e, 0x84, 0x1b, 0xe6, 0xbe, 0x03, 0xee,
+    0x75, 0x0e, 0x2f, 0x4f, 0x93, 0xc0, 0x9d, 0xce, 0x37, 0x14, 0x18, 0xb0,
+];
+const GCMP_256_PROTECTED_PAYLOAD: &[u8] = &[
+    0x0c, 0xe7, 0x00, 0x20, 0x76, 0x97, 0x03, 0xb5, 0xd7, 0x9f, 0xc4, 0xfc, 0xe4, 0x7e, 0xb1, 0x68,
+    0xdf, 0x0f, 0x1d, 0x37, 0xe4, 0xd3, 0x1e, 0xde, 0x18, 0x8a, 0x18, 0x13, 0x12, 0xf6, 0xab, 0xc0,
+    0xf1, 0x57, 0xb5, 0x5a, 0x7e, 0xa0, 0x5d, 0x27, 0x74, 0xe5, 0x68, 0x47,
+];
+const HEADER_LENGTH: usize = 24;
+
+/// The AAD of the MPDU, as specified in 12.5.5.3.3 of IEEE 802.11-2020.
+///
+/// The retry bit is masked in the FC, the protected bit is set and the sequence number is masked in the SC.
+const AAD: [u8; 22] = [
+    0x08, 0x40, // FC
+    0x0f, 0xd2, 0xe1, 0x28, 0xa5, 0x7c, // A1
+    0x50, 0x30, 0xf1, 0x84, 0x44, 0x08, // A2
+    0xab, 0xae, 0xa5, 0xb8, 0xfc, 0xba, // A3
+    0x00, 0x00, // SC
+];
+/// The nonce of the MPDU, which is A2 followed by the big endian PN, as specified in 12.5.5.3.4 of IEEE 802.11-2020.
+const NONCE: [u8; 12] = [
+    0x50, 0x30, 0xf1, 0x84, 0x44, 0x08, // A2
+    0xb5, 0x03, 0x97, 0x76, 0xe7, 0x0c, // PN
+];
+
+#[test]
+fn test_gcmp_expected_outputs() {
+    use aes_gcm::{
+        aead::{Aead, KeyInit, Payload},
+        Aes128Gcm, Aes256Gcm,
+    };
+
+    let payload = || Payload {
+        msg: &PLAINTEXT_MPDU[HEADER_LENGTH..],
+        aad: &AAD,
+    };
+    let gcmp_128 = Aes128Gcm::new_from_slice(&TK_128)
+        .unwrap()
+        .encrypt(&NONCE.into(), payload())
+        .unwrap();
+    assert_eq!(gcmp_128, &GCMP_128_PROTECTED_PAYLOAD[GCMP_HEADER_LENGTH..]);
+    let gcmp_256 = Aes256Gcm::new_from_slice(&TK_256)
+        .unwrap()
+        .encrypt(&NONCE.into(), payload())
+        .unwrap();
+    assert_eq!(gcmp_256, &GCMP_256_PROTECTED_PAYLOAD[GCMP_HEADER_LENGTH..]);
+}
+#[test]
+fn test_gcmp_output_len() {
+    assert_eq!(gcmp_output_len(0), GCMP_HEADER_LENGTH + GCMP_MIC_LENGTH);
+    assert_eq!(gcmp_output_len(100), 124);
+}
+#[test]
+fn test_gcmp_encrypt() {
+    let header = PLAINTEXT_MPDU.pread::<DataFrame>(0).unwrap().header;
+    let plaintext = &PLAINTEXT_MPDU[HEADER_LENGTH..];
+
+    for (tk, expected) in [
+        (TK_128.as_slice(), GCMP_128_PROTECTED_PAYLOAD),
+        (TK_256.as_slice(), GCMP_256_PROTECTED_PAYLOAD),
+    ] {
+        let mut buf = [0x00; 64];
+        let written = gcmp_encrypt(tk, &header, PN, plaintext, &mut buf).unwrap();
+        assert_eq!(written, gcmp_output_len(plaintext.len()));
+        assert_eq!(&buf[..written], expected);
+
+        assert!(gcmp_encrypt(tk, &header, PN, plaintext, &mut buf[..written - 1]).is_err());
+    }
+    // Only 16 and 32 byte TKs are valid.
+    let mut buf = [0x00; 64];
+    assert!(gcmp_encrypt(&TK_256[..24], &header, PN, plaintext, &mut buf).is_err());
+}
+#[test]
+fn test_gcmp_decrypt() {
+    let header = PLAINTEXT_MPDU.pread::<DataFrame>(0).unwrap().header;
+
+    for (tk, protected_payload) in [
+        (TK_128.as_slice(), GCMP_128_PROTECTED_PAYLOAD),
+        (TK_256.as_slice(), GCMP_256_PROTECTED_PAYLOAD),
+    ] {
+        let mut buf = [0x00; 64];
+        let plaintext_len = gcmp_decrypt(tk, &header, protected_payload, &mut buf).unwrap();
+        assert_eq!(&buf[..plaintext_len], &PLAINTEXT_MPDU[HEADER_LENGTH..]);
+
+        // A modified MIC must be rejected.
+        let mut tampered = [0x00; 44];
+        tampered.copy_from_slice(protected_payload);
+        tampered[43] ^= 0x01;
+        assert!(gcmp_decrypt(tk, &header, &tampered, &mut buf).is_err());
+        // Modified ciphertext must be rejected too.
+        tampered.copy_from_slice(protected_payload);
+        tampered[GCMP_HEADER_LENGTH] ^= 0x01;
+        assert!(gcmp_decrypt(tk, &header, &tampered, &mut buf).is_err());
+        // Too short to contain the GCMP header and MIC.
+        assert!(gcmp_decrypt(tk, &header, &protected_payload[..23], &mut buf).is_err());
+    }
+    // Decrypting with the wrong key length must fail.
+    let mut buf = [0x00; 64];
+    assert!(gcmp_decrypt(&TK_256, &header, GCMP_128_PROTECTED_PAYLOAD, &mut buf).is_err());
+}
+#[test]
+fn test_cipher_suite_tk_len() {
+    assert_eq!(
+        IEEE80211CipherSuiteSelector::Gcmp128.tk_len(),
+        Some(TK_128.len())
+    );
+    assert_eq!(
+        IEEE80211CipherSuiteSelector::Gcmp256.tk_len(),
+        Some(TK_256.len())
+    );
+    assert_eq!(IEEE80211CipherSuiteSelector::Ccmp128.tk_len(), Some(16));
+    assert_eq!(
+        IEEE80211CipherSuiteSelector::UseGroupCipherSuite.tk_len(),
+        None
+    );
+}
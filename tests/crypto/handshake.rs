@@ -0,0 +1,333 @@
+use ieee80211::{
+    crypto::{
+        calculate_eapol_key_mic, derive_ptk, map_passphrase_to_psk, partition_ptk, prf,
+        unwrap_eapol_key_data, wrap_eapol_key_data, EAPOLKeyFrame, FourWayHandshake,
+        GroupKeyHandshake, GroupTemporalKey, HandshakeError, HandshakeState, KeyInformation,
+        PairwiseTransientKey,
+    },
+    mac_parser::MACAddress,
+};
+use scroll::{ctx::MeasureWith, Pread, Pwrite};
+
+#[test]
+fn test_prf() {
+    // Test case 1 from J.3.
+    assert_eq!(
+        prf::<64>(&[0x0b; 20], "prefix", b"Hi There"),
+        [
+            0xbc, 0xd4, 0xc6, 0x50, 0xb3, 0x0b, 0x96, 0x84, 0x95, 0x18, 0x29, 0xe0, 0xd7, 0x5f,
+            0x9d, 0x54, 0xb8, 0x62, 0x17, 0x5e, 0xd9, 0xf0, 0x06, 0x06, 0xe1, 0x7d, 0x8d, 0xa3,
+            0x54, 0x02, 0xff, 0xee, 0x75, 0xdf, 0x78, 0xc3, 0xd3, 0x1e, 0x0f, 0x88, 0x9f, 0x01,
+            0x21, 0x20, 0xc0, 0x86, 0x2b, 0xeb, 0x67, 0x75, 0x3e, 0x74, 0x39, 0xae, 0x24, 0x2e,
+            0xdb, 0x83, 0x73, 0x69, 0x83, 0x56, 0xcf, 0x5a
+        ]
+    );
+    // Test case 2 from J.3.
+    assert_eq!(
+        prf::<64>(b"Jefe", "prefix", b"what do ya want for nothing?"),
+        [
+            0x51, 0xf4, 0xde, 0x5b, 0x33, 0xf2, 0x49, 0xad, 0xf8, 0x1a, 0xeb, 0x71, 0x3a, 0x3c,
+            0x20, 0xf4, 0xfe, 0x63, 0x14, 0x46, 0xfa, 0xbd, 0xfa, 0x58, 0x24, 0x47, 0x59, 0xae,
+            0x58, 0xef, 0x90, 0x09, 0xa9, 0x9a, 0xbf, 0x4e, 0xac, 0x2c, 0xa5, 0xfa, 0x87, 0xe6,
+            0x92, 0xc4, 0x40, 0xeb, 0x40, 0x02, 0x3e, 0x7b, 0xab, 0xb2, 0x06, 0xd6, 0x1d, 0xe7,
+            0xb9, 0x2f, 0x41, 0x52, 0x90, 0x92, 0xb8, 0xfc
+        ]
+    );
+    // Test case 3 from J.3.
+    assert_eq!(
+        prf::<64>(&[0xaa; 20], "prefix", &[0xdd; 50]),
+        [
+            0xe1, 0xac, 0x54, 0x6e, 0xc4, 0xcb, 0x63, 0x6f, 0x99, 0x76, 0x48, 0x7b, 0xe5, 0xc8,
+            0x6b, 0xe1, 0x7a, 0x02, 0x52, 0xca, 0x5d, 0x8d, 0x8d, 0xf1, 0x2c, 0xfb, 0x04, 0x73,
+            0x52, 0x52, 0x49, 0xce, 0x9d, 0xd8, 0xd1, 0x77, 0xea, 0xd7, 0x10, 0xbc, 0x9b, 0x59,
+            0x05, 0x47, 0x23, 0x91, 0x07, 0xae, 0xf7, 0xb4, 0xab, 0xd4, 0x3d, 0x87, 0xf0, 0xa6,
+            0x8f, 0x1c, 0xbd, 0x9e, 0x2b, 0x6f, 0x76, 0x07
+        ]
+    );
+}
+
+// The pairwise key derivation test vector from Annex J.
+const TEST_VECTOR_PMK: [u8; 32] = [
+    0x0d, 0xc0, 0xd6, 0xeb, 0x90, 0x55, 0x5e, 0xd6, 0x41, 0x97, 0x56, 0xb9, 0xa1, 0x5e, 0xc3, 0xe3,
+    0x20, 0x9b, 0x63, 0xdf, 0x70, 0x7d, 0xd5, 0x08, 0xd1, 0x45, 0x81, 0xf8, 0x98, 0x27, 0x21, 0xaf,
+];
+const TEST_VECTOR_AUTHENTICATOR_ADDRESS: MACAddress =
+    MACAddress::new([0xa0, 0xa1, 0xa1, 0xa3, 0xa4, 0xa5]);
+const TEST_VECTOR_SUPPLICANT_ADDRESS: MACAddress =
+    MACAddress::new([0xb0, 0xb1, 0xb2, 0xb3, 0xb4, 0xb5]);
+const TEST_VECTOR_ANONCE: [u8; 32] = [
+    0xe0, 0xe1, 0xe2, 0xe3, 0xe4, 0xe5, 0xe6, 0xe7, 0xe8, 0xe9, 0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5,
+    0xf6, 0xf7, 0xf8, 0xf9, 0xfa, 0xfb, 0xfc, 0xfd, 0xfe, 0xff, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05,
+];
+const TEST_VECTOR_SNONCE: [u8; 32] = [
+    0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7, 0xc8, 0xc9, 0xd0, 0xd1, 0xd2, 0xd3, 0xd4, 0xd5,
+    0xd6, 0xd7, 0xd8, 0xd9, 0xda, 0xdb, 0xdc, 0xdd, 0xde, 0xdf, 0xe0, 0xe1, 0xe2, 0xe3, 0xe4, 0xe5,
+];
+const TEST_VECTOR_PTK: PairwiseTransientKey = PairwiseTransientKey {
+    kck: [
+        0x37, 0x9f, 0x98, 0x52, 0xd0, 0x19, 0x92, 0x36, 0xb9, 0x4e, 0x40, 0x7c, 0xe4, 0xc0, 0x0e,
+        0xc8,
+    ],
+    kek: [
+        0x47, 0xc9, 0xed, 0xc0, 0x1c, 0x2c, 0x6e, 0x5b, 0x49, 0x10, 0xca, 0xdd, 0xfb, 0x3e, 0x51,
+        0xa7,
+    ],
+    tk: [
+        0xb2, 0x36, 0x0c, 0x79, 0xe9, 0x71, 0x0f, 0xdd, 0x58, 0xbe, 0xa9, 0x3d, 0xea, 0xf0, 0x65,
+        0x99,
+    ],
+};
+
+#[test]
+fn test_derive_ptk() {
+    let ptk = derive_ptk(
+        &TEST_VECTOR_PMK,
+        TEST_VECTOR_AUTHENTICATOR_ADDRESS,
+        TEST_VECTOR_SUPPLICANT_ADDRESS,
+        &TEST_VECTOR_ANONCE,
+        &TEST_VECTOR_SNONCE,
+    );
+    assert_eq!(partition_ptk(&ptk), TEST_VECTOR_PTK);
+    // The order of the addresses and nonces doesn't matter.
+    assert_eq!(
+        derive_ptk(
+            &TEST_VECTOR_PMK,
+            TEST_VECTOR_SUPPLICANT_ADDRESS,
+            TEST_VECTOR_AUTHENTICATOR_ADDRESS,
+            &TEST_VECTOR_SNONCE,
+            &TEST_VECTOR_ANONCE,
+        ),
+        ptk
+    );
+    // The keys aren't printed.
+    assert_eq!(
+        format!("{:?}", TEST_VECTOR_PTK),
+        "PairwiseTransientKey { .. }"
+    );
+}
+#[test]
+fn test_calculate_eapol_key_mic() {
+    // Message 2 of the 4-way handshake, with the KCK from the test vector above.
+    let frame = EAPOLKeyFrame {
+        protocol_version: 2,
+        key_information: KeyInformation::new()
+            .with_key_descriptor_version(2)
+            .with_pairwise(true)
+            .with_mic(true),
+        key_replay_counter: 1,
+        key_nonce: TEST_VECTOR_SNONCE,
+        key_data: RSN_ELEMENT,
+        ..Default::default()
+    };
+    assert_eq!(
+        calculate_eapol_key_mic(&TEST_VECTOR_PTK.kck, &frame),
+        [
+            0xde, 0x94, 0x3d, 0xdd, 0x26, 0x39, 0x1d, 0x3a, 0x77, 0x49, 0x42, 0x20, 0x42, 0xd7,
+            0x5c, 0x53
+        ]
+    );
+}
+
+const AUTHENTICATOR_ADDRESS: MACAddress = MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x01]);
+const SUPPLICANT_ADDRESS: MACAddress = MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x00]);
+const RSN_ELEMENT: &[u8] = &[
+    0x30, 0x14, 0x01, 0x00, 0x00, 0x0f, 0xac, 0x04, 0x01, 0x00, 0x00, 0x0f, 0xac, 0x04, 0x01, 0x00,
+    0x00, 0x0f, 0xac, 0x02, 0x00, 0x00,
+];
+
+/// Serialize and reparse the frame, like it would be sent over the air.
+fn transmit<'a>(frame: EAPOLKeyFrame<'_>, buf: &'a mut [u8]) -> EAPOLKeyFrame<'a> {
+    let length = frame.measure_with(&());
+    buf.pwrite(frame, 0).unwrap();
+    buf[..length].pread(0).unwrap()
+}
+
+#[test]
+fn test_key_wrap() {
+    // Test vector from 4.1 of RFC 3394.
+    let kek = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f,
+    ];
+    let key_data = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff,
+    ];
+    let wrapped_key_data = [
+        0x1f, 0xa6, 0x8b, 0x0a, 0x81, 0x12, 0xb4, 0x47, 0xae, 0xf3, 0x4b, 0xd8, 0xfb, 0x5a, 0x7b,
+        0x82, 0x9d, 0x3e, 0x86, 0x23, 0x71, 0xd2, 0xcf, 0xe5,
+    ];
+    let mut buf = [0x00; 24];
+    assert_eq!(wrap_eapol_key_data(&kek, &key_data, &mut buf).unwrap(), 24);
+    assert_eq!(buf, wrapped_key_data);
+
+    let mut buf = [0x00; 16];
+    assert_eq!(
+        unwrap_eapol_key_data(&kek, &wrapped_key_data, &mut buf).unwrap(),
+        16
+    );
+    assert_eq!(buf, key_data);
+
+    let mut tampered = wrapped_key_data;
+    tampered[0] ^= 0xff;
+    assert!(unwrap_eapol_key_data(&kek, &tampered, &mut buf).is_err());
+    assert_eq!(buf, [0x00; 16]);
+
+    // Key data shorter than 16 bytes is padded.
+    let mut buf = [0x00; 24];
+    assert_eq!(wrap_eapol_key_data(&kek, &[0x01], &mut buf).unwrap(), 24);
+    let mut unwrapped = [0x00; 16];
+    unwrap_eapol_key_data(&kek, &buf, &mut unwrapped).unwrap();
+    assert_eq!(
+        unwrapped,
+        [0x01, 0xdd, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+    );
+}
+
+#[test]
+fn test_four_way_handshake() {
+    let pmk = map_passphrase_to_psk("password", "OpenRF");
+    let mut authenticator = FourWayHandshake::new_authenticator(
+        pmk,
+        AUTHENTICATOR_ADDRESS,
+        SUPPLICANT_ADDRESS,
+        [0xaa; 32],
+        RSN_ELEMENT,
+    );
+    let mut supplicant = FourWayHandshake::new_supplicant(
+        pmk,
+        AUTHENTICATOR_ADDRESS,
+        SUPPLICANT_ADDRESS,
+        [0x55; 32],
+        RSN_ELEMENT,
+    );
+    let mut bufs = [[0x00; 128]; 4];
+    let [buf_1, buf_2, buf_3, buf_4] = &mut bufs;
+
+    let message_1 = transmit(authenticator.initiate().unwrap(), buf_1);
+    assert_eq!(message_1.key_length, 16);
+    assert!(authenticator.initiate().is_none());
+    let message_2 = supplicant.process_message(&message_1).unwrap().unwrap();
+    assert_eq!(message_2.key_length, 0);
+
+    // A message 2 with a modified MIC must be rejected.
+    let mut tampered = message_2;
+    tampered.key_mic[0] ^= 0xff;
+    assert_eq!(
+        authenticator.process_message(&tampered),
+        Err(HandshakeError::MICMismatch)
+    );
+    assert_eq!(authenticator.state(), HandshakeState::WaitingForMessage(2));
+
+    let message_2 = transmit(message_2, buf_2);
+    assert_eq!(message_2.key_data, RSN_ELEMENT);
+    let message_3 = authenticator.process_message(&message_2).unwrap().unwrap();
+    let message_3 = transmit(message_3, buf_3);
+    assert_eq!(message_3.key_length, 16);
+    // Message 1 is unexpected at this point.
+    assert_eq!(
+        authenticator.process_message(&message_1),
+        Err(HandshakeError::UnexpectedMessage)
+    );
+    let message_4 = supplicant.process_message(&message_3).unwrap().unwrap();
+    assert!(supplicant.is_complete());
+    assert!(!authenticator.is_complete());
+    assert_eq!(authenticator.ptk(), None);
+
+    let message_4 = transmit(message_4, buf_4);
+    assert_eq!(message_4.key_length, 0);
+    assert_eq!(authenticator.process_message(&message_4), Ok(None));
+    assert!(authenticator.is_complete());
+    assert!(authenticator.ptk().is_some());
+    assert_eq!(authenticator.ptk(), supplicant.ptk());
+
+    // A replayed message 3 must be rejected.
+    assert_eq!(
+        supplicant.process_message(&message_3),
+        Err(HandshakeError::UnexpectedMessage)
+    );
+}
+
+#[test]
+fn test_group_key_handshake() {
+    let pmk = map_passphrase_to_psk("password", "OpenRF");
+    let mut authenticator = FourWayHandshake::new_authenticator(
+        pmk,
+        AUTHENTICATOR_ADDRESS,
+        SUPPLICANT_ADDRESS,
+        [0xaa; 32],
+        RSN_ELEMENT,
+    );
+    let mut supplicant = FourWayHandshake::new_supplicant(
+        pmk,
+        AUTHENTICATOR_ADDRESS,
+        SUPPLICANT_ADDRESS,
+        [0x55; 32],
+        RSN_ELEMENT,
+    );
+    let message_1 = authenticator.initiate().unwrap();
+    let message_2 = supplicant.process_message(&message_1).unwrap().unwrap();
+    let message_3 = authenticator.process_message(&message_2).unwrap().unwrap();
+    let message_4 = supplicant.process_message(&message_3).unwrap().unwrap();
+    authenticator.process_message(&message_4).unwrap();
+
+    let mut group_authenticator = GroupKeyHandshake::new_authenticator(
+        authenticator.ptk().unwrap(),
+        authenticator.key_replay_counter().unwrap(),
+    );
+    let mut group_supplicant = GroupKeyHandshake::new_supplicant(
+        supplicant.ptk().unwrap(),
+        supplicant.key_replay_counter().unwrap(),
+    );
+    let gtk = GroupTemporalKey {
+        key_id: 1,
+        gtk: [0x42; 16],
+        key_rsc: 0x1337,
+    };
+    let mut bufs = [[0x00; 256]; 2];
+    let [buf_1, buf_2] = &mut bufs;
+
+    let message_1 = transmit(group_authenticator.initiate(gtk).unwrap(), buf_1);
+    assert!(message_1.key_information.encrypted_key_data());
+    // The GTK mustn't be sent in the clear.
+    assert!(!message_1
+        .key_data
+        .windows(16)
+        .any(|window| window == gtk.gtk));
+    assert_eq!(
+        group_authenticator.state(),
+        HandshakeState::WaitingForMessage(2)
+    );
+    assert!(group_authenticator.initiate(gtk).is_none());
+    assert_eq!(group_authenticator.gtk(), None);
+
+    // A message 1 with a modified MIC must be rejected.
+    let mut tampered = message_1;
+    tampered.key_mic[0] ^= 0xff;
+    assert_eq!(
+        group_supplicant.process_message(&tampered),
+        Err(HandshakeError::MICMismatch)
+    );
+    assert_eq!(
+        group_supplicant.state(),
+        HandshakeState::WaitingForMessage(1)
+    );
+
+    let message_2 = group_supplicant
+        .process_message(&message_1)
+        .unwrap()
+        .unwrap();
+    assert!(group_supplicant.is_complete());
+    assert_eq!(group_supplicant.gtk(), Some(gtk));
+
+    let message_2 = transmit(message_2, buf_2);
+    assert_eq!(group_authenticator.process_message(&message_2), Ok(None));
+    assert!(group_authenticator.is_complete());
+    assert_eq!(group_authenticator.gtk(), Some(gtk));
+
+    // A replayed message 1 must be rejected.
+    assert_eq!(
+        group_supplicant.process_message(&message_1),
+        Err(HandshakeError::ReplayCounterMismatch)
+    );
+}
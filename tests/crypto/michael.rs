@@ -1,4 +1,11 @@
-use ieee80211::crypto::{michael, michael_block_function};
+use ieee80211::{
+    common::{DataFrameSubtype, FCFFlags},
+    crypto::{
+        append_michael_mic, michael, michael_block_function, verify_michael_mic, MICHAEL_MIC_LENGTH,
+    },
+    data_frame::header::DataFrameHeader,
+};
+use mac_parser::MACAddress;
 
 #[test]
 fn test_michael() {
@@ -24,3 +31,52 @@ fn test_block_function() {
         (0x9f04c4ad, 0x2ec6c2bf)
     );
 }
+
+const MIC_KEY: [u8; 8] = [0xd5, 0x5e, 0x10, 0x05, 0x10, 0x12, 0x89, 0x86];
+const DESTINATION_ADDRESS: MACAddress = MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x01]);
+const SOURCE_ADDRESS: MACAddress = MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x02]);
+const MSDU: &[u8] = b"Michael";
+
+#[test]
+fn test_michael_mic_for_msdu() {
+    let header = DataFrameHeader {
+        subtype: DataFrameSubtype::QoSData,
+        fcf_flags: FCFFlags::new(),
+        address_1: DESTINATION_ADDRESS,
+        address_2: SOURCE_ADDRESS,
+        qos: Some([0x05, 0x00]),
+        ..Default::default()
+    };
+
+    // The MIC is calculated over DA, SA, priority, three reserved bytes and the MSDU.
+    // The expected values were calculated with an independent implementation of michael.
+    let expected_mic = [0x6f, 0xad, 0xcc, 0xa7, 0x64, 0xbd, 0x90, 0x89];
+
+    let mut buf = [0x00; MSDU.len() + MICHAEL_MIC_LENGTH];
+    buf[..MSDU.len()].copy_from_slice(MSDU);
+    assert_eq!(
+        append_michael_mic(&MIC_KEY, &header, &mut buf, MSDU.len()).unwrap(),
+        buf.len()
+    );
+    assert_eq!(buf[MSDU.len()..], expected_mic);
+    assert!(verify_michael_mic(&MIC_KEY, &header, &buf));
+
+    // A different priority changes the MIC.
+    let non_qos_header = DataFrameHeader {
+        subtype: DataFrameSubtype::Data,
+        qos: None,
+        ..header
+    };
+    assert!(!verify_michael_mic(&MIC_KEY, &non_qos_header, &buf));
+    let mut non_qos_buf = buf;
+    append_michael_mic(&MIC_KEY, &non_qos_header, &mut non_qos_buf, MSDU.len()).unwrap();
+    assert_eq!(
+        non_qos_buf[MSDU.len()..],
+        [0x36, 0x8b, 0x96, 0x7b, 0x7e, 0x96, 0x47, 0xfa]
+    );
+
+    buf[0] ^= 0xff;
+    assert!(!verify_michael_mic(&MIC_KEY, &header, &buf));
+    assert!(!verify_michael_mic(&MIC_KEY, &header, &buf[..4]));
+    assert!(append_michael_mic(&MIC_KEY, &header, &mut buf, MSDU.len() + 1).is_err());
+}
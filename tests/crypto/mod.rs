@@ -1,4 +1,9 @@
 // All of the test vectors are taken from Annex J of IEEE 802.11-2020.
 
+mod ccmp;
+mod crypto_header;
+mod gcmp;
+mod handshake;
 mod michael;
 mod psk;
+mod replay;
@@ -0,0 +1,35 @@
+use ieee80211::crypto::{CryptoHeader, ReplayWindow};
+
+#[test]
+fn test_replay_window_in_order() {
+    let mut replay_window = ReplayWindow::new();
+    assert_eq!(replay_window.last_accepted_pn(0), None);
+    for pn in 1..10 {
+        assert!(replay_window.check_and_update(0, pn));
+    }
+    assert_eq!(replay_window.last_accepted_pn(0), Some(9));
+}
+#[test]
+fn test_replay_window_replayed() {
+    let mut replay_window = ReplayWindow::new();
+    let crypto_header = CryptoHeader::new_ccmp(0x1337, 1);
+    assert!(replay_window.check_and_update(crypto_header.key_id(), crypto_header.packet_number()));
+    assert!(!replay_window.check_and_update(crypto_header.key_id(), crypto_header.packet_number()));
+    // The PNs are tracked separately for every key ID.
+    assert!(replay_window.check_and_update(2, 0x1337));
+    assert_eq!(replay_window.last_accepted_pn(1), Some(0x1337));
+
+    // After a rekey, the PN starts over.
+    replay_window.reset(1);
+    assert!(replay_window.check_and_update(1, 1));
+}
+#[test]
+fn test_replay_window_out_of_order() {
+    let mut replay_window = ReplayWindow::new();
+    assert!(replay_window.check_and_update(0, 5));
+    assert!(!replay_window.check_and_update(0, 4));
+    assert!(replay_window.check_and_update(0, 7));
+    // Skipped PNs aren't accepted later on.
+    assert!(!replay_window.check_and_update(0, 6));
+    assert_eq!(replay_window.last_accepted_pn(0), Some(7));
+}
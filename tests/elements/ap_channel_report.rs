@@ -0,0 +1,26 @@
+use ieee80211::elements::{APChannelReportElement, ReadElements};
+
+use crate::roundtrip_test;
+
+const EXPECTED_AP_CHANNEL_REPORT_ELEMENT: APChannelReportElement = APChannelReportElement {
+    operating_class: 115,
+    channel_list: &[36, 40, 44],
+};
+const EXPECTED_AP_CHANNEL_REPORT_ELEMENT_BYTES: &[u8] = &[0x33, 0x04, 115, 36, 40, 44];
+
+roundtrip_test!(
+    test_ap_channel_report_element_rw,
+    APChannelReportElement,
+    EXPECTED_AP_CHANNEL_REPORT_ELEMENT,
+    &EXPECTED_AP_CHANNEL_REPORT_ELEMENT_BYTES[2..]
+);
+#[test]
+fn test_ap_channel_report_element_channels() {
+    let ap_channel_report = ReadElements {
+        bytes: EXPECTED_AP_CHANNEL_REPORT_ELEMENT_BYTES,
+    }
+    .get_first_element::<APChannelReportElement>()
+    .unwrap();
+    assert_eq!(ap_channel_report, EXPECTED_AP_CHANNEL_REPORT_ELEMENT);
+    assert!(ap_channel_report.channels().eq([36, 40, 44]));
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use ieee80211::elements::{BSSMaxIdlePeriodElement, IdleOptions};
+
+use crate::roundtrip_test;
+
+const EXPECTED_BSS_MAX_IDLE_PERIOD_ELEMENT: BSSMaxIdlePeriodElement = BSSMaxIdlePeriodElement {
+    max_idle_period: 10,
+    idle_options: IdleOptions::new().with_protected_keep_alive_required(true),
+};
+const EXPECTED_BSS_MAX_IDLE_PERIOD_ELEMENT_BYTES: &[u8] = &[0x0a, 0x00, 0x01];
+
+roundtrip_test!(
+    test_bss_max_idle_period_element_rw,
+    BSSMaxIdlePeriodElement,
+    EXPECTED_BSS_MAX_IDLE_PERIOD_ELEMENT,
+    EXPECTED_BSS_MAX_IDLE_PERIOD_ELEMENT_BYTES
+);
+#[test]
+fn test_bss_max_idle_period_element_duration() {
+    // 10 * 1000 TUs are 10.24 seconds.
+    assert_eq!(
+        EXPECTED_BSS_MAX_IDLE_PERIOD_ELEMENT.max_idle_period_tu(),
+        Duration::from_millis(10_240)
+    );
+    assert_eq!(
+        BSSMaxIdlePeriodElement {
+            max_idle_period: u16::MAX,
+            ..Default::default()
+        }
+        .max_idle_period_tu(),
+        Duration::from_micros(1024 * 1000 * u16::MAX as u64)
+    );
+}
@@ -0,0 +1,86 @@
+use ieee80211::elements::country::{
+    CountryElement, CountryEnvironment, CountrySubbandTriplet, CountrySubbandTripletIterator,
+};
+
+use crate::roundtrip_test;
+
+const EXPECTED_CHANNEL_TRIPLETS_ELEMENT: CountryElement<[CountrySubbandTriplet; 2]> =
+    CountryElement::new(
+        *b"DE",
+        CountryEnvironment::Any,
+        [
+            CountrySubbandTriplet::Channels {
+                first_channel: 1,
+                number_of_channels: 13,
+                max_transmit_power: 20,
+            },
+            CountrySubbandTriplet::Channels {
+                first_channel: 36,
+                number_of_channels: 4,
+                max_transmit_power: 23,
+            },
+        ],
+    );
+// Three bytes of header and two triplets are odd, so a pad byte is appended.
+const EXPECTED_CHANNEL_TRIPLETS_BYTES: &[u8] =
+    &[b'D', b'E', b' ', 0x01, 0x0d, 0x14, 0x24, 0x04, 0x17, 0x00];
+roundtrip_test!(
+    test_country_element_channel_triplets_rw,
+    CountryElement,
+    EXPECTED_CHANNEL_TRIPLETS_ELEMENT,
+    EXPECTED_CHANNEL_TRIPLETS_BYTES
+);
+
+const EXPECTED_OPERATING_TRIPLETS_ELEMENT: CountryElement<[CountrySubbandTriplet; 3]> =
+    CountryElement::new(
+        *b"US",
+        CountryEnvironment::Outdoor,
+        [
+            CountrySubbandTriplet::OperatingClass {
+                operating_extension_identifier: 201,
+                operating_class: 81,
+                coverage_class: 0,
+            },
+            CountrySubbandTriplet::Channels {
+                first_channel: 1,
+                number_of_channels: 11,
+                max_transmit_power: 30,
+            },
+            CountrySubbandTriplet::Channels {
+                first_channel: 36,
+                number_of_channels: 4,
+                max_transmit_power: -1,
+            },
+        ],
+    );
+// Three bytes of header and three triplets are even, so no padding is required.
+const EXPECTED_OPERATING_TRIPLETS_BYTES: &[u8] = &[
+    b'U', b'S', b'O', 0xc9, 0x51, 0x00, 0x01, 0x0b, 0x1e, 0x24, 0x04, 0xff,
+];
+roundtrip_test!(
+    test_country_element_operating_triplets_rw,
+    CountryElement,
+    EXPECTED_OPERATING_TRIPLETS_ELEMENT,
+    EXPECTED_OPERATING_TRIPLETS_BYTES
+);
+
+#[test]
+fn test_country_element_padding() {
+    use scroll::Pread;
+
+    // Only the header and the pad byte.
+    let country_element = [b'J', b'P', b'I', 0x00].pread::<CountryElement>(0).unwrap();
+    assert_eq!(
+        country_element,
+        CountryElement::new(
+            *b"JP",
+            CountryEnvironment::Indoor,
+            CountrySubbandTripletIterator::new(&[])
+        )
+    );
+    assert_eq!(country_element.subband_triplets().count(), 0);
+    // Two trailing bytes can't be padding.
+    assert!([b'J', b'P', b'I', 0x01, 0x02]
+        .pread::<CountryElement>(0)
+        .is_err());
+}
@@ -0,0 +1,60 @@
+use ieee80211::elements::{
+    ChannelSwitchAnnouncementElement, ExtendedChannelSwitchAnnouncementElement, ReadElements,
+};
+
+use crate::roundtrip_test;
+
+const EXPECTED_CSA_ELEMENT: ChannelSwitchAnnouncementElement = ChannelSwitchAnnouncementElement {
+    channel_switch_mode: 1,
+    new_channel_number: 36,
+    channel_switch_count: 5,
+};
+const EXPECTED_CSA_ELEMENT_BYTES: &[u8] = &[0x01, 0x24, 0x05];
+
+roundtrip_test!(
+    test_csa_element_rw,
+    ChannelSwitchAnnouncementElement,
+    EXPECTED_CSA_ELEMENT,
+    EXPECTED_CSA_ELEMENT_BYTES
+);
+
+const EXPECTED_ECSA_ELEMENT: ExtendedChannelSwitchAnnouncementElement =
+    ExtendedChannelSwitchAnnouncementElement {
+        channel_switch_mode: 0,
+        new_operating_class: 128,
+        new_channel_number: 36,
+        channel_switch_count: 10,
+    };
+const EXPECTED_ECSA_ELEMENT_BYTES: &[u8] = &[0x00, 0x80, 0x24, 0x0a];
+
+roundtrip_test!(
+    test_ecsa_element_rw,
+    ExtendedChannelSwitchAnnouncementElement,
+    EXPECTED_ECSA_ELEMENT,
+    EXPECTED_ECSA_ELEMENT_BYTES
+);
+#[test]
+fn test_csa_countdown() {
+    // The elements of two consecutive beacons, during a channel switch.
+    let beacons: [&[u8]; 2] = [
+        &[
+            0x25, 0x03, 0x01, 0x24, 0x02, 0x3c, 0x04, 0x01, 0x80, 0x24, 0x02,
+        ],
+        &[
+            0x25, 0x03, 0x01, 0x24, 0x01, 0x3c, 0x04, 0x01, 0x80, 0x24, 0x01,
+        ],
+    ];
+    for (bytes, expected_count) in beacons.into_iter().zip([2, 1]) {
+        let elements = ReadElements { bytes };
+        let csa = elements
+            .get_first_element::<ChannelSwitchAnnouncementElement>()
+            .unwrap();
+        let ecsa = elements
+            .get_first_element::<ExtendedChannelSwitchAnnouncementElement>()
+            .unwrap();
+        assert_eq!(csa.channel_switch_count, expected_count);
+        assert_eq!(ecsa.channel_switch_count, expected_count);
+        assert_eq!(csa.new_channel_number, ecsa.new_channel_number);
+        assert!(ecsa.transmissions_restricted());
+    }
+}
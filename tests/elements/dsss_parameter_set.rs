@@ -13,3 +13,14 @@ roundtrip_test!(
     EXPECTED_DSSS_PARAMETER_SET_ELEMENT,
     EXPECTED_DSSS_PARAMETER_SET_BYTES
 );
+#[test]
+fn test_dsss_parameter_set_channel() {
+    for (channel, center_frequency) in [(1, 2412), (6, 2437), (14, 2484)] {
+        let dsss_parameter_set =
+            DSSSParameterSetElement::from_channel(channel).expect("A valid channel was rejected.");
+        assert_eq!(dsss_parameter_set.current_channel, channel);
+        assert_eq!(dsss_parameter_set.center_frequency_mhz(), center_frequency);
+    }
+    assert!(DSSSParameterSetElement::from_channel(0).is_none());
+    assert!(DSSSParameterSetElement::from_channel(15).is_none());
+}
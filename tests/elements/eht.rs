@@ -0,0 +1,155 @@
+use ieee80211::elements::{
+    eht::{
+        EHTCapabilitiesElement, EHTChannelWidth, EHTMACCapabilitiesInformation,
+        EHTOperationElement, EHTOperationInformation, EHTOperationParameters,
+        EHTPHYCapabilitiesInformation,
+    },
+    he::HEPHYCapabilitiesInformation,
+    ReadElements,
+};
+use scroll::Pread;
+
+use crate::roundtrip_test;
+
+const EXPECTED_EHT_CAPABILITIES_ELEMENT: EHTCapabilitiesElement = EHTCapabilitiesElement {
+    eht_mac_capabilities_information: EHTMACCapabilitiesInformation::new()
+        .with_eht_om_control_support(true)
+        .with_maximum_mpdu_length(1),
+    eht_phy_capabilities_information: EHTPHYCapabilitiesInformation([
+        0x62, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+    ]),
+    supported_eht_mcs_and_nss_set: &[0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33],
+    eht_ppe_thresholds: Some(&[0x10, 0x00]),
+};
+const EXPECTED_EHT_CAPABILITIES_ELEMENT_BYTES: &[u8] = &[
+    0x42, 0x00, // EHT MAC Capabilities Information
+    0x62, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, // EHT PHY Capabilities Information
+    0x33, 0x33, 0x33, // EHT-MCS Map (BW <= 80 MHz)
+    0x33, 0x33, 0x33, // EHT-MCS Map (BW = 160 MHz)
+    0x33, 0x33, 0x33, // EHT-MCS Map (BW = 320 MHz)
+    0x10, 0x00, // EHT PPE Thresholds with one NSS and RU index.
+];
+
+roundtrip_test!(
+    test_eht_capabilities_element_rw,
+    EHTCapabilitiesElement,
+    EXPECTED_EHT_CAPABILITIES_ELEMENT,
+    EXPECTED_EHT_CAPABILITIES_ELEMENT_BYTES
+);
+
+#[test]
+fn test_eht_phy_capabilities() {
+    let phy_capabilities = EXPECTED_EHT_CAPABILITIES_ELEMENT.eht_phy_capabilities_information;
+    assert!(phy_capabilities.support_for_320mhz_in_6ghz());
+    assert!(phy_capabilities.su_beamformer());
+    assert!(phy_capabilities.su_beamformee());
+    assert!(phy_capabilities.ppe_thresholds_present());
+}
+
+#[test]
+fn test_eht_capabilities_mcs_set_length() {
+    // HE PHY Capabilities Information with support for 40 and 80 MHz, as well as 160 MHz in the 5 and 6 GHz bands.
+    let he_phy_capabilities_information = HEPHYCapabilitiesInformation([
+        0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ]);
+    assert_eq!(
+        EHTCapabilitiesElement::read_with_he_phy_capabilities(
+            EXPECTED_EHT_CAPABILITIES_ELEMENT_BYTES,
+            he_phy_capabilities_information,
+            true
+        )
+        .unwrap(),
+        EXPECTED_EHT_CAPABILITIES_ELEMENT
+    );
+    // Without support for 160 MHz, the 320 MHz map would directly follow the 80 MHz map, leaving too many bytes for the PPE Thresholds.
+    assert!(EHTCapabilitiesElement::read_with_he_phy_capabilities(
+        EXPECTED_EHT_CAPABILITIES_ELEMENT_BYTES,
+        HEPHYCapabilitiesInformation([
+            0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+        ]),
+        true
+    )
+    .is_err());
+
+    // A 20 MHz-only non-AP STA without 320 MHz support and PPE Thresholds.
+    let bytes = [
+        0x00, 0x00, // EHT MAC Capabilities Information
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // EHT PHY Capabilities Information
+        0x22, 0x22, 0x22, 0x22, // EHT-MCS Map (20 MHz-Only Non-AP STA)
+    ];
+    let twenty_mhz_only = HEPHYCapabilitiesInformation::default();
+    let eht_capabilities =
+        EHTCapabilitiesElement::read_with_he_phy_capabilities(&bytes, twenty_mhz_only, false)
+            .unwrap();
+    assert_eq!(eht_capabilities.supported_eht_mcs_and_nss_set, &bytes[11..]);
+    assert_eq!(
+        bytes.pread::<EHTCapabilitiesElement>(0).unwrap(),
+        eht_capabilities
+    );
+    // A 20 MHz-only AP uses the map for <= 80 MHz.
+    assert!(
+        EHTCapabilitiesElement::read_with_he_phy_capabilities(&bytes, twenty_mhz_only, true)
+            .is_err()
+    );
+}
+#[test]
+fn test_eht_capabilities_from_elements() {
+    let mut bytes = vec![
+        0xff, 0x0c, 0x23, // HE Capabilities element header
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // HE MAC Capabilities Information
+        0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // HE PHY Capabilities Information
+        0xfa, 0xff, 0xfa, 0xff, // HE-MCS maps for <= 80 MHz
+        0xfa, 0xff, 0xfa, 0xff, // HE-MCS maps for 160 MHz
+    ];
+    bytes[1] = bytes.len() as u8 - 2;
+    bytes.extend_from_slice(&[
+        0xff,
+        EXPECTED_EHT_CAPABILITIES_ELEMENT_BYTES.len() as u8 + 1,
+        0x6c,
+    ]);
+    bytes.extend_from_slice(EXPECTED_EHT_CAPABILITIES_ELEMENT_BYTES);
+
+    let elements = ReadElements { bytes: &bytes };
+    assert_eq!(
+        EHTCapabilitiesElement::from_elements(elements, true),
+        Some(EXPECTED_EHT_CAPABILITIES_ELEMENT)
+    );
+    // Without an HE Capabilities element, the length can't be determined.
+    assert_eq!(
+        EHTCapabilitiesElement::from_elements(
+            ReadElements {
+                bytes: &bytes[2 + bytes[1] as usize..]
+            },
+            true
+        ),
+        None
+    );
+}
+
+const EXPECTED_EHT_OPERATION_ELEMENT: EHTOperationElement = EHTOperationElement {
+    eht_operation_parameters: EHTOperationParameters::new().with_eht_default_pe_duration(true),
+    basic_eht_mcs_and_nss_set: [0x11, 0x11, 0x11, 0x11],
+    eht_operation_information: Some(EHTOperationInformation {
+        channel_width: EHTChannelWidth::ThreeHundredTwentyMHz,
+        ccfs0: 15,
+        ccfs1: 31,
+        disabled_subchannel_bitmap: Some(0x0002),
+    }),
+};
+const EXPECTED_EHT_OPERATION_ELEMENT_BYTES: &[u8] = &[
+    0x07, // EHT Operation Parameters
+    0x11, 0x11, 0x11, 0x11, // Basic EHT-MCS And NSS Set
+    0x04, // Control
+    0x0f, // CCFS0
+    0x1f, // CCFS1
+    0x02, 0x00, // Disabled Subchannel Bitmap
+];
+
+roundtrip_test!(
+    test_eht_operation_element_rw,
+    EHTOperationElement,
+    EXPECTED_EHT_OPERATION_ELEMENT,
+    EXPECTED_EHT_OPERATION_ELEMENT_BYTES
+);
@@ -0,0 +1,22 @@
+use ieee80211::elements::ERPInformationElement;
+
+use crate::roundtrip_test;
+
+const EXPECTED_ERP_INFORMATION_ELEMENT: ERPInformationElement = ERPInformationElement::new()
+    .with_non_erp_present(true)
+    .with_use_protection(true);
+const EXPECTED_ERP_INFORMATION_ELEMENT_BYTES: &[u8] = &[0b0000_0011];
+
+roundtrip_test!(
+    test_erp_information_element_rw,
+    ERPInformationElement,
+    EXPECTED_ERP_INFORMATION_ELEMENT,
+    EXPECTED_ERP_INFORMATION_ELEMENT_BYTES
+);
+#[test]
+fn test_erp_information_element_misc() {
+    assert!(EXPECTED_ERP_INFORMATION_ELEMENT.non_erp_present());
+    assert!(EXPECTED_ERP_INFORMATION_ELEMENT.use_protection());
+    assert!(!EXPECTED_ERP_INFORMATION_ELEMENT.barker_preamble_mode());
+    assert!(ERPInformationElement::from_bits(0b0000_0100).barker_preamble_mode());
+}
@@ -0,0 +1,75 @@
+use ieee80211::elements::ExtendedCapabilitiesElement;
+
+use crate::roundtrip_test;
+
+const EXPECTED_EXTENDED_CAPABILITIES_BYTES: &[u8] = &[
+    0x04, // Extended Channel Switching
+    0x00, 0x08, // BSS Transition
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, // TWT Requester and TWT Responder
+];
+const EXPECTED_EXTENDED_CAPABILITIES_ELEMENT: ExtendedCapabilitiesElement =
+    ExtendedCapabilitiesElement {
+        bytes: EXPECTED_EXTENDED_CAPABILITIES_BYTES,
+    };
+
+roundtrip_test!(
+    test_extended_capabilities_element_rw,
+    ExtendedCapabilitiesElement,
+    EXPECTED_EXTENDED_CAPABILITIES_ELEMENT,
+    EXPECTED_EXTENDED_CAPABILITIES_BYTES
+);
+
+#[test]
+fn test_extended_capabilities_set_capabilities() {
+    assert!(EXPECTED_EXTENDED_CAPABILITIES_ELEMENT.is_capability_set(19));
+    assert!(!EXPECTED_EXTENDED_CAPABILITIES_ELEMENT.is_capability_set(20));
+    // Bits beyond the end of the element aren't set.
+    assert!(!EXPECTED_EXTENDED_CAPABILITIES_ELEMENT.is_capability_set(200));
+    assert!(EXPECTED_EXTENDED_CAPABILITIES_ELEMENT
+        .set_capabilities()
+        .eq([
+            "Extended Channel Switching",
+            "BSS Transition",
+            "TWT Requester",
+            "TWT Responder"
+        ]));
+}
+
+#[test]
+fn test_extended_capabilities_accessors() {
+    let one_octet = ExtendedCapabilitiesElement { bytes: &[0x04] };
+    assert!(one_octet.extended_channel_switching());
+    // Bits beyond the end of the element are treated as not set.
+    assert!(!one_octet.bss_transition());
+    assert!(!one_octet.twt_requester());
+
+    let three_octets = ExtendedCapabilitiesElement {
+        bytes: &[0x00, 0x10, 0x0a],
+    };
+    assert!(three_octets.proxy_arp());
+    assert!(three_octets.bss_transition());
+    assert!(three_octets.wnm_sleep_mode() && !three_octets.tim_broadcast());
+    assert!(!three_octets.interworking());
+
+    let eight_octets = ExtendedCapabilitiesElement {
+        bytes: &[0x00, 0x00, 0x00, 0x80, 0x01, 0x00, 0x00, 0x40],
+    };
+    assert!(eight_octets.interworking());
+    assert!(eight_octets.qos_map());
+    assert!(eight_octets.operating_mode_notification());
+    assert!(!eight_octets.twt_requester() && !eight_octets.twt_responder());
+
+    assert!(EXPECTED_EXTENDED_CAPABILITIES_ELEMENT.twt_requester());
+    assert!(EXPECTED_EXTENDED_CAPABILITIES_ELEMENT.twt_responder());
+}
+#[test]
+fn test_extended_capabilities_accessor_names_match() {
+    // The accessors and the names are generated from the same list, so they have to agree.
+    let element = ExtendedCapabilitiesElement {
+        bytes: &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+        ],
+    };
+    assert!(element.beacon_protection_enabled());
+    assert!(element.set_capabilities().eq(["Beacon Protection Enabled"]));
+}
@@ -0,0 +1,77 @@
+use ieee80211::elements::ft::{
+    FTCapabilityAndPolicy, FastBSSTransitionElement, MICControl, MobilityDomainElement,
+};
+use mac_parser::MACAddress;
+use scroll::Pread;
+
+use crate::roundtrip_test;
+
+const EXPECTED_MOBILITY_DOMAIN_ELEMENT: MobilityDomainElement = MobilityDomainElement {
+    mdid: 0x1337,
+    ft_capability_and_policy: FTCapabilityAndPolicy::new().with_ft_over_ds(true),
+};
+const EXPECTED_MOBILITY_DOMAIN_ELEMENT_BYTES: &[u8] = &[0x37, 0x13, 0x01];
+roundtrip_test!(
+    test_mobility_domain_element_rw,
+    MobilityDomainElement,
+    EXPECTED_MOBILITY_DOMAIN_ELEMENT,
+    EXPECTED_MOBILITY_DOMAIN_ELEMENT_BYTES
+);
+
+const EXPECTED_FAST_BSS_TRANSITION_ELEMENT: FastBSSTransitionElement = FastBSSTransitionElement {
+    mic_control: MICControl::new().with_element_count(3),
+    mic: [0x11; 16],
+    anonce: [0x22; 32],
+    snonce: [0x33; 32],
+    r1kh_id: None,
+    gtk: None,
+    r0kh_id: None,
+};
+const EXPECTED_FAST_BSS_TRANSITION_ELEMENT_BYTES: &[u8] = &[
+    0x00, 0x03, // MIC Control
+    0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11,
+    0x11, // MIC
+    0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22,
+    0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22,
+    0x22, // ANonce
+    0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33,
+    0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33,
+    0x33, // SNonce
+];
+roundtrip_test!(
+    test_fast_bss_transition_element_rw,
+    FastBSSTransitionElement,
+    EXPECTED_FAST_BSS_TRANSITION_ELEMENT,
+    EXPECTED_FAST_BSS_TRANSITION_ELEMENT_BYTES
+);
+
+#[test]
+fn test_fast_bss_transition_element_sub_elements() {
+    assert!(EXPECTED_FAST_BSS_TRANSITION_ELEMENT.has_mic());
+    assert!(!FastBSSTransitionElement::default().has_mic());
+
+    let mut bytes = EXPECTED_FAST_BSS_TRANSITION_ELEMENT_BYTES.to_vec();
+    bytes.extend_from_slice(&[0x01, 0x06, 0x00, 0x20, 0x91, 0x13, 0x37, 0x00]); // R1KH-ID
+    bytes.extend_from_slice(&[0x05, 0x01, 0x42]); // OCI, which is skipped.
+    bytes.extend_from_slice(&[0x03, 0x04, b't', b'e', b's', b't']); // R0KH-ID
+    let read = bytes.pread::<FastBSSTransitionElement>(0).unwrap();
+    assert_eq!(
+        read.r1kh_id,
+        Some(MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x00]))
+    );
+    assert_eq!(read.gtk, None);
+    assert_eq!(read.r0kh_id, Some(b"test".as_slice()));
+
+    // The R1KH-ID has to be a MAC address.
+    let mut bytes = EXPECTED_FAST_BSS_TRANSITION_ELEMENT_BYTES.to_vec();
+    bytes.extend_from_slice(&[0x01, 0x02, 0x00, 0x20]);
+    assert!(bytes.pread::<FastBSSTransitionElement>(0).is_err());
+    // The R0KH-ID can't be empty.
+    let mut bytes = EXPECTED_FAST_BSS_TRANSITION_ELEMENT_BYTES.to_vec();
+    bytes.extend_from_slice(&[0x03, 0x00]);
+    assert!(bytes.pread::<FastBSSTransitionElement>(0).is_err());
+    // The fixed fields have to be complete.
+    assert!(EXPECTED_FAST_BSS_TRANSITION_ELEMENT_BYTES[..81]
+        .pread::<FastBSSTransitionElement>(0)
+        .is_err());
+}
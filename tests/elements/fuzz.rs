@@ -0,0 +1,191 @@
+//! This module contains round trip fuzzing for all elements.
+//!
+//! Random bytes are parsed as the element type. If that succeeds, the element is written out again, which must produce exactly as many bytes as returned by [MeasureWith], and parsing those bytes must yield the same element.
+//! Since parsing may ignore parts of the input, like trailing bytes, the written bytes aren't required to match the input. From there on, writing and reading must be stable.
+
+use ieee80211::elements::{
+    country::CountryElement,
+    eht::{EHTCapabilitiesElement, EHTOperationElement},
+    ft::{FastBSSTransitionElement, MobilityDomainElement},
+    he::HECapabilitiesElement,
+    ht::{HTCapabilitiesElement, HTOperationElement},
+    kde::{MACAddressKDE, GTKKDE, PMKIDKDE},
+    measurement::{MeasurementReportElement, MeasurementRequestElement},
+    mesh::{MeshConfigurationElement, PREPElement, PREQElement},
+    mlo::MultiLinkElement,
+    neighbor_report::NeighborReportElement,
+    operating_classes::SupportedOperatingClassesElement,
+    rates::{ExtendedSupportedRatesElement, SupportedRatesElement},
+    rnr::ReducedNeighborReportElement,
+    rsn::{RSNElement, RSNExtensionElement},
+    tim::TIMElement,
+    tspec::{TCLASElement, TSPECElement},
+    twt::TWTElement,
+    vht::{VHTCapabilitiesElement, VHTOperationElement},
+    wmm::{WMMInfoElement, WMMParameterElement},
+    wpa::WPAElement,
+    APChannelReportElement, BSSLoadElement, BSSMaxIdlePeriodElement,
+    ChannelSwitchAnnouncementElement, DSSSParameterSetElement, ERPInformationElement,
+    ExtendedCapabilitiesElement, ExtendedChannelSwitchAnnouncementElement, IBSSParameterSetElement,
+    MultipleBSSIDElement, OWETransitionModeElement, OperatingModeNotificationElement, QuietElement,
+    RMEnabledCapabilitiesElement, SSIDElement, VendorSpecificElement,
+};
+
+/// The number of random inputs generated for every element type.
+const ITERATIONS: usize = 20_000;
+
+/// A minimal xorshift PRNG, so that failures are reproducible.
+struct XorShift(u64);
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    /// Fill the buffer with random bytes and return a random length, which is biased towards short inputs.
+    fn fill(&mut self, buf: &mut [u8]) -> usize {
+        buf.iter_mut().for_each(|byte| *byte = self.next() as u8);
+        // Short inputs are far more likely to be valid.
+        let max_length = [16, 64, buf.len()][self.next() as usize % 3];
+        self.next() as usize % (max_length + 1)
+    }
+}
+
+macro_rules! element_fuzz_test {
+    ($test_name:ident, $element_type:ty) => {
+        #[test]
+        fn $test_name() {
+            use ::scroll::{ctx::MeasureWith, Pread, Pwrite};
+
+            let mut rng = XorShift(0x1337_4242_dead_beef);
+            let mut input = [0x00u8; 255];
+            let mut output = [0x00u8; 512];
+            for _ in 0..ITERATIONS {
+                let length = rng.fill(&mut input);
+                let input = &input[..length];
+                let Ok(read) = input.pread::<$element_type>(0) else {
+                    continue;
+                };
+
+                let expected_length = read.measure_with(&());
+                #[allow(clippy::clone_on_copy)]
+                let original = read.clone();
+                let written = output.pwrite(read, 0).unwrap_or_else(|err| {
+                    panic!(
+                        "Writing {} read from {:02x?} failed: {err:?}",
+                        stringify!($element_type),
+                        input
+                    )
+                });
+                assert_eq!(
+                    written,
+                    expected_length,
+                    "The amount of bytes written for {}, read from {:02x?}, didn't match MeasureWith.",
+                    stringify!($element_type),
+                    input
+                );
+                let reread = output[..written]
+                    .pread::<$element_type>(0)
+                    .unwrap_or_else(|err| {
+                        panic!(
+                            "Rereading {} from {:02x?}, which was read from {:02x?}, failed: {err:?}",
+                            stringify!($element_type),
+                            &output[..written],
+                            input
+                        )
+                    });
+                assert_eq!(
+                    original,
+                    reread,
+                    "{} read from {:02x?} didn't match, after being written and reread.",
+                    stringify!($element_type),
+                    input
+                );
+                let mut rewritten = [0x00u8; 512];
+                let rewritten_length = rewritten.pwrite(reread, 0).unwrap();
+                assert_eq!(
+                    &output[..written],
+                    &rewritten[..rewritten_length],
+                    "Writing {} read from {:02x?} wasn't stable.",
+                    stringify!($element_type),
+                    input
+                );
+                assert_eq!(
+                    reread,
+                    rewritten[..rewritten_length]
+                        .pread::<$element_type>(0)
+                        .unwrap(),
+                    "{} read from {:02x?} didn't survive the round trip.",
+                    stringify!($element_type),
+                    input
+                );
+            }
+        }
+    };
+}
+
+element_fuzz_test!(fuzz_ssid_element, SSIDElement);
+element_fuzz_test!(fuzz_supported_rates_element, SupportedRatesElement);
+element_fuzz_test!(
+    fuzz_extended_supported_rates_element,
+    ExtendedSupportedRatesElement
+);
+element_fuzz_test!(fuzz_dsss_parameter_set_element, DSSSParameterSetElement);
+element_fuzz_test!(fuzz_ibss_parameter_set_element, IBSSParameterSetElement);
+element_fuzz_test!(fuzz_tim_element, TIMElement);
+element_fuzz_test!(fuzz_bss_load_element, BSSLoadElement);
+element_fuzz_test!(fuzz_tspec_element, TSPECElement);
+element_fuzz_test!(fuzz_tclas_element, TCLASElement);
+element_fuzz_test!(
+    fuzz_supported_operating_classes_element,
+    SupportedOperatingClassesElement
+);
+element_fuzz_test!(fuzz_ht_capabilities_element, HTCapabilitiesElement);
+element_fuzz_test!(fuzz_ht_operation_element, HTOperationElement);
+element_fuzz_test!(
+    fuzz_extended_capabilities_element,
+    ExtendedCapabilitiesElement
+);
+element_fuzz_test!(fuzz_vht_capabilities_element, VHTCapabilitiesElement);
+element_fuzz_test!(fuzz_vht_operation_element, VHTOperationElement);
+element_fuzz_test!(fuzz_rsn_element, RSNElement);
+element_fuzz_test!(fuzz_rsn_extension_element, RSNExtensionElement);
+element_fuzz_test!(fuzz_preq_element, PREQElement);
+element_fuzz_test!(fuzz_prep_element, PREPElement);
+element_fuzz_test!(fuzz_mesh_configuration_element, MeshConfigurationElement);
+element_fuzz_test!(fuzz_owe_transition_mode_element, OWETransitionModeElement);
+element_fuzz_test!(fuzz_vendor_specific_element, VendorSpecificElement);
+element_fuzz_test!(fuzz_multi_link_element, MultiLinkElement);
+element_fuzz_test!(fuzz_eht_capabilities_element, EHTCapabilitiesElement);
+element_fuzz_test!(fuzz_eht_operation_element, EHTOperationElement);
+element_fuzz_test!(fuzz_country_element, CountryElement);
+element_fuzz_test!(fuzz_wmm_info_element, WMMInfoElement);
+element_fuzz_test!(fuzz_wmm_parameter_element, WMMParameterElement);
+element_fuzz_test!(fuzz_he_capabilities_element, HECapabilitiesElement);
+element_fuzz_test!(fuzz_rnr_element, ReducedNeighborReportElement);
+element_fuzz_test!(
+    fuzz_operating_mode_notification_element,
+    OperatingModeNotificationElement
+);
+element_fuzz_test!(fuzz_csa_element, ChannelSwitchAnnouncementElement);
+element_fuzz_test!(fuzz_measurement_request_element, MeasurementRequestElement);
+element_fuzz_test!(fuzz_measurement_report_element, MeasurementReportElement);
+element_fuzz_test!(fuzz_erp_information_element, ERPInformationElement);
+element_fuzz_test!(fuzz_bss_max_idle_period_element, BSSMaxIdlePeriodElement);
+element_fuzz_test!(fuzz_mobility_domain_element, MobilityDomainElement);
+element_fuzz_test!(fuzz_fast_bss_transition_element, FastBSSTransitionElement);
+element_fuzz_test!(fuzz_wpa_element, WPAElement);
+element_fuzz_test!(fuzz_twt_element, TWTElement);
+element_fuzz_test!(fuzz_ecsa_element, ExtendedChannelSwitchAnnouncementElement);
+element_fuzz_test!(fuzz_multiple_bssid_element, MultipleBSSIDElement);
+element_fuzz_test!(fuzz_quiet_element, QuietElement);
+element_fuzz_test!(fuzz_gtk_kde, GTKKDE);
+element_fuzz_test!(fuzz_pmkid_kde, PMKIDKDE);
+element_fuzz_test!(fuzz_mac_address_kde, MACAddressKDE);
+element_fuzz_test!(fuzz_ap_channel_report_element, APChannelReportElement);
+element_fuzz_test!(
+    fuzz_rm_enabled_capabilities_element,
+    RMEnabledCapabilitiesElement
+);
+element_fuzz_test!(fuzz_neighbor_report_element, NeighborReportElement);
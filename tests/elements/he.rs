@@ -0,0 +1,139 @@
+use ieee80211::elements::{
+    he::{
+        HECapabilitiesElement, HEMACCapabilitiesInformation, HEMCSMap, HEMCSMaps, HEMCSSupport,
+        HEPHYCapabilitiesInformation, PPEThresholds, SupportedHEMCSAndNSSSet,
+    },
+    ReadElements, SSIDElement,
+};
+use scroll::{Pread, Pwrite};
+
+use crate::roundtrip_test;
+
+const PPE_THRESHOLDS_BYTES: &[u8] = &[
+    0x98, // NSTS = 0, RU Index Bitmask = 0b0011
+    0x3d, 0x00, // PPET16 and PPET8 for RU index zero and one.
+];
+
+#[test]
+fn test_ppe_thresholds() {
+    // A trailing byte, which doesn't belong to the PPE Thresholds field.
+    let mut bytes = [0xff; PPE_THRESHOLDS_BYTES.len() + 1];
+    bytes[..PPE_THRESHOLDS_BYTES.len()].copy_from_slice(PPE_THRESHOLDS_BYTES);
+
+    let mut offset = 0;
+    let ppe_thresholds = bytes.gread::<PPEThresholds>(&mut offset).unwrap();
+    assert_eq!(offset, PPE_THRESHOLDS_BYTES.len());
+    assert_eq!(ppe_thresholds.nss(), 1);
+    assert_eq!(ppe_thresholds.ru_index_bitmask(), 0b0011);
+    assert_eq!(ppe_thresholds.thresholds(0, 0), Some((3, 7)));
+    assert_eq!(ppe_thresholds.thresholds(0, 1), Some((1, 0)));
+    assert_eq!(ppe_thresholds.thresholds(0, 2), None);
+    assert_eq!(ppe_thresholds.thresholds(1, 0), None);
+
+    let mut buf = [0x00; PPE_THRESHOLDS_BYTES.len()];
+    buf.pwrite(ppe_thresholds, 0).unwrap();
+    assert_eq!(buf, PPE_THRESHOLDS_BYTES);
+
+    assert!(PPE_THRESHOLDS_BYTES[..2].pread::<PPEThresholds>(0).is_err());
+    assert_eq!(
+        PPEThresholds::new(PPE_THRESHOLDS_BYTES),
+        Some(ppe_thresholds)
+    );
+    assert_eq!(PPEThresholds::new(&bytes), None);
+}
+
+const HE_CAPABILITIES_BYTES: &[u8] = &[
+    0x05, 0x00, 0x10, 0x02, 0x00, 0x00, // HE MAC Capabilities Information
+    0x0c, 0x20, 0x02, 0xc0, 0x0f, 0x03, 0x95, 0x18, 0x00, 0xcc,
+    0x00, // HE PHY Capabilities Information
+    0xfa, 0xff, 0xfa, 0xff, // HE-MCS maps for <= 80 MHz
+    0xfa, 0xff, 0xfa, 0xff, // HE-MCS maps for 160 MHz
+    0x79, 0x1c, 0xc7, 0x71, 0x1c, 0xc7, 0x71, // PPE Thresholds
+];
+const HE_MCS_MAPS: HEMCSMaps = HEMCSMaps {
+    rx_he_mcs_map: HEMCSMap::from_bits(0xfffa),
+    tx_he_mcs_map: HEMCSMap::from_bits(0xfffa),
+};
+fn expected_he_capabilities_element() -> HECapabilitiesElement<'static> {
+    HECapabilitiesElement {
+        he_mac_capabilities_information: HEMACCapabilitiesInformation([
+            0x05, 0x00, 0x10, 0x02, 0x00, 0x00,
+        ]),
+        he_phy_capabilities_information: HEPHYCapabilitiesInformation([
+            0x0c, 0x20, 0x02, 0xc0, 0x0f, 0x03, 0x95, 0x18, 0x00, 0xcc, 0x00,
+        ]),
+        supported_he_mcs_and_nss_set: SupportedHEMCSAndNSSSet {
+            he_mcs_80mhz: HE_MCS_MAPS,
+            he_mcs_160mhz: Some(HE_MCS_MAPS),
+            he_mcs_80p80mhz: None,
+        },
+        ppe_thresholds: PPEThresholds::new(&HE_CAPABILITIES_BYTES[25..]),
+    }
+}
+roundtrip_test!(
+    test_he_capabilities_element_rw,
+    HECapabilitiesElement,
+    expected_he_capabilities_element(),
+    HE_CAPABILITIES_BYTES
+);
+
+#[test]
+fn test_he_capabilities_element_accessors() {
+    let he_capabilities = expected_he_capabilities_element();
+    assert!(he_capabilities.supports_160mhz());
+    assert!(!he_capabilities.supports_80p80mhz());
+    assert!(he_capabilities.su_beamformer());
+    assert!(he_capabilities.su_beamformee());
+    assert!(he_capabilities
+        .he_mac_capabilities_information
+        .htc_he_support());
+    assert!(he_capabilities
+        .he_mac_capabilities_information
+        .twt_responder_support());
+    assert!(!he_capabilities
+        .he_mac_capabilities_information
+        .twt_requester_support());
+    assert_eq!(
+        he_capabilities
+            .he_mac_capabilities_information
+            .maximum_ampdu_length_exponent_extension(),
+        0
+    );
+    assert_eq!(
+        HE_MCS_MAPS.rx_he_mcs_map.he_mcs_support_for_nss(2),
+        Some(HEMCSSupport::ZeroToEleven)
+    );
+    assert_eq!(
+        HE_MCS_MAPS.rx_he_mcs_map.he_mcs_support_for_nss(3),
+        Some(HEMCSSupport::NotSupported)
+    );
+    assert_eq!(he_capabilities.ppe_thresholds.unwrap().nss(), 2);
+
+    // Without the 160 MHz bit, only the maps for <= 80 MHz are read.
+    let mut bytes = HE_CAPABILITIES_BYTES.to_vec();
+    bytes[6] &= !0b1000;
+    let he_capabilities = bytes.pread::<HECapabilitiesElement>(0).unwrap();
+    assert!(!he_capabilities.supports_160mhz());
+    assert_eq!(
+        he_capabilities.supported_he_mcs_and_nss_set.he_mcs_160mhz,
+        None
+    );
+}
+#[test]
+fn test_he_capabilities_element_followed_by_element() {
+    // HE Capabilities element, followed by an SSID element.
+    let mut bytes = vec![0xff, HE_CAPABILITIES_BYTES.len() as u8 + 1, 0x23];
+    bytes.extend_from_slice(HE_CAPABILITIES_BYTES);
+    bytes.extend_from_slice(&[0x00, 0x07, b'O', b'p', b'e', b'n', b'W', b'r', b't']);
+
+    let elements = ReadElements { bytes: &bytes };
+    assert_eq!(
+        elements.get_first_element::<HECapabilitiesElement>(),
+        Some(expected_he_capabilities_element())
+    );
+    assert_eq!(
+        elements.get_first_element::<SSIDElement>().unwrap().ssid(),
+        "OpenWrt"
+    );
+    assert_eq!(elements.raw_element_iterator().count(), 2);
+}
@@ -0,0 +1,79 @@
+use ieee80211::{
+    elements::ht::{
+        HTOperationElement, HTOperationInformation, HTProtectionMode, SecondaryChannelOffset,
+        SupportedMCSSet, SupportedMCSSetFlags,
+    },
+    mgmt_frame::BeaconFrame,
+    supported_rx_mcs_set,
+};
+use scroll::Pread;
+
+use crate::roundtrip_test;
+
+#[test]
+fn test_rx_spatial_streams() {
+    let mcs_set = |supported_rx_mcs_set| SupportedMCSSet {
+        supported_rx_mcs_set,
+        ..Default::default()
+    };
+    assert_eq!(mcs_set([0x00; 10]).rx_spatial_streams(), 0);
+    assert_eq!(
+        mcs_set(supported_rx_mcs_set!(0 => 8)).rx_spatial_streams(),
+        1
+    );
+    assert_eq!(
+        mcs_set(supported_rx_mcs_set!(0 => 24)).rx_spatial_streams(),
+        3
+    );
+    assert_eq!(
+        mcs_set(supported_rx_mcs_set!(0 => 32)).rx_spatial_streams(),
+        4
+    );
+    // The third spatial stream isn't counted, since the second one is missing.
+    assert_eq!(
+        mcs_set(supported_rx_mcs_set![0, 1, 16, 17]).rx_spatial_streams(),
+        1
+    );
+}
+
+// Taken from beacon.bin.
+const EXPECTED_HT_OPERATION_ELEMENT: HTOperationElement = HTOperationElement {
+    primary_channel: 44,
+    ht_operation_information: HTOperationInformation::new()
+        .with_secondary_channel_offset(SecondaryChannelOffset::NotPresent)
+        .with_ht_protection_mode(HTProtectionMode::NonMember)
+        .with_nongreenfield_ht_sta_present(true),
+    basic_ht_mcs_set: SupportedMCSSet {
+        supported_rx_mcs_set: [0x00; 10],
+        supported_rx_mcs_set_flags: SupportedMCSSetFlags::new(),
+    },
+};
+const EXPECTED_HT_OPERATION_BYTES: &[u8] = &[
+    0x2c, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+roundtrip_test!(
+    test_ht_operation_element_rw,
+    HTOperationElement,
+    EXPECTED_HT_OPERATION_ELEMENT,
+    EXPECTED_HT_OPERATION_BYTES
+);
+#[test]
+fn test_ht_operation_element_from_beacon() {
+    let beacon = include_bytes!("../../bins/frames/beacon.bin")
+        .pread::<BeaconFrame>(0)
+        .unwrap();
+    let ht_operation = beacon
+        .elements
+        .get_first_element::<HTOperationElement>()
+        .unwrap();
+    let ht_operation_information = ht_operation.ht_operation_information;
+    assert_eq!(
+        ht_operation_information.secondary_channel_offset(),
+        SecondaryChannelOffset::NotPresent
+    );
+    assert_eq!(
+        ht_operation_information.ht_protection_mode(),
+        HTProtectionMode::NonMember
+    );
+}
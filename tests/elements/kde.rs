@@ -0,0 +1,73 @@
+use ieee80211::elements::{
+    kde::{MACAddressKDE, GTKKDE, PMKIDKDE},
+    ReadElements,
+};
+use mac_parser::MACAddress;
+
+use crate::roundtrip_test;
+
+const EXPECTED_GTK_KDE: GTKKDE = GTKKDE {
+    key_id: 1,
+    tx: false,
+    gtk: &[
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff,
+    ],
+};
+const EXPECTED_PMKID_KDE: PMKIDKDE = PMKIDKDE { pmkid: [0x42; 16] };
+const EXPECTED_MAC_ADDRESS_KDE: MACAddressKDE = MACAddressKDE {
+    mac_address: MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x00]),
+};
+// The key data of a message 3, after unwrapping it with the KEK.
+const KEY_DATA_BYTES: &[u8] = &[
+    0xdd, 0x16, // Element header
+    0x00, 0x0f, 0xac, 0x01, // OUI and data type
+    0x01, 0x00, // Key ID and Tx
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+    0xff, // GTK
+    0xdd, 0x14, // Element header
+    0x00, 0x0f, 0xac, 0x04, // OUI and data type
+    0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42,
+    0x42, // PMKID
+    0xdd, 0x0a, // Element header
+    0x00, 0x0f, 0xac, 0x03, // OUI and data type
+    0x00, 0x20, 0x91, 0x13, 0x37, 0x00, // MAC address
+    0xdd, 0x00, 0x00, 0x00, // Padding
+];
+
+roundtrip_test!(
+    test_gtk_kde_rw,
+    GTKKDE,
+    EXPECTED_GTK_KDE,
+    &KEY_DATA_BYTES[6..24]
+);
+roundtrip_test!(
+    test_pmkid_kde_rw,
+    PMKIDKDE,
+    EXPECTED_PMKID_KDE,
+    &KEY_DATA_BYTES[30..46]
+);
+roundtrip_test!(
+    test_mac_address_kde_rw,
+    MACAddressKDE,
+    EXPECTED_MAC_ADDRESS_KDE,
+    &KEY_DATA_BYTES[52..58]
+);
+#[test]
+fn test_kdes_from_key_data() {
+    let key_data = ReadElements {
+        bytes: KEY_DATA_BYTES,
+    };
+    assert_eq!(
+        key_data.get_first_element::<GTKKDE>(),
+        Some(EXPECTED_GTK_KDE)
+    );
+    assert_eq!(
+        key_data.get_first_element::<PMKIDKDE>(),
+        Some(EXPECTED_PMKID_KDE)
+    );
+    assert_eq!(
+        key_data.get_first_element::<MACAddressKDE>(),
+        Some(EXPECTED_MAC_ADDRESS_KDE)
+    );
+}
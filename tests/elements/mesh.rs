@@ -0,0 +1,113 @@
+use std::marker::PhantomData;
+
+use ieee80211::{
+    elements::mesh::{
+        CongestionControlMode, MeshAuthenticationProtocol, MeshCapability,
+        MeshConfigurationElement, MeshFormationInfo, PREPElement, PREPFlags, PREQElement,
+        PREQFlags, PREQTarget, PREQTargetFlags, PathSelectionMetric, PathSelectionProtocol,
+        SynchronizationMethod,
+    },
+    mac_parser::MACAddress,
+};
+
+use scroll::{ctx::MeasureWith, Pwrite};
+
+use crate::roundtrip_test;
+
+const EXPECTED_PREQ_ELEMENT: PREQElement<'static, [PREQTarget; 1]> = PREQElement {
+    flags: PREQFlags::new().with_individually_addressed(true),
+    hop_count: 1,
+    element_ttl: 31,
+    path_discovery_id: 0x12,
+    originator_address: MACAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+    originator_hwmp_sequence_number: 0x1337,
+    originator_external_address: Some(MACAddress::new([0x02, 0x00, 0x00, 0x00, 0x00, 0x01])),
+    lifetime: 4882,
+    metric: 0x100,
+    targets: [PREQTarget {
+        flags: PREQTargetFlags::new().with_target_only(true),
+        target_address: MACAddress::new([0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb]),
+        target_hwmp_sequence_number: 0,
+    }],
+    _phantom: PhantomData,
+};
+const EXPECTED_PREQ_ELEMENT_BYTES: &[u8] = &[
+    0x42, 0x01, 0x1f, 0x12, 0x00, 0x00, 0x00, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x37, 0x13, 0x00,
+    0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x12, 0x13, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01,
+    0x01, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0x00, 0x00, 0x00, 0x00,
+];
+roundtrip_test!(
+    test_preq_element_rw,
+    PREQElement,
+    EXPECTED_PREQ_ELEMENT,
+    EXPECTED_PREQ_ELEMENT_BYTES
+);
+#[test]
+fn test_preq_element_too_many_targets() {
+    let preq_element = PREQElement {
+        flags: EXPECTED_PREQ_ELEMENT.flags,
+        hop_count: EXPECTED_PREQ_ELEMENT.hop_count,
+        element_ttl: EXPECTED_PREQ_ELEMENT.element_ttl,
+        path_discovery_id: EXPECTED_PREQ_ELEMENT.path_discovery_id,
+        originator_address: EXPECTED_PREQ_ELEMENT.originator_address,
+        originator_hwmp_sequence_number: EXPECTED_PREQ_ELEMENT.originator_hwmp_sequence_number,
+        originator_external_address: None,
+        lifetime: EXPECTED_PREQ_ELEMENT.lifetime,
+        metric: EXPECTED_PREQ_ELEMENT.metric,
+        targets: [EXPECTED_PREQ_ELEMENT.targets[0]; 256],
+        _phantom: PhantomData,
+    };
+    let mut buf = vec![0x00; preq_element.measure_with(&())];
+    assert!(buf.pwrite(preq_element, 0).is_err());
+}
+
+const EXPECTED_PREP_ELEMENT: PREPElement = PREPElement {
+    flags: PREPFlags::new(),
+    hop_count: 2,
+    element_ttl: 30,
+    target_address: MACAddress::new([0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb]),
+    target_hwmp_sequence_number: 0x42,
+    target_external_address: None,
+    lifetime: 4882,
+    metric: 0x200,
+    originator_address: MACAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+    originator_hwmp_sequence_number: 0x1337,
+};
+const EXPECTED_PREP_ELEMENT_BYTES: &[u8] = &[
+    0x00, 0x02, 0x1e, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0x42, 0x00, 0x00, 0x00, 0x12, 0x13, 0x00,
+    0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x37, 0x13, 0x00, 0x00,
+];
+roundtrip_test!(
+    test_prep_element_rw,
+    PREPElement,
+    EXPECTED_PREP_ELEMENT,
+    EXPECTED_PREP_ELEMENT_BYTES
+);
+
+const EXPECTED_MESH_CONFIGURATION_ELEMENT: MeshConfigurationElement = MeshConfigurationElement {
+    active_path_selection_protocol: PathSelectionProtocol::HWMP,
+    active_path_selection_metric: PathSelectionMetric::Airtime,
+    congestion_control_mode: CongestionControlMode::NotActivated,
+    synchronization_method: SynchronizationMethod::NeighborOffset,
+    authentication_protocol: MeshAuthenticationProtocol::SAE,
+    mesh_formation_info: MeshFormationInfo::new()
+        .with_connected_to_mesh_gate(true)
+        .with_number_of_peerings(2),
+    mesh_capability: MeshCapability::new()
+        .with_accepting_additional_mesh_peerings(true)
+        .with_forwarding(true),
+};
+const EXPECTED_MESH_CONFIGURATION_ELEMENT_BYTES: &[u8] =
+    &[0x01, 0x01, 0x00, 0x01, 0x01, 0x05, 0x09];
+
+roundtrip_test!(
+    test_mesh_configuration_element_rw,
+    MeshConfigurationElement,
+    EXPECTED_MESH_CONFIGURATION_ELEMENT,
+    EXPECTED_MESH_CONFIGURATION_ELEMENT_BYTES
+);
+#[test]
+fn test_mesh_configuration_element_misc() {
+    assert!(EXPECTED_MESH_CONFIGURATION_ELEMENT.accepts_additional_peerings());
+    assert!(!MeshConfigurationElement::default().accepts_additional_peerings());
+}
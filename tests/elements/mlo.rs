@@ -0,0 +1,72 @@
+use ieee80211::elements::{
+    mlo::{BasicMultiLinkCommonInfo, MultiLinkElement, PerSTAControl},
+    SSIDElement,
+};
+use mac_parser::MACAddress;
+
+use crate::roundtrip_test;
+
+const EXPECTED_MULTI_LINK_ELEMENT_BYTES: &[u8] = &[
+    0x30, 0x01, // Multi-Link Control
+    0x0b, // Common Info Length
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // MLD MAC Address
+    0x01, // Link ID Info
+    0x02, // BSS Parameters Change Count
+    0x11, 0x00, // MLD Capabilities and Operations
+    0x00, 0x0f, // Per-STA Profile subelement header
+    0x32, 0x00, // STA Control
+    0x07, // STA Info Length
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x66, // STA MAC Address
+    0x11, 0x04, // Capability Information
+    0x00, 0x02, b'h', b'i', // SSID
+];
+const EXPECTED_MULTI_LINK_ELEMENT: MultiLinkElement = MultiLinkElement {
+    common_info: BasicMultiLinkCommonInfo {
+        mld_mac_address: MACAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+        link_id_info: Some(0x01),
+        bss_parameters_change_count: Some(0x02),
+        medium_synchronization_delay_information: None,
+        eml_capabilities: None,
+        mld_capabilities_and_operations: Some(0x0011),
+        ap_mld_id: None,
+        extended_mld_capabilities_and_operations: None,
+    },
+    link_info: &[
+        0x00, 0x0f, 0x32, 0x00, 0x07, 0x00, 0x11, 0x22, 0x33, 0x44, 0x66, 0x11, 0x04, 0x00, 0x02,
+        b'h', b'i',
+    ],
+};
+
+roundtrip_test!(
+    test_multi_link_element_rw,
+    MultiLinkElement,
+    EXPECTED_MULTI_LINK_ELEMENT,
+    EXPECTED_MULTI_LINK_ELEMENT_BYTES
+);
+
+#[test]
+fn test_multi_link_per_sta_profiles() {
+    let mut per_sta_profiles = EXPECTED_MULTI_LINK_ELEMENT.per_sta_profiles();
+    let per_sta_profile = per_sta_profiles.next().unwrap();
+    assert_eq!(
+        per_sta_profile.sta_control,
+        PerSTAControl::new()
+            .with_link_id(2)
+            .with_complete_profile(true)
+            .with_sta_mac_address_present(true)
+    );
+    assert_eq!(
+        per_sta_profile.sta_mac_address(),
+        Some(MACAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x66]))
+    );
+    assert_eq!(
+        per_sta_profile
+            .sta_profile_elements(2)
+            .unwrap()
+            .get_first_element::<SSIDElement>()
+            .unwrap()
+            .ssid(),
+        "hi"
+    );
+    assert!(per_sta_profiles.next().is_none());
+}
@@ -1,18 +1,51 @@
 use std::marker::PhantomData;
 
 use ieee80211::{
-    elements::{ElementID, RawIEEE80211Element, ReadElements, SSIDElement},
-    ssid,
+    elements::{
+        rates::SupportedRatesElement, DSSSParameterSetElement, Element, ElementID, OwnedElements,
+        RawIEEE80211Element, ReadElements, SSIDElement,
+    },
+    ssid, supported_rates,
+};
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Pwrite,
 };
 
+mod ap_channel_report;
+mod bss_max_idle;
+mod country;
+mod csa;
 mod dsss_parameter_set;
+mod eht;
 #[allow(unused)]
 mod element_chain;
+mod erp;
+mod extended_capabilities;
+mod ft;
+mod fuzz;
+mod he;
+mod ht;
 mod ibss_parameter_set;
+mod kde;
+mod mesh;
+mod mlo;
+mod multiple_bssid;
+mod neighbor_report;
+mod operating_classes;
+mod opmode;
+mod quiet;
+mod rm_enabled;
+mod rnr;
 mod rsn;
 mod ssid;
 mod supported_rates;
 mod tim;
+mod tspec;
+mod twt;
+mod vendor_specific_element;
+mod wmm;
+mod wpa;
 
 #[test]
 fn test_read_elements() {
@@ -49,3 +82,217 @@ fn test_read_elements() {
         }
     );
 }
+#[test]
+fn test_read_elements_count() {
+    let elements = ReadElements {
+        bytes: &[
+            0x00, 0x04, b'T', b'e', b's', b't', 0xdd, 0x06, 0x00, 0x80, 0x41, 0x00, 0x13, 0x37,
+            0xff, 0x03, 0x00, 0x13, 0x37,
+        ],
+    };
+    assert_eq!(elements.count(), 3);
+    assert_eq!(elements.byte_len(), 19);
+    assert!(elements.element_ids().eq([0x00, 0xdd, 0xff]));
+
+    // A truncated trailing element isn't counted.
+    let elements = ReadElements {
+        bytes: &[0x00, 0x04, b'T', b'e', b's', b't', 0xdd, 0x06, 0x00],
+    };
+    assert_eq!(elements.count(), 1);
+    assert_eq!(elements.byte_len(), 6);
+}
+#[test]
+fn test_primary_channel() {
+    let mut ht_operation_bytes = [0x00; 24];
+    ht_operation_bytes[0] = 61;
+    ht_operation_bytes[1] = 22;
+    ht_operation_bytes[2] = 36;
+    // DSSS Parameter Set followed by the HT Operation.
+    let mut bytes = vec![0x03, 0x01, 0x06];
+    bytes.extend_from_slice(&ht_operation_bytes);
+
+    assert_eq!(ReadElements { bytes: &bytes }.primary_channel(), Some(6));
+    assert_eq!(
+        ReadElements { bytes: &bytes[3..] }.primary_channel(),
+        Some(36)
+    );
+    assert_eq!(ReadElements { bytes: &[] }.primary_channel(), None);
+}
+#[test]
+fn test_get_first_element_strict() {
+    assert_eq!(
+        ReadElements {
+            bytes: &[0x03, 0x01, 0x06]
+        }
+        .get_first_element_strict::<DSSSParameterSetElement>()
+        .unwrap(),
+        Some(DSSSParameterSetElement { current_channel: 6 })
+    );
+    assert!(ReadElements {
+        bytes: &[0x03, 0x02, 0x06, 0x00]
+    }
+    .get_first_element_strict::<DSSSParameterSetElement>()
+    .is_err());
+    assert!(ReadElements {
+        bytes: &[0x03, 0x00]
+    }
+    .get_first_element_strict::<DSSSParameterSetElement>()
+    .is_err());
+    assert_eq!(
+        ReadElements { bytes: &[] }
+            .get_first_element_strict::<DSSSParameterSetElement>()
+            .unwrap(),
+        None
+    );
+}
+#[test]
+fn test_get_first_element_strict_ssid() {
+    assert_eq!(
+        ReadElements {
+            bytes: &[0x00, 0x04, b'T', b'e', b's', b't']
+        }
+        .get_first_element_strict::<SSIDElement>()
+        .unwrap(),
+        Some(ssid!("Test"))
+    );
+
+    // An SSID element with a body longer than 32 bytes.
+    let mut over_long_ssid = [b'A'; 35];
+    over_long_ssid[..2].copy_from_slice(&[0x00, 33]);
+    let elements = ReadElements {
+        bytes: &over_long_ssid,
+    };
+    assert!(elements.get_first_element_strict::<SSIDElement>().is_err());
+    // The lenient variant silently drops the element.
+    assert_eq!(elements.get_first_element::<SSIDElement>(), None);
+
+    // The SSID always spans the entire element body, so an SSID element followed by a DSSS Parameter Set element with a trailing byte is used instead.
+    let elements = ReadElements {
+        bytes: &[0x00, 0x04, b'T', b'e', b's', b't', 0x03, 0x02, 0x06, 0xff],
+    };
+    assert_eq!(
+        elements.get_first_element_strict::<SSIDElement>().unwrap(),
+        Some(ssid!("Test"))
+    );
+    assert!(elements
+        .get_first_element_strict::<DSSSParameterSetElement>()
+        .is_err());
+    // The lenient variant parses the element from a prefix of the body.
+    assert_eq!(
+        elements.get_first_element::<DSSSParameterSetElement>(),
+        Some(DSSSParameterSetElement { current_channel: 6 })
+    );
+}
+#[test]
+fn test_max_ampdu_length_in_bytes() {
+    use ieee80211::elements::{ht::MAXAMpduLength, vht::VHTCapabilitiesInfo};
+
+    assert_eq!(MAXAMpduLength::Small.in_bytes(), Some(8_191));
+    assert_eq!(MAXAMpduLength::VeryLarge.in_bytes(), Some(65_535));
+    assert_eq!(
+        VHTCapabilitiesInfo::new()
+            .with_maximum_ampdu_length_exponent(7)
+            .maximum_ampdu_length_in_bytes(),
+        1_048_575
+    );
+}
+#[test]
+fn test_owned_elements() {
+    const EXPECTED_BYTES: &[u8] = &[
+        0x00, 0x04, b'T', b'e', b's', b't', // SSID
+        0x01, 0x02, 0x82, 0x84, // Supported Rates
+    ];
+
+    let mut elements = OwnedElements::<10>::new();
+    elements.push_element(ssid!("Test")).unwrap();
+    elements.push_element(supported_rates![1 B, 2 B]).unwrap();
+    assert_eq!(elements.as_read_elements().bytes, EXPECTED_BYTES);
+    // The container is full, so this should fail and leave it unchanged.
+    assert!(elements.push_element(ssid!("")).is_err());
+    assert_eq!(elements.as_read_elements().bytes, EXPECTED_BYTES);
+
+    let mut buf = [0x00; 10];
+    assert_eq!(buf.pwrite(elements, 0).unwrap(), EXPECTED_BYTES.len());
+    let read_elements = ReadElements { bytes: &buf };
+    assert_eq!(
+        read_elements.get_first_element::<SSIDElement>().unwrap(),
+        ssid!("Test")
+    );
+    assert_eq!(
+        read_elements
+            .get_first_element::<SupportedRatesElement>()
+            .unwrap()
+            .supported_rates
+            .map(|rate| rate.rate_in_kbps())
+            .collect::<Vec<_>>(),
+        [1000, 2000]
+    );
+}
+
+/// An extension element, which just contains bytes and is fragmentable.
+struct FragmentableElement<'a>(&'a [u8]);
+impl MeasureWith<()> for FragmentableElement<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        self.0.len()
+    }
+}
+impl<'a> TryFromCtx<'a> for FragmentableElement<'a> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        Ok((Self(from), from.len()))
+    }
+}
+impl TryIntoCtx for FragmentableElement<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        buf.pwrite(self.0, 0)
+    }
+}
+impl Element for FragmentableElement<'_> {
+    const ELEMENT_ID: ElementID = ElementID::ExtId(0x42);
+    const FRAGMENTABLE: bool = true;
+    type ReadType<'a> = FragmentableElement<'a>;
+}
+#[test]
+fn test_get_first_element_defragmented() {
+    let payload = (0..300).map(|i| i as u8).collect::<Vec<_>>();
+    // The element contains the extension ID and the first 254 bytes of the payload, the fragment contains the rest.
+    let mut bytes = vec![0x03, 0x01, 0x06, 0xff, 0xff, 0x42];
+    bytes.extend_from_slice(&payload[..254]);
+    bytes.extend_from_slice(&[0xf2, 46]);
+    bytes.extend_from_slice(&payload[254..]);
+    // This fragment doesn't belong to the element anymore, since the previous one was shorter than 255 bytes.
+    bytes.extend_from_slice(&[0xf2, 0x01, 0x13]);
+    let elements = ReadElements { bytes: &bytes };
+
+    let mut buffer = [0x00; 512];
+    let element = elements
+        .get_first_element_defragmented::<FragmentableElement>(&mut buffer)
+        .unwrap()
+        .unwrap();
+    assert_eq!(element.0, payload);
+
+    // Without fragment reassembly, only the first part is returned.
+    assert_eq!(
+        elements
+            .get_first_element::<FragmentableElement>()
+            .unwrap()
+            .0,
+        &payload[..254]
+    );
+    // The buffer is too small.
+    assert!(elements
+        .get_first_element_defragmented::<FragmentableElement>(&mut [0x00; 299])
+        .is_err());
+    // Non fragmentable elements aren't reassembled.
+    assert_eq!(
+        elements
+            .get_first_element_defragmented::<DSSSParameterSetElement>(&mut buffer)
+            .unwrap(),
+        Some(DSSSParameterSetElement { current_channel: 6 })
+    );
+    assert!(ReadElements { bytes: &[] }
+        .get_first_element_defragmented::<FragmentableElement>(&mut buffer)
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,58 @@
+use ieee80211::{
+    elements::{MultipleBSSIDElement, ReadElements, SSIDElement},
+    ssid,
+};
+use mac_parser::MACAddress;
+
+use crate::roundtrip_test;
+
+const EXPECTED_MULTIPLE_BSSID_ELEMENT: MultipleBSSIDElement = MultipleBSSIDElement {
+    max_bssid_indicator: 2,
+    sub_elements: &[
+        0x00, 0x0a, // Nontransmitted BSSID Profile
+        0x00, 0x06, b'H', b'i', b'd', b'd', b'e', b'n', // SSID
+        0x55, 0x01, 0x01, // Multiple BSSID-Index
+    ],
+};
+const EXPECTED_MULTIPLE_BSSID_ELEMENT_BYTES: &[u8] = &[
+    0x02, // MaxBSSID Indicator
+    0x00, 0x0a, // Nontransmitted BSSID Profile
+    0x00, 0x06, b'H', b'i', b'd', b'd', b'e', b'n', // SSID
+    0x55, 0x01, 0x01, // Multiple BSSID-Index
+];
+
+roundtrip_test!(
+    test_multiple_bssid_element_rw,
+    MultipleBSSIDElement,
+    EXPECTED_MULTIPLE_BSSID_ELEMENT,
+    EXPECTED_MULTIPLE_BSSID_ELEMENT_BYTES
+);
+#[test]
+fn test_multiple_bssid_nontransmitted_profiles() {
+    let mut bytes = vec![0x47, EXPECTED_MULTIPLE_BSSID_ELEMENT_BYTES.len() as u8];
+    bytes.extend_from_slice(EXPECTED_MULTIPLE_BSSID_ELEMENT_BYTES);
+    let multiple_bssid = ReadElements { bytes: &bytes }
+        .get_first_element::<MultipleBSSIDElement>()
+        .unwrap();
+    assert_eq!(multiple_bssid.max_bssids(), 4);
+
+    let mut profiles = multiple_bssid.nontransmitted_profiles();
+    let profile = profiles.next().unwrap();
+    assert_eq!(
+        profile.get_first_element::<SSIDElement>().unwrap(),
+        ssid!("Hidden")
+    );
+    assert!(profiles.next().is_none());
+}
+#[test]
+fn test_multiple_bssid_nontransmitted_bssid() {
+    let transmitted_bssid = MACAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x57]);
+    assert_eq!(
+        EXPECTED_MULTIPLE_BSSID_ELEMENT.nontransmitted_bssid(transmitted_bssid, 1),
+        MACAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x54])
+    );
+    assert_eq!(
+        EXPECTED_MULTIPLE_BSSID_ELEMENT.nontransmitted_bssid(transmitted_bssid, 2),
+        MACAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55])
+    );
+}
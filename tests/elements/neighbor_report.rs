@@ -0,0 +1,61 @@
+use ieee80211::elements::{
+    neighbor_report::{APReachability, BSSIDInformation, NeighborReportElement},
+    ReadElements,
+};
+use mac_parser::MACAddress;
+
+use crate::roundtrip_test;
+
+const EXPECTED_NEIGHBOR_REPORT_ELEMENT: NeighborReportElement = NeighborReportElement {
+    bssid: MACAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+    bssid_information: BSSIDInformation::new()
+        .with_ap_reachability(APReachability::Reachable)
+        .with_security(true)
+        .with_qos(true)
+        .with_radio_measurement(true)
+        .with_mobility_domain(true)
+        .with_high_throughput(true)
+        .with_very_high_throughput(true),
+    operating_class: 128,
+    channel_number: 36,
+    phy_type: 9,
+    optional_sub_elements: &[],
+};
+const EXPECTED_NEIGHBOR_REPORT_ELEMENT_BYTES: &[u8] = &[
+    0x34, 0x0d, // Element header
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // BSSID
+    0xa7, 0x1c, 0x00, 0x00, // BSSID Information
+    128,  // Operating class
+    36,   // Channel number
+    9,    // PHY type
+];
+
+roundtrip_test!(
+    test_neighbor_report_element_rw,
+    NeighborReportElement,
+    EXPECTED_NEIGHBOR_REPORT_ELEMENT,
+    &EXPECTED_NEIGHBOR_REPORT_ELEMENT_BYTES[2..]
+);
+#[test]
+fn test_neighbor_report_element_from_elements() {
+    let neighbor_report = ReadElements {
+        bytes: EXPECTED_NEIGHBOR_REPORT_ELEMENT_BYTES,
+    }
+    .get_first_element::<NeighborReportElement>()
+    .unwrap();
+    assert_eq!(neighbor_report, EXPECTED_NEIGHBOR_REPORT_ELEMENT);
+    assert_eq!(
+        neighbor_report.bssid_information.ap_reachability(),
+        APReachability::Reachable
+    );
+    assert_eq!(neighbor_report.sub_elements().count(), 0);
+
+    // The TSF Information subelement.
+    let neighbor_report = NeighborReportElement {
+        optional_sub_elements: &[0x01, 0x04, 0x00, 0x00, 0x64, 0x00],
+        ..EXPECTED_NEIGHBOR_REPORT_ELEMENT
+    };
+    let sub_element = neighbor_report.sub_elements().next().unwrap();
+    assert_eq!(sub_element.tlv_type, 1);
+    assert_eq!(sub_element.slice, [0x00, 0x00, 0x64, 0x00]);
+}
@@ -0,0 +1,71 @@
+use ieee80211::elements::operating_classes::{Band, SupportedOperatingClassesElement};
+
+use scroll::Pread;
+
+use crate::roundtrip_test;
+
+const EXPECTED_SUPPORTED_OPERATING_CLASSES_ELEMENT: SupportedOperatingClassesElement =
+    SupportedOperatingClassesElement {
+        current_operating_class: 128,
+        operating_classes: &[81, 115, 118, 121, 128],
+    };
+const EXPECTED_SUPPORTED_OPERATING_CLASSES_ELEMENT_BYTES: &[u8] = &[128, 81, 115, 118, 121, 128];
+roundtrip_test!(
+    test_supported_operating_classes_element_rw,
+    SupportedOperatingClassesElement,
+    EXPECTED_SUPPORTED_OPERATING_CLASSES_ELEMENT,
+    EXPECTED_SUPPORTED_OPERATING_CLASSES_ELEMENT_BYTES
+);
+
+#[test]
+fn test_supported_operating_classes_current_band() {
+    assert_eq!(
+        EXPECTED_SUPPORTED_OPERATING_CLASSES_ELEMENT.current_band(),
+        Some(Band::FiveGHz)
+    );
+    assert_eq!(Band::from_operating_class(81), Some(Band::TwoPointFourGHz));
+    assert_eq!(Band::from_operating_class(131), Some(Band::SixGHz));
+    assert_eq!(Band::from_operating_class(180), Some(Band::SixtyGHz));
+    assert_eq!(Band::from_operating_class(0), None);
+}
+#[test]
+fn test_supported_operating_classes_delimiters() {
+    assert_eq!(
+        EXPECTED_SUPPORTED_OPERATING_CLASSES_ELEMENT.current_operating_class(),
+        128
+    );
+    assert!(EXPECTED_SUPPORTED_OPERATING_CLASSES_ELEMENT
+        .alternate_operating_classes()
+        .eq([81, 115, 118, 121, 128]));
+    assert_eq!(
+        EXPECTED_SUPPORTED_OPERATING_CLASSES_ELEMENT.current_operating_class_extension(),
+        None
+    );
+
+    let element = [81, 115, 118, 130, 0x12, 0x34, 0, 81, 1]
+        .pread::<SupportedOperatingClassesElement>(0)
+        .unwrap();
+    assert_eq!(element.current_operating_class(), 81);
+    assert!(element.alternate_operating_classes().eq([115, 118]));
+    assert_eq!(
+        element.current_operating_class_extension(),
+        Some([0x12, 0x34].as_slice())
+    );
+
+    let element = SupportedOperatingClassesElement {
+        current_operating_class: 81,
+        operating_classes: &[115, 130, 0x12],
+    };
+    assert!(element.alternate_operating_classes().eq([115]));
+    assert_eq!(
+        element.current_operating_class_extension(),
+        Some([0x12].as_slice())
+    );
+
+    let element = SupportedOperatingClassesElement {
+        current_operating_class: 81,
+        operating_classes: &[115, 0, 130, 0x12],
+    };
+    assert!(element.alternate_operating_classes().eq([115]));
+    assert_eq!(element.current_operating_class_extension(), None);
+}
@@ -0,0 +1,36 @@
+use ieee80211::elements::{OperatingModeChannelWidth, OperatingModeNotificationElement};
+
+use crate::roundtrip_test;
+
+const EXPECTED_OPERATING_MODE_NOTIFICATION_ELEMENT: OperatingModeNotificationElement =
+    OperatingModeNotificationElement::new()
+        .with_channel_width(OperatingModeChannelWidth::TwentyMHz)
+        .with_rx_nss(2)
+        .with_rx_nss_type(true);
+const EXPECTED_OPERATING_MODE_NOTIFICATION_ELEMENT_BYTES: &[u8] = &[0b1001_0000];
+
+roundtrip_test!(
+    test_operating_mode_notification_element_rw,
+    OperatingModeNotificationElement,
+    EXPECTED_OPERATING_MODE_NOTIFICATION_ELEMENT,
+    EXPECTED_OPERATING_MODE_NOTIFICATION_ELEMENT_BYTES
+);
+#[test]
+fn test_operating_mode_notification_element_misc() {
+    let element = EXPECTED_OPERATING_MODE_NOTIFICATION_ELEMENT;
+    assert_eq!(
+        element.channel_width(),
+        OperatingModeChannelWidth::TwentyMHz
+    );
+    assert_eq!(element.rx_nss(), 2);
+    assert!(element.rx_nss_type());
+
+    let element = OperatingModeNotificationElement::from_bits(0b0111_0110);
+    assert_eq!(
+        element.channel_width(),
+        OperatingModeChannelWidth::EightyMHz
+    );
+    assert!(element.bw_160_or_80p80());
+    assert!(!element.no_ldpc());
+    assert_eq!(element.rx_nss(), 8);
+}
@@ -0,0 +1,31 @@
+use core::time::Duration;
+
+use ieee80211::elements::QuietElement;
+
+use crate::roundtrip_test;
+
+const EXPECTED_QUIET_ELEMENT: QuietElement = QuietElement {
+    quiet_count: 1,
+    quiet_period: 10,
+    quiet_duration: 20,
+    quiet_offset: 5,
+};
+const EXPECTED_QUIET_ELEMENT_BYTES: &[u8] = &[0x01, 0x0a, 0x14, 0x00, 0x05, 0x00];
+
+roundtrip_test!(
+    test_quiet_element_rw,
+    QuietElement,
+    EXPECTED_QUIET_ELEMENT,
+    EXPECTED_QUIET_ELEMENT_BYTES
+);
+#[test]
+fn test_quiet_element_durations() {
+    assert_eq!(
+        EXPECTED_QUIET_ELEMENT.duration(),
+        Duration::from_micros(20 * 1024)
+    );
+    assert_eq!(
+        EXPECTED_QUIET_ELEMENT.offset(),
+        Duration::from_micros(5 * 1024)
+    );
+}
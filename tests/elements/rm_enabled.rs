@@ -0,0 +1,38 @@
+use ieee80211::elements::{RMEnabledCapabilitiesElement, ReadElements};
+
+use crate::roundtrip_test;
+
+const EXPECTED_RM_ENABLED_CAPABILITIES_ELEMENT: RMEnabledCapabilitiesElement =
+    RMEnabledCapabilitiesElement::new()
+        .with_link_measurement(true)
+        .with_neighbor_report(true)
+        .with_beacon_passive_measurement(true)
+        .with_beacon_active_measurement(true)
+        .with_beacon_table_measurement(true)
+        .with_ap_channel_report(true)
+        .with_operating_channel_max_measurement_duration(2)
+        .with_ftm_range_report(true);
+// The element header followed by the capabilities.
+const EXPECTED_RM_ENABLED_CAPABILITIES_ELEMENT_BYTES: &[u8] =
+    &[0x46, 0x05, 0x73, 0x00, 0x09, 0x00, 0x04];
+
+roundtrip_test!(
+    test_rm_enabled_capabilities_element_rw,
+    RMEnabledCapabilitiesElement,
+    EXPECTED_RM_ENABLED_CAPABILITIES_ELEMENT,
+    &EXPECTED_RM_ENABLED_CAPABILITIES_ELEMENT_BYTES[2..]
+);
+#[test]
+fn test_rm_enabled_capabilities_neighbor_report() {
+    let rm_enabled_capabilities = ReadElements {
+        bytes: EXPECTED_RM_ENABLED_CAPABILITIES_ELEMENT_BYTES,
+    }
+    .get_first_element::<RMEnabledCapabilitiesElement>()
+    .unwrap();
+    assert!(rm_enabled_capabilities.neighbor_report());
+    assert!(!rm_enabled_capabilities.lci_measurement());
+    assert_eq!(
+        rm_enabled_capabilities.operating_channel_max_measurement_duration(),
+        2
+    );
+}
@@ -0,0 +1,133 @@
+use ieee80211::elements::rnr::{
+    BSSParameters, NeighborAPInformation, ReducedNeighborReportElement, TBTTInformation,
+    TBTTInformationHeader,
+};
+use mac_parser::MACAddress;
+use scroll::{Pread, Pwrite};
+
+use crate::roundtrip_test;
+
+const FIRST_BSSID: MACAddress = MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x01]);
+const SECOND_BSSID: MACAddress = MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x02]);
+const THIRD_BSSID: MACAddress = MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x03]);
+
+const FIRST_TBTT_INFORMATION_FIELDS: &[u8] = &[
+    0xff, // TBTT offset
+    0x00, 0x20, 0x91, 0x13, 0x37, 0x01, // BSSID
+    0x78, 0x56, 0x34, 0x12, // Short SSID
+    0x4e, // BSS parameters
+    0xee, // 20 MHz PSD
+    0xff, // TBTT offset
+    0x00, 0x20, 0x91, 0x13, 0x37, 0x02, // BSSID
+    0x21, 0x43, 0x65, 0x87, // Short SSID
+    0x00, // BSS parameters
+    0xee, // 20 MHz PSD
+];
+const SECOND_TBTT_INFORMATION_FIELDS: &[u8] = &[
+    0x10, // TBTT offset
+    0x00, 0x20, 0x91, 0x13, 0x37, 0x03, // BSSID
+];
+const THIRD_TBTT_INFORMATION_FIELDS: &[u8] = &[
+    0x20, // TBTT offset
+    0x78, 0x56, 0x34, 0x12, // Short SSID
+];
+const EXPECTED_NEIGHBOR_AP_INFORMATION: [NeighborAPInformation; 3] = [
+    NeighborAPInformation {
+        tbtt_information_header: TBTTInformationHeader::new()
+            .with_tbtt_information_count(1)
+            .with_tbtt_information_length(13),
+        operating_class: 131,
+        channel_number: 5,
+        tbtt_information_fields: FIRST_TBTT_INFORMATION_FIELDS,
+    },
+    NeighborAPInformation {
+        tbtt_information_header: TBTTInformationHeader::new().with_tbtt_information_length(7),
+        operating_class: 134,
+        channel_number: 37,
+        tbtt_information_fields: SECOND_TBTT_INFORMATION_FIELDS,
+    },
+    NeighborAPInformation {
+        tbtt_information_header: TBTTInformationHeader::new().with_tbtt_information_length(5),
+        operating_class: 131,
+        channel_number: 21,
+        tbtt_information_fields: THIRD_TBTT_INFORMATION_FIELDS,
+    },
+];
+const EXPECTED_RNR_BYTES: &[u8] = &[
+    0x10, 0x0d, // TBTT Information Header
+    0x83, // Operating class
+    0x05, // Channel
+    0xff, 0x00, 0x20, 0x91, 0x13, 0x37, 0x01, 0x78, 0x56, 0x34, 0x12, 0x4e, 0xee, 0xff, 0x00, 0x20,
+    0x91, 0x13, 0x37, 0x02, 0x21, 0x43, 0x65, 0x87, 0x00, 0xee, // TBTT Information
+    0x00, 0x07, // TBTT Information Header
+    0x86, // Operating class
+    0x25, // Channel
+    0x10, 0x00, 0x20, 0x91, 0x13, 0x37, 0x03, // TBTT Information
+    0x00, 0x05, // TBTT Information Header
+    0x83, // Operating class
+    0x15, // Channel
+    0x20, 0x78, 0x56, 0x34, 0x12, // TBTT Information
+];
+
+roundtrip_test!(
+    test_rnr_element_rw,
+    ReducedNeighborReportElement,
+    ReducedNeighborReportElement::new(EXPECTED_NEIGHBOR_AP_INFORMATION),
+    EXPECTED_RNR_BYTES
+);
+
+#[test]
+fn test_rnr_element_iterators() {
+    let rnr = EXPECTED_RNR_BYTES
+        .pread::<ReducedNeighborReportElement>(0)
+        .unwrap();
+    assert!(rnr.neighbor_bssids().eq([
+        (131, 5, FIRST_BSSID),
+        (131, 5, SECOND_BSSID),
+        (134, 37, THIRD_BSSID)
+    ]));
+
+    let first_tbtt_information = rnr
+        .neighbor_ap_information()
+        .next()
+        .unwrap()
+        .tbtt_information()
+        .next()
+        .unwrap();
+    assert_eq!(
+        first_tbtt_information,
+        TBTTInformation {
+            tbtt_offset: 0xff,
+            bssid: Some(FIRST_BSSID),
+            short_ssid: Some(0x1234_5678),
+            bss_parameters: Some(
+                BSSParameters::new()
+                    .with_same_ssid(true)
+                    .with_multiple_bssid(true)
+                    .with_transmitted_bssid(true)
+                    .with_colocated_ap(true)
+            ),
+            psd_20mhz: Some(-18),
+            mld_parameters: None,
+        }
+    );
+    let mut buf = [0x00; 13];
+    buf.pwrite(first_tbtt_information, 0).unwrap();
+    assert_eq!(buf, FIRST_TBTT_INFORMATION_FIELDS[..13]);
+
+    // The entry without a BSSID only has a short SSID.
+    let short_ssid_only = rnr
+        .neighbor_ap_information()
+        .nth(2)
+        .unwrap()
+        .tbtt_information()
+        .next()
+        .unwrap();
+    assert_eq!(short_ssid_only.bssid, None);
+    assert_eq!(short_ssid_only.short_ssid, Some(0x1234_5678));
+
+    // A truncated Neighbor AP Information field is rejected.
+    assert!(EXPECTED_RNR_BYTES[..EXPECTED_RNR_BYTES.len() - 1]
+        .pread::<ReducedNeighborReportElement>(0)
+        .is_err());
+}
@@ -1,8 +1,10 @@
 use std::marker::PhantomData;
 
 use ieee80211::elements::rsn::{
-    IEEE80211AKMType, IEEE80211CipherSuiteSelector, RSNCapabilities, RSNElement, IEEE80211PMKID,
+    IEEE80211AKMType, IEEE80211CipherSuiteSelector, RSNCapabilities, RSNElement,
+    RSNExtendedCapabilities, RSNExtensionElement, IEEE80211PMKID,
 };
+use scroll::{Pread, Pwrite};
 
 use crate::roundtrip_test;
 
@@ -37,3 +39,119 @@ fn test_rsn_element_builder() {
             .with_akm_list([IEEE80211AKMType::Psk])
     )
 }
+
+const EXPECTED_RSNXE: RSNExtensionElement = RSNExtensionElement {
+    extended_rsn_capabilities: RSNExtendedCapabilities::new().with_sae_hash_to_element(true),
+};
+const EXPECTED_RSNXE_BYTES: &[u8] = &[0x20];
+
+roundtrip_test!(
+    test_rsnxe_rw,
+    RSNExtensionElement,
+    EXPECTED_RSNXE,
+    EXPECTED_RSNXE_BYTES
+);
+#[test]
+fn test_rsnxe_field_length() {
+    let rsnxe = RSNExtensionElement {
+        extended_rsn_capabilities: RSNExtendedCapabilities::new()
+            .with_sae_pk(true)
+            .with_secure_ltf_support(true),
+    };
+    let mut buf = [0x00; 2];
+    assert_eq!(buf.pwrite(rsnxe, 0).unwrap(), 2);
+    assert_eq!(buf, [0x41, 0x01]);
+
+    // Trailing reserved octets are skipped.
+    let read = [0x62, 0x00, 0x00].pread::<RSNExtensionElement>(0).unwrap();
+    assert!(read.extended_rsn_capabilities.sae_hash_to_element());
+    assert!(read.extended_rsn_capabilities.sae_pk());
+    assert!([0x01].pread::<RSNExtensionElement>(0).is_err());
+}
+#[test]
+fn test_implied_group_cipher() {
+    assert_eq!(
+        IEEE80211CipherSuiteSelector::implied_group_cipher(&[
+            IEEE80211CipherSuiteSelector::Ccmp128
+        ]),
+        IEEE80211CipherSuiteSelector::Ccmp128
+    );
+    assert_eq!(
+        IEEE80211CipherSuiteSelector::implied_group_cipher(&[
+            IEEE80211CipherSuiteSelector::Ccmp128,
+            IEEE80211CipherSuiteSelector::Tkip
+        ]),
+        IEEE80211CipherSuiteSelector::Tkip
+    );
+    assert_eq!(
+        IEEE80211CipherSuiteSelector::implied_group_cipher(&[
+            IEEE80211CipherSuiteSelector::Gcmp256,
+            IEEE80211CipherSuiteSelector::Ccmp256
+        ]),
+        IEEE80211CipherSuiteSelector::Ccmp256
+    );
+    assert_eq!(
+        IEEE80211CipherSuiteSelector::implied_group_cipher(&[]),
+        IEEE80211CipherSuiteSelector::Ccmp128
+    );
+}
+#[test]
+fn test_mfp_compatibility() {
+    let disabled = RSNCapabilities::new();
+    let capable = RSNCapabilities::new().with_mfp_enabled(true);
+    let required = capable.with_mfp_required(true);
+    let invalid = RSNCapabilities::new().with_mfp_required(true);
+
+    // (own, other, compatible)
+    let matrix = [
+        (disabled, disabled, true),
+        (disabled, capable, true),
+        (disabled, required, false),
+        (capable, disabled, true),
+        (capable, capable, true),
+        (capable, required, true),
+        (required, disabled, false),
+        (required, capable, true),
+        (required, required, true),
+        (invalid, capable, false),
+        (capable, invalid, false),
+    ];
+    for (own, other, compatible) in matrix {
+        assert_eq!(
+            own.is_mfp_compatible(other),
+            compatible,
+            "MFP compatibility of {own:?} with {other:?} didn't match."
+        );
+    }
+}
+#[test]
+fn test_rsn_element_suite_accessors() {
+    let mut buf = [0x00; 64];
+    let written = buf.pwrite(RSNElement::WPA2_WPA3_PERSONAL, 0).unwrap();
+    let rsn_element = buf[..written].pread::<RSNElement>(0).unwrap();
+
+    // The accessors don't consume the lists, so they can be called repeatedly.
+    for _ in 0..2 {
+        assert!(rsn_element
+            .akm_suites()
+            .eq([IEEE80211AKMType::Psk, IEEE80211AKMType::Sae]));
+        assert!(rsn_element
+            .pairwise_ciphers()
+            .eq([IEEE80211CipherSuiteSelector::Ccmp128]));
+    }
+    assert!(rsn_element.supports_akm(IEEE80211AKMType::Sae));
+    assert!(!rsn_element.supports_akm(IEEE80211AKMType::OpportunisticWirelessEncryption));
+    assert!(rsn_element.supports_pairwise(IEEE80211CipherSuiteSelector::Ccmp128));
+    assert!(!rsn_element.supports_pairwise(IEEE80211CipherSuiteSelector::Tkip));
+
+    // Absent lists fall back to the defaults of 00-0F-AC:1 and CCMP-128.
+    assert!(!RSNElement::new().supports_akm(IEEE80211AKMType::Psk));
+    assert!(RSNElement::new().supports_akm(IEEE80211AKMType::Wpa));
+    assert!(RSNElement::new().supports_pairwise(IEEE80211CipherSuiteSelector::Ccmp128));
+    assert!(!RSNElement::new().supports_pairwise(IEEE80211CipherSuiteSelector::Tkip));
+    assert_eq!(RSNElement::new().pairwise_ciphers().count(), 0);
+    // Present, but empty, lists don't.
+    assert!(!RSNElement::new()
+        .with_akm_list([])
+        .supports_akm(IEEE80211AKMType::Wpa));
+}
@@ -70,3 +70,56 @@ roundtrip_test!(
     EXPECTED_EXTENDED_SUPPORTED_RATES,
     EXPECTED_EXTENDED_SUPPORTED_RATES_BYTES
 );
+#[cfg(feature = "alloc")]
+#[test]
+fn test_rates_collect() {
+    use ieee80211::elements::rates::RatesReadIterator;
+    use scroll::{Pread, Pwrite};
+
+    let supported_rates = EXPECTED_SUPPORTED_RATES_BYTES
+        .pread::<SupportedRatesElement>(0)
+        .unwrap();
+    let extended_supported_rates = EXPECTED_EXTENDED_SUPPORTED_RATES_BYTES
+        .pread::<ExtendedSupportedRatesElement>(0)
+        .unwrap();
+    let mut rates = supported_rates.supported_rates.to_vec();
+    rates.extend(extended_supported_rates.supported_rates);
+    assert_eq!(rates, [rate!(1.5 B), rate!(1.5 B), rate!(2)]);
+
+    let collected = rates
+        .iter()
+        .copied()
+        .chain([rate!(5.5 B); 8])
+        .collect::<SupportedRatesElement<_>>();
+    assert_eq!(collected.supported_rates.len(), 8);
+    let mut buf = [0x00u8; 8];
+    assert_eq!(buf.pwrite(collected, 0).unwrap(), 8);
+    assert_eq!(
+        RatesReadIterator::new(&buf).to_vec()[..3],
+        [rate!(1.5 B), rate!(1.5 B), rate!(2)]
+    );
+
+    let collected = rates
+        .into_iter()
+        .collect::<ExtendedSupportedRatesElement<_>>();
+    let mut buf = [0x00u8; 3];
+    buf.pwrite(collected, 0).unwrap();
+    assert_eq!(buf, [0x83, 0x83, 0x04]);
+}
+#[test]
+fn test_encoded_rate_units() {
+    assert_eq!(rate!(5.5 B).rate_half_mbps(), 11);
+    assert_eq!(rate!(5.5 B).rate_in_mbps(), 5.5);
+    assert_eq!(rate!(54).rate_in_mbps(), 54.0);
+}
+#[cfg(feature = "alloc")]
+#[test]
+fn test_encoded_rate_from_str() {
+    assert_eq!("11(B)".parse::<EncodedRate>().unwrap(), rate!(11 B));
+    assert_eq!("54".parse::<EncodedRate>().unwrap(), rate!(54));
+    assert_eq!("5.5 (B)".parse::<EncodedRate>().unwrap(), rate!(5.5 B));
+    assert!("5.25".parse::<EncodedRate>().is_err());
+    assert!("64".parse::<EncodedRate>().is_err());
+    assert!("0".parse::<EncodedRate>().is_err());
+    assert!("fast".parse::<EncodedRate>().is_err());
+}
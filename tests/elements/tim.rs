@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use ieee80211::{
     aid,
-    elements::tim::{ConstBitmap, TIMElement},
+    elements::tim::{ConstBitmap, TIMBitmap, TIMElement},
     tim_bitmap,
 };
 
@@ -32,3 +32,40 @@ fn test_tim_aid_decode() {
         .unwrap()
         .eq([aid!(12), aid!(13)]));
 }
+#[test]
+fn test_tim_is_dtim() {
+    assert!(!EXPECTED_TIM_ELEMENT.is_dtim());
+    assert!(TIMElement {
+        dtim_count: 0,
+        ..EXPECTED_TIM_ELEMENT
+    }
+    .is_dtim());
+}
+#[test]
+fn test_tim_from_partial_bitmap() {
+    let bitmap = TIMBitmap::from_partial_bitmap(false, 2, &[0b0000_0100, 0b0000_0001]).unwrap();
+    assert!(bitmap.aid_iter().unwrap().eq([aid!(18), aid!(24)]));
+
+    // N1 has to be even.
+    assert!(TIMBitmap::from_partial_bitmap(false, 3, &[0x01]).is_none());
+    // The partial virtual bitmap is at least one octet long.
+    assert!(TIMBitmap::from_partial_bitmap(false, 2, &[]).is_none());
+    // The bitmap would exceed the traffic indication virtual bitmap.
+    assert!(TIMBitmap::from_partial_bitmap(false, 250, &[0x01, 0x01]).is_none());
+    assert!(TIMBitmap::from_partial_bitmap(true, 250, &[0x01]).is_some());
+}
+#[test]
+fn test_tim_buffered_aids() {
+    assert!(EXPECTED_TIM_ELEMENT
+        .buffered_aids()
+        .eq([aid!(12), aid!(13)]));
+    assert_eq!(
+        TIMElement::<ConstBitmap> {
+            bitmap: None,
+            ..EXPECTED_TIM_ELEMENT
+        }
+        .buffered_aids()
+        .count(),
+        0
+    );
+}
@@ -0,0 +1,74 @@
+use ieee80211::elements::tspec::{
+    IPv4ClassifierParameters, TCLASElement, TSAccessPolicy, TSDirection, TSInfo, TSPECElement,
+};
+
+use crate::roundtrip_test;
+
+const EXPECTED_TSPEC_ELEMENT: TSPECElement = TSPECElement {
+    ts_info: TSInfo::new()
+        .with_tsid(6)
+        .with_direction(TSDirection::Bidirectional)
+        .with_access_policy(TSAccessPolicy::EDCA)
+        .with_user_priority(6),
+    nominal_msdu_size: 0x80d0,
+    maximum_msdu_size: 0,
+    minimum_service_interval: 0,
+    maximum_service_interval: 0,
+    inactivity_interval: 0,
+    suspension_interval: 0,
+    service_start_time: 0,
+    minimum_data_rate: 0,
+    mean_data_rate: 64_000,
+    peak_data_rate: 0,
+    burst_size: 0,
+    delay_bound: 0,
+    minimum_phy_rate: 6_000_000,
+    surplus_bandwidth_allowance: 0x2000,
+    medium_time: 0,
+};
+const EXPECTED_TSPEC_ELEMENT_BYTES: &[u8] = &[
+    0x6c, 0x31, 0x00, 0xd0, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xfa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80,
+    0x8d, 0x5b, 0x00, 0x00, 0x20, 0x00, 0x00,
+];
+roundtrip_test!(
+    test_tspec_element_rw,
+    TSPECElement,
+    EXPECTED_TSPEC_ELEMENT,
+    EXPECTED_TSPEC_ELEMENT_BYTES
+);
+
+const EXPECTED_TCLAS_ELEMENT_BYTES: &[u8] = &[
+    0x06, 0x04, 0x5f, 0x04, 0xc0, 0xa8, 0x00, 0x01, 0xc0, 0xa8, 0x00, 0x02, 0x13, 0x37, 0x00, 0x50,
+    0x2e, 0x11, 0x00,
+];
+const EXPECTED_TCLAS_ELEMENT: TCLASElement = TCLASElement {
+    user_priority: 6,
+    classifier_type: 4,
+    classifier_mask: 0x5f,
+    classifier_parameters: &[
+        0x04, 0xc0, 0xa8, 0x00, 0x01, 0xc0, 0xa8, 0x00, 0x02, 0x13, 0x37, 0x00, 0x50, 0x2e, 0x11,
+        0x00,
+    ],
+};
+roundtrip_test!(
+    test_tclas_element_rw,
+    TCLASElement,
+    EXPECTED_TCLAS_ELEMENT,
+    EXPECTED_TCLAS_ELEMENT_BYTES
+);
+#[test]
+fn test_tclas_ipv4_classifier_parameters() {
+    assert_eq!(
+        EXPECTED_TCLAS_ELEMENT.ipv4_classifier_parameters(),
+        Some(IPv4ClassifierParameters {
+            source_address: [192, 168, 0, 1],
+            destination_address: [192, 168, 0, 2],
+            source_port: 0x1337,
+            destination_port: 80,
+            dscp: 0x2e,
+            protocol: 17,
+        })
+    );
+}
@@ -0,0 +1,73 @@
+use core::time::Duration;
+
+use ieee80211::elements::twt::{
+    TWTControl, TWTElement, TWTNegotiationType, TWTRequestType, TWTSetupCommand,
+};
+use scroll::Pread;
+
+use crate::roundtrip_test;
+
+const EXPECTED_TWT_ELEMENT: TWTElement = TWTElement {
+    control: TWTControl::new(),
+    request_type: TWTRequestType::new()
+        .with_twt_request(true)
+        .with_twt_setup_command(TWTSetupCommand::Suggest)
+        .with_trigger(true)
+        .with_implicit(true)
+        .with_twt_wake_interval_exponent(10),
+    target_wake_time: 0x123456,
+    nominal_minimum_wake_duration: 64,
+    twt_wake_interval_mantissa: 500,
+    twt_channel: 0,
+    ndp_paging: None,
+};
+const EXPECTED_TWT_ELEMENT_BYTES: &[u8] = &[
+    0x00, // Control
+    0x33, 0x28, // Request Type
+    0x56, 0x34, 0x12, 0x00, 0x00, 0x00, 0x00, 0x00, // Target Wake Time
+    0x40, // Nominal Minimum TWT Wake Duration
+    0xf4, 0x01, // TWT Wake Interval Mantissa
+    0x00, // TWT Channel
+];
+
+roundtrip_test!(
+    test_twt_element_rw,
+    TWTElement,
+    EXPECTED_TWT_ELEMENT,
+    EXPECTED_TWT_ELEMENT_BYTES
+);
+#[test]
+fn test_twt_element_durations() {
+    assert_eq!(
+        EXPECTED_TWT_ELEMENT.wake_interval(),
+        Duration::from_micros(500 << 10)
+    );
+    assert_eq!(
+        EXPECTED_TWT_ELEMENT.minimum_wake_duration(),
+        Duration::from_micros(64 * 256)
+    );
+    let twt_element = TWTElement {
+        control: TWTControl::new().with_wake_duration_unit(true),
+        ..EXPECTED_TWT_ELEMENT
+    };
+    assert_eq!(
+        twt_element.minimum_wake_duration(),
+        Duration::from_micros(64 * 1024)
+    );
+}
+#[test]
+fn test_twt_element_ndp_paging() {
+    let mut bytes = EXPECTED_TWT_ELEMENT_BYTES.to_vec();
+    bytes[0] = TWTControl::new()
+        .with_ndp_paging_indicator(true)
+        .into_bits();
+    bytes.extend_from_slice(&[0x78, 0x56, 0x34, 0x12]);
+    let twt_element = bytes.pread::<TWTElement>(0).unwrap();
+    assert_eq!(twt_element.ndp_paging, Some(0x12345678));
+
+    // Broadcast TWT isn't supported.
+    bytes[0] = TWTControl::new()
+        .with_negotiation_type(TWTNegotiationType::Broadcast)
+        .into_bits();
+    assert!(bytes.pread::<TWTElement>(0).is_err());
+}
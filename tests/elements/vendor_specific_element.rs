@@ -0,0 +1,95 @@
+use ieee80211::{
+    common::{MICROSOFT_OUI, WIFI_ALLIANCE_OUI},
+    elements::{wmm::WMMInfoElement, ReadElements, VendorElement, VendorSpecificElement},
+    mgmt_frame::BeaconFrame,
+};
+use scroll::Pread;
+
+#[test]
+fn test_vendor_elements() {
+    let bytes = [
+        // WMM
+        0xdd, 0x07, 0x00, 0x50, 0xf2, 0x02, 0x00, 0x01, 0x00, // WPS with the version attribute
+        0xdd, 0x09, 0x00, 0x50, 0xf2, 0x04, 0x10, 0x4a, 0x00, 0x01,
+        0x10, // OWE Transition Mode
+        0xdd, 0x0d, 0x50, 0x6f, 0x9a, 0x1c, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x02, b'A', b'B',
+        // Apple
+        0xdd, 0x05, 0x00, 0x17, 0xf2, 0x13, 0x37, // Unknown
+        0xdd, 0x04, 0x12, 0x34, 0x56, 0x00,
+    ];
+    let mut vendor_elements = ReadElements { bytes: &bytes }.vendor_elements();
+    assert_eq!(
+        vendor_elements.next(),
+        Some(VendorElement::WmmInfo(WMMInfoElement { qos_info: 0x00 }))
+    );
+    assert_eq!(
+        vendor_elements.next(),
+        Some(VendorElement::Wps(&[0x10, 0x4a, 0x00, 0x01, 0x10]))
+    );
+    let Some(VendorElement::OWETransitionMode(owe_transition_mode_element)) =
+        vendor_elements.next()
+    else {
+        panic!("OWE Transition Mode element wasn't parsed.");
+    };
+    assert_eq!(owe_transition_mode_element.ssid, "AB");
+    assert_eq!(
+        vendor_elements.next(),
+        Some(VendorElement::Apple(&[0x13, 0x37]))
+    );
+    let Some(VendorElement::Unknown(vendor_specific_element)) = vendor_elements.next() else {
+        panic!("Unknown vendor specific element wasn't returned raw.");
+    };
+    assert_eq!(
+        vendor_specific_element.get_payload(),
+        &[0x12, 0x34, 0x56, 0x00].as_slice()
+    );
+    assert_eq!(vendor_elements.next(), None);
+}
+/// The returned element must only borrow the bytes and not the temporary [ReadElements].
+fn first_vendor_specific_element(bytes: &[u8]) -> Option<VendorSpecificElement<'_>> {
+    ReadElements { bytes }.get_first_element::<VendorSpecificElement>()
+}
+/// The returned element must outlive the parsed frame and it's [ReadElements].
+fn first_vendor_specific_element_of_beacon(bytes: &[u8]) -> Option<VendorSpecificElement<'_>> {
+    let beacon = bytes.pread::<BeaconFrame>(0).ok()?;
+    let elements = beacon.body.elements;
+    elements.get_first_element::<VendorSpecificElement>()
+}
+#[test]
+fn test_vendor_specific_element_lifetime() {
+    let bytes = [0xdd, 0x05, 0x00, 0x17, 0xf2, 0x13, 0x37];
+    let vendor_specific_element = first_vendor_specific_element(&bytes).unwrap();
+    assert_eq!(
+        *vendor_specific_element.get_payload(),
+        [0x00, 0x17, 0xf2, 0x13, 0x37]
+    );
+
+    let vendor_specific_element =
+        first_vendor_specific_element_of_beacon(include_bytes!("../../bins/frames/beacon.bin"))
+            .unwrap();
+    assert_eq!(vendor_specific_element.oui(), Some(MICROSOFT_OUI));
+}
+#[test]
+fn test_vendor_specific_element_oui() {
+    // A WMM Information element.
+    let bytes = [0xdd, 0x07, 0x00, 0x50, 0xf2, 0x02, 0x00, 0x01, 0x00];
+    let wmm_information_element = first_vendor_specific_element(&bytes).unwrap();
+    assert_eq!(wmm_information_element.oui(), Some(MICROSOFT_OUI));
+    assert_eq!(wmm_information_element.vendor_type(), Some(0x02));
+    assert!(wmm_information_element.matches_oui_type(MICROSOFT_OUI, 0x02));
+    assert!(!wmm_information_element.matches_oui_type(MICROSOFT_OUI, 0x01));
+    assert!(!wmm_information_element.matches_oui_type(WIFI_ALLIANCE_OUI, 0x02));
+
+    // Only an OUI, without a vendor specific type.
+    let bytes = [0xdd, 0x03, 0x00, 0x50, 0xf2];
+    let vendor_specific_element = first_vendor_specific_element(&bytes).unwrap();
+    assert_eq!(vendor_specific_element.oui(), Some(MICROSOFT_OUI));
+    assert_eq!(vendor_specific_element.vendor_type(), None);
+    assert!(!vendor_specific_element.matches_oui_type(MICROSOFT_OUI, 0x02));
+
+    // Too short to contain an OUI.
+    let bytes = [0xdd, 0x02, 0x00, 0x50];
+    let vendor_specific_element = first_vendor_specific_element(&bytes).unwrap();
+    assert_eq!(vendor_specific_element.oui(), None);
+    assert_eq!(vendor_specific_element.vendor_type(), None);
+}
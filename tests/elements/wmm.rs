@@ -0,0 +1,95 @@
+use ieee80211::{
+    elements::{
+        wmm::{
+            ACIAndAIFSN, AccessCategory, ECWMinMax, EDCAParameterRecord, WMMInfoElement,
+            WMMParameterElement,
+        },
+        VendorElement,
+    },
+    mgmt_frame::BeaconFrame,
+};
+use scroll::Pread;
+
+use crate::roundtrip_test;
+
+/// Creates an [EDCAParameterRecord].
+const fn record(
+    aci: AccessCategory,
+    aifsn: u8,
+    ecw_min: u8,
+    ecw_max: u8,
+    txop_limit: u16,
+) -> EDCAParameterRecord {
+    EDCAParameterRecord {
+        aci_aifsn: ACIAndAIFSN::new().with_aci(aci).with_aifsn(aifsn),
+        ecw: ECWMinMax::new().with_ecw_min(ecw_min).with_ecw_max(ecw_max),
+        txop_limit,
+    }
+}
+
+// Taken from beacon.bin.
+const EXPECTED_WMM_PARAMETER_ELEMENT: WMMParameterElement = WMMParameterElement {
+    qos_info: 0x0f,
+    ac_parameters: [
+        record(AccessCategory::BestEffort, 3, 4, 10, 0),
+        record(AccessCategory::Background, 7, 4, 10, 0),
+        record(AccessCategory::Video, 2, 3, 4, 94),
+        record(AccessCategory::Voice, 2, 2, 3, 47),
+    ],
+};
+const EXPECTED_WMM_PARAMETER_BYTES: &[u8] = &[
+    0x01, // Version
+    0x0f, // QoS Info
+    0x00, // Reserved
+    0x03, 0xa4, 0x00, 0x00, // Best Effort
+    0x27, 0xa4, 0x00, 0x00, // Background
+    0x42, 0x43, 0x5e, 0x00, // Video
+    0x62, 0x32, 0x2f, 0x00, // Voice
+];
+roundtrip_test!(
+    test_wmm_parameter_element_rw,
+    WMMParameterElement,
+    EXPECTED_WMM_PARAMETER_ELEMENT,
+    EXPECTED_WMM_PARAMETER_BYTES
+);
+
+#[test]
+fn test_wmm_parameter_element_from_beacon() {
+    let beacon = include_bytes!("../../bins/frames/beacon.bin")
+        .pread::<BeaconFrame>(0)
+        .unwrap();
+    let wmm_parameter = beacon
+        .elements
+        .get_first_element::<WMMParameterElement>()
+        .unwrap();
+    assert_eq!(wmm_parameter, EXPECTED_WMM_PARAMETER_ELEMENT);
+
+    let voice = wmm_parameter.voice();
+    assert_eq!(voice.txop_limit_in_us(), 1504);
+    assert_eq!(voice.ecw.cw_min(), 3);
+    assert_eq!(voice.ecw.cw_max(), 7);
+    assert_eq!(wmm_parameter.best_effort().aci_aifsn.aifsn(), 3);
+}
+#[test]
+fn test_wmm_vendor_element_from_beacon() {
+    let beacon = include_bytes!("../../bins/frames/beacon.bin")
+        .pread::<BeaconFrame>(0)
+        .unwrap();
+    assert!(beacon
+        .elements
+        .vendor_elements()
+        .any(|vendor_element| vendor_element
+            == VendorElement::WmmParameter(EXPECTED_WMM_PARAMETER_ELEMENT)));
+}
+
+const EXPECTED_WMM_INFO_ELEMENT: WMMInfoElement = WMMInfoElement { qos_info: 0x0f };
+const EXPECTED_WMM_INFO_BYTES: &[u8] = &[
+    0x01, // Version
+    0x0f, // QoS Info
+];
+roundtrip_test!(
+    test_wmm_info_element_rw,
+    WMMInfoElement,
+    EXPECTED_WMM_INFO_ELEMENT,
+    EXPECTED_WMM_INFO_BYTES
+);
@@ -0,0 +1,76 @@
+use std::marker::PhantomData;
+
+use ieee80211::elements::{
+    wpa::{WPAAKMType, WPACipherSuiteSelector, WPAElement},
+    ReadElements, VendorElement,
+};
+
+use crate::roundtrip_test;
+
+const EXPECTED_WPA_ELEMENT: WPAElement<[WPACipherSuiteSelector; 2], [WPAAKMType; 1]> = WPAElement {
+    multicast_cipher_suite: Some(WPACipherSuiteSelector::Tkip),
+    unicast_cipher_suite_list: Some([
+        WPACipherSuiteSelector::Tkip,
+        WPACipherSuiteSelector::Ccmp128,
+    ]),
+    akm_list: Some([WPAAKMType::Psk]),
+    _phantom: PhantomData,
+};
+// Taken from the beacon of an AP in WPA/WPA2-Personal mixed mode.
+const EXPECTED_WPA_ELEMENT_BYTES: &[u8] = &[
+    0xdd, 0x1a, // Element header
+    0x00, 0x50, 0xf2, 0x01, // OUI and type
+    0x01, 0x00, // Version
+    0x00, 0x50, 0xf2, 0x02, // Multicast cipher suite
+    0x02, 0x00, // Unicast cipher suite count
+    0x00, 0x50, 0xf2, 0x02, // TKIP
+    0x00, 0x50, 0xf2, 0x04, // CCMP
+    0x01, 0x00, // AKM suite count
+    0x00, 0x50, 0xf2, 0x02, // PSK
+];
+
+roundtrip_test!(
+    test_wpa_element_rw,
+    WPAElement,
+    EXPECTED_WPA_ELEMENT,
+    &EXPECTED_WPA_ELEMENT_BYTES[6..]
+);
+#[test]
+fn test_wpa_element_from_elements() {
+    let wpa_element = ReadElements {
+        bytes: EXPECTED_WPA_ELEMENT_BYTES,
+    }
+    .get_first_element::<WPAElement>()
+    .unwrap();
+    assert_eq!(wpa_element, EXPECTED_WPA_ELEMENT);
+    assert_eq!(
+        wpa_element.unicast_ciphers().collect::<Vec<_>>(),
+        [
+            WPACipherSuiteSelector::Tkip,
+            WPACipherSuiteSelector::Ccmp128
+        ]
+    );
+    assert_eq!(
+        wpa_element.akm_suites().collect::<Vec<_>>(),
+        [WPAAKMType::Psk]
+    );
+}
+#[test]
+fn test_wpa_vendor_element() {
+    let Some(VendorElement::Wpa(wpa_element)) = ReadElements {
+        bytes: EXPECTED_WPA_ELEMENT_BYTES,
+    }
+    .vendor_elements()
+    .next() else {
+        panic!("WPA element wasn't parsed.");
+    };
+    assert_eq!(wpa_element, EXPECTED_WPA_ELEMENT);
+
+    // Version two doesn't exist, so the element is returned raw.
+    let mut bytes = EXPECTED_WPA_ELEMENT_BYTES.to_vec();
+    bytes[6] = 0x02;
+    assert!(matches!(
+        ReadElements { bytes: &bytes }.vendor_elements().next(),
+        Some(VendorElement::Unknown(_))
+    ));
+}
@@ -0,0 +1,60 @@
+use ieee80211::{
+    common::Fcs,
+    elements::{DSSSParameterSetElement, SSIDElement},
+    mgmt_frame::{body::BeaconBody, BeaconFrame, DynamicManagementFrame},
+};
+use scroll::Pread;
+
+#[test]
+fn test_fcs_incremental() {
+    let bytes = (0..=255u8).collect::<Vec<u8>>();
+    for split in [0, 1, 24, 100, 256] {
+        let (first, second) = bytes.split_at(split);
+        let mut fcs = Fcs::new();
+        fcs.update(first);
+        fcs.update(second);
+        assert_eq!(fcs.finalize(), crc32fast::hash(&bytes));
+    }
+    let mut fcs = Fcs::new();
+    bytes.chunks(7).for_each(|chunk| fcs.update(chunk));
+    assert_eq!(fcs.finalize(), crc32fast::hash(&bytes));
+    assert_eq!(Fcs::new().finalize(), crc32fast::hash(&[]));
+}
+#[test]
+fn test_dynamic_frame_precomputed_fcs() {
+    let beacon = BeaconFrame {
+        body: BeaconBody {
+            elements: SSIDElement::new("Test").unwrap(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let dsss = DSSSParameterSetElement { current_channel: 1 };
+
+    let mut expected = [0x00u8; 64];
+    let mut dynamic_frame = DynamicManagementFrame::new(beacon, expected.as_mut_slice()).unwrap();
+    dynamic_frame.add_element(dsss).unwrap();
+    let expected_length = dynamic_frame.finish(true).unwrap();
+    let expected = &expected[..expected_length];
+    expected.pread_with::<BeaconFrame>(0, true).unwrap();
+
+    // Calculate the FCS over the frame in two pieces.
+    let (without_fcs, _) = expected.split_at(expected_length - 4);
+    let (first, second) = without_fcs.split_at(24);
+    let mut fcs = Fcs::new();
+    fcs.update(first);
+    fcs.update(second);
+
+    let mut buf = [0x00u8; 64];
+    let mut dynamic_frame = DynamicManagementFrame::new(beacon, buf.as_mut_slice()).unwrap();
+    dynamic_frame.add_element(dsss).unwrap();
+    let length = dynamic_frame.finish_with_fcs(Some(fcs.finalize())).unwrap();
+    assert_eq!(&buf[..length], expected);
+
+    let mut buf = [0x00u8; 64];
+    let dynamic_frame = DynamicManagementFrame::new(beacon, buf.as_mut_slice()).unwrap();
+    assert_eq!(
+        dynamic_frame.finish_with_fcs(None).unwrap(),
+        expected_length - 4 - 3
+    );
+}
@@ -0,0 +1,55 @@
+use ieee80211::common::{ControlFrameSubtype, DataFrameSubtype, FrameType, ManagementFrameSubtype};
+
+#[test]
+fn test_frame_type_display() {
+    assert_eq!(
+        FrameType::Management(ManagementFrameSubtype::Beacon).to_string(),
+        "Management/Beacon"
+    );
+    assert_eq!(
+        FrameType::Control(ControlFrameSubtype::RTS).to_string(),
+        "Control/RTS"
+    );
+    assert_eq!(
+        FrameType::Data(DataFrameSubtype::QoSData).to_string(),
+        "Data/QoS-Data"
+    );
+    assert_eq!(
+        FrameType::Management(ManagementFrameSubtype::Unknown(0b1111)).to_string(),
+        "Management/Unknown(15)"
+    );
+    assert_eq!(FrameType::Unknown(3).to_string(), "Unknown(3)");
+}
+#[test]
+fn test_frame_type_display_without_alloc() {
+    use core::fmt::Write;
+
+    /// A fixed size buffer, which implements [Write].
+    struct StackBuffer {
+        buf: [u8; 32],
+        len: usize,
+    }
+    impl Write for StackBuffer {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            self.buf
+                .get_mut(self.len..end)
+                .ok_or(core::fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    let mut buffer = StackBuffer {
+        buf: [0x00; 32],
+        len: 0,
+    };
+    write!(
+        buffer,
+        "{}",
+        FrameType::Management(ManagementFrameSubtype::ProbeRequest)
+    )
+    .unwrap();
+    assert_eq!(&buffer.buf[..buffer.len], b"Management/Probe Request");
+}
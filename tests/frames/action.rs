@@ -1,11 +1,34 @@
+use core::marker::PhantomData;
+
 use ieee80211::{
+    common::{IEEE80211Reason, IEEE80211StatusCode, SequenceControl},
+    elements::{
+        measurement::{MeasurementReportMode, MeasurementType},
+        neighbor_report::{APReachability, BSSIDInformation},
+        ChannelSwitchAnnouncementElement, ReadElements,
+    },
     match_frames,
     mgmt_frame::{
-        body::action::{CategoryCode, RawVendorSpecificActionFrame},
+        body::action::{
+            AddBARequestBody, AddBARequestFrame, AddBAResponseBody, AddBAResponseFrame,
+            BSSTerminationDuration, BSSTransitionManagementQueryFrame,
+            BSSTransitionManagementRequestBody, BSSTransitionManagementRequestFrame,
+            BSSTransitionManagementRequestMode, BSSTransitionManagementResponseFrame,
+            BlockAckParameterSet, CategoryCode, ChannelSwitchAnnouncementBody,
+            ChannelSwitchAnnouncementFrame, DelBABody, DelBAParameterSet, FTMBody, FTMFrame,
+            FTMRequestBody, FTMRequestFrame, MeasurementReportBody, MeasurementReportFrame,
+            RawVendorSpecificActionFrame, SAQueryRequestBody, SAQueryRequestFrame,
+            SAQueryResponseBody, SAQueryResponseFrame,
+        },
         RawActionFrame,
     },
 };
 
+use mac_parser::MACAddress;
+use scroll::Pread;
+
+use crate::roundtrip_test;
+
 #[test]
 fn test_raw_action_frame() {
     let bytes = include_bytes!("../../bins/frames/awdl_action.bin");
@@ -28,3 +51,449 @@ fn test_raw_vendor_action_frame() {
     }
     .expect("Failed to match raw action frame.");
 }
+
+const EXPECTED_FTM_REQUEST_BODY: FTMRequestBody = FTMRequestBody {
+    trigger: 1,
+    elements: ReadElements { bytes: &[] },
+    _phantom: PhantomData,
+};
+const EXPECTED_FTM_REQUEST_BODY_BYTES: &[u8] = &[
+    0x04, // Category code
+    0x20, // Public action code
+    0x01, // Trigger
+];
+roundtrip_test!(
+    test_ftm_request_body_rw,
+    FTMRequestBody,
+    EXPECTED_FTM_REQUEST_BODY,
+    EXPECTED_FTM_REQUEST_BODY_BYTES
+);
+
+const EXPECTED_FTM_BODY: FTMBody = FTMBody {
+    dialog_token: 2,
+    follow_up_dialog_token: 1,
+    tod: 0x0000_1122_3344_5566,
+    toa: 0x0000_2233_4455_6677,
+    tod_error: 0x0003,
+    toa_error: 0x0004,
+    elements: ReadElements { bytes: &[] },
+    _phantom: PhantomData,
+};
+const EXPECTED_FTM_BODY_BYTES: &[u8] = &[
+    0x04, // Category code
+    0x21, // Public action code
+    0x02, // Dialog token
+    0x01, // Follow up dialog token
+    0x66, 0x55, 0x44, 0x33, 0x22, 0x11, // TOD
+    0x77, 0x66, 0x55, 0x44, 0x33, 0x22, // TOA
+    0x03, 0x00, // TOD error
+    0x04, 0x00, // TOA error
+];
+roundtrip_test!(
+    test_ftm_body_rw,
+    FTMBody,
+    EXPECTED_FTM_BODY,
+    EXPECTED_FTM_BODY_BYTES
+);
+
+#[test]
+fn test_ftm_frame_matching() {
+    const MANAGEMENT_HEADER_BYTES: &[u8] = &[
+        0xd0, 0x00, // FCF
+        0x00, 0x00, // Duration
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // Address 1
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x66, // Address 2
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x66, // BSSID
+        0x00, 0x00, // Sequence control
+    ];
+    let bytes = [MANAGEMENT_HEADER_BYTES, EXPECTED_FTM_BODY_BYTES].concat();
+    match_frames! {
+        bytes.as_slice(),
+        _ = FTMRequestFrame => {
+            false
+        }
+        ftm_frame = FTMFrame => {
+            assert_eq!(ftm_frame.body, EXPECTED_FTM_BODY);
+            true
+        }
+    }
+    .map(|matched_ftm| assert!(matched_ftm, "FTM frame was matched as FTM request."))
+    .expect("Failed to match FTM frame.");
+}
+#[test]
+fn test_raw_vendor_action_payload_if_matches() {
+    let bytes = include_bytes!("../../bins/frames/awdl_action.bin");
+    let raw_vendor_action_frame = bytes.pread::<RawVendorSpecificActionFrame>(0).unwrap();
+    let payload = raw_vendor_action_frame
+        .payload_if_matches([0x00, 0x17, 0xf2], 0x08)
+        .expect("Payload didn't match the AWDL OUI and type.");
+    assert_eq!(payload, &raw_vendor_action_frame.payload[1..]);
+    assert!(raw_vendor_action_frame
+        .payload_if_matches([0x00, 0x17, 0xf2], 0x00)
+        .is_none());
+    assert!(raw_vendor_action_frame
+        .payload_if_matches([0x00, 0x00, 0x00], 0x08)
+        .is_none());
+}
+
+const EXPECTED_BLOCK_ACK_PARAMETERS: BlockAckParameterSet = BlockAckParameterSet::new()
+    .with_amsdu_supported(true)
+    .with_immediate_block_ack(true)
+    .with_tid(0)
+    .with_buffer_size(64);
+const EXPECTED_ADDBA_REQUEST_BODY: AddBARequestBody = AddBARequestBody {
+    dialog_token: 1,
+    parameters: EXPECTED_BLOCK_ACK_PARAMETERS,
+    timeout: 0,
+    starting_sequence_control: SequenceControl::new().with_sequence_number(16),
+    elements: ReadElements { bytes: &[] },
+    _phantom: PhantomData,
+};
+const EXPECTED_ADDBA_REQUEST_BODY_BYTES: &[u8] = &[
+    0x03, // Category code
+    0x00, // Block Ack action code
+    0x01, // Dialog token
+    0x03, 0x10, // Block Ack parameter set
+    0x00, 0x00, // Timeout
+    0x00, 0x01, // Starting sequence control
+];
+roundtrip_test!(
+    test_addba_request_body_rw,
+    AddBARequestBody,
+    EXPECTED_ADDBA_REQUEST_BODY,
+    EXPECTED_ADDBA_REQUEST_BODY_BYTES
+);
+
+const EXPECTED_ADDBA_RESPONSE_BODY: AddBAResponseBody = AddBAResponseBody {
+    dialog_token: 1,
+    status_code: IEEE80211StatusCode::Success,
+    parameters: EXPECTED_BLOCK_ACK_PARAMETERS,
+    timeout: 0,
+    elements: ReadElements { bytes: &[] },
+    _phantom: PhantomData,
+};
+const EXPECTED_ADDBA_RESPONSE_BODY_BYTES: &[u8] = &[
+    0x03, // Category code
+    0x01, // Block Ack action code
+    0x01, // Dialog token
+    0x00, 0x00, // Status code
+    0x03, 0x10, // Block Ack parameter set
+    0x00, 0x00, // Timeout
+];
+roundtrip_test!(
+    test_addba_response_body_rw,
+    AddBAResponseBody,
+    EXPECTED_ADDBA_RESPONSE_BODY,
+    EXPECTED_ADDBA_RESPONSE_BODY_BYTES
+);
+
+const EXPECTED_DELBA_BODY: DelBABody = DelBABody {
+    parameters: DelBAParameterSet::new().with_initiator(true).with_tid(0),
+    reason: IEEE80211Reason::Unspecified,
+    elements: ReadElements { bytes: &[] },
+    _phantom: PhantomData,
+};
+const EXPECTED_DELBA_BODY_BYTES: &[u8] = &[
+    0x03, // Category code
+    0x02, // Block Ack action code
+    0x00, 0x08, // DELBA parameter set
+    0x01, 0x00, // Reason code
+];
+roundtrip_test!(
+    test_delba_body_rw,
+    DelBABody,
+    EXPECTED_DELBA_BODY,
+    EXPECTED_DELBA_BODY_BYTES
+);
+
+#[test]
+fn test_addba_frame_matching() {
+    const MANAGEMENT_HEADER_BYTES: &[u8] = &[
+        0xd0, 0x00, // FCF
+        0x00, 0x00, // Duration
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // Address 1
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x66, // Address 2
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x66, // BSSID
+        0x00, 0x00, // Sequence control
+    ];
+    let bytes = [MANAGEMENT_HEADER_BYTES, EXPECTED_ADDBA_REQUEST_BODY_BYTES].concat();
+    match_frames! {
+        bytes.as_slice(),
+        _ = AddBAResponseFrame => {
+            None
+        }
+        addba_request_frame = AddBARequestFrame => {
+            Some(addba_request_frame.parameters.buffer_size())
+        }
+    }
+    .map(|buffer_size| assert_eq!(buffer_size, Some(64)))
+    .expect("Failed to match ADDBA request frame.");
+}
+
+const EXPECTED_CSA_BODY: ChannelSwitchAnnouncementBody = ChannelSwitchAnnouncementBody {
+    channel_switch_announcement: ChannelSwitchAnnouncementElement {
+        channel_switch_mode: 1,
+        new_channel_number: 36,
+        channel_switch_count: 5,
+    },
+    elements: ReadElements {
+        bytes: &[0x3e, 0x01, 0x01],
+    },
+    _phantom: PhantomData,
+};
+const EXPECTED_CSA_BODY_BYTES: &[u8] = &[
+    0x00, // Category code
+    0x04, // Spectrum Management action code
+    0x25, 0x03, 0x01, 0x24, 0x05, // Channel Switch Announcement element
+    0x3e, 0x01, 0x01, // Secondary Channel Offset element
+];
+roundtrip_test!(
+    test_csa_body_rw,
+    ChannelSwitchAnnouncementBody,
+    EXPECTED_CSA_BODY,
+    EXPECTED_CSA_BODY_BYTES
+);
+#[test]
+fn test_csa_body_misc() {
+    assert!(EXPECTED_CSA_BODY
+        .channel_switch_announcement
+        .transmissions_restricted());
+    // The first element isn't a Channel Switch Announcement element.
+    assert!([0x00, 0x04, 0x3e, 0x01, 0x01]
+        .pread::<ChannelSwitchAnnouncementBody>(0)
+        .is_err());
+}
+
+const MEASUREMENT_REPORT_BODY_BYTES: &[u8] = &[
+    0x00, // Category code
+    0x01, // Spectrum Management action code
+    0x07, // Dialog token
+    0x27, 0x0f, // Measurement Report element header
+    0x02, // Measurement token
+    0x00, // Measurement report mode
+    0x00, // Measurement type
+    0x34, // Channel number
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Measurement start time
+    0x64, 0x00, // Measurement duration
+    0x08, // Map
+];
+#[test]
+fn test_radar_triggered_channel_switch() {
+    const MANAGEMENT_HEADER_BYTES: &[u8] = &[
+        0xd0, 0x00, // FCF
+        0x00, 0x00, // Duration
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // Address 1
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x66, // Address 2
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x66, // BSSID
+        0x00, 0x00, // Sequence control
+    ];
+    let bytes = [MANAGEMENT_HEADER_BYTES, MEASUREMENT_REPORT_BODY_BYTES].concat();
+    let radar_detected = match_frames! {
+        bytes.as_slice(),
+        _ = ChannelSwitchAnnouncementFrame => {
+            None
+        }
+        measurement_report_frame = MeasurementReportFrame => {
+            let measurement_report = measurement_report_frame.measurement_reports().next().unwrap();
+            assert_eq!(measurement_report.measurement_token, 2);
+            assert_eq!(measurement_report.measurement_report_mode, MeasurementReportMode::new());
+            assert_eq!(measurement_report.measurement_type, MeasurementType::Basic);
+            measurement_report.radar_detected()
+        }
+    }
+    .expect("Failed to match measurement report frame.");
+    assert_eq!(radar_detected, Some(true));
+
+    let bytes = [MANAGEMENT_HEADER_BYTES, EXPECTED_CSA_BODY_BYTES].concat();
+    let new_channel_number = match_frames! {
+        bytes.as_slice(),
+        _ = MeasurementReportFrame => {
+            None
+        }
+        csa_frame = ChannelSwitchAnnouncementFrame => {
+            Some(csa_frame.channel_switch_announcement.new_channel_number)
+        }
+    }
+    .expect("Failed to match channel switch announcement frame.");
+    assert_eq!(new_channel_number, Some(36));
+
+    let measurement_report_body = MEASUREMENT_REPORT_BODY_BYTES
+        .pread::<MeasurementReportBody>(0)
+        .unwrap();
+    assert_eq!(measurement_report_body.dialog_token, 7);
+}
+
+const EXPECTED_SA_QUERY_REQUEST_BODY: SAQueryRequestBody = SAQueryRequestBody {
+    transaction_identifier: 0x1337,
+    elements: ReadElements { bytes: &[] },
+    _phantom: PhantomData,
+};
+const EXPECTED_SA_QUERY_REQUEST_BODY_BYTES: &[u8] = &[
+    0x08, // Category code
+    0x00, // SA Query action code
+    0x37, 0x13, // Transaction identifier
+];
+roundtrip_test!(
+    test_sa_query_request_body_rw,
+    SAQueryRequestBody,
+    EXPECTED_SA_QUERY_REQUEST_BODY,
+    EXPECTED_SA_QUERY_REQUEST_BODY_BYTES
+);
+
+const EXPECTED_SA_QUERY_RESPONSE_BODY: SAQueryResponseBody = SAQueryResponseBody {
+    transaction_identifier: 0x1337,
+    elements: ReadElements { bytes: &[] },
+    _phantom: PhantomData,
+};
+const EXPECTED_SA_QUERY_RESPONSE_BODY_BYTES: &[u8] = &[
+    0x08, // Category code
+    0x01, // SA Query action code
+    0x37, 0x13, // Transaction identifier
+];
+roundtrip_test!(
+    test_sa_query_response_body_rw,
+    SAQueryResponseBody,
+    EXPECTED_SA_QUERY_RESPONSE_BODY,
+    EXPECTED_SA_QUERY_RESPONSE_BODY_BYTES
+);
+
+#[test]
+fn test_sa_query_frame_matching() {
+    const MANAGEMENT_HEADER_BYTES: &[u8] = &[
+        0xd0, 0x00, // FCF
+        0x00, 0x00, // Duration
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // Address 1
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x66, // Address 2
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x66, // BSSID
+        0x00, 0x00, // Sequence control
+    ];
+    let bytes = [
+        MANAGEMENT_HEADER_BYTES,
+        EXPECTED_SA_QUERY_REQUEST_BODY_BYTES,
+    ]
+    .concat();
+    let request = match_frames! {
+        bytes.as_slice(),
+        _ = SAQueryResponseFrame => {
+            None
+        }
+        sa_query_request = SAQueryRequestFrame => {
+            Some(sa_query_request.body)
+        }
+    }
+    .expect("Failed to match SA Query request frame.")
+    .expect("SA Query request was matched as a response.");
+    assert_eq!(request, EXPECTED_SA_QUERY_REQUEST_BODY);
+
+    let response = request.response();
+    assert_eq!(response, EXPECTED_SA_QUERY_RESPONSE_BODY);
+    assert!(response.answers(&request));
+    assert!(!SAQueryResponseBody {
+        transaction_identifier: 0x4242,
+        ..response
+    }
+    .answers(&request));
+
+    let bytes = [
+        MANAGEMENT_HEADER_BYTES,
+        EXPECTED_SA_QUERY_RESPONSE_BODY_BYTES,
+    ]
+    .concat();
+    assert!(bytes.pread::<SAQueryRequestFrame>(0).is_err());
+    assert_eq!(
+        bytes.pread::<SAQueryResponseFrame>(0).unwrap().body,
+        EXPECTED_SA_QUERY_RESPONSE_BODY
+    );
+}
+
+const EXPECTED_BTM_REQUEST_BODY: BSSTransitionManagementRequestBody =
+    BSSTransitionManagementRequestBody {
+        dialog_token: 0x01,
+        request_mode: BSSTransitionManagementRequestMode::new()
+            .with_preferred_candidate_list_included(true)
+            .with_disassociation_imminent(true)
+            .with_bss_termination_included(true),
+        disassociation_timer: 0x0064,
+        validity_interval: 0xff,
+        bss_termination_duration: Some(BSSTerminationDuration {
+            bss_termination_tsf: 0x1337,
+            duration: 5,
+        }),
+        session_information_url: None,
+        candidate_list: ReadElements {
+            bytes: &[
+                0x34, 0x0d, // Neighbor Report element header
+                0x00, 0x11, 0x22, 0x33, 0x44, 0x77, // BSSID
+                0x03, 0x00, 0x00, 0x00, // BSSID information
+                0x51, // Operating class
+                0x06, // Channel number
+                0x07, // PHY type
+            ],
+        },
+        _phantom: PhantomData,
+    };
+const EXPECTED_BTM_REQUEST_BODY_BYTES: &[u8] = &[
+    0x0a, // Category code
+    0x07, // WNM action code
+    0x01, // Dialog token
+    0x0d, // Request mode
+    0x64, 0x00, // Disassociation timer
+    0xff, // Validity interval
+    0x04, 0x0a, // BSS Termination Duration subelement header
+    0x37, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // BSS termination TSF
+    0x05, 0x00, // Duration
+    0x34, 0x0d, // Neighbor Report element header
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x77, // BSSID
+    0x03, 0x00, 0x00, 0x00, // BSSID information
+    0x51, // Operating class
+    0x06, // Channel number
+    0x07, // PHY type
+];
+roundtrip_test!(
+    test_btm_request_body_rw,
+    BSSTransitionManagementRequestBody,
+    EXPECTED_BTM_REQUEST_BODY,
+    EXPECTED_BTM_REQUEST_BODY_BYTES
+);
+
+#[test]
+fn test_btm_frame_matching() {
+    const MANAGEMENT_HEADER_BYTES: &[u8] = &[
+        0xd0, 0x00, // FCF
+        0x00, 0x00, // Duration
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // Address 1
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x66, // Address 2
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x66, // BSSID
+        0x00, 0x00, // Sequence control
+    ];
+    let bytes = [MANAGEMENT_HEADER_BYTES, EXPECTED_BTM_REQUEST_BODY_BYTES].concat();
+    let request = match_frames! {
+        bytes.as_slice(),
+        _ = BSSTransitionManagementQueryFrame => {
+            None
+        }
+        _ = BSSTransitionManagementResponseFrame => {
+            None
+        }
+        btm_request = BSSTransitionManagementRequestFrame => {
+            Some(btm_request.body)
+        }
+    }
+    .expect("Failed to match BTM request frame.")
+    .expect("BTM request was matched as a different WNM frame.");
+    assert_eq!(request, EXPECTED_BTM_REQUEST_BODY);
+    assert!(request.request_mode.disassociation_imminent());
+
+    let mut candidates = request.candidates();
+    let candidate = candidates.next().expect("No candidate was present.");
+    assert_eq!(
+        candidate.bssid,
+        MACAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x77])
+    );
+    assert_eq!(
+        candidate.bssid_information,
+        BSSIDInformation::new().with_ap_reachability(APReachability::Reachable)
+    );
+    assert_eq!(candidate.channel_number, 6);
+    assert!(candidates.next().is_none());
+}
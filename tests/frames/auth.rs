@@ -0,0 +1,208 @@
+use core::marker::PhantomData;
+
+use ieee80211::{
+    common::{
+        FCFFlags, IEEE80211AuthenticationAlgorithmNumber, IEEE80211StatusCode, SequenceControl,
+    },
+    elements::ReadElements,
+    match_frames,
+    mgmt_frame::{
+        body::{AuthenticationBody, SAECommit, SAEConfirm, SAEGroup, SAEMessage},
+        AuthenticationFrame, ManagementFrameHeader,
+    },
+};
+use mac_parser::MACAddress;
+use scroll::{Pread, Pwrite};
+
+const SCALAR: [u8; 32] = [0x11; 32];
+const FINITE_FIELD_ELEMENT: [u8; 64] = [0x22; 64];
+const ANTI_CLOGGING_TOKEN: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+const CONFIRM: [u8; 32] = [0x33; 32];
+
+fn sae_commit_bytes(anti_clogging_token: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![0x13, 0x00];
+    bytes.extend_from_slice(anti_clogging_token);
+    bytes.extend_from_slice(&SCALAR);
+    bytes.extend_from_slice(&FINITE_FIELD_ELEMENT);
+    bytes
+}
+fn test_sae_message_rw(
+    bytes: &[u8],
+    authentication_transaction_sequence_number: u16,
+    status_code: IEEE80211StatusCode,
+    expected: SAEMessage<'_>,
+) {
+    let sae_message = bytes
+        .pread_with::<SAEMessage>(0, (authentication_transaction_sequence_number, status_code))
+        .unwrap();
+    assert_eq!(sae_message, expected);
+
+    let mut buf = vec![0x00; bytes.len()];
+    assert_eq!(buf.pwrite(sae_message, 0).unwrap(), bytes.len());
+    assert_eq!(buf, bytes);
+}
+
+#[test]
+fn test_sae_commit() {
+    test_sae_message_rw(
+        &sae_commit_bytes(&[]),
+        1,
+        IEEE80211StatusCode::Success,
+        SAEMessage::Commit(SAECommit {
+            group: SAEGroup::ECP256,
+            anti_clogging_token: None,
+            scalar: &SCALAR,
+            finite_field_element: &FINITE_FIELD_ELEMENT,
+            elements: ReadElements { bytes: &[] },
+        }),
+    );
+    test_sae_message_rw(
+        &sae_commit_bytes(&ANTI_CLOGGING_TOKEN),
+        1,
+        IEEE80211StatusCode::Success,
+        SAEMessage::Commit(SAECommit {
+            group: SAEGroup::ECP256,
+            anti_clogging_token: Some(&ANTI_CLOGGING_TOKEN),
+            scalar: &SCALAR,
+            finite_field_element: &FINITE_FIELD_ELEMENT,
+            elements: ReadElements { bytes: &[] },
+        }),
+    );
+    test_sae_message_rw(
+        &[0x13, 0x00, 0xde, 0xad, 0xbe, 0xef],
+        1,
+        IEEE80211StatusCode::AntiCloggingTokenRequired,
+        SAEMessage::AntiCloggingTokenRequest {
+            group: SAEGroup::ECP256,
+            anti_clogging_token: &ANTI_CLOGGING_TOKEN,
+        },
+    );
+
+    // The commit is one byte short.
+    let bytes = sae_commit_bytes(&[]);
+    assert!(bytes[..bytes.len() - 1]
+        .pread_with::<SAEMessage>(0, (1, IEEE80211StatusCode::Success))
+        .is_err());
+    // Group 1 isn't supported.
+    let mut bytes = sae_commit_bytes(&[]);
+    bytes[0] = 0x01;
+    assert!(bytes
+        .pread_with::<SAEMessage>(0, (1, IEEE80211StatusCode::Success))
+        .is_err());
+}
+#[test]
+fn test_sae_h2e_commit() {
+    // An H2E commit for group 19, where the element is the generator of P-256.
+    const SCALAR: [u8; 32] = [
+        0x8a, 0x51, 0x3b, 0x6e, 0x0f, 0x9c, 0x24, 0xd7, 0x51, 0x0e, 0xa3, 0x7c, 0x2b, 0x19, 0xf4,
+        0x68, 0xc5, 0x3d, 0x90, 0x12, 0x7e, 0xab, 0x46, 0x0c, 0xf1, 0x28, 0x93, 0x5d, 0x6a, 0xe7,
+        0x04, 0xbf,
+    ];
+    const FINITE_FIELD_ELEMENT: [u8; 64] = [
+        0x6b, 0x17, 0xd1, 0xf2, 0xe1, 0x2c, 0x42, 0x47, 0xf8, 0xbc, 0xe6, 0xe5, 0x63, 0xa4, 0x40,
+        0xf2, 0x77, 0x03, 0x7d, 0x81, 0x2d, 0xeb, 0x33, 0xa0, 0xf4, 0xa1, 0x39, 0x45, 0xd8, 0x98,
+        0xc2, 0x96, 0x4f, 0xe3, 0x42, 0xe2, 0xfe, 0x1a, 0x7f, 0x9b, 0x8e, 0xe7, 0xeb, 0x4a, 0x7c,
+        0x0f, 0x9e, 0x16, 0x2b, 0xce, 0x33, 0x57, 0x6b, 0x31, 0x5e, 0xce, 0xcb, 0xb6, 0x40, 0x68,
+        0x37, 0xbf, 0x51, 0xf5,
+    ];
+    const ELEMENTS: &[u8] = &[
+        0xff, 0x03, 0x5c, 0x14, 0x00, // Rejected Groups element rejecting group 20
+        0xff, 0x05, 0x5d, 0xde, 0xad, 0xbe, 0xef, // Anti-Clogging Token Container element
+    ];
+    let mut bytes = vec![0x13, 0x00];
+    bytes.extend_from_slice(&SCALAR);
+    bytes.extend_from_slice(&FINITE_FIELD_ELEMENT);
+    bytes.extend_from_slice(ELEMENTS);
+
+    test_sae_message_rw(
+        &bytes,
+        1,
+        IEEE80211StatusCode::SaeHashToElement,
+        SAEMessage::Commit(SAECommit {
+            group: SAEGroup::ECP256,
+            anti_clogging_token: None,
+            scalar: &SCALAR,
+            finite_field_element: &FINITE_FIELD_ELEMENT,
+            elements: ReadElements { bytes: ELEMENTS },
+        }),
+    );
+    let Ok(SAEMessage::Commit(commit)) =
+        bytes.pread_with::<SAEMessage>(0, (1, IEEE80211StatusCode::SaeHashToElement))
+    else {
+        panic!("Failed to parse H2E commit.");
+    };
+    assert!(commit
+        .elements
+        .raw_element_iterator()
+        .map(|raw_element| (raw_element.tlv_type, raw_element.slice[0]))
+        .eq([(0xff, 0x5c), (0xff, 0x5d)]));
+}
+#[test]
+fn test_sae_confirm() {
+    let mut bytes = vec![0x01, 0x00];
+    bytes.extend_from_slice(&CONFIRM);
+    test_sae_message_rw(
+        &bytes,
+        2,
+        IEEE80211StatusCode::Success,
+        SAEMessage::Confirm(SAEConfirm {
+            send_confirm: 1,
+            confirm: &CONFIRM,
+        }),
+    );
+    assert!(bytes
+        .pread_with::<SAEMessage>(0, (3, IEEE80211StatusCode::Success))
+        .is_err());
+}
+/// The returned message must outlive the parsed frame.
+fn sae_message_of_frame(bytes: &[u8]) -> Option<SAEMessage<'_>> {
+    let authentication_frame = bytes.pread::<AuthenticationFrame>(0).ok()?;
+    authentication_frame.sae_message()?.ok()
+}
+#[test]
+fn test_sae_authentication_frame() {
+    let sae_message = SAEMessage::Commit(SAECommit {
+        group: SAEGroup::ECP256,
+        anti_clogging_token: None,
+        scalar: &SCALAR,
+        finite_field_element: &FINITE_FIELD_ELEMENT,
+        elements: ReadElements { bytes: &[] },
+    });
+    let authentication_frame = AuthenticationFrame {
+        header: ManagementFrameHeader {
+            fcf_flags: FCFFlags::new(),
+            duration: 0,
+            receiver_address: MACAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+            transmitter_address: MACAddress::new([0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb]),
+            bssid: MACAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+            sequence_control: SequenceControl::new(),
+            ht_control: None,
+        },
+        body: AuthenticationBody {
+            authentication_algorithm_number:
+                IEEE80211AuthenticationAlgorithmNumber::SimultaneousAuthenticationOfEquals,
+            authentication_transaction_sequence_number: 1,
+            status_code: IEEE80211StatusCode::Success,
+            elements: sae_message,
+            _phantom: PhantomData,
+        },
+    };
+    let mut buf = [0x00; 128];
+    let written = buf.pwrite_with(authentication_frame, 0, false).unwrap();
+
+    match_frames! {
+        &buf[..written],
+        authentication_frame = AuthenticationFrame => {
+            assert_eq!(authentication_frame.sae_message().unwrap().unwrap(), sae_message);
+        }
+    }
+    .unwrap();
+    assert_eq!(sae_message_of_frame(&buf[..written]), Some(sae_message));
+
+    // The SAE message isn't parsed for open system authentication.
+    let authentication_body = AuthenticationBody {
+        authentication_algorithm_number: IEEE80211AuthenticationAlgorithmNumber::OpenSystem,
+        ..buf[24..written].pread::<AuthenticationBody>(0).unwrap()
+    };
+    assert!(authentication_body.sae_message().is_none());
+}
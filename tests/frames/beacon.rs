@@ -0,0 +1,256 @@
+use core::time::Duration;
+
+use ieee80211::{
+    common::{BeaconInterval, FrameType, ManagementFrameSubtype, SequenceControl, TU},
+    mgmt_frame::{
+        body::{BeaconBody, TransitionMode},
+        BeaconFrame, ManagementFrameHeader, ManagementFrameHeaderBuilder,
+    },
+};
+use mac_parser::{MACAddress, BROADCAST};
+use scroll::{Pread, Pwrite};
+
+const BEACON_BYTES: &[u8] = include_bytes!("../../bins/frames/beacon.bin");
+
+#[test]
+fn test_beacon_dtim_period() {
+    let beacon = BEACON_BYTES.pread::<BeaconFrame>(0).unwrap();
+    assert_eq!(beacon.dtim_period(), Some(1), "DTIM period didn't match.");
+}
+#[test]
+fn test_beacon_content_eq() {
+    let beacon = BEACON_BYTES.pread::<BeaconFrame>(0).unwrap();
+    let mut other = beacon;
+    other.body.timestamp += 102_400;
+    assert_ne!(beacon.body, other.body);
+    assert!(beacon.body.content_eq(&other.body));
+
+    other.body.beacon_interval += 1;
+    assert!(!beacon.body.content_eq(&other.body));
+}
+#[test]
+fn test_beacon_max_ampdu_length() {
+    let beacon = BEACON_BYTES.pread::<BeaconFrame>(0).unwrap();
+    // The VHT Capabilities element takes precedence over the HT Capabilities element.
+    // Since the VHT exponent is at it's maximum, the HE exponent extension of three is added.
+    assert_eq!(beacon.max_ampdu_length(), Some(8_388_607));
+}
+#[test]
+fn test_beacon_subtype() {
+    let beacon = BEACON_BYTES.pread::<BeaconFrame>(0).unwrap();
+    assert_eq!(beacon.subtype(), ManagementFrameSubtype::Beacon);
+    assert_eq!(
+        beacon.frame_type(),
+        FrameType::Management(ManagementFrameSubtype::Beacon)
+    );
+}
+#[test]
+fn test_beacon_time_to_next_dtim() {
+    let beacon = BEACON_BYTES.pread::<BeaconFrame>(0).unwrap();
+    // The DTIM count in the beacon is zero, so this beacon is a DTIM beacon.
+    assert_eq!(beacon.time_to_next_dtim(), Some(Duration::ZERO));
+}
+#[test]
+fn test_beacon_interval() {
+    let beacon = BEACON_BYTES.pread::<BeaconFrame>(0).unwrap();
+    assert_eq!(beacon.beacon_interval(), 100);
+    assert_eq!(
+        beacon.beacon_interval_as_duration(),
+        Duration::from_micros(102_400)
+    );
+    assert_eq!(
+        beacon.timestamp_as_duration(),
+        Duration::from_micros(beacon.timestamp)
+    );
+}
+#[test]
+fn test_beacon_interval_newtype() {
+    let beacon_interval = BeaconInterval::from(100);
+    assert_eq!(beacon_interval.tu(), 100);
+    assert_eq!(
+        beacon_interval.as_duration(),
+        Duration::from_micros(102_400)
+    );
+    assert_eq!(
+        Duration::from(beacon_interval),
+        Duration::from_micros(102_400)
+    );
+    assert_eq!(BeaconInterval(1).as_duration(), TU);
+    assert_eq!(BeaconInterval(0).as_duration(), Duration::ZERO);
+}
+#[test]
+fn test_beacon_transition_mode() {
+    const FIXED_FIELDS: [u8; 12] = [0x00; 12];
+    const RSN_ELEMENT_HEADER: [u8; 2] = [0x30, 0x18];
+    const RSN_ELEMENT_BODY: [u8; 22] = [
+        0x01, 0x00, // Version
+        0x00, 0x0f, 0xac, 0x04, // Group data cipher suite
+        0x01, 0x00, 0x00, 0x0f, 0xac, 0x04, // Pairwise cipher suites
+        0x02, 0x00, 0x00, 0x0f, 0xac, 0x02, 0x00, 0x0f, 0xac, 0x08, // PSK and SAE
+    ];
+    let transition_mode = |rsn_capabilities: [u8; 2]| {
+        let bytes = [
+            FIXED_FIELDS.as_slice(),
+            RSN_ELEMENT_HEADER.as_slice(),
+            RSN_ELEMENT_BODY.as_slice(),
+            rsn_capabilities.as_slice(),
+        ]
+        .concat();
+        bytes.pread::<BeaconBody>(0).unwrap().transition_mode()
+    };
+    // MFP capable.
+    assert_eq!(
+        transition_mode([0x80, 0x00]),
+        Some(TransitionMode::Wpa2Wpa3)
+    );
+    // MFP capable and required.
+    assert_eq!(transition_mode([0xc0, 0x00]), None);
+
+    const OWE_TRANSITION_MODE_ELEMENT: [u8; 17] = [
+        0xdd, 0x0f, 0x50, 0x6f, 0x9a, 0x1c, // Header
+        0x00, 0x80, 0x41, 0x13, 0x37, 0x43, // BSSID
+        0x04, b'T', b'e', b's', b't', // SSID
+    ];
+    let bytes = [
+        FIXED_FIELDS.as_slice(),
+        OWE_TRANSITION_MODE_ELEMENT.as_slice(),
+    ]
+    .concat();
+    assert_eq!(
+        bytes.pread::<BeaconBody>(0).unwrap().transition_mode(),
+        Some(TransitionMode::Owe)
+    );
+
+    let beacon = BEACON_BYTES.pread::<BeaconFrame>(0).unwrap();
+    assert_eq!(beacon.transition_mode(), None);
+}
+#[cfg(feature = "alloc")]
+#[test]
+fn test_beacon_edit_elements() {
+    use ieee80211::elements::{
+        tim::TIMElement, BSSLoadElement, DSSSParameterSetElement, Element, SSIDElement,
+    };
+
+    let beacon = BEACON_BYTES.pread::<BeaconFrame>(0).unwrap();
+    let edited_frame = beacon
+        .edit_elements(|editor| {
+            editor.remove(TIMElement::<&[u8]>::ELEMENT_ID);
+            editor.replace(SSIDElement::new("Edited").unwrap()).unwrap();
+            editor
+                .replace(DSSSParameterSetElement {
+                    current_channel: 11,
+                })
+                .unwrap();
+            editor
+                .add(BSSLoadElement {
+                    station_count: 1,
+                    channel_utilization: 0,
+                    available_admission_capacity: 0,
+                })
+                .unwrap();
+        })
+        .unwrap();
+    let edited = edited_frame.frame::<BeaconBody>().unwrap();
+    assert_eq!(
+        edited_frame.as_bytes().pread::<BeaconFrame>(0).unwrap(),
+        edited
+    );
+
+    assert_eq!(edited.header, beacon.header);
+    assert_eq!(edited.body.timestamp, beacon.body.timestamp);
+    assert_eq!(edited.ssid(), Some("Edited"));
+    assert!(edited.elements.get_first_element::<TIMElement>().is_none());
+    assert_eq!(
+        edited
+            .elements
+            .get_first_element::<DSSSParameterSetElement>()
+            .map(|dsss| dsss.current_channel),
+        Some(11)
+    );
+    assert!(edited
+        .elements
+        .get_first_element::<BSSLoadElement>()
+        .is_some());
+}
+#[test]
+fn test_beacon_builder() {
+    use ieee80211::{
+        element_chain,
+        elements::DSSSParameterSetElement,
+        mac_parser::{MACAddress, BROADCAST},
+        mgmt_frame::builder::BeaconBuilder,
+        ssid, supported_rates,
+    };
+    use scroll::{ctx::MeasureWith, Pwrite};
+
+    const AP_MAC_ADDRESS: MACAddress = MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x01]);
+
+    let beacon = BeaconBuilder::new()
+        .bssid(AP_MAC_ADDRESS)
+        .ssid(ssid!("OpenRF"))
+        .elements(element_chain! {
+            supported_rates![1 B, 2 B],
+            DSSSParameterSetElement {
+                current_channel: 6
+            }
+        })
+        .timestamp(1337)
+        .build();
+    let mut buf = vec![0x00; beacon.measure_with(&false)];
+    buf.pwrite(beacon, 0).unwrap();
+
+    let beacon = buf.pread::<BeaconFrame>(0).unwrap();
+    assert_eq!(beacon.header.receiver_address, BROADCAST);
+    assert_eq!(beacon.header.transmitter_address, AP_MAC_ADDRESS);
+    assert_eq!(beacon.header.bssid, AP_MAC_ADDRESS);
+    assert_eq!(beacon.timestamp, 1337);
+    assert_eq!(beacon.beacon_interval, 100);
+    assert_eq!(beacon.ssid(), Some("OpenRF"));
+    assert_eq!(beacon.elements.primary_channel(), Some(6));
+
+    // Without any further elements, only the SSID is present.
+    let beacon = BeaconBuilder::new()
+        .ssid(ssid!("OpenRF"))
+        .bssid(AP_MAC_ADDRESS)
+        .build();
+    let mut buf = vec![0x00; beacon.measure_with(&false)];
+    buf.pwrite(beacon, 0).unwrap();
+    let beacon = buf.pread::<BeaconFrame>(0).unwrap();
+    assert_eq!(
+        beacon.elements.bytes,
+        [0x00, 0x06, b'O', b'p', b'e', b'n', b'R', b'F']
+    );
+}
+#[test]
+fn test_beacon_header_builder() {
+    let bssid = MACAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+    let header = ManagementFrameHeaderBuilder::new()
+        .bssid(bssid)
+        .source_address(bssid)
+        .destination_address(BROADCAST)
+        .sequence_control(SequenceControl::new().with_sequence_number(0x123))
+        .build();
+    assert_eq!(
+        header,
+        ManagementFrameHeader {
+            receiver_address: BROADCAST,
+            transmitter_address: bssid,
+            bssid,
+            sequence_control: SequenceControl::new().with_sequence_number(0x123),
+            ..Default::default()
+        }
+    );
+
+    let mut buf = [0x00; 22];
+    assert_eq!(buf.pwrite(header, 0).unwrap(), 22);
+    assert_eq!(
+        buf,
+        [
+            0x00, 0x00, // Duration
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // DA
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // SA
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // BSSID
+            0x30, 0x12, // Sequence control
+        ]
+    );
+}
@@ -0,0 +1,70 @@
+use ieee80211::{
+    common::{AssociationID, ControlFrameSubtype, FCFFlags, SequenceControl},
+    control_frame::{BlockAckControl, BlockAckType, ControlFrame},
+};
+use mac_parser::MACAddress;
+use scroll::{Pread, Pwrite};
+
+const COMPRESSED_BLOCK_ACK_BYTES: &[u8] = &[
+    0x00, 0x00, // Duration
+    0x00, 0x20, 0x91, 0x13, 0x37, 0x01, // Receiver Address
+    0x00, 0x20, 0x91, 0x13, 0x37, 0x02, // Transmitter Address
+    0x04, 0x50, // BA Control
+    0xf0, 0xff, // Starting Sequence Control
+    0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, // Bitmap
+];
+const COMPRESSED_BLOCK_ACK: ControlFrame<'static> = ControlFrame::BlockAck {
+    fcf_flags: FCFFlags::new(),
+    duration: 0,
+    receiver_address: MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x01]),
+    transmitter_address: MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x02]),
+    ba_control: BlockAckControl::new()
+        .with_block_ack_type(BlockAckType::Compressed)
+        .with_tid_info(5),
+    starting_sequence_control: SequenceControl::new().with_sequence_number(4095),
+    bitmap: &[0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80],
+};
+
+#[test]
+fn test_compressed_block_ack() {
+    let read = COMPRESSED_BLOCK_ACK_BYTES
+        .pread_with::<ControlFrame>(0, (ControlFrameSubtype::BlockAck, FCFFlags::new()))
+        .unwrap();
+    assert_eq!(read, COMPRESSED_BLOCK_ACK);
+
+    let mut buf = [0x00; COMPRESSED_BLOCK_ACK_BYTES.len()];
+    let written = buf.pwrite(COMPRESSED_BLOCK_ACK, 0).unwrap();
+    assert_eq!(&buf[..written], COMPRESSED_BLOCK_ACK_BYTES);
+
+    // The sequence numbers wrap around after 4095.
+    assert!(COMPRESSED_BLOCK_ACK
+        .acknowledged_sequence_numbers()
+        .unwrap()
+        .eq([4095, 1, 62]));
+}
+
+const PS_POLL_BYTES: &[u8] = &[
+    0x01, 0xc0, // AID
+    0x00, 0x20, 0x91, 0x13, 0x37, 0x01, // BSSID
+    0x00, 0x20, 0x91, 0x13, 0x37, 0x02, // Transmitter Address
+];
+
+#[test]
+fn test_ps_poll() {
+    let read = PS_POLL_BYTES
+        .pread_with::<ControlFrame>(0, (ControlFrameSubtype::PSPoll, FCFFlags::new()))
+        .unwrap();
+    assert_eq!(read.association_id(), AssociationID::new_checked(1));
+    assert_eq!(
+        read.receiver_address(),
+        MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x01])
+    );
+    assert_eq!(
+        read.transmitter_address(),
+        Some(MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x02]))
+    );
+
+    let mut buf = [0x00; PS_POLL_BYTES.len()];
+    let written = buf.pwrite(read, 0).unwrap();
+    assert_eq!(&buf[..written], PS_POLL_BYTES);
+}
@@ -1,7 +1,15 @@
-use ieee80211::data_frame::{builder::DataFrameBuilder, DataFrame, DataFrameReadPayload};
+use ieee80211::data_frame::{
+    amsdu::{AMSDUBuilder, AMSDUPayload, AMSDUSubframe, AMSDUSubframeIterator},
+    builder::DataFrameBuilder,
+    header::{AckPolicy, DataFrameHeader, FrameDirection, QoSControl},
+    mesh::{MeshAddressExtension, MeshControl, MeshControlFlags},
+    DataFrame, DataFrameReadPayload,
+};
 use mac_parser::MACAddress;
 use scroll::{ctx::MeasureWith, Pread, Pwrite};
 
+use crate::roundtrip_test;
+
 const OUR_MAC_ADDRESS: MACAddress = MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x00]);
 const AP_MAC_ADDRESS: MACAddress = MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x01]);
 
@@ -31,3 +39,339 @@ fn test_data_frame_rw() {
     buf.pwrite(EXPECTED_DATA_FRAME, 0).unwrap();
     assert_eq!(buf, EXPECTED_BYTES);
 }
+
+#[test]
+fn test_data_frame_builder_qos_control() {
+    let data_frame = DataFrameBuilder::new()
+        .to_ds()
+        .category_qos()
+        .payload::<&[u8]>(&[0x13, 0x37])
+        .qos_control(5, AckPolicy::NoAck, false)
+        .destination_address(AP_MAC_ADDRESS)
+        .source_address(OUR_MAC_ADDRESS)
+        .bssid(AP_MAC_ADDRESS)
+        .build();
+    assert_eq!(data_frame.header.qos, Some([0x25, 0x00]));
+
+    let mut buf = vec![0x00u8; data_frame.measure_with(&false)];
+    buf.pwrite(data_frame, 0).unwrap();
+    let read = buf.pread_with::<DataFrame>(0, false).unwrap();
+    assert_eq!(read.header, data_frame.header);
+}
+#[test]
+fn test_data_frame_builder_tid() {
+    let data_frame = DataFrameBuilder::new()
+        .to_ds()
+        .category_qos()
+        .payload::<&[u8]>(&[0x13, 0x37])
+        .qos_control(5, AckPolicy::BlockAck, true)
+        .tid(0x1a)
+        .destination_address(AP_MAC_ADDRESS)
+        .source_address(OUR_MAC_ADDRESS)
+        .bssid(AP_MAC_ADDRESS)
+        .build();
+    let qos_control = data_frame.header.qos_control().unwrap();
+    assert_eq!(qos_control.tid(), 0x0a);
+    assert_eq!(qos_control.ack_policy(), AckPolicy::BlockAck);
+    assert!(qos_control.amsdu_present());
+}
+#[test]
+fn test_qos_control() {
+    let qos_control = QoSControl::from_bits(0x0000);
+    assert_eq!(qos_control.tid(), 0);
+    assert!(!qos_control.eosp());
+    assert_eq!(qos_control.ack_policy(), AckPolicy::NormalAck);
+    assert!(!qos_control.amsdu_present());
+    assert_eq!(qos_control.txop_duration_requested(), Some(0));
+    assert_eq!(qos_control.queue_size(), None);
+
+    let qos_control = QoSControl::from_bits(u16::from_le_bytes([0xb7, 0x42]));
+    assert_eq!(qos_control.tid(), 7);
+    assert!(qos_control.eosp());
+    assert_eq!(qos_control.ack_policy(), AckPolicy::NoAck);
+    assert!(qos_control.amsdu_present());
+    assert_eq!(qos_control.txop_duration_requested(), None);
+    assert_eq!(qos_control.queue_size(), Some(0x42));
+    assert!(!qos_control.mesh_control_present());
+
+    let qos_control = QoSControl::from_bits(u16::from_le_bytes([0x43, 0x01]));
+    assert_eq!(qos_control.tid(), 3);
+    assert!(!qos_control.eosp());
+    assert_eq!(qos_control.ack_policy(), AckPolicy::NoExplicitAck);
+    assert!(!qos_control.amsdu_present());
+    assert_eq!(qos_control.txop_duration_requested(), Some(0x01));
+    assert!(qos_control.mesh_control_present());
+
+    let header = DataFrameHeader {
+        qos: Some([0xb7, 0x42]),
+        ..Default::default()
+    };
+    assert_eq!(header.qos_control(), Some(QoSControl::from_bits(0x42b7)));
+    assert_eq!(DataFrameHeader::default().qos_control(), None);
+}
+#[test]
+fn test_data_frame_builder_qos_default() {
+    let data_frame = DataFrameBuilder::new()
+        .to_ds()
+        .category_qos()
+        .payload::<&[u8]>(&[0x13, 0x37])
+        .destination_address(AP_MAC_ADDRESS)
+        .source_address(OUR_MAC_ADDRESS)
+        .bssid(AP_MAC_ADDRESS)
+        .build();
+    assert_eq!(data_frame.header.qos, Some([0x00; 2]));
+
+    let mut buf = vec![0x00u8; data_frame.measure_with(&false)];
+    buf.pwrite(data_frame, 0).unwrap();
+    let read = buf.pread_with::<DataFrame>(0, false).unwrap();
+    assert_eq!(read.header, data_frame.header);
+    let Some(DataFrameReadPayload::Single(payload)) = read.payload else {
+        panic!("Data frame payload wasn't single.");
+    };
+    assert_eq!(payload, [0x13, 0x37]);
+
+    assert_eq!(EXPECTED_DATA_FRAME.header.qos, None);
+}
+#[test]
+fn test_data_frame_builder_roundtrip() {
+    let data_frame = DataFrameBuilder::new()
+        .neither_to_nor_from_ds()
+        .category_data()
+        .payload::<&[u8]>(&[0x13, 0x37])
+        .destination_address(AP_MAC_ADDRESS)
+        .source_address(OUR_MAC_ADDRESS)
+        .bssid(AP_MAC_ADDRESS)
+        .build();
+    let mut buf = vec![0x00u8; data_frame.measure_with(&false)];
+    buf.pwrite(data_frame, 0).unwrap();
+    let read = buf.pread_with::<DataFrame>(0, false).unwrap();
+    assert_eq!(read.header, data_frame.header);
+    assert_eq!(read.header.qos, None);
+    let Some(DataFrameReadPayload::Single(payload)) = read.payload else {
+        panic!("Data frame payload wasn't single.");
+    };
+    assert_eq!(payload, [0x13, 0x37]);
+
+    let data_frame = DataFrameBuilder::new()
+        .to_and_from_ds()
+        .category_qos()
+        .payload_amsdu(AMSDU_SUBFRAMES)
+        .receiver_address(AP_MAC_ADDRESS)
+        .transmitter_address(OUR_MAC_ADDRESS)
+        .bssid(AP_MAC_ADDRESS)
+        .build();
+    let mut buf = vec![0x00u8; data_frame.measure_with(&false)];
+    buf.pwrite(data_frame, 0).unwrap();
+    let read = buf.pread_with::<DataFrame>(0, false).unwrap();
+    assert_eq!(read.header, data_frame.header);
+    let qos_control = read.header.qos_control().unwrap();
+    assert!(qos_control.amsdu_present());
+    let Some(DataFrameReadPayload::AMSDU(sub_frames)) = read.payload else {
+        panic!("Data frame payload wasn't an A-MSDU.");
+    };
+    assert!(sub_frames.eq(AMSDU_SUBFRAMES));
+}
+
+const EXPECTED_MESH_CONTROL: MeshControl = MeshControl {
+    flags: MeshControlFlags::new(),
+    mesh_ttl: 31,
+    mesh_sequence_number: 0x1337,
+    mesh_address_extension: MeshAddressExtension::Addresses5And6 {
+        address_5: AP_MAC_ADDRESS,
+        address_6: OUR_MAC_ADDRESS,
+    },
+};
+const EXPECTED_MESH_CONTROL_BYTES: &[u8] = &[
+    0x02, 0x1f, 0x37, 0x13, 0x00, 0x00, 0x00, 0x20, 0x91, 0x13, 0x37, 0x01, 0x00, 0x20, 0x91, 0x13,
+    0x37, 0x00,
+];
+roundtrip_test!(
+    test_mesh_control_rw,
+    MeshControl,
+    EXPECTED_MESH_CONTROL,
+    EXPECTED_MESH_CONTROL_BYTES
+);
+
+#[test]
+fn test_data_frame_mesh_control() {
+    let mut data_frame = DataFrameBuilder::new()
+        .to_and_from_ds()
+        .category_qos()
+        .payload(EXPECTED_MESH_CONTROL_BYTES)
+        .receiver_address(AP_MAC_ADDRESS)
+        .transmitter_address(OUR_MAC_ADDRESS)
+        .destination_address(AP_MAC_ADDRESS)
+        .source_address(OUR_MAC_ADDRESS)
+        .build();
+    let mut buf = vec![0x00u8; data_frame.measure_with(&false)];
+    buf.pwrite(data_frame, 0).unwrap();
+    assert_eq!(
+        buf.pread_with::<DataFrame>(0, false)
+            .unwrap()
+            .mesh_control(),
+        None
+    );
+
+    // Set the Mesh Control Present bit.
+    data_frame.header.qos = Some([0x00, 0x01]);
+    buf.pwrite(data_frame, 0).unwrap();
+    assert_eq!(
+        buf.pread_with::<DataFrame>(0, false)
+            .unwrap()
+            .mesh_control(),
+        Some(EXPECTED_MESH_CONTROL)
+    );
+}
+#[test]
+fn test_data_frame_direction() {
+    let mut header = EXPECTED_DATA_FRAME.header;
+    assert_eq!(header.direction(), FrameDirection::ToAp);
+
+    header.fcf_flags = header.fcf_flags.with_to_ds(false).with_from_ds(true);
+    assert_eq!(header.direction(), FrameDirection::FromAp);
+
+    header.fcf_flags = header.fcf_flags.with_to_ds(false).with_from_ds(false);
+    assert_eq!(header.direction(), FrameDirection::Ibss);
+
+    header.fcf_flags = header.fcf_flags.with_to_ds(true).with_from_ds(true);
+    assert_eq!(header.direction(), FrameDirection::Wds);
+}
+
+const AMSDU_SUBFRAMES: [AMSDUSubframe<&[u8]>; 2] = [
+    AMSDUSubframe {
+        destination_address: AP_MAC_ADDRESS,
+        source_address: OUR_MAC_ADDRESS,
+        payload: &[0x13, 0x37, 0x42],
+    },
+    AMSDUSubframe {
+        destination_address: OUR_MAC_ADDRESS,
+        source_address: AP_MAC_ADDRESS,
+        payload: &[0xde, 0xad, 0xbe, 0xef, 0x00],
+    },
+];
+fn amsdu_bytes() -> Vec<u8> {
+    let amsdu_payload = AMSDUPayload {
+        sub_frames: AMSDU_SUBFRAMES,
+    };
+    let mut buf = vec![0xff; 64];
+    let length = buf.pwrite(amsdu_payload, 0).unwrap();
+    assert_eq!(length, amsdu_payload.measure_with(&()));
+    assert_eq!(
+        length,
+        AMSDUPayload {
+            sub_frames: AMSDU_SUBFRAMES.as_slice()
+        }
+        .length_in_bytes()
+    );
+    // The first subframe is padded from 17 to 20 bytes, while the last one isn't padded.
+    assert_eq!(length, 20 + AMSDU_SUBFRAMES[1].length_in_bytes());
+    assert_eq!(buf[17..20], [0x00; 3]);
+    buf.truncate(length);
+    buf
+}
+#[test]
+fn test_amsdu_subframe_iterator() {
+    let bytes = amsdu_bytes();
+    let iterator = AMSDUSubframeIterator::from_bytes(&bytes);
+    assert!(iterator.validate());
+    assert!(iterator.eq(AMSDU_SUBFRAMES));
+    let mut sub_frames = Vec::new();
+    iterator
+        .try_for_each_subframe(|sub_frame| sub_frames.push(sub_frame))
+        .unwrap();
+    assert_eq!(sub_frames, AMSDU_SUBFRAMES);
+}
+#[test]
+fn test_amsdu_builder() {
+    let amsdu = AMSDUBuilder::new(AMSDU_SUBFRAMES.map(|sub_frame| {
+        (
+            sub_frame.destination_address,
+            sub_frame.source_address,
+            sub_frame.payload,
+        )
+    }));
+    // The first subframe is padded from 17 to 20 bytes, while the last one isn't padded.
+    let length = amsdu.measure_with(&());
+    assert_eq!(length, 20 + AMSDU_SUBFRAMES[1].length_in_bytes());
+
+    let mut buf = vec![0xff; 64];
+    assert_eq!(buf.pwrite(amsdu, 0).unwrap(), length);
+    buf.truncate(length);
+    assert_eq!(buf[17..20], [0x00; 3]);
+    assert_eq!(buf, amsdu_bytes());
+
+    let iterator = AMSDUSubframeIterator::from_bytes(&buf);
+    assert!(iterator.validate());
+    assert!(iterator.eq(AMSDU_SUBFRAMES));
+}
+#[test]
+fn test_amsdu_oversized_msdu() {
+    let msdu = vec![0x00; u16::MAX as usize + 1];
+    let mut buf = vec![0x00; msdu.len() + 14];
+    assert!(buf
+        .pwrite(
+            AMSDUSubframe {
+                destination_address: AP_MAC_ADDRESS,
+                source_address: OUR_MAC_ADDRESS,
+                payload: msdu.as_slice(),
+            },
+            0
+        )
+        .is_err());
+    assert!(buf
+        .pwrite(
+            AMSDUBuilder::new([(AP_MAC_ADDRESS, OUR_MAC_ADDRESS, msdu.as_slice())]),
+            0
+        )
+        .is_err());
+}
+#[test]
+fn test_amsdu_subframe_iterator_malformed() {
+    // Truncated
+    let bytes = amsdu_bytes();
+    let iterator = AMSDUSubframeIterator::from_bytes(&bytes[..bytes.len() - 1]);
+    assert!(!iterator.validate());
+    assert_eq!(iterator.count(), 1);
+    let mut count = 0;
+    assert!(iterator.try_for_each_subframe(|_| count += 1).is_err());
+    assert_eq!(count, 1);
+
+    // Oversized length field
+    let mut bytes = amsdu_bytes();
+    bytes[12..14].copy_from_slice(&u16::MAX.to_le_bytes());
+    let iterator = AMSDUSubframeIterator::from_bytes(&bytes);
+    assert!(!iterator.validate());
+    assert_eq!(iterator.count(), 0);
+
+    // Only part of a subframe header
+    let iterator = AMSDUSubframeIterator::from_bytes(&bytes[..13]);
+    assert!(!iterator.validate());
+    assert_eq!(iterator.count(), 0);
+
+    assert!(AMSDUSubframeIterator::from_bytes(&[]).validate());
+}
+#[test]
+fn test_amsdu_subframe_iterator_fuzz() {
+    let mut state = 0x1337_4242_dead_beefu64;
+    let mut buf = [0x00u8; 64];
+    for _ in 0..20_000 {
+        buf.iter_mut().for_each(|byte| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            *byte = state as u8;
+        });
+        // Keep the length fields small, so that valid chains are generated as well.
+        buf[12] &= 0x1f;
+        buf[13] = 0x00;
+        let bytes = &buf[..(state as usize % buf.len())];
+
+        let iterator = AMSDUSubframeIterator::from_bytes(bytes);
+        let mut count = 0;
+        let valid = iterator.try_for_each_subframe(|_| count += 1).is_ok();
+        assert_eq!(valid, iterator.validate());
+        if valid {
+            assert_eq!(iterator.count(), count);
+        }
+    }
+}
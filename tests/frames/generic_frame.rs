@@ -1,8 +1,10 @@
 use ieee80211::{
     common::{ControlFrameSubtype, FrameControlField, FrameType, SequenceControl},
-    GenericFrame,
+    data_frame::DataFrame,
+    match_frames, FrameIterator, GenericFrame,
 };
 use mac_parser::{MACAddress, BROADCAST};
+use scroll::Pread;
 
 const ACK_FRAME_BYTES: &[u8] = &[0xd4, 0x00, 0x37, 0x13, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
 
@@ -66,3 +68,305 @@ fn test_gf_beacon() {
         "Sequence control didn't match."
     );
 }
+
+const CTS_FRAME_BYTES: &[u8] = &[0xc4, 0x00, 0x37, 0x13, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+
+/// This test checks, that the shortest valid frames are accepted and that accessors for fields,
+/// which aren't present, return `None` instead of panicking.
+#[test]
+fn test_gf_minimal_length() {
+    let generic_frame = GenericFrame::new(CTS_FRAME_BYTES, false)
+        .expect("Creating a GenericFrame for a CTS failed, even though it's valid.");
+    assert_eq!(
+        generic_frame.frame_control_field().frame_type(),
+        FrameType::Control(ControlFrameSubtype::CTS),
+        "Frame type wasn't CTS."
+    );
+    assert!(generic_frame.address_2().is_none());
+    assert!(generic_frame.address_3().is_none());
+    assert!(generic_frame.sequence_control().is_none());
+
+    assert!(
+        GenericFrame::new(&CTS_FRAME_BYTES[..9], false).is_err(),
+        "Creating a GenericFrame from a slice shorter than ten bytes succeeded."
+    );
+    assert!(
+        GenericFrame::new(&CTS_FRAME_BYTES[..3], true).is_err(),
+        "Creating a GenericFrame from a slice shorter than the FCS succeeded."
+    );
+
+    // A beacon truncated after the second address.
+    let generic_frame = GenericFrame::new(&BEACON_FRAME_BYTES[..16], false).unwrap();
+    assert!(generic_frame.address_2().is_some());
+    assert!(generic_frame.address_3().is_none());
+    assert!(generic_frame.sequence_control().is_none());
+}
+
+const RTS_FRAME_BYTES: &[u8] = &[
+    0xb4, 0x00, 0x37, 0x13, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55,
+];
+
+#[test]
+fn test_gf_transmitter_address() {
+    assert_eq!(
+        GenericFrame::new(RTS_FRAME_BYTES, false)
+            .unwrap()
+            .transmitter_address(),
+        Some(MACAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55])),
+        "Transmitter address of RTS didn't match."
+    );
+    assert_eq!(
+        GenericFrame::new(BEACON_FRAME_BYTES, false)
+            .unwrap()
+            .transmitter_address(),
+        Some(MACAddress::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06])),
+        "Transmitter address of beacon didn't match."
+    );
+    assert!(GenericFrame::new(CTS_FRAME_BYTES, false)
+        .unwrap()
+        .transmitter_address()
+        .is_none());
+    assert!(GenericFrame::new(ACK_FRAME_BYTES, false)
+        .unwrap()
+        .transmitter_address()
+        .is_none());
+}
+#[test]
+fn test_gf_write_with_fcs() {
+    let mut buf = [0x00u8; BEACON_FRAME_BYTES.len() + 4];
+    let generic_frame = GenericFrame::new(BEACON_FRAME_BYTES, false).unwrap();
+    assert_eq!(generic_frame.as_bytes(), BEACON_FRAME_BYTES);
+    assert_eq!(generic_frame.write_with_fcs(&mut buf).unwrap(), buf.len());
+
+    let generic_frame =
+        GenericFrame::new(&buf, true).expect("The FCS written by write_with_fcs didn't validate.");
+    assert_eq!(generic_frame.as_bytes(), BEACON_FRAME_BYTES);
+
+    assert!(
+        generic_frame
+            .write_with_fcs(&mut [0x00u8; BEACON_FRAME_BYTES.len()])
+            .is_err(),
+        "Writing to a buffer, which is too short for the FCS succeeded."
+    );
+}
+#[test]
+fn test_gf_fcs() {
+    let mut buf = [0x00u8; BEACON_FRAME_BYTES.len() + 4];
+    GenericFrame::new(BEACON_FRAME_BYTES, false)
+        .unwrap()
+        .write_with_fcs(&mut buf)
+        .unwrap();
+    let fcs = u32::from_le_bytes(buf[BEACON_FRAME_BYTES.len()..].try_into().unwrap());
+
+    assert!(GenericFrame::fcs_valid(&buf));
+    assert_eq!(GenericFrame::new(&buf, true).unwrap().fcs(), Some(fcs));
+    assert_eq!(
+        GenericFrame::new(BEACON_FRAME_BYTES, false).unwrap().fcs(),
+        None
+    );
+    let (generic_frame, read_fcs) = GenericFrame::new_keep_fcs(&buf).unwrap();
+    assert_eq!(read_fcs, fcs);
+    assert_eq!(generic_frame.fcs(), Some(fcs));
+    assert_eq!(generic_frame.as_bytes(), BEACON_FRAME_BYTES);
+    // The FCS isn't taken into account for equality.
+    assert_eq!(
+        generic_frame,
+        GenericFrame::new(BEACON_FRAME_BYTES, false).unwrap()
+    );
+
+    // Corrupt the payload, so that the FCS no longer matches.
+    buf[30] ^= 0xff;
+    assert!(!GenericFrame::fcs_valid(&buf));
+    assert!(GenericFrame::new(&buf, true).is_err());
+    let (generic_frame, read_fcs) = GenericFrame::new_keep_fcs(&buf)
+        .expect("Creating a GenericFrame without validating the FCS failed.");
+    assert_eq!(read_fcs, fcs);
+    assert_eq!(
+        generic_frame.address_1(),
+        MACAddress::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06])
+    );
+
+    assert!(!GenericFrame::fcs_valid(&[0x00; 3]));
+}
+/// A four address data frame, which ends in the middle of the fourth address.
+const TRUNCATED_FOUR_ADDRESS_DATA_FRAME_BYTES: &[u8] = &[
+    0x08, 0x03, // FCF with ToDS and FromDS set
+    0x00, 0x00, // Duration
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Address 1
+    0x11, 0x12, 0x13, 0x14, 0x15, 0x16, // Address 2
+    0x21, 0x22, 0x23, 0x24, 0x25, 0x26, // Address 3
+    0x00, 0x00, // Sequence control
+    0x31, 0x32, 0x33, // Truncated address 4
+];
+#[test]
+fn test_gf_truncated_four_address_data_frame() {
+    let generic_frame = GenericFrame::new(TRUNCATED_FOUR_ADDRESS_DATA_FRAME_BYTES, false).unwrap();
+    assert!(generic_frame.address_3().is_some());
+    assert!(generic_frame.sequence_control().is_some());
+    assert!(
+        generic_frame.address_4().is_none(),
+        "Fourth address of truncated frame wasn't None."
+    );
+    assert!(matches!(
+        generic_frame.parse_to_typed::<DataFrame>(),
+        Some(Err(_))
+    ));
+
+    // Truncating the frame at every possible length must never panic.
+    for length in 10..TRUNCATED_FOUR_ADDRESS_DATA_FRAME_BYTES.len() {
+        let generic_frame =
+            GenericFrame::new(&TRUNCATED_FOUR_ADDRESS_DATA_FRAME_BYTES[..length], false).unwrap();
+        let _ = generic_frame.address_2();
+        let _ = generic_frame.address_3();
+        let _ = generic_frame.sequence_control();
+        assert!(generic_frame.address_4().is_none());
+        assert!(matches!(
+            generic_frame.parse_to_typed::<DataFrame>(),
+            Some(Err(_))
+        ));
+    }
+}
+const ADDRESS_1: MACAddress = MACAddress::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+const ADDRESS_2: MACAddress = MACAddress::new([0x11, 0x12, 0x13, 0x14, 0x15, 0x16]);
+const ADDRESS_3: MACAddress = MACAddress::new([0x21, 0x22, 0x23, 0x24, 0x25, 0x26]);
+const ADDRESS_4: MACAddress = MACAddress::new([0x31, 0x32, 0x33, 0x34, 0x35, 0x36]);
+
+/// Check the address helpers for all ToDS and FromDS combinations against the mapping of [DataFrameHeader](ieee80211::data_frame::header::DataFrameHeader).
+#[test]
+fn test_gf_address_helpers() {
+    let mut bytes = [0x00u8; 30];
+    bytes[0] = 0x08;
+    bytes[4..10].copy_from_slice(ADDRESS_1.as_slice());
+    bytes[10..16].copy_from_slice(ADDRESS_2.as_slice());
+    bytes[16..22].copy_from_slice(ADDRESS_3.as_slice());
+    bytes[24..30].copy_from_slice(ADDRESS_4.as_slice());
+
+    // (ToDS, FromDS, destination, source, BSSID, fourth address)
+    let cases = [
+        (false, false, ADDRESS_1, Some(ADDRESS_2), ADDRESS_3, None),
+        (true, false, ADDRESS_3, Some(ADDRESS_2), ADDRESS_1, None),
+        (false, true, ADDRESS_1, Some(ADDRESS_3), ADDRESS_2, None),
+        (
+            true,
+            true,
+            ADDRESS_3,
+            Some(ADDRESS_4),
+            ADDRESS_2,
+            Some(ADDRESS_4),
+        ),
+    ];
+    for (to_ds, from_ds, destination, source, bssid, address_4) in cases {
+        bytes[1] = to_ds as u8 | ((from_ds as u8) << 1);
+        let generic_frame = GenericFrame::new(&bytes, false).unwrap();
+        assert_eq!(generic_frame.destination_address(), Some(destination));
+        assert_eq!(generic_frame.source_address(), source);
+        assert_eq!(generic_frame.bssid(), Some(bssid));
+        assert_eq!(generic_frame.address_4(), address_4);
+
+        let header = bytes.pread::<DataFrame>(0).unwrap().header;
+        assert_eq!(
+            generic_frame.destination_address(),
+            header.destination_address().copied()
+        );
+        assert_eq!(
+            generic_frame.source_address(),
+            header.source_address().copied()
+        );
+        assert_eq!(generic_frame.bssid(), header.bssid().copied());
+    }
+
+    // For management frames, the addresses are fixed.
+    let generic_frame = GenericFrame::new(BEACON_FRAME_BYTES, false).unwrap();
+    assert_eq!(generic_frame.destination_address(), Some(ADDRESS_1));
+    assert_eq!(
+        generic_frame.source_address(),
+        Some(MACAddress::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06]))
+    );
+    assert_eq!(generic_frame.bssid(), Some(BROADCAST));
+    // Control frames don't have these addresses.
+    let generic_frame = GenericFrame::new(RTS_FRAME_BYTES, false).unwrap();
+    assert!(generic_frame.destination_address().is_none());
+    assert!(generic_frame.source_address().is_none());
+    assert!(generic_frame.bssid().is_none());
+}
+#[test]
+fn test_gf_qos_control() {
+    // QoS data frame with TID 5 and the A-MSDU Present bit set.
+    let mut bytes = [0x00u8; 26];
+    bytes[0] = 0x88;
+    bytes[24] = 0x85;
+    let generic_frame = GenericFrame::new(&bytes, false).unwrap();
+    let qos_control = generic_frame.qos_control().unwrap();
+    assert_eq!(qos_control.tid(), 5);
+    assert!(qos_control.amsdu_present());
+    // With ToDS and FromDS set, the QoS control field follows the fourth address, which is truncated here.
+    bytes[1] = 0b11;
+    assert!(GenericFrame::new(&bytes, false)
+        .unwrap()
+        .qos_control()
+        .is_none());
+
+    // Non-QoS data frames don't have a QoS control field.
+    bytes[0] = 0x08;
+    bytes[1] = 0x00;
+    assert!(GenericFrame::new(&bytes, false)
+        .unwrap()
+        .qos_control()
+        .is_none());
+}
+#[test]
+fn test_frame_iterator() {
+    let bytes = [RTS_FRAME_BYTES, CTS_FRAME_BYTES, ACK_FRAME_BYTES].concat();
+    let expected_subtypes = [
+        ControlFrameSubtype::RTS,
+        ControlFrameSubtype::CTS,
+        ControlFrameSubtype::Ack,
+    ];
+    let frame_types = |generic_frame: Result<GenericFrame<'_>, scroll::Error>| {
+        generic_frame.unwrap().frame_control_field().frame_type()
+    };
+
+    assert!(FrameIterator::best_effort(&bytes, false)
+        .map(frame_types)
+        .eq(expected_subtypes.map(FrameType::Control)));
+
+    let mut lengths = [16, 10, 10].into_iter();
+    assert!(FrameIterator::new(&bytes, false, |_| lengths.next())
+        .map(frame_types)
+        .eq(expected_subtypes.map(FrameType::Control)));
+
+    // A truncated last frame is reported as an error.
+    let mut frame_iterator = FrameIterator::best_effort(&bytes[..bytes.len() - 1], false);
+    assert!(frame_iterator.by_ref().take(2).all(|frame| frame.is_ok()));
+    assert!(frame_iterator.next().unwrap().is_err());
+    assert!(frame_iterator.next().is_none());
+
+    // The length of management frames can't be determined.
+    assert!(FrameIterator::best_effort(BEACON_FRAME_BYTES, false)
+        .next()
+        .is_none());
+}
+#[test]
+fn test_match_frames_generic_frame_arm() {
+    let frame_type = match_frames! {
+        ACK_FRAME_BYTES,
+        _data_frame = DataFrame => {
+            None
+        }
+        generic_frame = GenericFrame => {
+            Some(generic_frame.frame_control_field().frame_type())
+        }
+    }
+    .unwrap();
+    assert_eq!(
+        frame_type,
+        Some(FrameType::Control(ControlFrameSubtype::Ack)),
+        "The GenericFrame arm wasn't matched."
+    );
+
+    assert!(match_frames! {
+        ACK_FRAME_BYTES,
+        _data_frame = DataFrame => {}
+    }
+    .is_err());
+}
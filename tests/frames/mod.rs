@@ -1,3 +1,7 @@
 mod action;
+mod auth;
+mod beacon;
+mod control_frame;
 mod data_frame;
 mod generic_frame;
+mod probe;
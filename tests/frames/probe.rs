@@ -0,0 +1,109 @@
+use ieee80211::{
+    common::CapabilitiesInformation,
+    elements::{
+        ht::HTCapabilitiesElement, rates::ExtendedSupportedRatesElement, rsn::RSNElement,
+        vht::VHTCapabilitiesElement, DSSSParameterSetElement,
+    },
+    mgmt_frame::{
+        body::{BssConfig, ProbeRequestBody, ProbeRequestBuilder, ProbeResponseBody},
+        ProbeRequestFrame,
+    },
+    rate, ssid, supported_rates,
+};
+use mac_parser::{MACAddress, BROADCAST};
+use scroll::{ctx::MeasureWith, Pread, Pwrite};
+
+const EXPECTED_RESPONSE_BYTES: &[u8] = &[
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Timestamp
+    0x64, 0x00, // Beacon interval
+    0x01, 0x00, // Capabilities
+    0x00, 0x04, b'T', b'e', b's', b't', // SSID
+    0x01, 0x02, 0x82, 0x84, // Supported rates
+    0x03, 0x01, 0x06, // DSSS Parameter Set
+];
+
+#[test]
+fn test_probe_response_respond_to() {
+    let config = BssConfig {
+        ssid: ssid!("Test"),
+        channel: 6,
+        beacon_interval: 100,
+        capabilities_info: CapabilitiesInformation::new().with_is_ess(true),
+        supported_rates: supported_rates![1 B, 2 B],
+        rsn: None::<RSNElement>,
+    };
+
+    let directed_request = [0x00, 0x04, b'T', b'e', b's', b't'];
+    let directed_request = directed_request.pread::<ProbeRequestBody>(0).unwrap();
+    let response = ProbeResponseBody::respond_to(&directed_request, &config).unwrap();
+    let mut buf = [0x00; EXPECTED_RESPONSE_BYTES.len()];
+    let written = buf.pwrite(response, 0).unwrap();
+    assert_eq!(&buf[..written], EXPECTED_RESPONSE_BYTES);
+
+    let wildcard_request = [0x00, 0x00];
+    let wildcard_request = wildcard_request.pread::<ProbeRequestBody>(0).unwrap();
+    assert!(ProbeResponseBody::respond_to(&wildcard_request, &config).is_some());
+
+    let other_request = [0x00, 0x05, b'O', b't', b'h', b'e', b'r'];
+    let other_request = other_request.pread::<ProbeRequestBody>(0).unwrap();
+    assert!(ProbeResponseBody::respond_to(&other_request, &config).is_none());
+}
+
+const OUR_MAC_ADDRESS: MACAddress = MACAddress::new([0x00, 0x20, 0x91, 0x13, 0x37, 0x00]);
+
+#[test]
+fn test_probe_request_builder_element_order() {
+    let rates = [
+        rate!(1 B),
+        rate!(2 B),
+        rate!(5.5 B),
+        rate!(11 B),
+        rate!(6),
+        rate!(9),
+        rate!(12),
+        rate!(18),
+        rate!(24),
+        rate!(36),
+    ];
+    let probe_request = ProbeRequestBuilder::new(OUR_MAC_ADDRESS, rates)
+        .ssid(ssid!("Test"))
+        .vht_capabilities(VHTCapabilitiesElement::default())
+        .ht_capabilities(HTCapabilitiesElement::default())
+        .channel(6)
+        .build();
+    assert_eq!(probe_request.header.receiver_address, BROADCAST);
+    assert_eq!(probe_request.header.bssid, BROADCAST);
+    assert_eq!(probe_request.header.transmitter_address, OUR_MAC_ADDRESS);
+
+    let mut buf = vec![0x00; probe_request.measure_with(&false)];
+    buf.pwrite(probe_request, 0).unwrap();
+    let read = buf.pread::<ProbeRequestFrame>(0).unwrap();
+    assert_eq!(read.ssid(), Some("Test"));
+    let element_ids = read
+        .elements
+        .raw_element_iterator()
+        .map(|element| element.tlv_type)
+        .collect::<Vec<_>>();
+    assert_eq!(element_ids, [0, 1, 50, 3, 45, 191]);
+    assert!(read
+        .elements
+        .get_first_element::<ExtendedSupportedRatesElement>()
+        .unwrap()
+        .supported_rates
+        .eq([rate!(24), rate!(36)]));
+    assert_eq!(
+        read.elements.get_first_element::<DSSSParameterSetElement>(),
+        Some(DSSSParameterSetElement { current_channel: 6 })
+    );
+
+    // Without the optional elements, only the SSID and Supported Rates elements are present.
+    let probe_request = ProbeRequestBuilder::new(OUR_MAC_ADDRESS, [rate!(1 B), rate!(2 B)])
+        .bssid(OUR_MAC_ADDRESS)
+        .build();
+    assert_eq!(probe_request.header.receiver_address, OUR_MAC_ADDRESS);
+    let mut buf = vec![0x00; probe_request.measure_with(&false)];
+    buf.pwrite(probe_request, 0).unwrap();
+    let read = buf.pread::<ProbeRequestFrame>(0).unwrap();
+    assert_eq!(read.ssid(), Some(""));
+    assert_eq!(read.elements.bytes, [0x00, 0x00, 0x01, 0x02, 0x82, 0x84]);
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "radiotap")]
+
+use ieee80211::{
+    common::{DataFrameSubtype, FrameType, ManagementFrameSubtype},
+    radiotap::{RadiotapChannel, RadiotapHeader},
+    GenericFrame,
+};
+use scroll::Pread;
+
+const BEACON_BYTES: &[u8] = include_bytes!("../bins/frames/beacon.bin");
+/// A radiotap header in the format emitted by mac80211 in monitor mode.
+const RADIOTAP_HEADER_BYTES: [u8; 18] = [
+    0x00, 0x00, // Version and padding
+    0x12, 0x00, // Length
+    0x2e, 0x48, 0x00, 0x00, // Present flags
+    0x00, // Flags
+    0x02, // Rate
+    0x6c, 0x09, 0xa0, 0x00, // Channel
+    0xc5, // Antenna signal
+    0x01, // Antenna
+    0x00, 0x00, // RX flags
+];
+
+#[test]
+fn test_radiotap_header() {
+    let radiotap_header = RADIOTAP_HEADER_BYTES.pread::<RadiotapHeader>(0).unwrap();
+    assert_eq!(radiotap_header.mpdu_offset(), 18);
+    assert!(!radiotap_header.fcs_present());
+    assert_eq!(radiotap_header.rate_in_kbps(), Some(1000));
+    assert_eq!(
+        radiotap_header.channel,
+        Some(RadiotapChannel {
+            frequency: 2412,
+            flags: 0xa0
+        })
+    );
+    assert_eq!(radiotap_header.antenna_signal, Some(-59));
+    assert_eq!(radiotap_header.antenna_noise, None);
+    assert_eq!(radiotap_header.tsft, None);
+
+    // The header is longer than the slice.
+    assert!(RADIOTAP_HEADER_BYTES[..17]
+        .pread::<RadiotapHeader>(0)
+        .is_err());
+}
+#[test]
+fn test_generic_frame_from_radiotap() {
+    let mut bytes = RADIOTAP_HEADER_BYTES.to_vec();
+    bytes.extend_from_slice(BEACON_BYTES);
+    let (radiotap_header, generic_frame) = GenericFrame::from_radiotap(&bytes).unwrap();
+    assert!(!radiotap_header.fcs_present());
+    assert_eq!(generic_frame.as_bytes(), BEACON_BYTES);
+    assert_eq!(
+        generic_frame.frame_control_field().frame_type(),
+        FrameType::Management(ManagementFrameSubtype::Beacon)
+    );
+
+    // Set the FCS at end flag and append the FCS.
+    let mut bytes = RADIOTAP_HEADER_BYTES.to_vec();
+    bytes[8] = 0x10;
+    bytes.resize(RADIOTAP_HEADER_BYTES.len() + BEACON_BYTES.len() + 4, 0x00);
+    generic_frame
+        .write_with_fcs(&mut bytes[RADIOTAP_HEADER_BYTES.len()..])
+        .unwrap();
+    let (radiotap_header, generic_frame) = GenericFrame::from_radiotap(&bytes).unwrap();
+    assert!(radiotap_header.fcs_present());
+    assert_eq!(generic_frame.as_bytes(), BEACON_BYTES);
+    assert!(generic_frame.fcs().is_some());
+
+    // A corrupted FCS is rejected.
+    *bytes.last_mut().unwrap() ^= 0xff;
+    assert!(GenericFrame::from_radiotap(&bytes).is_err());
+}
+/// A QoS data frame with a 26 byte MAC header, followed by an LLC/SNAP header for EAPOL.
+///
+/// This was constructed by hand and isn't a capture.
+const QOS_DATA_FRAME_BYTES: [u8; 34] = [
+    0x88, 0x01, // Frame control
+    0x00, 0x00, // Duration
+    0x00, 0x20, 0x91, 0x13, 0x37, 0x01, // Address 1
+    0x00, 0x20, 0x91, 0x13, 0x37, 0x00, // Address 2
+    0x00, 0x20, 0x91, 0x13, 0x37, 0x01, // Address 3
+    0x10, 0x00, // Sequence control
+    0x06, 0x00, // QoS control
+    0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00, 0x88, 0x8e, // LLC/SNAP
+];
+#[test]
+fn test_generic_frame_from_radiotap_data_pad() {
+    // Set the FCS at end and data pad flags.
+    let mut radiotap_header_bytes = RADIOTAP_HEADER_BYTES;
+    radiotap_header_bytes[8] = 0x30;
+    let mut mpdu = [0x00; QOS_DATA_FRAME_BYTES.len() + 4];
+    GenericFrame::new(&QOS_DATA_FRAME_BYTES, false)
+        .unwrap()
+        .write_with_fcs(&mut mpdu)
+        .unwrap();
+    // The FCS is calculated over the frame without the padding, which follows the MAC header.
+    let mut bytes = radiotap_header_bytes.to_vec();
+    bytes.extend_from_slice(&mpdu[..26]);
+    bytes.extend_from_slice(&[0x00; 2]);
+    bytes.extend_from_slice(&mpdu[26..]);
+
+    let radiotap_header = bytes.pread::<RadiotapHeader>(0).unwrap();
+    assert_eq!(radiotap_header.data_padding(26), 2);
+    assert_eq!(radiotap_header.data_padding(24), 0);
+    assert_eq!(
+        RADIOTAP_HEADER_BYTES
+            .pread::<RadiotapHeader>(0)
+            .unwrap()
+            .data_padding(26),
+        0
+    );
+
+    // The padding can't be removed from an immutable slice.
+    assert!(GenericFrame::from_radiotap(&bytes).is_err());
+    let (radiotap_header, generic_frame) = GenericFrame::from_radiotap_mut(&mut bytes).unwrap();
+    assert!(radiotap_header.fcs_present());
+    assert_eq!(generic_frame.as_bytes(), QOS_DATA_FRAME_BYTES);
+    assert_eq!(
+        generic_frame.frame_control_field().frame_type(),
+        FrameType::Data(DataFrameSubtype::QoSData)
+    );
+    assert_eq!(generic_frame.qos_control().unwrap().tid(), 6);
+
+    // Without padding, both constructors are equivalent.
+    let mut bytes = RADIOTAP_HEADER_BYTES.to_vec();
+    bytes.extend_from_slice(BEACON_BYTES);
+    let generic_frame = GenericFrame::from_radiotap(&bytes).unwrap().1;
+    assert_eq!(generic_frame.as_bytes(), BEACON_BYTES);
+    let generic_frame = GenericFrame::from_radiotap_mut(&mut bytes).unwrap().1;
+    assert_eq!(generic_frame.as_bytes(), BEACON_BYTES);
+
+    // The padding is out of bounds.
+    let mut bytes = radiotap_header_bytes.to_vec();
+    bytes.extend_from_slice(&mpdu[..27]);
+    assert!(GenericFrame::from_radiotap_mut(&mut bytes).is_err());
+}
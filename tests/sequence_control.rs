@@ -0,0 +1,27 @@
+use ieee80211::common::SequenceControl;
+
+#[test]
+fn test_sequence_control_next() {
+    let sequence_control = SequenceControl::new()
+        .with_sequence_number(42)
+        .with_fragment_number(3);
+    let next = sequence_control.next();
+    assert_eq!(next.sequence_number(), 43);
+    assert!(next.is_first_fragment());
+
+    let last = SequenceControl::new().with_sequence_number(SequenceControl::MAX_SEQUENCE_NUMBER);
+    assert_eq!(last.next().sequence_number(), 0);
+}
+#[test]
+fn test_sequence_control_next_fragment() {
+    let sequence_control = SequenceControl::new().with_sequence_number(4095);
+    assert!(sequence_control.is_first_fragment());
+
+    let fragment = sequence_control.with_next_fragment().unwrap();
+    assert_eq!(fragment.fragment_number(), 1);
+    assert_eq!(fragment.sequence_number(), 4095);
+    assert!(!fragment.is_first_fragment());
+
+    let last_fragment = sequence_control.with_fragment_number(SequenceControl::MAX_FRAGMENT_NUMBER);
+    assert_eq!(last_fragment.with_next_fragment(), None);
+}
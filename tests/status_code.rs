@@ -0,0 +1,35 @@
+use ieee80211::common::IEEE80211StatusCode;
+
+#[test]
+fn test_status_code_categories() {
+    assert!(IEEE80211StatusCode::Success.is_success());
+    assert!(IEEE80211StatusCode::SaeHashToElement.is_success());
+    assert!(!IEEE80211StatusCode::Success.is_permanent_rejection());
+
+    assert!(IEEE80211StatusCode::RefusedTemporarily.is_temporary());
+    assert!(IEEE80211StatusCode::AntiCloggingTokenRequired.is_temporary());
+    assert!(!IEEE80211StatusCode::AntiCloggingTokenRequired.is_success());
+
+    assert!(IEEE80211StatusCode::DeniedNoHtSupport.is_permanent_rejection());
+    assert!(IEEE80211StatusCode::Unknown(1337).is_permanent_rejection());
+}
+#[test]
+fn test_status_code_display() {
+    assert_eq!(IEEE80211StatusCode::Success.to_string(), "Successful");
+    assert_eq!(
+        IEEE80211StatusCode::DeniedNoHtSupport.to_string(),
+        "Association denied because the requesting STA does not support HT features"
+    );
+    assert_eq!(
+        IEEE80211StatusCode::StatusInvalidPmkid.to_string(),
+        "Invalid pairwise master key identifier (PMKID)"
+    );
+    assert_eq!(
+        IEEE80211StatusCode::GasQueryResponseToo.to_string(),
+        "GAS Response is larger than query response length limit"
+    );
+    assert_eq!(
+        IEEE80211StatusCode::Unknown(1337).to_string(),
+        "Unknown(1337)"
+    );
+}